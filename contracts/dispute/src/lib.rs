@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env,
-    IntoVal, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
 
 // Import reputation contract types for cross-contract calls
@@ -17,6 +17,16 @@ mod reputation {
         pub total_weight: u64,
         pub review_count: u32,
     }
+
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ReputationTier {
+        None,
+        Bronze,
+        Silver,
+        Gold,
+        Platinum,
+    }
 }
 
 #[contracterror]
@@ -48,6 +58,74 @@ pub enum DisputeError {
     DuplicateArbitrator = 23,
     /// Returned when a vote_choice value does not map to a defined VoteChoice variant.
     InvalidVoteChoice = 24,
+    /// No resolution record exists for the requested (dispute_id, round).
+    ResolutionNotFound = 25,
+    /// Amount must be strictly positive.
+    InvalidAmount = 26,
+    /// A payout would exceed the dispute's own pool balance for that token.
+    PoolInsufficient = 27,
+    /// Voter privacy is enabled; raw voter addresses are not exposed.
+    VotesPrivate = 28,
+    /// Caller did not cast a vote on the winning side of a resolved dispute.
+    NotEligibleForReward = 29,
+    /// Reward for this (dispute, voter, token) was already claimed.
+    RewardAlreadyClaimed = 30,
+    /// `set_parameter` was called with a name that is not a tunable parameter.
+    UnknownParameter = 31,
+    /// A parameter value is outside its allowed range.
+    ParameterOutOfBounds = 32,
+    /// Only the party that lost the most recent round may appeal it.
+    NotLosingParty = 33,
+    /// The appellant already appealed this same outcome in an earlier round.
+    OutcomeAlreadyAppealed = 34,
+    /// The dispute's latest appeal has not been resolved yet.
+    AppealInProgress = 35,
+    /// `reactivate_juror` was called by a juror who is not inactive.
+    JurorNotInactive = 36,
+    /// The initiator cannot cover the minimum stake for the disputed value.
+    InsufficientStake = 37,
+    /// Rewards unlock once the dispute's appeal window has closed.
+    RewardsLocked = 38,
+    /// `raise_bundled_dispute` was given no jobs, or the same job twice.
+    InvalidBundle = 39,
+    /// A bundled job could not be read from escrow, or is not between the
+    /// dispute's client and freelancer.
+    BundlePartyMismatch = 40,
+    /// The applicant already has a juror application awaiting review.
+    ApplicationPending = 41,
+    /// No pending juror application exists for the address.
+    ApplicationNotFound = 42,
+    /// A vote summary is longer than `MAX_VOTE_SUMMARY_LEN` bytes.
+    SummaryTooLong = 43,
+    /// `expire_dispute` was called before the dispute's maximum lifetime elapsed.
+    DisputeNotExpired = 44,
+    /// The dispute has not reached a resolution that could become final.
+    NotResolved = 45,
+    /// The appeal window of the dispute has not closed yet.
+    AppealWindowOpen = 46,
+    /// `finalize_after_appeal_window` already ran for the dispute.
+    AlreadyFinalized = 47,
+    /// The minimum voting period since the first vote has not elapsed yet.
+    MinVotingPeriodNotElapsed = 48,
+    /// The dispute is past its evidence cutoff.
+    EvidencePhaseClosed = 49,
+}
+
+/// Reward pool errors.
+///
+/// Kept apart from `DisputeError`, which is at the SDK's 50-case cap. Entry
+/// points returning `DisputeError` raise these with `panic_with_error!`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PoolError {
+    /// The token is neither allowlisted for pools nor already held by the
+    /// dispute's pool.
+    TokenNotAllowed = 100,
+    /// The dispute has not expired, so deposits are not refundable.
+    NotRefundable = 101,
+    /// The depositor has no unrefunded deposit of the token in the pool.
+    NoDeposit = 102,
 }
 
 #[contracttype]
@@ -61,10 +139,13 @@ pub enum DisputeStatus {
     RefundSplit(u32),
     SplitAward(u32),
     Escalated,
-    /// Filing was determined to be in bad faith by a 4/5 supermajority of arbitrators.
+    /// Filing was determined to be in bad faith by a supermajority of arbitrators (4/5 by default).
     MaliciousDisputeFiling,
     /// Escrow callback failed; the intended resolution is cached and can be retried.
     ResolutionFailed,
+    /// Outlived the `max_dur` lifetime without a final outcome and was closed
+    /// by `expire_dispute`.
+    Expired,
 }
 
 #[contracttype]
@@ -76,19 +157,43 @@ pub enum TieBreakMethod {
     RefundBoth,
 }
 
+/// Category a dispute is filed under, so analytics and triage can classify
+/// disputes without parsing the free-text reason.
 #[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum DisputeResolution {
-    ClientWins,
-    FreelancerWins,
-    RefundBoth,
-    RefundSplit(u32),
-    SplitAward(u32),
-    Escalate,
-    /// Dispute was filed in bad faith; initiator stake is slashed to treasury and reputation penalised.
-    MaliciousFiling,
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReasonCode {
+    /// The work was never delivered.
+    NonDelivery = 0,
+    /// The work was delivered but does not meet the agreed standard.
+    Quality = 1,
+    /// A milestone or job deadline was missed.
+    MissedDeadline = 2,
+    /// Approved or delivered work has not been paid for.
+    PaymentWithheld = 3,
+    /// The parties disagree on what the job covers.
+    ScopeDispute = 4,
+    /// One party acted fraudulently or abusively.
+    Misconduct = 5,
+    /// Anything else; see the dispute's reason text.
+    Other = 6,
 }
 
+/// Every `ReasonCode`, in code order.
+pub const REASON_CODES: [ReasonCode; 7] = [
+    ReasonCode::NonDelivery,
+    ReasonCode::Quality,
+    ReasonCode::MissedDeadline,
+    ReasonCode::PaymentWithheld,
+    ReasonCode::ScopeDispute,
+    ReasonCode::Misconduct,
+    ReasonCode::Other,
+];
+
+/// Outcome sent to escrow's `resolve_dispute_callback`; shared with escrow
+/// through `stellar-market-interface`.
+pub use stellar_market_interface::DisputeResolution;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AppealStatus {
@@ -99,6 +204,9 @@ pub enum AppealStatus {
     RefundedBoth,
     RefundSplit(u32),
     Escalated,
+    /// The appeal panel did not reach the overturn threshold; the previous
+    /// resolution stands.
+    Upheld,
 }
 
 #[contracttype]
@@ -115,6 +223,18 @@ pub struct Appeal {
     pub refund_split_sum: u64,
     pub created_at: u64,
     pub voting_deadline: u64,
+    /// 1-based appeal round; also the `ResolutionRecord` round it produces.
+    pub round: u32,
+}
+
+/// Who appealed which outcome in one appeal round of a dispute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealRound {
+    pub appeal_id: u64,
+    pub appellant: Address,
+    /// Dispute outcome the appellant contested.
+    pub appealed_outcome: DisputeStatus,
 }
 
 #[contracttype]
@@ -129,12 +249,37 @@ pub enum VoteChoice {
     SplitAward(u32, u32),
 }
 
+/// A juror's vote. The rationale itself lives off-chain; the vote keeps its
+/// hash so the published text can be checked against it, plus an optional
+/// short summary of at most `MAX_VOTE_SUMMARY_LEN` bytes.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Vote {
-    pub voter: Address,
+    /// The juror's address; `None` when voter privacy is enabled, in which
+    /// case only `voter_commitment` identifies them.
+    pub voter: Option<Address>,
+    /// `sha256(salt || dispute_id || voter)`.
+    pub voter_commitment: BytesN<32>,
     pub choice: VoteChoice,
-    pub reason: String,
+    pub reason_hash: BytesN<32>,
+    pub summary: Option<String>,
+    pub timestamp: u64,
+}
+
+/// Maximum length in bytes of a vote's on-chain summary.
+pub const MAX_VOTE_SUMMARY_LEN: u32 = 140;
+
+/// Public view of a vote. `voter_commitment` is
+/// `sha256(salt || dispute_id || voter)` and is always populated; `voter` is
+/// only filled in when voter privacy is disabled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicVote {
+    pub voter: Option<Address>,
+    pub voter_commitment: BytesN<32>,
+    pub choice: VoteChoice,
+    pub reason_hash: BytesN<32>,
+    pub summary: Option<String>,
     pub timestamp: u64,
 }
 
@@ -147,6 +292,39 @@ pub struct EvidenceRecord {
     pub ledger: u32,
 }
 
+/// A pending request to join the arbitrator pool, from `apply_as_juror`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JurorApplication {
+    pub applicant: Address,
+    /// Hash of the applicant's off-chain statement of qualifications.
+    pub statement_hash: BytesN<32>,
+    pub token: Address,
+    /// Stake held while the application is pending and for as long as the
+    /// applicant stays in the pool.
+    pub stake: i128,
+    pub applied_at: u64,
+}
+
+/// An operational duty that can be handed to a separate key with
+/// `grant_role`. The address set at `initialize` holds every role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Grants and revokes roles, pauses the contract; implies every other role.
+    Admin,
+    /// Changes tunables: `set_parameter`, `set_min_voter_reputation`,
+    /// `set_cooldown_duration`, `set_juror_stake`, `set_pool_token`.
+    ParameterManager,
+    /// Moves funds out of dispute pools with `payout_from_pool`.
+    Resolver,
+    /// Manages the arbitrator pool and reviews juror applications.
+    Moderator,
+}
+
+/// Maximum page size of the juror listing views.
+pub const MAX_JUROR_PAGE: u32 = 50;
+
 /// Maximum number of arbitrators that can be assigned to a single dispute.
 /// This limit ensures O(1) resolution complexity and prevents instruction limit exceeded errors.
 pub const MAX_ARBITRATORS: u32 = 7;
@@ -186,12 +364,14 @@ pub struct Dispute {
     pub freelancer: Address,
     pub initiator: Address,
     pub reason: String,
+    /// Category the initiator filed the dispute under.
+    pub reason_code: ReasonCode,
     pub status: DisputeStatus,
     pub votes_for_client: u32,
     pub votes_for_freelancer: u32,
     pub votes_for_refund_split: u32,
     pub refund_split_sum: u64,
-    /// Votes that the filing was malicious (bad-faith). Requires 4/5 supermajority to trigger.
+    /// Votes that the filing was malicious (bad-faith). Requires a supermajority (4/5 by default) to trigger.
     pub votes_for_malicious: u32,
     /// Votes for a proportional split award (SplitAward variant).
     pub votes_for_split_award: u32,
@@ -207,6 +387,93 @@ pub struct Dispute {
     pub tally: DisputeTally,
     /// Number of arbitrators assigned to this dispute (max 7).
     pub arbitrator_count: u32,
+    /// Discount (bps) the initiator's reputation tier earned on the stake.
+    pub stake_discount_bps: u32,
+}
+
+/// Round number of the original arbitration panel in a `ResolutionRecord`.
+pub const ROUND_ORIGINAL: u32 = 0;
+/// Round number of the first appeal panel in a `ResolutionRecord`; later
+/// appeal rounds are numbered 2, 3, ….
+pub const ROUND_APPEAL: u32 = 1;
+
+/// Auditable rationale for a dispute outcome, written once per resolution round.
+/// Captures the tallies the outcome was derived from so appeals and off-chain
+/// compliance can reconstruct why the verdict held.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionRecord {
+    pub dispute_id: u64,
+    /// `ROUND_ORIGINAL` for the first panel, `ROUND_APPEAL` onwards for appeal panels.
+    pub round: u32,
+    /// Outcome the panel reached (the intended outcome if the escrow callback failed).
+    pub status: DisputeStatus,
+    pub votes_for_client: u32,
+    pub votes_for_freelancer: u32,
+    pub votes_for_refund_split: u32,
+    pub votes_for_split_award: u32,
+    pub votes_for_malicious: u32,
+    /// Weighted tallies from `DisputeTally`; equal to the raw counts while weighting is uniform.
+    pub client_weight: u64,
+    pub freelancer_weight: u64,
+    pub total_weight_cast: u64,
+    /// True when the malicious-filing supermajority (4/5 by default) decided the outcome.
+    pub malicious: bool,
+    /// True when the vote was not decisive and `tie_break_method` decided the outcome.
+    pub tie_break_applied: bool,
+    /// Tie-break policy in force for this round.
+    pub tie_break_method: TieBreakMethod,
+    /// Address whose action triggered resolution (the final voter on auto-resolve);
+    /// `None` for permissionless `resolve_dispute` / `force_resolve_timeout` / `resolve_appeal` calls.
+    pub resolver: Option<Address>,
+    pub resolved_at: u64,
+}
+
+/// What resolving a dispute would do right now, from `preview_resolution`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionPreview {
+    /// Whether `resolve_dispute` would succeed now (the vote quorum is met).
+    /// Past the voting deadline `force_resolve_timeout` yields the same outcome.
+    pub resolvable: bool,
+    pub status: DisputeStatus,
+    pub resolution: DisputeResolution,
+    /// Party the outcome favours; `None` for splits and escalation.
+    pub winner: Option<Address>,
+    pub malicious: bool,
+    pub tie_break_applied: bool,
+    /// Escrowed value not yet released to the freelancer, summed over the
+    /// jobs of a bundled dispute; 0 if the escrow job could not be read.
+    pub amount_at_stake: i128,
+    pub to_client: i128,
+    pub to_freelancer: i128,
+    /// Slashed to the treasury on a malicious-filing verdict.
+    pub to_treasury: i128,
+}
+
+/// A milestone as it stood when a dispute was raised.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneSnapshot {
+    pub id: u32,
+    pub amount: i128,
+    pub status: escrow::MilestoneStatus,
+    pub deadline: u64,
+}
+
+/// Frozen copy of the escrow job taken when a dispute was raised, so jurors
+/// see the state the dispute is about rather than whatever it became later.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JobSnapshot {
+    pub job_id: u64,
+    pub status: escrow::JobStatus,
+    pub token: Address,
+    pub total_amount: i128,
+    pub funded_amount: i128,
+    pub job_deadline: u64,
+    pub milestones: Vec<MilestoneSnapshot>,
+    pub taken_at: u64,
 }
 
 #[contracttype]
@@ -214,10 +481,12 @@ pub struct Dispute {
 enum DataKey {
     Dispute(u64),
     DisputeCount,
-    Votes(u64),
-    Voters(u64),
+    /// The `index`-th vote cast on a dispute: (dispute_id, index) → Vote.
+    VoteAt(u64, u32),
     LastDisputeClosedAt(u64),
-    HasVoted(u64, Address),
+    /// Set once a juror has voted, keyed by their voter commitment so the
+    /// marker does not name them: (dispute_id, commitment) → bool.
+    HasVoted(u64, BytesN<32>),
     ReputationContract,
     MinVoterReputation,
     Admin,
@@ -241,14 +510,16 @@ enum DataKey {
     Arbitrators(u64),
     /// Pool of eligible arbitrators that can be randomly selected for disputes
     ArbitratorPool,
-    /// Maps dispute_id → appeal_id (one appeal per dispute).
+    /// Maps dispute_id → appeal_id of the dispute's latest appeal.
     DisputeAppeal(u64),
+    /// Maps dispute_id → Vec<AppealRound>, one entry per appeal filed.
+    AppealRounds(u64),
     /// Global monotonic appeal counter.
     AppealCount,
     /// Maps appeal_id → Appeal struct.
     Appeal(u64),
-    /// Maps appeal_id → Vec<Vote>.
-    AppealVotes(u64),
+    /// The `index`-th vote cast on an appeal: (appeal_id, index) → Vote.
+    AppealVoteAt(u64, u32),
     /// Tracks whether a voter has already voted on a given appeal.
     HasVotedAppeal(u64, Address),
     /// Per-caller nonce to prevent replay attacks within the TTL window.
@@ -259,6 +530,72 @@ enum DataKey {
     Evidence(u64),
     /// Caches the intended `DisputeResolution` when the escrow callback fails; cleared on retry success.
     PendingResolution(u64),
+    /// Maps (dispute_id, round) → ResolutionRecord explaining the outcome of that round.
+    Resolution(u64, u32),
+    /// Per-dispute per-token pool balance: (dispute_id, token) → i128.
+    PoolBalance(u64, Address),
+    /// Tokens that have ever been credited to a dispute's pool: dispute_id → Vec<Address>.
+    PoolTokens(u64),
+    /// What a depositor put into a dispute's pool, so an expired dispute can
+    /// return it: (dispute_id, depositor, token) → i128.
+    PoolDeposit(u64, Address, Address),
+    /// Tokens `fund_pool` accepts for any dispute (instance, bool).
+    PoolTokenAllowed(Address),
+    /// Whether public views hide voter addresses behind commitments (instance, bool).
+    VoterPrivacy,
+    /// Contract-wide salt for voter commitments (instance, BytesN<32>). No view
+    /// returns it.
+    VoterSalt,
+    /// Per-voter reward share for a (dispute_id, token), fixed at the first claim.
+    RewardShare(u64, Address),
+    /// Marks a reward as claimed: (dispute_id, voter_commitment, token) → bool.
+    RewardClaimed(u64, BytesN<32>, Address),
+    /// Governance-set value of a tunable without a dedicated key (instance, i128).
+    Parameter(Symbol),
+    /// Consecutive resolved disputes an arbitrator was assigned to but did not vote on.
+    JurorMisses(Address),
+    /// Marks an arbitrator pruned for inactivity until they call `reactivate_juror`.
+    InactiveJuror(Address),
+    /// Maps dispute_id → JobSnapshot of the escrow job when the dispute was raised.
+    JobSnapshot(u64),
+    /// Every escrow job covered by a bundled dispute, primary job first: dispute_id → Vec<u64>.
+    BundledJobs(u64),
+    /// Maps (dispute_id, job_id) → JobSnapshot for the non-primary jobs of a bundled dispute.
+    BundledJobSnapshot(u64, u64),
+    /// Number of a bundled dispute's jobs already settled by escrow while a
+    /// later callback failed; cleared once every job is settled.
+    BundleSettled(u64),
+    /// Token and minimum amount juror applicants must stake (instance).
+    JurorStakeConfig,
+    /// Addresses with a pending juror application, in application order (instance).
+    PendingJurors,
+    /// Maps applicant → pending JurorApplication.
+    JurorApplication(Address),
+    /// Stake (token, amount) held for an approved juror, refunded on removal.
+    JurorStake(Address),
+    /// Marks `Address` as holding `Role` through `grant_role` (instance, bool).
+    RoleMember(Role, Address),
+    /// Reason code a juror gave for recusing from a dispute: (dispute_id, juror) → u32.
+    Recusal(u64, Address),
+    /// Number of disputes a juror has recused from.
+    JurorRecusals(Address),
+    /// When `finalize_after_appeal_window` made a dispute's outcome final.
+    FinalizedAt(u64),
+    /// Stake the initiator paid in per token: dispute_id → Vec<(token, amount)>.
+    InitiatorStake(u64),
+    /// Executor owed the `exec_bps` bounty once the dispute's funds settle.
+    PendingBounty(u64),
+    /// Set once `settle_held_funds` has run for a dispute.
+    HeldFundsSettled(u64),
+    /// Timestamp of the first vote cast on a dispute.
+    FirstVoteAt(u64),
+    /// Disputes raised per `ReasonCode`, indexed by code (instance, Vec<u32>).
+    ReasonCounts,
+    /// Hash of a dispute's off-chain reason statement: dispute_id → BytesN<32>.
+    ReasonHash(u64),
+    /// Evidence cutoff for which the `ev_closed` event was last published:
+    /// dispute_id → u64.
+    EvidenceClosedAt(u64),
 }
 
 fn require_not_paused(env: &Env) -> Result<(), DisputeError> {
@@ -274,18 +611,209 @@ fn require_not_paused(env: &Env) -> Result<(), DisputeError> {
 }
 
 fn require_admin(env: &Env, admin: &Address) -> Result<(), DisputeError> {
+    require_role(env, admin, Role::Admin)
+}
+
+/// Whether `account` holds `role`: the initial admin and `Role::Admin`
+/// grantees hold every role.
+fn has_role(env: &Env, account: &Address, role: Role) -> Result<bool, DisputeError> {
     let stored_admin: Address = env
         .storage()
         .instance()
         .get(&DataKey::Admin)
         .ok_or(DisputeError::NotInitialized)?;
+    let granted = |role: Role| {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoleMember(role, account.clone()))
+            .unwrap_or(false)
+    };
+    Ok(account == &stored_admin || granted(Role::Admin) || granted(role))
+}
 
-    if admin != &stored_admin {
+fn require_role(env: &Env, account: &Address, role: Role) -> Result<(), DisputeError> {
+    if !has_role(env, account, role)? {
         return Err(DisputeError::NotAdmin);
     }
     Ok(())
 }
 
+// ── Governance parameters ────────────────────────────────────────────────────
+//
+// Every tunable is addressable by a short symbol through `set_parameter` /
+// `get_parameter`, so an external governance contract can manage the module
+// through one stable interface. Tunables that already had a dedicated storage
+// key keep it; the rest live under `DataKey::Parameter`.
+
+/// Malicious-filing supermajority, in bps of all votes cast.
+const PARAM_MALICIOUS_BPS: Symbol = symbol_short!("mal_bps");
+/// Floor on the `min_votes` a dispute may be raised with.
+const PARAM_MIN_VOTES: Symbol = symbol_short!("min_votes");
+/// Appeals allowed per dispute (0 disables appeals).
+const PARAM_MAX_APPEALS: Symbol = symbol_short!("max_appl");
+/// Seconds after resolution during which an appeal may be filed.
+const PARAM_APPEAL_WINDOW: Symbol = symbol_short!("appl_win");
+/// Tie-break policy for disputes raised without one: 0 FavorFreelancer,
+/// 1 FavorClient, 2 Escalate, 3 RefundBoth.
+const PARAM_TIE_POLICY: Symbol = symbol_short!("tie_pol");
+/// Minimum reputation score to vote.
+const PARAM_MIN_VOTER_REPUTATION: Symbol = symbol_short!("min_rep");
+/// Per-party-pair cooldown between disputes, in seconds.
+const PARAM_PARTY_COOLDOWN: Symbol = symbol_short!("cooldown");
+/// Reputation slashed from the losing party, in bps.
+const PARAM_SLASH_BPS: Symbol = symbol_short!("slash_bps");
+/// Share of appeal votes (bps) the winning outcome needs to overturn the
+/// previous resolution.
+const PARAM_OVERTURN_BPS: Symbol = symbol_short!("ovrt_bps");
+/// Consecutive missed disputes after which an arbitrator becomes inactive
+/// (0 disables pruning).
+const PARAM_MAX_MISSES: Symbol = symbol_short!("max_miss");
+/// Stake the initiator puts at risk, in bps of the disputed escrow remainder
+/// (0 disables the stake).
+const PARAM_STAKE_BPS: Symbol = symbol_short!("stake_bps");
+/// Bounty for whoever resolves a dispute through `execute_resolution`, in bps
+/// of each token in the dispute's pool (0 disables the bounty).
+const PARAM_EXECUTOR_BPS: Symbol = symbol_short!("exec_bps");
+/// Stake discount, in bps, for initiators in each reputation tier.
+const PARAM_DISCOUNT_BRONZE: Symbol = symbol_short!("disc_brz");
+const PARAM_DISCOUNT_SILVER: Symbol = symbol_short!("disc_slv");
+const PARAM_DISCOUNT_GOLD: Symbol = symbol_short!("disc_gld");
+const PARAM_DISCOUNT_PLATINUM: Symbol = symbol_short!("disc_plt");
+/// Seconds after it was raised at which an unsettled dispute may be expired.
+const PARAM_MAX_DURATION: Symbol = symbol_short!("max_dur");
+/// Escrow outcome applied when a dispute expires: 0 RefundBoth,
+/// 1 ClientWins, 2 FreelancerWins.
+const PARAM_EXPIRY_RESOLUTION: Symbol = symbol_short!("exp_res");
+/// Seconds a dispute must stay open after its first vote before it can be
+/// resolved on quorum (0 lets the quorum resolve it at once).
+const PARAM_MIN_VOTING_PERIOD: Symbol = symbol_short!("min_open");
+/// Seconds before the voting deadline after which `submit_evidence` is
+/// rejected (0 accepts evidence until the deadline).
+const PARAM_EVIDENCE_CUTOFF: Symbol = symbol_short!("ev_cutoff");
+
+/// Inclusive `(min, max)` bounds for `param`, or `None` if it is not tunable.
+fn parameter_bounds(param: &Symbol) -> Option<(i128, i128)> {
+    let bounds = if *param == PARAM_MALICIOUS_BPS {
+        (5_001, 10_000)
+    } else if *param == PARAM_MIN_VOTES {
+        (1, 5)
+    } else if *param == PARAM_MAX_APPEALS {
+        (0, 3)
+    } else if *param == PARAM_APPEAL_WINDOW {
+        (3_600, 30 * 86_400)
+    } else if *param == PARAM_TIE_POLICY {
+        (0, 3)
+    } else if *param == PARAM_MIN_VOTER_REPUTATION {
+        (0, u32::MAX as i128)
+    } else if *param == PARAM_PARTY_COOLDOWN {
+        (0, 365 * 86_400)
+    } else if *param == PARAM_SLASH_BPS {
+        (0, 10_000)
+    } else if *param == PARAM_OVERTURN_BPS {
+        (5_000, 10_000)
+    } else if *param == PARAM_MAX_MISSES {
+        (0, 100)
+    } else if *param == PARAM_STAKE_BPS {
+        (0, 10_000)
+    } else if *param == PARAM_EXECUTOR_BPS {
+        (0, 1_000)
+    } else if *param == PARAM_DISCOUNT_BRONZE
+        || *param == PARAM_DISCOUNT_SILVER
+        || *param == PARAM_DISCOUNT_GOLD
+        || *param == PARAM_DISCOUNT_PLATINUM
+    {
+        (0, 10_000)
+    } else if *param == PARAM_MAX_DURATION {
+        (VOTING_PERIOD_SECS as i128, 365 * 86_400)
+    } else if *param == PARAM_EXPIRY_RESOLUTION {
+        (0, 2)
+    } else if *param == PARAM_MIN_VOTING_PERIOD || *param == PARAM_EVIDENCE_CUTOFF {
+        (0, VOTING_PERIOD_SECS as i128)
+    } else {
+        return None;
+    };
+    Some(bounds)
+}
+
+fn stored_parameter(env: &Env, param: Symbol, default: i128) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Parameter(param))
+        .unwrap_or(default)
+}
+
+/// Current value of `param`, or `None` if it is not tunable.
+fn parameter(env: &Env, param: &Symbol) -> Option<i128> {
+    let instance = env.storage().instance();
+    let value = if *param == PARAM_MIN_VOTER_REPUTATION {
+        instance
+            .get::<_, u32>(&DataKey::MinVoterReputation)
+            .unwrap_or(MIN_VOTER_REPUTATION) as i128
+    } else if *param == PARAM_PARTY_COOLDOWN {
+        instance
+            .get::<_, u64>(&DataKey::CooldownDuration)
+            .unwrap_or(DEFAULT_PARTY_COOLDOWN_SECS) as i128
+    } else if *param == PARAM_SLASH_BPS {
+        instance
+            .get::<_, u32>(&DataKey::ReputationSlashBps)
+            .unwrap_or(DEFAULT_REPUTATION_SLASH_BPS) as i128
+    } else if *param == PARAM_MALICIOUS_BPS {
+        stored_parameter(
+            env,
+            PARAM_MALICIOUS_BPS,
+            DEFAULT_MALICIOUS_THRESHOLD_BPS as i128,
+        )
+    } else if *param == PARAM_MIN_VOTES {
+        stored_parameter(env, PARAM_MIN_VOTES, DEFAULT_MIN_VOTES_FLOOR as i128)
+    } else if *param == PARAM_MAX_APPEALS {
+        stored_parameter(env, PARAM_MAX_APPEALS, DEFAULT_MAX_APPEALS as i128)
+    } else if *param == PARAM_APPEAL_WINDOW {
+        stored_parameter(env, PARAM_APPEAL_WINDOW, APPEAL_WINDOW_SECS as i128)
+    } else if *param == PARAM_TIE_POLICY {
+        stored_parameter(env, PARAM_TIE_POLICY, 3)
+    } else if *param == PARAM_OVERTURN_BPS {
+        stored_parameter(env, PARAM_OVERTURN_BPS, DEFAULT_APPEAL_OVERTURN_BPS as i128)
+    } else if *param == PARAM_MAX_MISSES {
+        stored_parameter(env, PARAM_MAX_MISSES, DEFAULT_MAX_JUROR_MISSES as i128)
+    } else if *param == PARAM_STAKE_BPS {
+        stored_parameter(env, PARAM_STAKE_BPS, 0)
+    } else if *param == PARAM_EXECUTOR_BPS {
+        stored_parameter(env, PARAM_EXECUTOR_BPS, 0)
+    } else if *param == PARAM_DISCOUNT_BRONZE
+        || *param == PARAM_DISCOUNT_SILVER
+        || *param == PARAM_DISCOUNT_GOLD
+        || *param == PARAM_DISCOUNT_PLATINUM
+    {
+        stored_parameter(env, param.clone(), 0)
+    } else if *param == PARAM_MAX_DURATION {
+        stored_parameter(env, PARAM_MAX_DURATION, DEFAULT_MAX_DISPUTE_SECS as i128)
+    } else if *param == PARAM_EXPIRY_RESOLUTION {
+        stored_parameter(env, PARAM_EXPIRY_RESOLUTION, 0)
+    } else if *param == PARAM_MIN_VOTING_PERIOD {
+        stored_parameter(env, PARAM_MIN_VOTING_PERIOD, 0)
+    } else if *param == PARAM_EVIDENCE_CUTOFF {
+        stored_parameter(env, PARAM_EVIDENCE_CUTOFF, 0)
+    } else {
+        return None;
+    };
+    Some(value)
+}
+
+/// Value of a parameter known to be tunable. Bounds are enforced on write, so
+/// the narrowing casts at the call sites cannot truncate.
+fn known_parameter(env: &Env, param: &Symbol) -> i128 {
+    parameter(env, param).unwrap_or(0)
+}
+
+fn default_tie_break_method(env: &Env) -> TieBreakMethod {
+    match known_parameter(env, &PARAM_TIE_POLICY) {
+        0 => TieBreakMethod::FavorFreelancer,
+        1 => TieBreakMethod::FavorClient,
+        2 => TieBreakMethod::Escalate,
+        _ => TieBreakMethod::RefundBoth,
+    }
+}
+
 const MIN_VOTER_REPUTATION: u32 = 300;
 
 /// Default reputation points slashed from the losing party after a resolved dispute.
@@ -303,6 +831,19 @@ const APPEAL_WINDOW_SECS: u64 = 172_800; // 48 hours
 /// Minimum votes required to resolve an appeal.
 const APPEAL_MIN_VOTES: u32 = 3;
 
+/// Default share of votes (bps) a malicious-filing verdict needs (4/5).
+const DEFAULT_MALICIOUS_THRESHOLD_BPS: u32 = 8_000;
+/// Default floor applied to the `min_votes` requested by `raise_dispute`.
+const DEFAULT_MIN_VOTES_FLOOR: u32 = 3;
+/// Default number of appeals allowed per dispute.
+const DEFAULT_MAX_APPEALS: u32 = 1;
+/// Default share of appeal votes (bps) needed to overturn a resolution (60%).
+const DEFAULT_APPEAL_OVERTURN_BPS: u32 = 6_000;
+/// Default number of consecutive missed disputes before a juror is pruned.
+const DEFAULT_MAX_JUROR_MISSES: u32 = 3;
+/// Default lifetime of a dispute before `expire_dispute` may close it.
+const DEFAULT_MAX_DISPUTE_SECS: u64 = 5_184_000; // 60 days
+
 const NONCE_EXPIRY_LEDGERS: u32 = 3;
 
 const MIN_TTL_THRESHOLD: u32 = 1_000;
@@ -326,12 +867,74 @@ fn bump_dispute_ttl(env: &Env, dispute_id: u64) {
     );
 }
 
-fn bump_votes_ttl(env: &Env, dispute_id: u64) {
-    env.storage().persistent().extend_ttl(
-        &DataKey::Votes(dispute_id),
-        MIN_TTL_THRESHOLD,
-        MIN_TTL_EXTEND_TO,
-    );
+/// Store a vote under its own key so casting it does not rewrite the votes
+/// before it.
+fn store_vote(env: &Env, key: &DataKey, vote: &Vote) {
+    env.storage().persistent().set(key, vote);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+}
+
+/// Votes `offset..offset + limit` of the `cast` votes on a dispute, in the
+/// order they were cast.
+fn load_votes(env: &Env, dispute_id: u64, cast: u32, offset: u32, limit: u32) -> Vec<Vote> {
+    let mut votes = Vec::new(env);
+    for index in offset.min(cast)..offset.saturating_add(limit).min(cast) {
+        if let Some(vote) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoteAt(dispute_id, index))
+        {
+            votes.push_back(vote);
+        }
+    }
+    votes
+}
+
+/// Every vote cast on `dispute`; its tally holds how many there are.
+fn dispute_votes(env: &Env, dispute: &Dispute) -> Vec<Vote> {
+    load_votes(env, dispute.id, dispute.tally.vote_count, 0, u32::MAX)
+}
+
+/// Addresses that voted on `dispute`, in voting order. Votes stored by
+/// commitment only are matched against the dispute's panel and the delegates
+/// its jurors named for the job; a delegation cannot be revoked once used, so
+/// a delegate's vote still resolves.
+fn dispute_voters(env: &Env, dispute: &Dispute) -> Vec<Address> {
+    let mut candidates = Vec::new(env);
+    if voter_privacy(env) {
+        for juror in dispute.assigned_arbitrators.iter() {
+            if let Some(delegate) = env
+                .storage()
+                .persistent()
+                .get::<_, Address>(&DataKey::VoteDelegation(juror.clone(), dispute.job_id))
+            {
+                candidates.push_back(delegate);
+            }
+            candidates.push_back(juror);
+        }
+    }
+    let mut voters = Vec::new(env);
+    for vote in dispute_votes(env, dispute).iter() {
+        let voter = vote.voter.or_else(|| {
+            candidates
+                .iter()
+                .find(|candidate| voter_commitment(env, dispute.id, candidate) == vote.voter_commitment)
+        });
+        if let Some(voter) = voter {
+            voters.push_back(voter);
+        }
+    }
+    voters
+}
+
+/// `dispute` as the public views return it: in privacy mode without its panel.
+fn public_dispute(env: &Env, mut dispute: Dispute) -> Dispute {
+    if voter_privacy(env) {
+        dispute.assigned_arbitrators = Vec::new(env);
+    }
+    dispute
 }
 
 fn bump_last_dispute_closed_ttl(env: &Env, job_id: u64) {
@@ -342,14 +945,182 @@ fn bump_last_dispute_closed_ttl(env: &Env, job_id: u64) {
     );
 }
 
+/// Whether voter rewards of `dispute` can be claimed: it has closed, its
+/// latest appeal (if any) is settled and the appeal window has run out. Until
+/// then the outcome may still flip, and in privacy mode votes stay out of
+/// public views.
+fn rewards_unlocked(env: &Env, dispute: &Dispute) -> bool {
+    if matches!(dispute.status, DisputeStatus::Open | DisputeStatus::Voting) {
+        return false;
+    }
+    let latest_appeal: Option<Appeal> = env
+        .storage()
+        .persistent()
+        .get::<_, u64>(&DataKey::DisputeAppeal(dispute.id))
+        .and_then(|id| env.storage().persistent().get(&DataKey::Appeal(id)));
+    if matches!(
+        latest_appeal.map(|ap| ap.status),
+        Some(AppealStatus::Open | AppealStatus::Voting)
+    ) {
+        return false;
+    }
+    let closed_at: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LastDisputeClosedAt(dispute.job_id))
+        .unwrap_or(0);
+    let appeal_window = known_parameter(env, &PARAM_APPEAL_WINDOW) as u64;
+    env.ledger().timestamp() > closed_at.saturating_add(appeal_window)
+}
+
+/// When `dispute` reaches the `max_dur` lifetime.
+fn dispute_expires_at(env: &Env, dispute: &Dispute) -> u64 {
+    let max_duration = known_parameter(env, &PARAM_MAX_DURATION) as u64;
+    dispute.created_at.saturating_add(max_duration)
+}
+
+/// Escrow jobs covered by `dispute`: the bundle of a bundled dispute, else
+/// its single job.
+fn dispute_job_ids(env: &Env, dispute: &Dispute) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BundledJobs(dispute.id))
+        .unwrap_or_else(|| vec![env, dispute.job_id])
+}
+
+/// Start the appeal window (and the re-dispute cooldown) of every job in
+/// `dispute` from now, after the dispute or one of its appeals has closed.
+fn mark_jobs_closed(env: &Env, dispute: &Dispute) {
+    for job_id in dispute_job_ids(env, dispute).iter() {
+        env.storage().persistent().set(
+            &DataKey::LastDisputeClosedAt(job_id),
+            &env.ledger().timestamp(),
+        );
+        bump_last_dispute_closed_ttl(env, job_id);
+    }
+}
+
+/// Apply `resolution` to every job in `dispute` through the escrow's
+/// `resolve_dispute_callback`. Jobs settled by an earlier, partially failed
+/// attempt are skipped so a retry never applies an outcome twice. Returns
+/// whether every job is now settled.
+fn notify_escrow(
+    env: &Env,
+    escrow_addr: &Address,
+    dispute: &Dispute,
+    resolution: &DisputeResolution,
+) -> bool {
+    let key = DataKey::BundleSettled(dispute.id);
+    let settled: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    let job_ids = dispute_job_ids(env, dispute);
+    let escrow = escrow::EscrowClient::new(env, escrow_addr);
+    for i in settled..job_ids.len() {
+        let ok = matches!(
+            escrow.try_resolve_dispute_callback(&job_ids.get_unchecked(i), resolution),
+            Ok(Ok(_))
+        );
+        if !ok {
+            if i > 0 {
+                env.storage().persistent().set(&key, &i);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+            }
+            return false;
+        }
+    }
+    if settled > 0 {
+        env.storage().persistent().remove(&key);
+    }
+    true
+}
+
+/// Storage key marking that `voter` has voted on `dispute_id`.
+fn has_voted_key(env: &Env, dispute_id: u64, voter: &Address) -> DataKey {
+    DataKey::HasVoted(dispute_id, voter_commitment(env, dispute_id, voter))
+}
+
 fn bump_has_voted_ttl(env: &Env, dispute_id: u64, voter: &Address) {
     env.storage().persistent().extend_ttl(
-        &DataKey::HasVoted(dispute_id, voter.clone()),
+        &has_voted_key(env, dispute_id, voter),
         MIN_TTL_THRESHOLD,
         MIN_TTL_EXTEND_TO,
     );
 }
 
+fn is_inactive_juror(env: &Env, juror: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::InactiveJuror(juror.clone()))
+}
+
+/// Update the miss streak of every arbitrator assigned to a dispute that is
+/// being resolved: voters start over, non-voters add a miss and are marked
+/// inactive once the streak reaches the `max_miss` parameter. A dispute the
+/// panel resolved before its voting deadline counts no misses, since the
+/// jurors who had not voted yet were not given the full period to do so.
+fn record_juror_participation(env: &Env, dispute_id: u64, dispute: &Dispute) {
+    let max_misses = known_parameter(env, &PARAM_MAX_MISSES) as u32;
+    let count_misses = env.ledger().timestamp() >= dispute.voting_deadline;
+    for juror in dispute.assigned_arbitrators.iter() {
+        let misses_key = DataKey::JurorMisses(juror.clone());
+        if env
+            .storage()
+            .persistent()
+            .has(&has_voted_key(env, dispute_id, &juror))
+        {
+            env.storage().persistent().remove(&misses_key);
+            continue;
+        }
+        if !count_misses {
+            continue;
+        }
+        let misses = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&misses_key)
+            .unwrap_or(0)
+            .saturating_add(1);
+        env.storage().persistent().set(&misses_key, &misses);
+        env.storage()
+            .persistent()
+            .extend_ttl(&misses_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+
+        if max_misses > 0 && misses >= max_misses && !is_inactive_juror(env, &juror) {
+            let inactive_key = DataKey::InactiveJuror(juror.clone());
+            env.storage().persistent().set(&inactive_key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&inactive_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("jur_inact")),
+                (juror, misses),
+            );
+        }
+    }
+}
+
+/// Votes needed to resolve `dispute` on quorum: its `min_votes`, capped at the
+/// number of panel jurors still able to vote. Jurors pruned as inactive since
+/// the panel was drawn no longer count towards it, unless they already voted.
+fn quorum(env: &Env, dispute_id: u64, dispute: &Dispute) -> u32 {
+    if dispute.assigned_arbitrators.is_empty() {
+        return dispute.min_votes;
+    }
+    let mut eligible: u32 = 0;
+    for juror in dispute.assigned_arbitrators.iter() {
+        if !is_inactive_juror(env, &juror)
+            || env
+                .storage()
+                .persistent()
+                .has(&has_voted_key(env, dispute_id, &juror))
+        {
+            eligible += 1;
+        }
+    }
+    dispute.min_votes.min(eligible.max(1))
+}
+
 fn bump_dispute_count_ttl(env: &Env) {
     env.storage()
         .instance()
@@ -412,119 +1183,438 @@ fn bump_arbitrators_ttl(env: &Env, dispute_id: u64) {
     );
 }
 
-fn bump_evidence_ttl(env: &Env, dispute_id: u64) {
+fn bump_job_snapshot_ttl(env: &Env, dispute_id: u64) {
     env.storage().persistent().extend_ttl(
-        &DataKey::Evidence(dispute_id),
+        &DataKey::JobSnapshot(dispute_id),
         MIN_TTL_THRESHOLD,
         MIN_TTL_EXTEND_TO,
     );
 }
 
-fn bump_pending_resolution_ttl(env: &Env, dispute_id: u64) {
-    env.storage().persistent().extend_ttl(
-        &DataKey::PendingResolution(dispute_id),
-        MIN_TTL_THRESHOLD,
-        MIN_TTL_EXTEND_TO,
-    );
+fn pending_jurors(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingJurors)
+        .unwrap_or(Vec::new(env))
 }
 
-/// Creates a default (zeroed) DisputeTally for a new dispute.
-fn new_tally() -> DisputeTally {
-    DisputeTally {
-        client_weight: 0,
-        freelancer_weight: 0,
-        total_weight_cast: 0,
-        vote_count: 0,
-        refund_split_weight: 0,
-        refund_split_sum: 0,
-        refund_split_count: 0,
-        malicious_weight: 0,
-        malicious_count: 0,
+/// Take `applicant` off the pending queue and return their application.
+fn take_juror_application(
+    env: &Env,
+    applicant: &Address,
+) -> Result<JurorApplication, DisputeError> {
+    let key = DataKey::JurorApplication(applicant.clone());
+    let application: JurorApplication = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(DisputeError::ApplicationNotFound)?;
+    env.storage().persistent().remove(&key);
+    let mut queue = pending_jurors(env);
+    if let Some(i) = queue.first_index_of(applicant) {
+        queue.remove(i);
     }
+    env.storage().instance().set(&DataKey::PendingJurors, &queue);
+    Ok(application)
 }
 
-fn bump_appeal_ttl(env: &Env, appeal_id: u64) {
-    env.storage().persistent().extend_ttl(
-        &DataKey::Appeal(appeal_id),
-        MIN_TTL_THRESHOLD,
-        MIN_TTL_EXTEND_TO,
-    );
+/// Entries `offset..offset + limit` of `items`, with `limit` capped at
+/// `MAX_JUROR_PAGE`.
+fn juror_page<T: soroban_sdk::TryFromVal<Env, soroban_sdk::Val> + IntoVal<Env, soroban_sdk::Val>>(
+    items: Vec<T>,
+    offset: u32,
+    limit: u32,
+) -> Vec<T> {
+    let start = offset.min(items.len());
+    let end = start.saturating_add(limit.min(MAX_JUROR_PAGE)).min(items.len());
+    items.slice(start..end)
 }
 
-fn bump_appeal_votes_ttl(env: &Env, appeal_id: u64) {
+fn bump_evidence_ttl(env: &Env, dispute_id: u64) {
     env.storage().persistent().extend_ttl(
-        &DataKey::AppealVotes(appeal_id),
+        &DataKey::Evidence(dispute_id),
         MIN_TTL_THRESHOLD,
         MIN_TTL_EXTEND_TO,
     );
 }
 
-fn bump_dispute_appeal_ttl(env: &Env, dispute_id: u64) {
-    env.storage().persistent().extend_ttl(
-        &DataKey::DisputeAppeal(dispute_id),
-        MIN_TTL_THRESHOLD,
-        MIN_TTL_EXTEND_TO,
+/// Time from which `submit_evidence` is rejected: `ev_cutoff` seconds before
+/// the dispute's current voting deadline.
+fn evidence_cutoff(env: &Env, dispute: &Dispute) -> u64 {
+    dispute
+        .voting_deadline
+        .saturating_sub(known_parameter(env, &PARAM_EVIDENCE_CUTOFF) as u64)
+}
+
+/// Publish `("dispute", "ev_closed")` the first time a dispute is seen past
+/// its evidence cutoff. An appeal moves the deadline, so its round gets its
+/// own event. Returns whether the event was published.
+fn close_evidence_phase_if_due(env: &Env, dispute_id: u64, dispute: &Dispute) -> bool {
+    let cutoff = evidence_cutoff(env, dispute);
+    let key = DataKey::EvidenceClosedAt(dispute_id);
+    if env.ledger().timestamp() < cutoff
+        || env.storage().persistent().get::<_, u64>(&key) == Some(cutoff)
+    {
+        return false;
+    }
+    env.storage().persistent().set(&key, &cutoff);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+    env.events().publish(
+        (symbol_short!("dispute"), symbol_short!("ev_closed")),
+        (dispute_id, cutoff),
     );
+    true
 }
 
-fn bump_has_voted_appeal_ttl(env: &Env, appeal_id: u64, voter: &Address) {
+fn bump_pending_resolution_ttl(env: &Env, dispute_id: u64) {
     env.storage().persistent().extend_ttl(
-        &DataKey::HasVotedAppeal(appeal_id, voter.clone()),
+        &DataKey::PendingResolution(dispute_id),
         MIN_TTL_THRESHOLD,
         MIN_TTL_EXTEND_TO,
     );
 }
 
-// Import Job struct from escrow for cross-contract calls
-mod escrow {
-    use soroban_sdk::{contracttype, Address, String, Vec};
-
-    #[contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub enum JobStatus {
-        Created,
-        Funded,
-        InProgress,
-        Completed,
-        Disputed,
-        Cancelled,
-        Expired,
-    }
+/// Creates a default (zeroed) DisputeTally for a new dispute.
+fn store_resolution_record(env: &Env, record: &ResolutionRecord) {
+    let key = DataKey::Resolution(record.dispute_id, record.round);
+    env.storage().persistent().set(&key, record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+}
 
-    #[contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub enum MilestoneStatus {
-        Pending,
-        InProgress,
-        Submitted,
-        Approved,
-        PartiallyPaid,
-    }
+fn pool_balance(env: &Env, dispute_id: u64, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PoolBalance(dispute_id, token.clone()))
+        .unwrap_or(0)
+}
 
-    #[contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Milestone {
-        pub id: u32,
-        pub description: String,
-        pub amount: i128,
-        pub status: MilestoneStatus,
-        pub deadline: u64,
-    }
+fn set_pool_balance(env: &Env, dispute_id: u64, token: &Address, balance: i128) {
+    let key = DataKey::PoolBalance(dispute_id, token.clone());
+    env.storage().persistent().set(&key, &balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+}
 
-    #[contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Job {
-        pub id: u64,
-        pub client: Address,
-        pub freelancer: Address,
-        pub token: Address,
-        pub total_amount: i128,
-        pub funded_amount: i128,
-        pub status: JobStatus,
-        pub milestones: Vec<Milestone>,
-        pub job_deadline: u64,
-        pub auto_refund_after: u64,
+/// Record `amount` of `token` from `from` as belonging to `dispute_id`'s pool.
+/// Funds held by the contract are only ever paid out against the pool they
+/// were credited to.
+fn credit_pool(env: &Env, dispute_id: u64, from: &Address, token: &Address, amount: i128) {
+    let tokens_key = DataKey::PoolTokens(dispute_id);
+    let mut tokens: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&tokens_key)
+        .unwrap_or(Vec::new(env));
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage().persistent().set(&tokens_key, &tokens);
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&tokens_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+
+    let deposit_key = DataKey::PoolDeposit(dispute_id, from.clone(), token.clone());
+    let deposited: i128 = env.storage().persistent().get(&deposit_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&deposit_key, &deposited.saturating_add(amount));
+    env.storage()
+        .persistent()
+        .extend_ttl(&deposit_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+
+    let balance = pool_balance(env, dispute_id, token).saturating_add(amount);
+    set_pool_balance(env, dispute_id, token, balance);
+}
+
+/// Transfer `amount` of `token` out of the contract on behalf of `dispute_id`.
+/// Invariant: a dispute can never pay out more than its own pool holds, so an
+/// accounting bug in one dispute cannot spend another dispute's funds.
+fn debit_pool(
+    env: &Env,
+    dispute_id: u64,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), DisputeError> {
+    if amount <= 0 {
+        return Err(DisputeError::InvalidAmount);
+    }
+    let balance = pool_balance(env, dispute_id, token);
+    if amount > balance {
+        return Err(DisputeError::PoolInsufficient);
+    }
+    set_pool_balance(env, dispute_id, token, balance - amount);
+    token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    Ok(())
+}
+
+/// Pay `executor` the `exec_bps` share of every token in `dispute_id`'s pool.
+/// Runs when the pool's held funds settle, before any voter reward share is
+/// fixed, so winners split what is left.
+fn pay_executor_bounty(env: &Env, dispute_id: u64, executor: &Address) {
+    let bps = known_parameter(env, &PARAM_EXECUTOR_BPS);
+    if bps == 0 {
+        return;
+    }
+    let tokens: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PoolTokens(dispute_id))
+        .unwrap_or(Vec::new(env));
+    for token in tokens.iter() {
+        let bounty = pool_balance(env, dispute_id, &token) * bps / 10_000;
+        if bounty > 0 && debit_pool(env, dispute_id, &token, executor, bounty).is_ok() {
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("exec_paid")),
+                (dispute_id, executor.clone(), token, bounty),
+            );
+        }
+    }
+}
+
+/// Share of the initiator's stake, in bps, given back once `dispute` is
+/// final: all of it when the initiator won or the parties were refunded on a
+/// tie, none when they lost or filed in bad faith, and their share of the
+/// award on a split. `None` while the dispute has no settled outcome.
+fn initiator_stake_return_bps(dispute: &Dispute) -> Option<u32> {
+    let client_bps = match dispute.status {
+        DisputeStatus::ResolvedForClient => 10_000,
+        DisputeStatus::ResolvedForFreelancer => 0,
+        DisputeStatus::RefundSplit(pct) => pct.min(100) * 100,
+        DisputeStatus::SplitAward(bps) => bps.min(10_000),
+        DisputeStatus::RefundedBoth => return Some(10_000),
+        DisputeStatus::MaliciousDisputeFiling => return Some(0),
+        _ => return None,
+    };
+    Some(if dispute.initiator == dispute.client {
+        client_bps
+    } else {
+        10_000 - client_bps
+    })
+}
+
+/// Settle the funds a dispute's pool holds back until its outcome can no
+/// longer be appealed: return the initiator's stake as
+/// `initiator_stake_return_bps` allows, then pay any executor bounty owed by
+/// `execute_resolution`. Whatever is left goes to the winning voters. Only
+/// call once the appeal window has closed; runs at most once per dispute.
+fn settle_held_funds(env: &Env, dispute: &Dispute) {
+    let settled_key = DataKey::HeldFundsSettled(dispute.id);
+    if env.storage().persistent().has(&settled_key) {
+        return;
+    }
+    let Some(return_bps) = initiator_stake_return_bps(dispute) else {
+        return;
+    };
+
+    let stakes: Vec<(Address, i128)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InitiatorStake(dispute.id))
+        .unwrap_or(Vec::new(env));
+    for (token, amount) in stakes.iter() {
+        let refund = (amount * return_bps as i128 / 10_000)
+            .min(pool_balance(env, dispute.id, &token));
+        if refund > 0
+            && debit_pool(env, dispute.id, &token, &dispute.initiator, refund).is_ok()
+        {
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("stk_rtrn")),
+                (dispute.id, dispute.initiator.clone(), token, refund),
+            );
+        }
+    }
+
+    let bounty_key = DataKey::PendingBounty(dispute.id);
+    if let Some(executor) = env.storage().persistent().get::<_, Address>(&bounty_key) {
+        pay_executor_bounty(env, dispute.id, &executor);
+        env.storage().persistent().remove(&bounty_key);
+    }
+
+    env.storage().persistent().set(&settled_key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&settled_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+}
+
+fn voter_privacy(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::VoterPrivacy)
+        .unwrap_or(false)
+}
+
+/// `sha256(salt || dispute_id || voter)`. Including the dispute ID keeps a
+/// juror's commitments unlinkable across disputes.
+fn voter_commitment(env: &Env, dispute_id: u64, voter: &Address) -> BytesN<32> {
+    let salt: BytesN<32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::VoterSalt)
+        .unwrap_or(BytesN::from_array(env, &[0u8; 32]));
+    let mut preimage = Bytes::from_array(env, &salt.to_array());
+    preimage.extend_from_array(&dispute_id.to_be_bytes());
+    preimage.append(&voter.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Whether a vote for `choice` is on the winning side of a dispute that ended
+/// in `status`. Every voter wins on a tie refunded to both parties. Errors if
+/// the dispute has not reached a final, rewardable outcome.
+fn winning_choice_matches(
+    status: &DisputeStatus,
+    choice: &VoteChoice,
+) -> Result<bool, DisputeError> {
+    match status {
+        DisputeStatus::ResolvedForClient => Ok(*choice == VoteChoice::Client),
+        DisputeStatus::ResolvedForFreelancer => Ok(*choice == VoteChoice::Freelancer),
+        DisputeStatus::RefundSplit(_) => Ok(matches!(choice, VoteChoice::RefundSplit(_))),
+        DisputeStatus::SplitAward(_) => Ok(matches!(choice, VoteChoice::SplitAward(_, _))),
+        DisputeStatus::MaliciousDisputeFiling => Ok(*choice == VoteChoice::MaliciousFiling),
+        DisputeStatus::RefundedBoth => Ok(true),
+        _ => Err(DisputeError::NotEligibleForReward),
+    }
+}
+
+fn new_tally() -> DisputeTally {
+    DisputeTally {
+        client_weight: 0,
+        freelancer_weight: 0,
+        total_weight_cast: 0,
+        vote_count: 0,
+        refund_split_weight: 0,
+        refund_split_sum: 0,
+        refund_split_count: 0,
+        malicious_weight: 0,
+        malicious_count: 0,
+    }
+}
+
+fn bump_appeal_ttl(env: &Env, appeal_id: u64) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Appeal(appeal_id),
+        MIN_TTL_THRESHOLD,
+        MIN_TTL_EXTEND_TO,
+    );
+}
+
+fn bump_dispute_appeal_ttl(env: &Env, dispute_id: u64) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::DisputeAppeal(dispute_id),
+        MIN_TTL_THRESHOLD,
+        MIN_TTL_EXTEND_TO,
+    );
+}
+
+fn bump_has_voted_appeal_ttl(env: &Env, appeal_id: u64, voter: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::HasVotedAppeal(appeal_id, voter.clone()),
+        MIN_TTL_THRESHOLD,
+        MIN_TTL_EXTEND_TO,
+    );
+}
+
+// Escrow job types for cross-contract calls.
+use stellar_market_interface as escrow;
+
+/// Store a snapshot of escrow job `job_id` under `key`. Skipped when the
+/// escrow job cannot be read.
+fn record_job_snapshot(env: &Env, escrow_contract: &Address, key: &DataKey, job_id: u64) {
+    let job = match escrow::EscrowClient::new(env, escrow_contract).try_get_job(&job_id) {
+        Ok(Ok(job)) => job,
+        _ => return,
+    };
+    let mut milestones = Vec::new(env);
+    for m in job.milestones.iter() {
+        milestones.push_back(MilestoneSnapshot {
+            id: m.id,
+            amount: m.amount,
+            status: m.status,
+            deadline: m.deadline,
+        });
+    }
+    let snapshot = JobSnapshot {
+        job_id,
+        status: job.status,
+        token: job.token,
+        total_amount: job.total_amount,
+        funded_amount: job.funded_amount,
+        job_deadline: job.job_deadline,
+        milestones,
+        taken_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(key, &snapshot);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+}
+
+/// Escrowed value still in play on `job`: its total minus approved milestones.
+fn disputed_remainder(job: &escrow::Job) -> i128 {
+    let approved: i128 = job
+        .milestones
+        .iter()
+        .filter(|m| m.status == escrow::MilestoneStatus::Approved)
+        .map(|m| m.amount)
+        .sum();
+    job.total_amount - approved
+}
+
+/// Stake discount (bps) for `initiator`'s reputation tier. No discount when
+/// no tier has one configured, or the tier cannot be read.
+fn tier_stake_discount_bps(env: &Env, initiator: &Address) -> u32 {
+    let discounts = [
+        PARAM_DISCOUNT_BRONZE,
+        PARAM_DISCOUNT_SILVER,
+        PARAM_DISCOUNT_GOLD,
+        PARAM_DISCOUNT_PLATINUM,
+    ];
+    if discounts.iter().all(|p| known_parameter(env, p) == 0) {
+        return 0;
+    }
+    let Some(reputation_contract) = env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::ReputationContract)
+    else {
+        return 0;
+    };
+    let tier = match env.try_invoke_contract::<reputation::ReputationTier, soroban_sdk::Error>(
+        &reputation_contract,
+        &Symbol::new(env, "get_tier"),
+        vec![env, initiator.into_val(env)],
+    ) {
+        Ok(Ok(tier)) => tier,
+        _ => return 0,
+    };
+    let param = match tier {
+        reputation::ReputationTier::None => return 0,
+        reputation::ReputationTier::Bronze => PARAM_DISCOUNT_BRONZE,
+        reputation::ReputationTier::Silver => PARAM_DISCOUNT_SILVER,
+        reputation::ReputationTier::Gold => PARAM_DISCOUNT_GOLD,
+        reputation::ReputationTier::Platinum => PARAM_DISCOUNT_PLATINUM,
+    };
+    known_parameter(env, &param) as u32
+}
+
+/// Token and amount an initiator must stake to dispute escrow job `job_id`,
+/// or `None` when staking is disabled or the job cannot be read.
+fn required_stake(env: &Env, escrow_contract: &Address, job_id: u64) -> Option<(Address, i128)> {
+    let bps = known_parameter(env, &PARAM_STAKE_BPS);
+    if bps == 0 {
+        return None;
     }
+    let job = escrow::EscrowClient::new(env, escrow_contract)
+        .try_get_job(&job_id)
+        .ok()?
+        .ok()?;
+    let stake = disputed_remainder(&job).max(0) * bps / 10_000;
+    Some((job.token, stake))
 }
 
 fn detect_conflicts(
@@ -560,11 +1650,7 @@ fn detect_conflicts(
 
     // Iterate through recent jobs
     for job_id in start_id..=job_count {
-        let job_result = env.try_invoke_contract::<escrow::Job, soroban_sdk::Error>(
-            escrow_contract,
-            &Symbol::new(env, "get_job"),
-            vec![env, job_id.into_val(env)],
-        );
+        let job_result = escrow::EscrowClient::new(env, escrow_contract).try_get_job(&job_id);
 
         let job = match job_result {
             Ok(Ok(j)) => j,
@@ -631,12 +1717,13 @@ fn select_arbitrators(
         return selected;
     }
 
-    // Filter out excluded addresses, client, and freelancer
+    // Filter out excluded addresses, client, freelancer and inactive jurors
     let mut eligible = Vec::<Address>::new(env);
     for addr in pool.iter() {
         if !excluded.contains(&addr) 
             && &addr != client 
             && &addr != freelancer 
+            && !is_inactive_juror(env, &addr)
         {
             eligible.push_back(addr);
         }
@@ -654,7 +1741,7 @@ fn select_arbitrators(
     let mut attempts = 0u32;
     let max_attempts = count.saturating_mul(3); // Prevent infinite loops
     
-    while selected.len() < count as u32 && attempts < max_attempts && selected.len() < eligible.len() as u32 {
+    while selected.len() < count && attempts < max_attempts && selected.len() < eligible.len() {
         let index = ((seed.wrapping_add(attempts as u64).wrapping_mul(2654435761)) % pool_size) as u32;
         let candidate = eligible.get(index).unwrap();
         
@@ -667,6 +1754,287 @@ fn select_arbitrators(
     selected
 }
 
+fn check_vote_summary(summary: &Option<String>) -> Result<(), DisputeError> {
+    match summary {
+        Some(summary) if summary.len() > MAX_VOTE_SUMMARY_LEN => Err(DisputeError::SummaryTooLong),
+        _ => Ok(()),
+    }
+}
+
+/// Open a dispute over `job_ids` (primary job first) once the caller has been
+/// authorized: cooldown and stake checks, panel selection, per-job bookkeeping
+/// and snapshots, and the escrow `mark_job_disputed` notifications.
+#[allow(clippy::too_many_arguments)]
+/// Disputes raised so far per `ReasonCode`, indexed by code.
+fn reason_counts(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReasonCounts)
+        .unwrap_or_else(|| Vec::from_array(env, [0; REASON_CODES.len()]))
+}
+
+fn count_reason(env: &Env, code: ReasonCode) {
+    let mut counts = reason_counts(env);
+    let i = code as u32;
+    counts.set(i, counts.get(i).unwrap_or(0).saturating_add(1));
+    env.storage().instance().set(&DataKey::ReasonCounts, &counts);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn open_dispute(
+    env: &Env,
+    job_ids: &Vec<u64>,
+    client: Address,
+    freelancer: Address,
+    initiator: Address,
+    reason: String,
+    reason_code: ReasonCode,
+    reason_hash: Option<BytesN<32>>,
+    min_votes: u32,
+    tie_break_method: Option<TieBreakMethod>,
+) -> Result<u64, DisputeError> {
+    if initiator != client && initiator != freelancer {
+        return Err(DisputeError::InvalidParty);
+    }
+
+    let now = env.ledger().timestamp();
+    for job_id in job_ids.iter() {
+        if let Some(last_closed_at) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::LastDisputeClosedAt(job_id))
+        {
+            if now <= last_closed_at.saturating_add(DISPUTE_COOLDOWN_SECS) {
+                return Err(DisputeError::DisputeCooldown);
+            }
+            bump_last_dispute_closed_ttl(env, job_id);
+        }
+    }
+
+    // Per-party-pair cooldown: same client/freelancer pair cannot re-dispute until the
+    // configured window has elapsed since the last dispute resolved between them.
+    let party_cooldown: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CooldownDuration)
+        .unwrap_or(DEFAULT_PARTY_COOLDOWN_SECS);
+    if let Some(last_ts) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u64>(&DataKey::LastDisputeLedger(client.clone(), freelancer.clone()))
+    {
+        if now < last_ts.saturating_add(party_cooldown) {
+            return Err(DisputeError::DisputeCooldown);
+        }
+        bump_last_dispute_ledger_ttl(env, &client, &freelancer);
+    }
+
+    let escrow_contract = env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::EscrowContract);
+
+    // The initiator stakes a share of the disputed value, so large disputes
+    // carry proportionate skin in the game. Bundled jobs paid in the same
+    // token add up to one stake. Initiators in a higher reputation tier, who
+    // file frivolous disputes less often, may get a discount on it.
+    let mut stakes: Vec<(Address, i128)> = Vec::new(env);
+    let mut stake_discount_bps = 0;
+    if let Some(escrow_contract) = &escrow_contract {
+        if known_parameter(env, &PARAM_STAKE_BPS) > 0 {
+            stake_discount_bps = tier_stake_discount_bps(env, &initiator);
+        }
+        for job_id in job_ids.iter() {
+            let Some((token, amount)) = required_stake(env, escrow_contract, job_id)
+                .map(|(token, amount)| {
+                    (token, amount * (10_000 - stake_discount_bps as i128) / 10_000)
+                })
+                .filter(|(_, amount)| *amount > 0)
+            else {
+                continue;
+            };
+            match stakes.iter().position(|(t, _)| t == token) {
+                Some(i) => {
+                    let (token, total) = stakes.get_unchecked(i as u32);
+                    stakes.set(i as u32, (token, total + amount));
+                }
+                None => stakes.push_back((token, amount)),
+            }
+        }
+    }
+    for (token, amount) in stakes.iter() {
+        if token::Client::new(env, &token).balance(&initiator) < amount {
+            return Err(DisputeError::InsufficientStake);
+        }
+    }
+
+    let mut count: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::DisputeCount)
+        .unwrap_or(0);
+    count += 1;
+
+    // Detect conflicts of interest by querying escrow contract
+    let excluded_voters = if let Some(escrow_contract) = &escrow_contract {
+        detect_conflicts(env, escrow_contract, &client, &freelancer)
+    } else {
+        Vec::<Address>::new(env)
+    };
+
+    // Select 5 random arbitrators for this dispute
+    let assigned_arbitrators = select_arbitrators(env, count, &excluded_voters, &client, &freelancer, 5);
+
+    let job_id = job_ids.get_unchecked(0);
+    let dispute = Dispute {
+        id: count,
+        job_id,
+        client: client.clone(),
+        freelancer: freelancer.clone(),
+        initiator: initiator.clone(),
+        reason,
+        reason_code,
+        status: DisputeStatus::Open,
+        votes_for_client: 0,
+        votes_for_freelancer: 0,
+        votes_for_refund_split: 0,
+        refund_split_sum: 0,
+        votes_for_malicious: 0,
+        votes_for_split_award: 0,
+        min_votes: min_votes.max(known_parameter(env, &PARAM_MIN_VOTES) as u32),
+        tie_break_method: tie_break_method.unwrap_or_else(|| default_tie_break_method(env)),
+        created_at: now,
+        voting_deadline: now.saturating_add(VOTING_PERIOD_SECS),
+        excluded_voters,
+        assigned_arbitrators: assigned_arbitrators.clone(),
+        tally: new_tally(),
+        arbitrator_count: assigned_arbitrators.len() as u32,
+        stake_discount_bps,
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(count), &dispute);
+    env.storage().instance().set(&DataKey::DisputeCount, &count);
+    bump_dispute_ttl(env, count);
+    bump_dispute_count_ttl(env);
+
+    // Initialize DisputeTally for O(1) verdict finalization
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisputeTally(count), &new_tally());
+    bump_dispute_tally_ttl(env, count);
+
+    // Store assigned arbitrators for this dispute
+    env.storage()
+        .persistent()
+        .set(&DataKey::Arbitrators(count), &assigned_arbitrators);
+    bump_arbitrators_ttl(env, count);
+
+    if job_ids.len() > 1 {
+        let key = DataKey::BundledJobs(count);
+        env.storage().persistent().set(&key, job_ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+    }
+
+    for job_id in job_ids.iter() {
+        // Maintain job → dispute_id mapping so callers can look up a dispute by job_id
+        env.storage()
+            .persistent()
+            .set(&DataKey::JobDispute(job_id), &count);
+        bump_job_dispute_ttl(env, job_id);
+
+        // Maintain job → dispute_ids list so callers can fetch historical disputes.
+        let list_key = DataKey::JobDisputes(job_id);
+        let mut dispute_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+        dispute_ids.push_back(count);
+        env.storage().persistent().set(&list_key, &dispute_ids);
+        bump_job_disputes_ttl(env, job_id);
+    }
+
+    if !stakes.is_empty() {
+        let key = DataKey::InitiatorStake(count);
+        env.storage().persistent().set(&key, &stakes);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+    }
+    for (token, amount) in stakes.iter() {
+        token::Client::new(env, &token).transfer(
+            &initiator,
+            &env.current_contract_address(),
+            &amount,
+        );
+        credit_pool(env, count, &initiator, &token, amount);
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("staked")),
+            (count, initiator.clone(), token, amount),
+        );
+    }
+
+    count_reason(env, dispute.reason_code);
+    if let Some(reason_hash) = reason_hash {
+        let key = DataKey::ReasonHash(count);
+        env.storage().persistent().set(&key, &reason_hash);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+    }
+
+    // Emit event with assigned arbitrators, unless the panel is private.
+    let published_panel = if voter_privacy(env) {
+        Vec::new(env)
+    } else {
+        assigned_arbitrators
+    };
+    env.events().publish(
+        (symbol_short!("dispute"), symbol_short!("raised")),
+        (count, job_id, initiator, client, freelancer, published_panel),
+    );
+    if job_ids.len() > 1 {
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("bundled")),
+            (count, job_ids.clone()),
+        );
+    }
+
+    // Notify the escrow contract so it can transition each job to Disputed and emit
+    // a structured escrow-side DisputeRaised event that indexers can consume. Each
+    // job is snapshotted first, so the snapshot shows its pre-dispute status.
+    // The freeze happens in this same transaction, so there is no gap in which
+    // the client could still approve milestones or claim a refund. A combined
+    // `frozen` event lists the jobs the escrow accepted; a job missing from it
+    // (e.g. this contract is not registered with the escrow) was not frozen.
+    if let Some(escrow_contract) = &escrow_contract {
+        let mut frozen = Vec::new(env);
+        for job_id in job_ids.iter() {
+            let snapshot_key = if job_id == dispute.job_id {
+                DataKey::JobSnapshot(count)
+            } else {
+                DataKey::BundledJobSnapshot(count, job_id)
+            };
+            record_job_snapshot(env, escrow_contract, &snapshot_key, job_id);
+            let marked = escrow::EscrowClient::new(env, escrow_contract)
+                .try_mark_job_disputed(&job_id, &count);
+            if matches!(marked, Ok(Ok(()))) {
+                frozen.push_back(job_id);
+            }
+        }
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("frozen")),
+            (count, escrow_contract.clone(), frozen),
+        );
+    }
+
+    Ok(count)
+}
+
 #[contract]
 pub struct DisputeContract;
 
@@ -679,6 +2047,7 @@ impl DisputeContract {
         reputation_contract: Address,
         min_voter_reputation: u32,
         escrow_contract: Address,
+        voter_privacy: bool,
     ) -> Result<(), DisputeError> {
         admin.require_auth();
 
@@ -704,6 +2073,11 @@ impl DisputeContract {
         env.storage()
             .instance()
             .set(&DataKey::ReputationSlashBps, &DEFAULT_REPUTATION_SLASH_BPS);
+        env.storage()
+            .instance()
+            .set(&DataKey::VoterPrivacy, &voter_privacy);
+        let salt: BytesN<32> = env.prng().gen();
+        env.storage().instance().set(&DataKey::VoterSalt, &salt);
 
         bump_dispute_count_ttl(&env);
 
@@ -750,22 +2124,66 @@ impl DisputeContract {
         Ok(())
     }
 
-    /// Set the minimum voter reputation threshold (admin only).
-    pub fn set_min_voter_reputation(
+    /// Give `account` a role (`Admin` role). Roles let operational duties sit
+    /// on separate keys; see [`Role`] for what each one allows.
+    pub fn grant_role(
         env: Env,
         admin: Address,
-        min_reputation: u32,
+        account: Address,
+        role: Role,
     ) -> Result<(), DisputeError> {
         admin.require_auth();
-        require_not_paused(&env)?;
+        require_admin(&env, &admin)?;
 
-        let stored_admin: Address = env
-            .storage()
+        env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .ok_or(DisputeError::NotInitialized)?;
+            .set(&DataKey::RoleMember(role, account.clone()), &true);
+        bump_dispute_count_ttl(&env);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("role_add")),
+            (admin, account, role),
+        );
+        Ok(())
+    }
+
+    /// Take a granted role back from `account` (`Admin` role). The address set
+    /// at `initialize` keeps every role regardless.
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        account: Address,
+        role: Role,
+    ) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::RoleMember(role, account.clone()));
+        bump_dispute_count_ttl(&env);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("role_del")),
+            (admin, account, role),
+        );
+        Ok(())
+    }
 
-        if admin != stored_admin {
+    /// Whether `account` may act in `role`, directly or through `Admin`.
+    pub fn has_role(env: Env, account: Address, role: Role) -> Result<bool, DisputeError> {
+        has_role(&env, &account, role)
+    }
+
+    /// Set the minimum voter reputation threshold (`ParameterManager` role).
+    pub fn set_min_voter_reputation(
+        env: Env,
+        admin: Address,
+        min_reputation: u32,
+    ) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        if !has_role(&env, &admin, Role::ParameterManager)? {
             return Err(DisputeError::Unauthorized);
         }
 
@@ -783,11 +2201,12 @@ impl DisputeContract {
         Ok(())
     }
 
-    /// Set the per-party-pair cooldown duration in seconds (admin only).
+    /// Set the per-party-pair cooldown duration in seconds (`ParameterManager`
+    /// role).
     pub fn set_cooldown_duration(env: Env, admin: Address, seconds: u64) -> Result<(), DisputeError> {
         admin.require_auth();
         require_not_paused(&env)?;
-        require_admin(&env, &admin)?;
+        require_role(&env, &admin, Role::ParameterManager)?;
 
         env.storage().instance().set(&DataKey::CooldownDuration, &seconds);
         bump_dispute_count_ttl(&env);
@@ -800,6 +2219,54 @@ impl DisputeContract {
         Ok(())
     }
 
+    /// Set a tunable parameter by name (`ParameterManager` role). Intended as the single
+    /// entry point for an external governance contract. Accepted names:
+    /// `mal_bps`, `min_votes`, `max_appl`, `appl_win`, `tie_pol`, `min_rep`,
+    /// `cooldown`, `slash_bps`, `ovrt_bps`, `max_miss`, `stake_bps`,
+    /// `exec_bps`, the per-tier stake discounts `disc_brz`, `disc_slv`,
+    /// `disc_gld` and `disc_plt`, the expiry settings `max_dur` and
+    /// `exp_res`, `min_open` and `ev_cutoff`; each is range-checked. Changes apply to
+    /// disputes raised, resolved or appealed afterwards.
+    pub fn set_parameter(
+        env: Env,
+        admin: Address,
+        param: Symbol,
+        value: i128,
+    ) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        require_role(&env, &admin, Role::ParameterManager)?;
+
+        let (min, max) = parameter_bounds(&param).ok_or(DisputeError::UnknownParameter)?;
+        if value < min || value > max {
+            return Err(DisputeError::ParameterOutOfBounds);
+        }
+        let old = known_parameter(&env, &param);
+
+        let instance = env.storage().instance();
+        if param == PARAM_MIN_VOTER_REPUTATION {
+            instance.set(&DataKey::MinVoterReputation, &(value as u32));
+        } else if param == PARAM_PARTY_COOLDOWN {
+            instance.set(&DataKey::CooldownDuration, &(value as u64));
+        } else if param == PARAM_SLASH_BPS {
+            instance.set(&DataKey::ReputationSlashBps, &(value as u32));
+        } else {
+            instance.set(&DataKey::Parameter(param.clone()), &value);
+        }
+        bump_dispute_count_ttl(&env);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("param_set")),
+            (param, old, value),
+        );
+        Ok(())
+    }
+
+    /// Current value of a tunable parameter (see `set_parameter`).
+    pub fn get_parameter(env: Env, param: Symbol) -> Result<i128, DisputeError> {
+        parameter(&env, &param).ok_or(DisputeError::UnknownParameter)
+    }
+
     /// Check if an address is eligible to vote based on reputation.
     pub fn is_eligible_voter(env: Env, voter: Address) -> Result<bool, DisputeError> {
         let reputation_contract: Address = env
@@ -833,6 +2300,8 @@ impl DisputeContract {
 
     /// Raise a dispute on a job. Either the client or freelancer can initiate.
     /// Automatically selects 5 random arbitrators from the pool to vote on this dispute.
+    /// `reason_code` classifies the dispute; `reason_hash` optionally commits to
+    /// a longer off-chain statement than `reason`.
     #[allow(clippy::too_many_arguments)]
     pub fn raise_dispute(
         env: Env,
@@ -841,152 +2310,96 @@ impl DisputeContract {
         freelancer: Address,
         initiator: Address,
         reason: String,
+        reason_code: ReasonCode,
+        reason_hash: Option<BytesN<32>>,
         min_votes: u32,
         tie_break_method: Option<TieBreakMethod>,
     ) -> Result<u64, DisputeError> {
         initiator.require_auth();
         require_not_paused(&env)?;
 
-        if initiator != client && initiator != freelancer {
-            return Err(DisputeError::InvalidParty);
-        }
+        open_dispute(
+            &env,
+            &vec![&env, job_id],
+            client,
+            freelancer,
+            initiator,
+            reason,
+            reason_code,
+            reason_hash,
+            min_votes,
+            tie_break_method,
+        )
+    }
 
-        if let Some(last_closed_at) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, u64>(&DataKey::LastDisputeClosedAt(job_id))
-        {
-            let now = env.ledger().timestamp();
-            if now <= last_closed_at.saturating_add(DISPUTE_COOLDOWN_SECS) {
-                return Err(DisputeError::DisputeCooldown);
+    /// Raise one dispute covering several escrow jobs between the same client
+    /// and freelancer. The jobs are snapshotted individually but share a single
+    /// panel and vote, and the outcome is applied to each job through its own
+    /// escrow callback. The first job is the dispute's `job_id`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn raise_bundled_dispute(
+        env: Env,
+        job_ids: Vec<u64>,
+        client: Address,
+        freelancer: Address,
+        initiator: Address,
+        reason: String,
+        reason_code: ReasonCode,
+        reason_hash: Option<BytesN<32>>,
+        min_votes: u32,
+        tie_break_method: Option<TieBreakMethod>,
+    ) -> Result<u64, DisputeError> {
+        initiator.require_auth();
+        require_not_paused(&env)?;
+
+        if job_ids.is_empty() {
+            return Err(DisputeError::InvalidBundle);
+        }
+        for (i, job_id) in job_ids.iter().enumerate() {
+            if job_ids.iter().skip(i + 1).any(|other| other == job_id) {
+                return Err(DisputeError::InvalidBundle);
             }
-            bump_last_dispute_closed_ttl(&env, job_id);
         }
 
-        // Per-party-pair cooldown: same client/freelancer pair cannot re-dispute until the
-        // configured window has elapsed since the last dispute resolved between them.
-        let party_cooldown: u64 = env
+        let escrow_contract: Address = env
             .storage()
             .instance()
-            .get(&DataKey::CooldownDuration)
-            .unwrap_or(DEFAULT_PARTY_COOLDOWN_SECS);
-        if let Some(last_ts) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, u64>(&DataKey::LastDisputeLedger(client.clone(), freelancer.clone()))
-        {
-            if env.ledger().timestamp() < last_ts.saturating_add(party_cooldown) {
-                return Err(DisputeError::DisputeCooldown);
+            .get(&DataKey::EscrowContract)
+            .ok_or(DisputeError::NotInitialized)?;
+        for job_id in job_ids.iter() {
+            let escrow = escrow::EscrowClient::new(&env, &escrow_contract);
+            let job = match escrow.try_get_job(&job_id) {
+                Ok(Ok(job)) => job,
+                _ => return Err(DisputeError::BundlePartyMismatch),
+            };
+            if job.client != client || job.freelancer != freelancer {
+                return Err(DisputeError::BundlePartyMismatch);
             }
-            bump_last_dispute_ledger_ttl(&env, &client, &freelancer);
         }
 
-        let mut count: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::DisputeCount)
-            .unwrap_or(0);
-        count += 1;
-
-        // Detect conflicts of interest by querying escrow contract
-        let excluded_voters = if let Some(escrow_contract) = env
-            .storage()
-            .instance()
-            .get::<DataKey, Address>(&DataKey::EscrowContract)
-        {
-            detect_conflicts(&env, &escrow_contract, &client, &freelancer)
-        } else {
-            Vec::<Address>::new(&env)
-        };
-
-        // Select 5 random arbitrators for this dispute
-        let assigned_arbitrators = select_arbitrators(&env, count, &excluded_voters, &client, &freelancer, 5);
-
-        let dispute = Dispute {
-            id: count,
-            job_id,
-            client: client.clone(),
-            freelancer: freelancer.clone(),
-            initiator: initiator.clone(),
+        open_dispute(
+            &env,
+            &job_ids,
+            client,
+            freelancer,
+            initiator,
             reason,
-            status: DisputeStatus::Open,
-            votes_for_client: 0,
-            votes_for_freelancer: 0,
-            votes_for_refund_split: 0,
-            refund_split_sum: 0,
-            votes_for_malicious: 0,
-            votes_for_split_award: 0,
-            min_votes: if min_votes < 3 { 3 } else { min_votes },
-            tie_break_method: tie_break_method.unwrap_or(TieBreakMethod::RefundBoth),
-            created_at: env.ledger().timestamp(),
-            voting_deadline: env.ledger().timestamp().saturating_add(VOTING_PERIOD_SECS),
-            excluded_voters,
-            assigned_arbitrators: assigned_arbitrators.clone(),
-            tally: new_tally(),
-            arbitrator_count: assigned_arbitrators.len() as u32,
-        };
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Dispute(count), &dispute);
-        env.storage().instance().set(&DataKey::DisputeCount, &count);
-        bump_dispute_ttl(&env, count);
-        bump_dispute_count_ttl(&env);
-        env.storage()
-            .persistent()
-            .set(&DataKey::Votes(count), &Vec::<Vote>::new(&env));
-        bump_votes_ttl(&env, count);
-
-        // Initialize DisputeTally for O(1) verdict finalization
-        env.storage()
-            .persistent()
-            .set(&DataKey::DisputeTally(count), &new_tally());
-        bump_dispute_tally_ttl(&env, count);
-
-        // Store assigned arbitrators for this dispute
-        env.storage()
-            .persistent()
-            .set(&DataKey::Arbitrators(count), &assigned_arbitrators);
-        bump_arbitrators_ttl(&env, count);
-
-        // Maintain job → dispute_id mapping so callers can look up a dispute by job_id
-        env.storage()
-            .persistent()
-            .set(&DataKey::JobDispute(job_id), &count);
-        bump_job_dispute_ttl(&env, job_id);
+            reason_code,
+            reason_hash,
+            min_votes,
+            tie_break_method,
+        )
+    }
 
-        // Maintain job → dispute_ids list so callers can fetch historical disputes.
-        let list_key = DataKey::JobDisputes(job_id);
-        let mut dispute_ids: Vec<u64> = env
+    /// Escrow jobs covered by a dispute: a single job unless it was raised
+    /// with `raise_bundled_dispute`.
+    pub fn get_dispute_jobs(env: Env, dispute_id: u64) -> Result<Vec<u64>, DisputeError> {
+        let dispute: Dispute = env
             .storage()
             .persistent()
-            .get(&list_key)
-            .unwrap_or(Vec::new(&env));
-        dispute_ids.push_back(count);
-        env.storage().persistent().set(&list_key, &dispute_ids);
-        bump_job_disputes_ttl(&env, job_id);
-
-        // Emit event with assigned arbitrators
-        env.events().publish(
-            (symbol_short!("dispute"), symbol_short!("raised")),
-            (count, job_id, initiator, client, freelancer, assigned_arbitrators),
-        );
-
-        // Notify the escrow contract so it can transition the job to Disputed and emit
-        // a structured escrow-side DisputeRaised event that indexers can consume.
-        if let Some(escrow_contract) = env
-            .storage()
-            .instance()
-            .get::<DataKey, Address>(&DataKey::EscrowContract)
-        {
-            let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
-                &escrow_contract,
-                &Symbol::new(&env, "mark_job_disputed"),
-                vec![&env, job_id.into_val(&env), count.into_val(&env)],
-            );
-        }
-
-        Ok(count)
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        Ok(dispute_job_ids(&env, &dispute))
     }
 
     /// Cast a vote on a dispute. Only assigned arbitrators can vote.
@@ -994,17 +2407,22 @@ impl DisputeContract {
     /// reputation threshold. When no reputation contract is configured, voting
     /// proceeds without a reputation check to allow graceful degradation.
     /// Auto-resolves the dispute when 3 votes are cast for the same decision.
+    /// `reason_hash` commits to the juror's off-chain rationale; `summary` is
+    /// an optional short excerpt of at most `MAX_VOTE_SUMMARY_LEN` bytes.
+    #[allow(clippy::too_many_arguments)]
     pub fn cast_vote(
         env: Env,
         dispute_id: u64,
         voter: Address,
         choice: VoteChoice,
-        reason: String,
+        reason_hash: BytesN<32>,
+        summary: Option<String>,
         nonce: u64,
     ) -> Result<(), DisputeError> {
         consume_nonce(&env, &voter, &Symbol::new(&env, "cast_vote"), nonce)?;
         voter.require_auth();
         require_not_paused(&env)?;
+        check_vote_summary(&summary)?;
 
         let mut dispute: Dispute = env
             .storage()
@@ -1016,6 +2434,7 @@ impl DisputeContract {
         if dispute.status != DisputeStatus::Open && dispute.status != DisputeStatus::Voting {
             return Err(DisputeError::VotingClosed);
         }
+        close_evidence_phase_if_due(&env, dispute_id, &dispute);
 
         // Resolve delegation first: if the voter is acting as a delegate, look up the
         // stake owner so the arbitrator-membership and double-vote checks use the owner.
@@ -1053,49 +2472,38 @@ impl DisputeContract {
         }
 
         // Prevent double-vote: check both the physical voter and the stake owner.
-        let voted_key = DataKey::HasVoted(dispute_id, voter.clone());
+        let voted_key = has_voted_key(&env, dispute_id, &voter);
         if env.storage().persistent().has(&voted_key) {
             return Err(DisputeError::AlreadyVoted);
         }
         if delegation_owner.is_some() {
-            let owner_voted_key = DataKey::HasVoted(dispute_id, stake_owner.clone());
+            let owner_voted_key = has_voted_key(&env, dispute_id, stake_owner);
             if env.storage().persistent().has(&owner_voted_key) {
                 return Err(DisputeError::AlreadyVoted);
             }
         }
 
-        // Maintain Voters set
-        let mut voters: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Voters(dispute_id))
-            .unwrap_or(Vec::new(&env));
-        if voters.contains(&voter) {
-            return Err(DisputeError::AlreadyVoted);
-        }
-        voters.push_back(voter.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::Voters(dispute_id), &voters);
-
         // Record vote
         let vote = Vote {
-            voter: voter.clone(),
+            voter: if voter_privacy(&env) {
+                None
+            } else {
+                Some(voter.clone())
+            },
+            voter_commitment: voter_commitment(&env, dispute_id, &voter),
             choice: choice.clone(),
-            reason,
+            reason_hash,
+            summary,
             timestamp: env.ledger().timestamp(),
         };
 
-        let mut votes: Vec<Vote> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Votes(dispute_id))
-            .unwrap_or(Vec::new(&env));
-        votes.push_back(vote);
-        env.storage()
-            .persistent()
-            .set(&DataKey::Votes(dispute_id), &votes);
-        bump_votes_ttl(&env, dispute_id);
+        // Only the counters on the dispute grow; the vote itself takes the
+        // next free index.
+        store_vote(
+            &env,
+            &DataKey::VoteAt(dispute_id, dispute.tally.vote_count),
+            &vote,
+        );
 
         match choice {
             VoteChoice::Client => dispute.votes_for_client += 1,
@@ -1122,7 +2530,7 @@ impl DisputeContract {
             .storage()
             .persistent()
             .get(&DataKey::DisputeTally(dispute_id))
-            .unwrap_or_else(|| new_tally());
+            .unwrap_or_else(new_tally);
         bump_dispute_tally_ttl(&env, dispute_id);
 
         // For now, weight = 1 for each vote (uniform weighting).
@@ -1158,53 +2566,199 @@ impl DisputeContract {
         // Store updated tally
         env.storage()
             .persistent()
-            .set(&DataKey::DisputeTally(dispute_id), &tally);
-        bump_dispute_tally_ttl(&env, dispute_id);
+            .set(&DataKey::DisputeTally(dispute_id), &tally);
+        bump_dispute_tally_ttl(&env, dispute_id);
+
+        // Update dispute tally field for consistency
+        dispute.tally = tally;
+
+        dispute.status = DisputeStatus::Voting;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Dispute(dispute_id), &dispute);
+        env.storage().persistent().set(&voted_key, &true);
+        let first_vote_key = DataKey::FirstVoteAt(dispute_id);
+        if !env.storage().persistent().has(&first_vote_key) {
+            env.storage()
+                .persistent()
+                .set(&first_vote_key, &env.ledger().timestamp());
+            env.storage().persistent().extend_ttl(
+                &first_vote_key,
+                MIN_TTL_THRESHOLD,
+                MIN_TTL_EXTEND_TO,
+            );
+        }
+        bump_dispute_ttl(&env, dispute_id);
+        bump_has_voted_ttl(&env, dispute_id, &voter);
+
+        // When voting via delegation, also mark the owner as having voted so they
+        // cannot vote again directly and the delegation cannot be reused.
+        if let Some(ref owner) = delegation_owner {
+            let owner_voted_key = has_voted_key(&env, dispute_id, owner);
+            env.storage().persistent().set(&owner_voted_key, &true);
+            bump_has_voted_ttl(&env, dispute_id, owner);
+        }
+
+        // Emit VoteCast event. In privacy mode the voter is identified by
+        // commitment only, and the choice is left out so a briber cannot match
+        // it to the juror's later reward claim.
+        if voter_privacy(&env) {
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("voted")),
+                (
+                    dispute_id,
+                    voter_commitment(&env, dispute_id, &voter),
+                    dispute.job_id,
+                    dispute.client.clone(),
+                    dispute.freelancer.clone(),
+                ),
+            );
+        } else {
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("voted")),
+                (dispute_id, voter.clone(), choice.clone(), dispute.job_id, dispute.client.clone(), dispute.freelancer.clone()),
+            );
+        }
+
+        // Auto-resolve if 3 votes reached for the same decision (majority threshold).
+        // If the minimum voting period has not elapsed the dispute stays open
+        // for a later `resolve_dispute`.
+        let escrow_addr: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract);
+
+        if let Some(escrow) = escrow_addr {
+            if dispute.votes_for_client >= 3
+                || dispute.votes_for_freelancer >= 3
+                || dispute.votes_for_refund_split >= 3
+                || dispute.votes_for_malicious >= 3
+                || dispute.votes_for_split_award >= 3
+            {
+                // Auto-resolve the dispute
+                let _ = internal_resolve(
+                    &env,
+                    dispute_id,
+                    &mut dispute,
+                    &escrow,
+                    false,
+                    if voter_privacy(&env) {
+                        None
+                    } else {
+                        Some(voter.clone())
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Step down from a dispute the juror was assigned to, before voting on
+    /// it. `reason_code` is an off-chain code for why (conflict, workload...).
+    /// The juror joins the dispute's exclusion list so neither they nor a
+    /// delegate can vote on it, and one replacement is drawn from the pool.
+    /// A recusal is counted against the juror but is not a missed dispute.
+    /// Returns the replacement, if the pool had an eligible juror left; in
+    /// privacy mode the replacement is neither returned nor published.
+    pub fn recuse(
+        env: Env,
+        dispute_id: u64,
+        juror: Address,
+        reason_code: u32,
+    ) -> Result<Option<Address>, DisputeError> {
+        juror.require_auth();
+        require_not_paused(&env)?;
+
+        let mut dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        bump_dispute_ttl(&env, dispute_id);
+
+        if dispute.status != DisputeStatus::Open && dispute.status != DisputeStatus::Voting {
+            return Err(DisputeError::VotingClosed);
+        }
+        let Some(index) = dispute.assigned_arbitrators.first_index_of(&juror) else {
+            return Err(DisputeError::Unauthorized);
+        };
+        if env
+            .storage()
+            .persistent()
+            .has(&has_voted_key(&env, dispute_id, &juror))
+        {
+            return Err(DisputeError::AlreadyVoted);
+        }
 
-        // Update dispute tally field for consistency
-        dispute.tally = tally;
+        dispute.assigned_arbitrators.remove(index);
+        if !dispute.excluded_voters.contains(&juror) {
+            dispute.excluded_voters.push_back(juror.clone());
+        }
+        let mut excluded = dispute.excluded_voters.clone();
+        excluded.append(&dispute.assigned_arbitrators);
+        let replacement = select_arbitrators(
+            &env,
+            dispute_id,
+            &excluded,
+            &dispute.client,
+            &dispute.freelancer,
+            1,
+        )
+        .first();
+        if let Some(replacement) = &replacement {
+            dispute.assigned_arbitrators.push_back(replacement.clone());
+        }
+        dispute.arbitrator_count = dispute.assigned_arbitrators.len();
 
-        dispute.status = DisputeStatus::Voting;
         env.storage()
             .persistent()
             .set(&DataKey::Dispute(dispute_id), &dispute);
-        env.storage().persistent().set(&voted_key, &true);
         bump_dispute_ttl(&env, dispute_id);
-        bump_has_voted_ttl(&env, dispute_id, &voter);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Arbitrators(dispute_id), &dispute.assigned_arbitrators);
+        bump_arbitrators_ttl(&env, dispute_id);
 
-        // When voting via delegation, also mark the owner as having voted so they
-        // cannot vote again directly and the delegation cannot be reused.
-        if let Some(ref owner) = delegation_owner {
-            let owner_voted_key = DataKey::HasVoted(dispute_id, owner.clone());
-            env.storage().persistent().set(&owner_voted_key, &true);
-            bump_has_voted_ttl(&env, dispute_id, owner);
-        }
+        let recusal_key = DataKey::Recusal(dispute_id, juror.clone());
+        env.storage().persistent().set(&recusal_key, &reason_code);
+        env.storage()
+            .persistent()
+            .extend_ttl(&recusal_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+        let count_key = DataKey::JurorRecusals(juror.clone());
+        let recusals = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&count_key)
+            .unwrap_or(0)
+            .saturating_add(1);
+        env.storage().persistent().set(&count_key, &recusals);
+        env.storage()
+            .persistent()
+            .extend_ttl(&count_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
 
-        // Emit VoteCast event
+        let replacement = replacement.filter(|_| !voter_privacy(&env));
         env.events().publish(
-            (symbol_short!("dispute"), symbol_short!("voted")),
-            (dispute_id, voter.clone(), choice.clone(), dispute.job_id, dispute.client.clone(), dispute.freelancer.clone()),
+            (symbol_short!("dispute"), symbol_short!("recused")),
+            (dispute_id, juror, reason_code, replacement.clone()),
         );
 
-        // Auto-resolve if 3 votes reached for the same decision (majority threshold)
-        let escrow_addr: Option<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::EscrowContract);
+        Ok(replacement)
+    }
 
-        if let Some(escrow) = escrow_addr {
-            if dispute.votes_for_client >= 3
-                || dispute.votes_for_freelancer >= 3
-                || dispute.votes_for_refund_split >= 3
-                || dispute.votes_for_malicious >= 3
-                || dispute.votes_for_split_award >= 3
-            {
-                // Auto-resolve the dispute
-                let _ = internal_resolve(&env, dispute_id, &mut dispute, &escrow, false);
-            }
-        }
+    /// Reason code `juror` gave when recusing from `dispute_id`, if they did.
+    pub fn get_recusal(env: Env, dispute_id: u64, juror: Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Recusal(dispute_id, juror))
+    }
 
-        Ok(())
+    /// Number of disputes `juror` has recused from.
+    pub fn get_juror_recusal_count(env: Env, juror: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::JurorRecusals(juror))
+            .unwrap_or(0)
     }
 
     /// Add a voter to the exclusion list for a dispute (only during Open status).
@@ -1261,6 +2815,9 @@ impl DisputeContract {
         Ok(())
     }
 
+    /// Resolve a dispute that has reached its quorum. When `min_open` is set,
+    /// the dispute must also have been open that many seconds since its first
+    /// vote; until then this fails with `MinVotingPeriodNotElapsed`.
     pub fn resolve_dispute(env: Env, dispute_id: u64) -> Result<DisputeStatus, DisputeError> {
         require_not_paused(&env)?;
 
@@ -1277,7 +2834,7 @@ impl DisputeContract {
             .get(&DataKey::EscrowContract)
             .ok_or(DisputeError::NotInitialized)?;
 
-        internal_resolve(&env, dispute_id, &mut dispute, &escrow_addr, false)
+        internal_resolve(&env, dispute_id, &mut dispute, &escrow_addr, false, None)
     }
 
     /// Force resolution of a dispute after the voting deadline has passed,
@@ -1303,11 +2860,236 @@ impl DisputeContract {
             return Err(DisputeError::VotingPeriodNotExpired);
         }
 
-        internal_resolve(&env, dispute_id, &mut dispute, &escrow_addr, true)
+        internal_resolve(&env, dispute_id, &mut dispute, &escrow_addr, true, None)
+    }
+
+    /// Resolve a dispute whose voting deadline has passed, as
+    /// `force_resolve_timeout` does, and owe `executor` the `exec_bps` bounty
+    /// from the dispute's pool. Anyone may call this, so keepers can settle
+    /// disputes nobody else gets round to. The bounty is paid with the rest of
+    /// the pool's held funds once the appeal window closes; none is owed if
+    /// the escrow callback fails and the dispute ends up `ResolutionFailed`.
+    pub fn execute_resolution(
+        env: Env,
+        dispute_id: u64,
+        executor: Address,
+    ) -> Result<DisputeStatus, DisputeError> {
+        executor.require_auth();
+        require_not_paused(&env)?;
+
+        let mut dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        bump_dispute_ttl(&env, dispute_id);
+
+        let escrow_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::EscrowContract)
+            .ok_or(DisputeError::NotInitialized)?;
+
+        if env.ledger().timestamp() < dispute.voting_deadline {
+            return Err(DisputeError::VotingPeriodNotExpired);
+        }
+
+        let status = internal_resolve(&env, dispute_id, &mut dispute, &escrow_addr, true, None)?;
+        if status != DisputeStatus::ResolutionFailed {
+            let key = DataKey::PendingBounty(dispute_id);
+            env.storage().persistent().set(&key, &executor);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+        }
+        Ok(status)
+    }
+
+    /// Close a dispute that outlived the `max_dur` lifetime (60 days by default)
+    /// without a final outcome: still open or voting, or escalated with no
+    /// appeal pending. Anyone may call this. Every job is settled through the
+    /// escrow with the `exp_res` outcome (`RefundBoth` by default), and the
+    /// dispute ends `Expired`. Stakes and pool deposits then become
+    /// refundable: each depositor takes theirs back with `claim_pool_refund`.
+    ///
+    /// The dispute closes even if the escrow rejects the callback, so funds
+    /// held here are never stuck behind it; the escrow job can still be
+    /// expired on its own terms.
+    pub fn expire_dispute(env: Env, dispute_id: u64) -> Result<DisputeResolution, DisputeError> {
+        require_not_paused(&env)?;
+
+        let mut dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        bump_dispute_ttl(&env, dispute_id);
+
+        if !matches!(
+            dispute.status,
+            DisputeStatus::Open | DisputeStatus::Voting | DisputeStatus::Escalated
+        ) {
+            return Err(DisputeError::AlreadyResolved);
+        }
+        if let Some(appeal_id) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::DisputeAppeal(dispute_id))
+        {
+            let appeal: Option<Appeal> =
+                env.storage().persistent().get(&DataKey::Appeal(appeal_id));
+            if matches!(
+                appeal.map(|ap| ap.status),
+                Some(AppealStatus::Open | AppealStatus::Voting)
+            ) {
+                return Err(DisputeError::AppealInProgress);
+            }
+        }
+        if env.ledger().timestamp() < dispute_expires_at(&env, &dispute) {
+            return Err(DisputeError::DisputeNotExpired);
+        }
+
+        if matches!(dispute.status, DisputeStatus::Open | DisputeStatus::Voting) {
+            record_juror_participation(&env, dispute_id, &dispute);
+        }
+
+        let resolution = match known_parameter(&env, &PARAM_EXPIRY_RESOLUTION) {
+            1 => DisputeResolution::ClientWins,
+            2 => DisputeResolution::FreelancerWins,
+            _ => DisputeResolution::RefundBoth,
+        };
+        if let Some(escrow_addr) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::EscrowContract)
+        {
+            if !notify_escrow(&env, &escrow_addr, &dispute, &resolution) {
+                env.events().publish(
+                    (symbol_short!("dispute"), Symbol::new(&env, "escrow_fail")),
+                    (dispute_id, dispute.job_id),
+                );
+            }
+        }
+
+        dispute.status = DisputeStatus::Expired;
+        mark_jobs_closed(&env, &dispute);
+        env.storage().persistent().set(
+            &DataKey::LastDisputeLedger(dispute.client.clone(), dispute.freelancer.clone()),
+            &env.ledger().timestamp(),
+        );
+        bump_last_dispute_ledger_ttl(&env, &dispute.client, &dispute.freelancer);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Dispute(dispute_id), &dispute);
+        bump_dispute_ttl(&env, dispute_id);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("expired")),
+            (dispute_id, dispute.job_id, resolution.clone()),
+        );
+        Ok(resolution)
+    }
+
+    /// Timestamp from which `expire_dispute` may close the dispute.
+    pub fn get_dispute_expiry(env: Env, dispute_id: u64) -> Result<u64, DisputeError> {
+        let dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        Ok(dispute_expires_at(&env, &dispute))
+    }
+
+    /// Simulate `resolve_dispute` against the current tallies without writing
+    /// state or moving tokens. Amounts mirror the escrow's payout for the
+    /// outcome, based on the job's value not yet released to the freelancer.
+    pub fn preview_resolution(
+        env: Env,
+        dispute_id: u64,
+    ) -> Result<ResolutionPreview, DisputeError> {
+        let dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        if !matches!(dispute.status, DisputeStatus::Open | DisputeStatus::Voting) {
+            return Err(DisputeError::AlreadyResolved);
+        }
+
+        let total_votes = dispute.votes_for_client
+            + dispute.votes_for_freelancer
+            + dispute.votes_for_refund_split
+            + dispute.votes_for_malicious
+            + dispute.votes_for_split_award;
+        let outcome = compute_outcome(&env, &dispute);
+        let resolution = status_to_resolution(&outcome.status);
+
+        let amount_at_stake: i128 = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::EscrowContract)
+            .map(|escrow_addr| {
+                dispute_job_ids(&env, &dispute)
+                    .iter()
+                    .filter_map(|job_id| {
+                        escrow::EscrowClient::new(&env, &escrow_addr)
+                            .try_get_job(&job_id)
+                            .ok()
+                            .and_then(|r| r.ok())
+                    })
+                    .map(|job| disputed_remainder(&job))
+                    .sum()
+            })
+            .unwrap_or(0);
+
+        let (to_client, to_freelancer, to_treasury) = match resolution {
+            DisputeResolution::ClientWins => (amount_at_stake, 0, 0),
+            DisputeResolution::FreelancerWins => (0, amount_at_stake, 0),
+            DisputeResolution::RefundBoth => {
+                let half = amount_at_stake / 2;
+                (half, amount_at_stake - half, 0)
+            }
+            DisputeResolution::RefundSplit(pct) => {
+                let client = amount_at_stake * pct.min(100) as i128 / 100;
+                (client, amount_at_stake - client, 0)
+            }
+            DisputeResolution::SplitAward(bps) => {
+                let client = amount_at_stake * bps.min(10_000) as i128 / 10_000;
+                (client, amount_at_stake - client, 0)
+            }
+            DisputeResolution::MaliciousFiling => (0, 0, amount_at_stake),
+            DisputeResolution::Escalate => (0, 0, 0),
+        };
+        let winner = match resolution {
+            DisputeResolution::ClientWins => Some(dispute.client.clone()),
+            DisputeResolution::FreelancerWins => Some(dispute.freelancer.clone()),
+            DisputeResolution::MaliciousFiling => Some(if dispute.initiator == dispute.client {
+                dispute.freelancer.clone()
+            } else {
+                dispute.client.clone()
+            }),
+            _ => None,
+        };
+
+        Ok(ResolutionPreview {
+            resolvable: total_votes >= quorum(&env, dispute_id, &dispute),
+            status: outcome.status,
+            resolution,
+            winner,
+            malicious: outcome.malicious,
+            tie_break_applied: outcome.tie_break_applied,
+            amount_at_stake,
+            to_client,
+            to_freelancer,
+            to_treasury,
+        })
     }
 
     /// File an appeal on a resolved dispute within the 48-hour appeal window.
-    /// Only the client or freelancer may appeal. Each dispute can be appealed at most once.
+    /// Only the party that lost the latest round may appeal (either party after
+    /// a split or escalated outcome), never the same outcome twice, and only
+    /// once the previous appeal has been resolved. Each dispute can be appealed
+    /// `max_appl` times (once by default).
     /// The original arbitrators are excluded from the appeal panel.
     pub fn appeal(
         env: Env,
@@ -1339,24 +3121,71 @@ impl DisputeContract {
         if !is_resolved {
             return Err(DisputeError::VotingClosed);
         }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::FinalizedAt(dispute_id))
+        {
+            return Err(DisputeError::AlreadyFinalized);
+        }
 
-        // Enforce 48-hour appeal window from when the dispute was closed.
+        // Enforce the appeal window (48 hours by default) from when the dispute was closed.
         let resolved_at: u64 = env
             .storage()
             .persistent()
             .get(&DataKey::LastDisputeClosedAt(dispute.job_id))
             .unwrap_or(0);
-        if env.ledger().timestamp() > resolved_at.saturating_add(APPEAL_WINDOW_SECS) {
+        let appeal_window = known_parameter(&env, &PARAM_APPEAL_WINDOW) as u64;
+        if env.ledger().timestamp() > resolved_at.saturating_add(appeal_window) {
             return Err(DisputeError::AppealWindowExpired);
         }
 
-        // Each dispute may only be appealed once.
-        if env.storage().persistent().has(&DataKey::DisputeAppeal(dispute_id)) {
+        // Each dispute may be appealed at most `max_appl` times (once by
+        // default, never when governance disables appeals).
+        let mut rounds: Vec<AppealRound> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AppealRounds(dispute_id))
+            .unwrap_or(Vec::new(&env));
+        let latest_appeal: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeAppeal(dispute_id));
+        // Appeals filed before rounds were tracked left no `AppealRounds` entry.
+        let appeals_filed = rounds.len().max(latest_appeal.is_some() as u32) as i128;
+        if appeals_filed >= known_parameter(&env, &PARAM_MAX_APPEALS) {
             return Err(DisputeError::AlreadyAppealed);
         }
+        if let Some(latest_id) = latest_appeal {
+            let latest: Option<Appeal> =
+                env.storage().persistent().get(&DataKey::Appeal(latest_id));
+            if matches!(
+                latest.map(|ap| ap.status),
+                Some(AppealStatus::Open | AppealStatus::Voting)
+            ) {
+                return Err(DisputeError::AppealInProgress);
+            }
+        }
+
+        // Only the loser of the latest round may appeal it; split and escalated
+        // outcomes may be appealed by either party.
+        let loser_only = match dispute.status {
+            DisputeStatus::ResolvedForClient => Some(&dispute.freelancer),
+            DisputeStatus::ResolvedForFreelancer => Some(&dispute.client),
+            _ => None,
+        };
+        if loser_only.is_some_and(|loser| *loser != appellant) {
+            return Err(DisputeError::NotLosingParty);
+        }
+        if rounds
+            .iter()
+            .any(|r| r.appellant == appellant && r.appealed_outcome == dispute.status)
+        {
+            return Err(DisputeError::OutcomeAlreadyAppealed);
+        }
 
         // Snapshot the original arbitrators to exclude them from the appeal panel.
-        let excluded = Self::get_arbitrators(env.clone(), dispute_id);
+        let excluded = dispute_voters(&env, &dispute);
 
         let mut appeal_count: u64 = env
             .storage()
@@ -1378,14 +3207,24 @@ impl DisputeContract {
             refund_split_sum: 0,
             created_at: now,
             voting_deadline: now.saturating_add(VOTING_PERIOD_SECS),
+            round: appeals_filed as u32 + 1,
         };
+        rounds.push_back(AppealRound {
+            appeal_id: appeal_count,
+            appellant: appellant.clone(),
+            appealed_outcome: dispute.status.clone(),
+        });
 
         env.storage().persistent().set(&DataKey::Appeal(appeal_count), &new_appeal);
         env.storage().instance().set(&DataKey::AppealCount, &appeal_count);
         env.storage().persistent().set(&DataKey::DisputeAppeal(dispute_id), &appeal_count);
-        env.storage().persistent().set(&DataKey::AppealVotes(appeal_count), &Vec::<Vote>::new(&env));
+        env.storage().persistent().set(&DataKey::AppealRounds(dispute_id), &rounds);
+        env.storage().persistent().extend_ttl(
+            &DataKey::AppealRounds(dispute_id),
+            MIN_TTL_THRESHOLD,
+            MIN_TTL_EXTEND_TO,
+        );
         bump_appeal_ttl(&env, appeal_count);
-        bump_appeal_votes_ttl(&env, appeal_count);
         bump_dispute_appeal_ttl(&env, dispute_id);
         bump_dispute_count_ttl(&env);
 
@@ -1398,15 +3237,18 @@ impl DisputeContract {
     }
 
     /// Cast a vote on an appeal. Original arbitrators from the base dispute are excluded.
+    /// The rationale is recorded as in `cast_vote`.
     pub fn cast_appeal_vote(
         env: Env,
         appeal_id: u64,
         voter: Address,
         choice: VoteChoice,
-        reason: String,
+        reason_hash: BytesN<32>,
+        summary: Option<String>,
     ) -> Result<(), DisputeError> {
         voter.require_auth();
         require_not_paused(&env)?;
+        check_vote_summary(&summary)?;
 
         let mut ap: Appeal = env
             .storage()
@@ -1441,20 +3283,20 @@ impl DisputeContract {
         }
 
         let vote = Vote {
-            voter: voter.clone(),
+            voter: if voter_privacy(&env) {
+                None
+            } else {
+                Some(voter.clone())
+            },
+            voter_commitment: voter_commitment(&env, ap.dispute_id, &voter),
             choice: choice.clone(),
-            reason,
+            reason_hash,
+            summary,
             timestamp: env.ledger().timestamp(),
         };
 
-        let mut votes: Vec<Vote> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AppealVotes(appeal_id))
-            .unwrap_or(Vec::new(&env));
-        votes.push_back(vote);
-        env.storage().persistent().set(&DataKey::AppealVotes(appeal_id), &votes);
-        bump_appeal_votes_ttl(&env, appeal_id);
+        let cast = ap.votes_for_client + ap.votes_for_freelancer + ap.votes_for_refund_split;
+        store_vote(&env, &DataKey::AppealVoteAt(appeal_id, cast), &vote);
 
         match choice {
             VoteChoice::Client => ap.votes_for_client += 1,
@@ -1486,8 +3328,13 @@ impl DisputeContract {
     }
 
     /// Resolve an appeal once enough votes have been cast.
-    /// The decision is binding — it overwrites the original dispute's resolution.
-    /// The losing party's reputation is slashed at double the normal rate.
+    /// An outcome different from the original resolution only takes effect if
+    /// it carries at least the `ovrt_bps` share of appeal votes (60% by
+    /// default); otherwise the appeal is `Upheld` and the original resolution
+    /// stands. Either way the round counts toward the dispute's appeal limit.
+    /// An effective decision is binding — it overwrites the original dispute's
+    /// resolution and the losing party's reputation is slashed at double the
+    /// normal rate.
     pub fn resolve_appeal(env: Env, appeal_id: u64) -> Result<AppealStatus, DisputeError> {
         require_not_paused(&env)?;
 
@@ -1504,6 +3351,7 @@ impl DisputeContract {
                 | AppealStatus::ResolvedForFreelancer
                 | AppealStatus::RefundedBoth
                 | AppealStatus::Escalated
+                | AppealStatus::Upheld
         ) || matches!(ap.status, AppealStatus::RefundSplit(_));
         if already_resolved {
             return Err(DisputeError::AlreadyResolved);
@@ -1516,6 +3364,7 @@ impl DisputeContract {
         }
 
         // Determine the appeal outcome by plurality.
+        let mut tie_break_applied = false;
         if ap.votes_for_client > ap.votes_for_freelancer
             && ap.votes_for_client > ap.votes_for_refund_split
         {
@@ -1531,6 +3380,7 @@ impl DisputeContract {
             ap.status = AppealStatus::RefundSplit(avg as u32);
         } else {
             ap.status = AppealStatus::RefundedBoth;
+            tie_break_applied = true;
         }
 
         let resolution = match ap.status {
@@ -1555,6 +3405,85 @@ impl DisputeContract {
             AppealStatus::RefundSplit(pct) => DisputeStatus::RefundSplit(pct),
             _ => DisputeStatus::Escalated,
         };
+
+        // A different outcome only overturns the original resolution with a
+        // supermajority of the appeal panel.
+        let winning_votes = ap
+            .votes_for_client
+            .max(ap.votes_for_freelancer)
+            .max(ap.votes_for_refund_split);
+        let overturn_bps = known_parameter(&env, &PARAM_OVERTURN_BPS) as u64;
+        let overturns = dispute_outcome != dispute.status;
+        if overturns && (winning_votes as u64) * 10_000 < overturn_bps * total_votes as u64 {
+            ap.status = AppealStatus::Upheld;
+            store_resolution_record(
+                &env,
+                &ResolutionRecord {
+                    dispute_id: ap.dispute_id,
+                    round: ap.round,
+                    status: dispute.status.clone(),
+                    votes_for_client: ap.votes_for_client,
+                    votes_for_freelancer: ap.votes_for_freelancer,
+                    votes_for_refund_split: ap.votes_for_refund_split,
+                    votes_for_split_award: 0,
+                    votes_for_malicious: 0,
+                    client_weight: ap.votes_for_client as u64,
+                    freelancer_weight: ap.votes_for_freelancer as u64,
+                    total_weight_cast: total_votes as u64,
+                    malicious: false,
+                    tie_break_applied,
+                    tie_break_method: TieBreakMethod::RefundBoth,
+                    resolver: None,
+                    resolved_at: env.ledger().timestamp(),
+                },
+            );
+            mark_jobs_closed(&env, &dispute);
+            env.storage().persistent().set(&DataKey::Appeal(appeal_id), &ap);
+            bump_appeal_ttl(&env, appeal_id);
+
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("ap_upheld")),
+                (appeal_id, ap.dispute_id, winning_votes, total_votes),
+            );
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("ap_done")),
+                (appeal_id, ap.status.clone(), ap.dispute_id),
+            );
+            return Ok(ap.status);
+        }
+        let outcome_event = if overturns {
+            symbol_short!("ap_ovrtn")
+        } else {
+            symbol_short!("ap_upheld")
+        };
+        env.events().publish(
+            (symbol_short!("dispute"), outcome_event),
+            (appeal_id, ap.dispute_id, winning_votes, total_votes),
+        );
+
+        store_resolution_record(
+            &env,
+            &ResolutionRecord {
+                dispute_id: ap.dispute_id,
+                round: ap.round,
+                status: dispute_outcome.clone(),
+                votes_for_client: ap.votes_for_client,
+                votes_for_freelancer: ap.votes_for_freelancer,
+                votes_for_refund_split: ap.votes_for_refund_split,
+                votes_for_split_award: 0,
+                votes_for_malicious: 0,
+                client_weight: ap.votes_for_client as u64,
+                freelancer_weight: ap.votes_for_freelancer as u64,
+                total_weight_cast: total_votes as u64,
+                malicious: false,
+                tie_break_applied,
+                // Appeals fall back to a refund for both parties on a tie.
+                tie_break_method: TieBreakMethod::RefundBoth,
+                resolver: None,
+                resolved_at: env.ledger().timestamp(),
+            },
+        );
+
         dispute.status = dispute_outcome;
         env.storage().persistent().set(&DataKey::Dispute(ap.dispute_id), &dispute);
         bump_dispute_ttl(&env, ap.dispute_id);
@@ -1567,14 +3496,7 @@ impl DisputeContract {
                 .get(&DataKey::EscrowContract)
                 .ok_or(DisputeError::NotInitialized)?;
 
-            let escrow_ok = matches!(
-                env.try_invoke_contract::<(), soroban_sdk::Error>(
-                    &escrow_addr,
-                    &Symbol::new(&env, "resolve_dispute_callback"),
-                    vec![&env, dispute.job_id.into_val(&env), resolution.clone().into_val(&env)],
-                ),
-                Ok(Ok(_))
-            );
+            let escrow_ok = notify_escrow(&env, &escrow_addr, &dispute, &resolution);
 
             if !escrow_ok {
                 dispute.status = DisputeStatus::ResolutionFailed;
@@ -1652,6 +3574,7 @@ impl DisputeContract {
             }
         }
 
+        mark_jobs_closed(&env, &dispute);
         env.storage().persistent().set(&DataKey::Appeal(appeal_id), &ap);
         bump_appeal_ttl(&env, appeal_id);
 
@@ -1663,6 +3586,14 @@ impl DisputeContract {
         Ok(ap.status.clone())
     }
 
+    /// Appeal rounds filed on a dispute, oldest first.
+    pub fn get_appeal_rounds(env: Env, dispute_id: u64) -> Vec<AppealRound> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AppealRounds(dispute_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
     /// Get appeal details.
     pub fn get_appeal(env: Env, appeal_id: u64) -> Result<Appeal, DisputeError> {
         let ap: Appeal = env
@@ -1674,7 +3605,8 @@ impl DisputeContract {
         Ok(ap)
     }
 
-    /// Get dispute details.
+    /// Get dispute details. In privacy mode `assigned_arbitrators` is left
+    /// empty.
     pub fn get_dispute(env: Env, dispute_id: u64) -> Result<Dispute, DisputeError> {
         let dispute: Dispute = env
             .storage()
@@ -1682,7 +3614,7 @@ impl DisputeContract {
             .get(&DataKey::Dispute(dispute_id))
             .ok_or(DisputeError::DisputeNotFound)?;
         bump_dispute_ttl(&env, dispute_id);
-        Ok(dispute)
+        Ok(public_dispute(&env, dispute))
     }
 
     /// Look up the dispute associated with a given job ID.
@@ -1703,46 +3635,202 @@ impl DisputeContract {
             .get(&DataKey::Dispute(dispute_id))?;
         bump_dispute_ttl(&env, dispute_id);
 
-        Some(dispute)
+        Some(public_dispute(&env, dispute))
+    }
+
+    /// Look up all historical disputes associated with a job.
+    /// Returns an empty vec when no disputes have ever been raised.
+    pub fn get_disputes_for_job(env: Env, job_id: u64) -> Vec<Dispute> {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::JobDisputes(job_id))
+            .unwrap_or(Vec::new(&env));
+        bump_job_disputes_ttl(&env, job_id);
+
+        let mut disputes = Vec::<Dispute>::new(&env);
+        for id in ids.iter() {
+            if let Some(dispute) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Dispute>(&DataKey::Dispute(id))
+            {
+                bump_dispute_ttl(&env, id);
+                disputes.push_back(public_dispute(&env, dispute));
+            }
+        }
+
+        disputes
+    }
+
+    /// Get all votes for a dispute; `get_votes_page` reads a large one in
+    /// pages.
+    /// Fails with `VotesPrivate` when voter privacy is enabled; use
+    /// `get_public_votes` instead.
+    pub fn get_votes(env: Env, dispute_id: u64) -> Result<Vec<Vote>, DisputeError> {
+        Self::get_votes_page(env, dispute_id, 0, u32::MAX)
+    }
+
+    /// Votes cast on a dispute in casting order, `limit` starting at
+    /// `offset`. Same visibility as `get_votes`.
+    pub fn get_votes_page(
+        env: Env,
+        dispute_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Vote>, DisputeError> {
+        if voter_privacy(&env) {
+            return Err(DisputeError::VotesPrivate);
+        }
+        let cast = env
+            .storage()
+            .persistent()
+            .get::<_, Dispute>(&DataKey::Dispute(dispute_id))
+            .map_or(0, |dispute| dispute.tally.vote_count);
+        Ok(load_votes(&env, dispute_id, cast, offset, limit))
+    }
+
+    /// Get all votes with voters identified by salted commitment. Raw
+    /// addresses are included only when voter privacy is disabled. In privacy
+    /// mode the list stays empty until the dispute's rewards unlock, so a
+    /// juror's direction cannot be tied to their commitment while the outcome
+    /// can still change; the tallies on the dispute remain readable.
+    pub fn get_public_votes(env: Env, dispute_id: u64) -> Vec<PublicVote> {
+        let private = voter_privacy(&env);
+        let mut public = Vec::new(&env);
+        let Some(dispute) = env
+            .storage()
+            .persistent()
+            .get::<_, Dispute>(&DataKey::Dispute(dispute_id))
+        else {
+            return public;
+        };
+        if private && !rewards_unlocked(&env, &dispute) {
+            return public;
+        }
+        let votes = dispute_votes(&env, &dispute);
+        for vote in votes.iter() {
+            public.push_back(PublicVote {
+                voter_commitment: vote.voter_commitment,
+                voter: if private { None } else { vote.voter },
+                choice: vote.choice,
+                reason_hash: vote.reason_hash,
+                summary: vote.summary,
+                timestamp: vote.timestamp,
+            });
+        }
+        public
+    }
+
+    /// Whether voter privacy was enabled at initialize.
+    pub fn is_voter_privacy_enabled(env: Env) -> bool {
+        voter_privacy(&env)
     }
 
-    /// Look up all historical disputes associated with a job.
-    /// Returns an empty vec when no disputes have ever been raised.
-    pub fn get_disputes_for_job(env: Env, job_id: u64) -> Vec<Dispute> {
-        let ids: Vec<u64> = env
+    /// Claim `voter`'s equal share of every token in the dispute's pool once
+    /// the dispute is resolved and its appeal window has closed, so a claim
+    /// cannot prove a vote while the outcome is still open. The first claim
+    /// settles the pool's held funds (initiator stake, executor bounty)
+    /// against the final outcome before shares are fixed. Only voters on the winning side are eligible
+    /// (every voter on a tie refunded to both parties). Eligibility is checked
+    /// by matching the caller's commitment against the stored votes, so it
+    /// works the same in privacy mode.
+    ///
+    /// Appeal bonds and incentive pools may be funded in a different token
+    /// than the dispute fee, so each token is settled independently and the
+    /// `(token, amount)` pairs transferred are returned. Tokens credited to the
+    /// pool after an earlier claim can be claimed by calling again.
+    pub fn claim_voter_reward(
+        env: Env,
+        dispute_id: u64,
+        voter: Address,
+    ) -> Result<Vec<(Address, i128)>, DisputeError> {
+        voter.require_auth();
+        require_not_paused(&env)?;
+
+        let dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        if !rewards_unlocked(&env, &dispute) {
+            return Err(DisputeError::RewardsLocked);
+        }
+        settle_held_funds(&env, &dispute);
+        let votes = dispute_votes(&env, &dispute);
+
+        let commitment = voter_commitment(&env, dispute_id, &voter);
+        let mut winners: u32 = 0;
+        let mut is_winner = false;
+        for vote in votes.iter() {
+            if winning_choice_matches(&dispute.status, &vote.choice)? {
+                winners += 1;
+                if vote.voter_commitment == commitment {
+                    is_winner = true;
+                }
+            }
+        }
+        if !is_winner {
+            return Err(DisputeError::NotEligibleForReward);
+        }
+
+        let tokens: Vec<Address> = env
             .storage()
             .persistent()
-            .get(&DataKey::JobDisputes(job_id))
+            .get(&DataKey::PoolTokens(dispute_id))
             .unwrap_or(Vec::new(&env));
-        bump_job_disputes_ttl(&env, job_id);
-
-        let mut disputes = Vec::<Dispute>::new(&env);
-        for id in ids.iter() {
-            if let Some(dispute) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Dispute>(&DataKey::Dispute(id))
-            {
-                bump_dispute_ttl(&env, id);
-                disputes.push_back(dispute);
+        let mut paid: Vec<(Address, i128)> = Vec::new(&env);
+        let mut claimed_any = false;
+        for token in tokens.iter() {
+            let claimed_key = DataKey::RewardClaimed(dispute_id, commitment.clone(), token.clone());
+            if env.storage().persistent().has(&claimed_key) {
+                continue;
             }
-        }
+            claimed_any = true;
+
+            // Fix the per-voter share on the first claim so later claimants are
+            // not affected by earlier payouts shrinking the pool.
+            let share_key = DataKey::RewardShare(dispute_id, token.clone());
+            let share: i128 = match env.storage().persistent().get(&share_key) {
+                Some(share) => share,
+                None => {
+                    let share = pool_balance(&env, dispute_id, &token) / winners as i128;
+                    env.storage().persistent().set(&share_key, &share);
+                    share
+                }
+            };
+            env.storage()
+                .persistent()
+                .extend_ttl(&share_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
 
-        disputes
-    }
+            env.storage().persistent().set(&claimed_key, &true);
+            env.storage().persistent().extend_ttl(
+                &claimed_key,
+                MIN_TTL_THRESHOLD,
+                MIN_TTL_EXTEND_TO,
+            );
 
-    /// Get all votes for a dispute.
-    pub fn get_votes(env: Env, dispute_id: u64) -> Vec<Vote> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Votes(dispute_id))
-            .unwrap_or(Vec::new(&env))
+            if share > 0 {
+                debit_pool(&env, dispute_id, &token, &voter, share)?;
+                paid.push_back((token.clone(), share));
+            }
+
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("rwd_claim")),
+                (dispute_id, commitment.clone(), token, share),
+            );
+        }
+        if !tokens.is_empty() && !claimed_any {
+            return Err(DisputeError::RewardAlreadyClaimed);
+        }
+        Ok(paid)
     }
 
     /// Submit evidence for an active dispute.
     ///
     /// Only the client or freelancer involved in the dispute may submit evidence.
-    /// The dispute must be in `Open` or `Voting` status.
+    /// The dispute must be in `Open` or `Voting` status and before its
+    /// evidence cutoff (`get_evidence_cutoff`).
     /// Emits `EvidenceSubmitted` so indexers can track evidence without polling storage.
     pub fn submit_evidence(
         env: Env,
@@ -1767,6 +3855,9 @@ impl DisputeContract {
         if submitted_by != dispute.client && submitted_by != dispute.freelancer {
             return Err(DisputeError::InvalidParty);
         }
+        if env.ledger().timestamp() >= evidence_cutoff(&env, &dispute) {
+            return Err(DisputeError::EvidencePhaseClosed);
+        }
 
         let record = EvidenceRecord {
             submitted_by: submitted_by.clone(),
@@ -1793,6 +3884,28 @@ impl DisputeContract {
         Ok(())
     }
 
+    /// Time from which `submit_evidence` rejects new evidence for the dispute.
+    pub fn get_evidence_cutoff(env: Env, dispute_id: u64) -> Result<u64, DisputeError> {
+        let dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        Ok(evidence_cutoff(&env, &dispute))
+    }
+
+    /// Publish the `ev_closed` event for a dispute past its evidence cutoff,
+    /// if no vote has done so yet. Callable by anyone; returns whether the
+    /// event was published.
+    pub fn close_evidence_phase(env: Env, dispute_id: u64) -> Result<bool, DisputeError> {
+        let dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        Ok(close_evidence_phase_if_due(&env, dispute_id, &dispute))
+    }
+
     /// Get all evidence submitted for a dispute.
     pub fn get_evidence(env: Env, dispute_id: u64) -> Vec<EvidenceRecord> {
         let evidence = env
@@ -1806,21 +3919,89 @@ impl DisputeContract {
         evidence
     }
 
-    /// Get all arbitrators (voters) who have voted on a dispute.
-    pub fn get_arbitrators(env: Env, dispute_id: u64) -> Vec<Address> {
+    /// Stake an initiator would currently have to put at risk to dispute
+    /// escrow job `job_id`, in the job's token; `0` when staking is disabled.
+    pub fn get_required_stake(env: Env, job_id: u64) -> i128 {
         env.storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::EscrowContract)
+            .and_then(|escrow_contract| required_stake(&env, &escrow_contract, job_id))
+            .map_or(0, |(_, amount)| amount)
+    }
+
+    /// Escrow job as it stood when the dispute was raised; `None` if the job
+    /// could not be read at the time.
+    pub fn get_job_snapshot(env: Env, dispute_id: u64) -> Option<JobSnapshot> {
+        let snapshot = env
+            .storage()
+            .persistent()
+            .get(&DataKey::JobSnapshot(dispute_id));
+        if snapshot.is_some() {
+            bump_job_snapshot_ttl(&env, dispute_id);
+        }
+        snapshot
+    }
+
+    /// Escrow job `job_id` as it stood when dispute `dispute_id` was raised,
+    /// for any job of a bundled dispute; `None` if the job is not part of the
+    /// dispute or could not be read at the time.
+    pub fn get_bundled_job_snapshot(
+        env: Env,
+        dispute_id: u64,
+        job_id: u64,
+    ) -> Option<JobSnapshot> {
+        let key = DataKey::BundledJobSnapshot(dispute_id, job_id);
+        let snapshot = env.storage().persistent().get(&key);
+        if snapshot.is_some() {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+            return snapshot;
+        }
+        Self::get_job_snapshot(env, dispute_id).filter(|s| s.job_id == job_id)
+    }
+
+    /// Get all arbitrators (voters) who have voted on a dispute.
+    /// Fails with `VotesPrivate` when voter privacy is enabled.
+    pub fn get_arbitrators(env: Env, dispute_id: u64) -> Result<Vec<Address>, DisputeError> {
+        if voter_privacy(&env) {
+            return Err(DisputeError::VotesPrivate);
+        }
+        Ok(env
+            .storage()
             .persistent()
-            .get(&DataKey::Voters(dispute_id))
-            .unwrap_or(Vec::<Address>::new(&env))
+            .get::<_, Dispute>(&DataKey::Dispute(dispute_id))
+            .map_or(Vec::new(&env), |dispute| dispute_voters(&env, &dispute)))
     }
 
     /// Get the assigned arbitrators for a dispute (those assigned via assign_arbitrators).
     /// This is different from get_arbitrators which returns voters who have actually cast votes.
-    pub fn get_assigned_arbitrators(env: Env, dispute_id: u64) -> Vec<Address> {
-        env.storage()
+    /// Fails with `VotesPrivate` when voter privacy is enabled; jurors check
+    /// their own assignment with `is_on_panel`.
+    pub fn get_assigned_arbitrators(
+        env: Env,
+        dispute_id: u64,
+    ) -> Result<Vec<Address>, DisputeError> {
+        if voter_privacy(&env) {
+            return Err(DisputeError::VotesPrivate);
+        }
+        Ok(env
+            .storage()
             .persistent()
             .get(&DataKey::Arbitrators(dispute_id))
-            .unwrap_or(Vec::<Address>::new(&env))
+            .unwrap_or(Vec::<Address>::new(&env)))
+    }
+
+    /// Whether `juror` sits on the panel of `dispute_id`. In privacy mode only
+    /// the juror can ask.
+    pub fn is_on_panel(env: Env, dispute_id: u64, juror: Address) -> bool {
+        if voter_privacy(&env) {
+            juror.require_auth();
+        }
+        env.storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&DataKey::Arbitrators(dispute_id))
+            .is_some_and(|panel| panel.contains(&juror))
     }
 
     /// Get the DisputeTally for O(1) access to vote weights and counts.
@@ -1835,6 +4016,25 @@ impl DisputeContract {
         Ok(tally)
     }
 
+    /// Get the resolution record explaining the outcome of `round`
+    /// (`ROUND_ORIGINAL` or `ROUND_APPEAL`) for a dispute.
+    pub fn get_resolution(
+        env: Env,
+        dispute_id: u64,
+        round: u32,
+    ) -> Result<ResolutionRecord, DisputeError> {
+        let key = DataKey::Resolution(dispute_id, round);
+        let record = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(DisputeError::ResolutionNotFound)?;
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+        Ok(record)
+    }
+
     /// Finalize the verdict for a dispute using O(1) tally accumulator.
     /// This function reads the pre-computed DisputeTally and determines the winner
     /// without iterating over individual votes, ensuring constant-time complexity
@@ -1855,6 +4055,25 @@ impl DisputeContract {
             .unwrap_or(0)
     }
 
+    /// Hash of the off-chain reason statement given when the dispute was
+    /// raised, if any.
+    pub fn get_dispute_reason_hash(env: Env, dispute_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReasonHash(dispute_id))
+    }
+
+    /// Number of disputes raised under each `ReasonCode`, in code order. A
+    /// bundled dispute counts once.
+    pub fn get_dispute_counts_by_reason(env: Env) -> Vec<(ReasonCode, u32)> {
+        let counts = reason_counts(&env);
+        let mut result = Vec::new(&env);
+        for code in REASON_CODES {
+            result.push_back((code, counts.get(code as u32).unwrap_or(0)));
+        }
+        result
+    }
+
     /// Delegate vote rights for a specific job's dispute to another address.
     /// The owner retains token ownership; only the right to cast the vote is transferred.
     /// The delegation is scoped to a single job so the delegate cannot vote on other jobs.
@@ -1895,13 +4114,13 @@ impl DisputeContract {
                 }
 
                 // Reject if the delegate already cast a vote on this dispute.
-                let voted_key = DataKey::HasVoted(dispute_id, delegate.clone());
+                let voted_key = has_voted_key(&env, dispute_id, &delegate);
                 if env.storage().persistent().has(&voted_key) {
                     return Err(DisputeError::DelegateAlreadyVoted);
                 }
 
                 // Reject if the owner already voted directly.
-                let owner_voted_key = DataKey::HasVoted(dispute_id, owner.clone());
+                let owner_voted_key = has_voted_key(&env, dispute_id, &owner);
                 if env.storage().persistent().has(&owner_voted_key) {
                     return Err(DisputeError::AlreadyVoted);
                 }
@@ -1942,7 +4161,7 @@ impl DisputeContract {
             .persistent()
             .get::<DataKey, u64>(&DataKey::JobDispute(job_id))
         {
-            let voted_key = DataKey::HasVoted(dispute_id, delegate.clone());
+            let voted_key = has_voted_key(&env, dispute_id, &delegate);
             if env.storage().persistent().has(&voted_key) {
                 return Err(DisputeError::DelegateAlreadyVoted);
             }
@@ -1984,70 +4203,407 @@ impl DisputeContract {
         }
     }
 
-    /// Add an arbitrator to the pool (admin only).
+    /// Deposit `amount` of `token` into the reward pool of `dispute_id`
+    /// (fees, stakes, bonds). Funds are tracked per dispute and per token.
+    /// `token` must be allowlisted with `set_pool_token`, or already held by
+    /// the dispute's pool (the initiator's stake token).
+    pub fn fund_pool(
+        env: Env,
+        dispute_id: u64,
+        from: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), DisputeError> {
+        from.require_auth();
+        require_not_paused(&env)?;
+        if amount <= 0 {
+            return Err(DisputeError::InvalidAmount);
+        }
+        if !env.storage().persistent().has(&DataKey::Dispute(dispute_id)) {
+            return Err(DisputeError::DisputeNotFound);
+        }
+        let pool_tokens: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PoolTokens(dispute_id))
+            .unwrap_or(Vec::new(&env));
+        let allowed = env
+            .storage()
+            .instance()
+            .get(&DataKey::PoolTokenAllowed(token.clone()))
+            .unwrap_or(false);
+        if !allowed && !pool_tokens.contains(&token) {
+            panic_with_error!(&env, PoolError::TokenNotAllowed);
+        }
+
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        credit_pool(&env, dispute_id, &from, &token, amount);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("pool_in")),
+            (dispute_id, from, token, amount),
+        );
+        Ok(())
+    }
+
+    /// Take back what `depositor` put into the pool of an expired dispute in
+    /// `token`, stake included. Returns the amount refunded, which is cut short
+    /// if admin payouts already drained part of the pool.
+    pub fn claim_pool_refund(
+        env: Env,
+        dispute_id: u64,
+        depositor: Address,
+        token: Address,
+    ) -> Result<i128, DisputeError> {
+        depositor.require_auth();
+        require_not_paused(&env)?;
+        let dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        if dispute.status != DisputeStatus::Expired {
+            panic_with_error!(&env, PoolError::NotRefundable);
+        }
+        let deposit_key = DataKey::PoolDeposit(dispute_id, depositor.clone(), token.clone());
+        let Some(amount) = env.storage().persistent().get::<_, i128>(&deposit_key) else {
+            panic_with_error!(&env, PoolError::NoDeposit);
+        };
+        env.storage().persistent().remove(&deposit_key);
+
+        let refund = amount.min(pool_balance(&env, dispute_id, &token));
+        if refund > 0 {
+            debit_pool(&env, dispute_id, &token, &depositor, refund)?;
+        }
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("pool_rfnd")),
+            (dispute_id, depositor, token, refund),
+        );
+        Ok(refund)
+    }
+
+    /// What `depositor` has put into the pool of `dispute_id` in `token` and
+    /// not taken back.
+    pub fn get_pool_deposit(env: Env, dispute_id: u64, depositor: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PoolDeposit(dispute_id, depositor, token))
+            .unwrap_or(0)
+    }
+
+    /// Allow or disallow deposits of `token` into any dispute's pool
+    /// (`ParameterManager` role).
+    pub fn set_pool_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+        allowed: bool,
+    ) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        require_role(&env, &admin, Role::ParameterManager)?;
+
+        let key = DataKey::PoolTokenAllowed(token.clone());
+        if allowed {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+        bump_dispute_count_ttl(&env);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("pool_tok")),
+            (admin, token, allowed),
+        );
+        Ok(())
+    }
+
+    /// Pay `amount` of `token` from the pool of `dispute_id` to `recipient`
+    /// (`Resolver` role).
+    /// Fails with `PoolInsufficient` if the dispute's own pool cannot cover it.
+    pub fn payout_from_pool(
+        env: Env,
+        admin: Address,
+        dispute_id: u64,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        require_role(&env, &admin, Role::Resolver)?;
+
+        debit_pool(&env, dispute_id, &token, &recipient, amount)?;
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("pool_out")),
+            (dispute_id, recipient, token, amount),
+        );
+        Ok(())
+    }
+
+    /// Get the remaining pool balance of a dispute as `(token, amount)` pairs.
+    pub fn get_pool_balance(env: Env, dispute_id: u64) -> Vec<(Address, i128)> {
+        let tokens: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PoolTokens(dispute_id))
+            .unwrap_or(Vec::new(&env));
+        let mut balances = Vec::new(&env);
+        for token in tokens.iter() {
+            let amount = pool_balance(&env, dispute_id, &token);
+            balances.push_back((token, amount));
+        }
+        balances
+    }
+
+    /// Add an arbitrator to the pool (`Moderator` role).
     pub fn add_arbitrator(env: Env, admin: Address, arbitrator: Address) -> Result<(), DisputeError> {
         admin.require_auth();
         require_not_paused(&env)?;
-        require_admin(&env, &admin)?;
+        require_role(&env, &admin, Role::Moderator)?;
+
+        let mut pool: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ArbitratorPool)
+            .unwrap_or(Vec::new(&env));
+
+        if pool.contains(&arbitrator) {
+            return Err(DisputeError::DuplicateArbitrator);
+        }
+
+        pool.push_back(arbitrator.clone());
+        env.storage().instance().set(&DataKey::ArbitratorPool, &pool);
+        bump_dispute_count_ttl(&env);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("arb_added")),
+            (admin, arbitrator),
+        );
+
+        Ok(())
+    }
+
+    /// Remove an arbitrator from the pool (`Moderator` role).
+    pub fn remove_arbitrator(env: Env, admin: Address, arbitrator: Address) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        require_role(&env, &admin, Role::Moderator)?;
+
+        let pool: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ArbitratorPool)
+            .unwrap_or(Vec::new(&env));
+
+        let mut new_pool = Vec::<Address>::new(&env);
+        let mut removed = false;
+
+        for addr in pool.iter() {
+            if addr != arbitrator {
+                new_pool.push_back(addr);
+            } else {
+                removed = true;
+            }
+        }
+
+        if removed {
+            env.storage().instance().set(&DataKey::ArbitratorPool, &new_pool);
+            bump_dispute_count_ttl(&env);
+
+            let stake_key = DataKey::JurorStake(arbitrator.clone());
+            if let Some((token, amount)) = env
+                .storage()
+                .persistent()
+                .get::<_, (Address, i128)>(&stake_key)
+            {
+                env.storage().persistent().remove(&stake_key);
+                token::Client::new(&env, &token).transfer(
+                    &env.current_contract_address(),
+                    &arbitrator,
+                    &amount,
+                );
+            }
+
+            env.events().publish(
+                (symbol_short!("dispute"), symbol_short!("arb_rmvd")),
+                (admin, arbitrator),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Set the token and minimum amount juror applicants must stake
+    /// (`ParameterManager` role). Applications are refused until this is configured.
+    pub fn set_juror_stake(
+        env: Env,
+        admin: Address,
+        token: Address,
+        min_stake: i128,
+    ) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        require_role(&env, &admin, Role::ParameterManager)?;
+        if min_stake < 0 {
+            return Err(DisputeError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::JurorStakeConfig, &(token.clone(), min_stake));
+        bump_dispute_count_ttl(&env);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("jr_stake")),
+            (admin, token, min_stake),
+        );
+        Ok(())
+    }
+
+    /// Apply to join the arbitrator pool. The applicant stakes at least the
+    /// configured minimum, held by the contract, and joins the pending queue
+    /// until the admin approves or rejects the application.
+    pub fn apply_as_juror(
+        env: Env,
+        applicant: Address,
+        statement_hash: BytesN<32>,
+        stake: i128,
+    ) -> Result<(), DisputeError> {
+        applicant.require_auth();
+        require_not_paused(&env)?;
 
-        let mut pool: Vec<Address> = env
+        let (token, min_stake): (Address, i128) = env
             .storage()
             .instance()
-            .get(&DataKey::ArbitratorPool)
-            .unwrap_or(Vec::new(&env));
-
-        if pool.contains(&arbitrator) {
+            .get(&DataKey::JurorStakeConfig)
+            .ok_or(DisputeError::NotInitialized)?;
+        if stake < min_stake {
+            return Err(DisputeError::InsufficientStake);
+        }
+        if Self::get_arbitrator_pool(env.clone()).contains(&applicant) {
             return Err(DisputeError::DuplicateArbitrator);
         }
+        let key = DataKey::JurorApplication(applicant.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(DisputeError::ApplicationPending);
+        }
 
-        pool.push_back(arbitrator.clone());
-        env.storage().instance().set(&DataKey::ArbitratorPool, &pool);
+        if stake > 0 {
+            token::Client::new(&env, &token).transfer(
+                &applicant,
+                &env.current_contract_address(),
+                &stake,
+            );
+        }
+        let application = JurorApplication {
+            applicant: applicant.clone(),
+            statement_hash,
+            token,
+            stake,
+            applied_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &application);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+        let mut queue = pending_jurors(&env);
+        queue.push_back(applicant.clone());
+        env.storage().instance().set(&DataKey::PendingJurors, &queue);
         bump_dispute_count_ttl(&env);
 
         env.events().publish(
-            (symbol_short!("dispute"), symbol_short!("arb_added")),
-            (admin, arbitrator),
+            (symbol_short!("dispute"), symbol_short!("jr_apply")),
+            (applicant, stake),
         );
-
         Ok(())
     }
 
-    /// Remove an arbitrator from the pool (admin only).
-    pub fn remove_arbitrator(env: Env, admin: Address, arbitrator: Address) -> Result<(), DisputeError> {
+    /// Approve a pending juror application (`Moderator` role): the applicant
+    /// joins the arbitrator pool and their stake stays held until they are removed.
+    pub fn approve_juror(env: Env, admin: Address, applicant: Address) -> Result<(), DisputeError> {
         admin.require_auth();
         require_not_paused(&env)?;
-        require_admin(&env, &admin)?;
-
-        let mut pool: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::ArbitratorPool)
-            .unwrap_or(Vec::new(&env));
-
-        let mut new_pool = Vec::<Address>::new(&env);
-        let mut removed = false;
+        require_role(&env, &admin, Role::Moderator)?;
 
-        for addr in pool.iter() {
-            if addr != arbitrator {
-                new_pool.push_back(addr);
-            } else {
-                removed = true;
-            }
+        let application = take_juror_application(&env, &applicant)?;
+        let mut pool = Self::get_arbitrator_pool(env.clone());
+        if !pool.contains(&applicant) {
+            pool.push_back(applicant.clone());
+            env.storage().instance().set(&DataKey::ArbitratorPool, &pool);
+        }
+        if application.stake > 0 {
+            let stake_key = DataKey::JurorStake(applicant.clone());
+            env.storage()
+                .persistent()
+                .set(&stake_key, &(application.token, application.stake));
+            env.storage()
+                .persistent()
+                .extend_ttl(&stake_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
         }
+        bump_dispute_count_ttl(&env);
 
-        if removed {
-            env.storage().instance().set(&DataKey::ArbitratorPool, &new_pool);
-            bump_dispute_count_ttl(&env);
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("jr_appr")),
+            (admin, applicant),
+        );
+        Ok(())
+    }
 
-            env.events().publish(
-                (symbol_short!("dispute"), symbol_short!("arb_rmvd")),
-                (admin, arbitrator),
+    /// Reject a pending juror application (`Moderator` role), refunding the stake.
+    pub fn reject_juror(env: Env, admin: Address, applicant: Address) -> Result<(), DisputeError> {
+        admin.require_auth();
+        require_not_paused(&env)?;
+        require_role(&env, &admin, Role::Moderator)?;
+
+        let application = take_juror_application(&env, &applicant)?;
+        if application.stake > 0 {
+            token::Client::new(&env, &application.token).transfer(
+                &env.current_contract_address(),
+                &applicant,
+                &application.stake,
             );
         }
+        bump_dispute_count_ttl(&env);
 
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("jr_rej")),
+            (admin, applicant, application.stake),
+        );
         Ok(())
     }
 
+    /// Pending juror applications in application order, `limit` (at most
+    /// `MAX_JUROR_PAGE`) starting at `offset`.
+    pub fn get_pending_jurors(env: Env, offset: u32, limit: u32) -> Vec<JurorApplication> {
+        let mut applications = Vec::new(&env);
+        for applicant in juror_page(pending_jurors(&env), offset, limit).iter() {
+            if let Some(application) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::JurorApplication(applicant))
+            {
+                applications.push_back(application);
+            }
+        }
+        applications
+    }
+
+    /// Arbitrators that can currently be selected for disputes (pool members
+    /// not marked inactive), `limit` (at most `MAX_JUROR_PAGE`) starting at
+    /// `offset`.
+    pub fn get_active_jurors(env: Env, offset: u32, limit: u32) -> Vec<Address> {
+        let mut active = Vec::new(&env);
+        for juror in Self::get_arbitrator_pool(env.clone()).iter() {
+            if !is_inactive_juror(&env, &juror) {
+                active.push_back(juror);
+            }
+        }
+        juror_page(active, offset, limit)
+    }
+
     /// Get the current arbitrator pool.
     pub fn get_arbitrator_pool(env: Env) -> Vec<Address> {
         env.storage()
@@ -2056,6 +4612,45 @@ impl DisputeContract {
             .unwrap_or(Vec::new(&env))
     }
 
+    /// Number of arbitrators in the pool that are not inactive, i.e. that can
+    /// be selected for new disputes.
+    pub fn get_active_juror_count(env: Env) -> u32 {
+        let mut count = 0;
+        for juror in Self::get_arbitrator_pool(env.clone()).iter() {
+            if !is_inactive_juror(&env, &juror) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Whether an arbitrator is currently pruned for inactivity.
+    pub fn is_juror_inactive(env: Env, juror: Address) -> bool {
+        is_inactive_juror(&env, &juror)
+    }
+
+    /// Return to the selection pool after being pruned for missing too many
+    /// consecutive disputes. The miss streak starts over.
+    pub fn reactivate_juror(env: Env, juror: Address) -> Result<(), DisputeError> {
+        juror.require_auth();
+        require_not_paused(&env)?;
+        if !is_inactive_juror(&env, &juror) {
+            return Err(DisputeError::JurorNotInactive);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::InactiveJuror(juror.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::JurorMisses(juror.clone()));
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("jur_react")),
+            juror,
+        );
+        Ok(())
+    }
+
     /// Retry the escrow callback for a dispute whose resolution previously failed.
     ///
     /// Permissionless and idempotent: safe to call repeatedly until escrow accepts.
@@ -2085,14 +4680,7 @@ impl DisputeContract {
             .get(&DataKey::EscrowContract)
             .ok_or(DisputeError::NotInitialized)?;
 
-        let escrow_ok = matches!(
-            env.try_invoke_contract::<(), soroban_sdk::Error>(
-                &escrow_addr,
-                &Symbol::new(&env, "resolve_dispute_callback"),
-                vec![&env, dispute.job_id.into_val(&env), resolution.clone().into_val(&env)],
-            ),
-            Ok(Ok(_))
-        );
+        let escrow_ok = notify_escrow(&env, &escrow_addr, &dispute, &resolution);
 
         if !escrow_ok {
             env.events().publish(
@@ -2108,11 +4696,7 @@ impl DisputeContract {
             .persistent()
             .remove(&DataKey::PendingResolution(dispute_id));
 
-        env.storage().persistent().set(
-            &DataKey::LastDisputeClosedAt(dispute.job_id),
-            &env.ledger().timestamp(),
-        );
-        bump_last_dispute_closed_ttl(&env, dispute.job_id);
+        mark_jobs_closed(&env, &dispute);
 
         env.storage().persistent().set(
             &DataKey::LastDisputeLedger(dispute.client.clone(), dispute.freelancer.clone()),
@@ -2139,6 +4723,112 @@ impl DisputeContract {
 
         Ok(dispute.status.clone())
     }
+
+    /// Make a resolved dispute's outcome final once its appeal window has
+    /// closed with no appeal pending. Anyone may call this. A dispute whose
+    /// escrow callback failed is retried first and stays `ResolutionFailed`,
+    /// unfinalized, if the escrow still rejects it. Finalizing settles the
+    /// pool's held funds, and finalized disputes can no longer be appealed.
+    pub fn finalize_after_appeal_window(
+        env: Env,
+        dispute_id: u64,
+    ) -> Result<DisputeStatus, DisputeError> {
+        require_not_paused(&env)?;
+
+        let mut dispute: Dispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound)?;
+        bump_dispute_ttl(&env, dispute_id);
+
+        let final_key = DataKey::FinalizedAt(dispute_id);
+        if env.storage().persistent().has(&final_key) {
+            return Err(DisputeError::AlreadyFinalized);
+        }
+        match dispute.status {
+            DisputeStatus::Open | DisputeStatus::Voting | DisputeStatus::Escalated => {
+                return Err(DisputeError::NotResolved)
+            }
+            DisputeStatus::MaliciousDisputeFiling | DisputeStatus::Expired => {
+                return Err(DisputeError::AlreadyFinalized)
+            }
+            _ => {}
+        }
+        if let Some(appeal_id) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::DisputeAppeal(dispute_id))
+        {
+            let appeal: Option<Appeal> =
+                env.storage().persistent().get(&DataKey::Appeal(appeal_id));
+            if matches!(
+                appeal.map(|ap| ap.status),
+                Some(AppealStatus::Open | AppealStatus::Voting)
+            ) {
+                return Err(DisputeError::AppealInProgress);
+            }
+        }
+        let closed_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LastDisputeClosedAt(dispute.job_id))
+            .unwrap_or(0);
+        let appeal_window = known_parameter(&env, &PARAM_APPEAL_WINDOW) as u64;
+        if env.ledger().timestamp() <= closed_at.saturating_add(appeal_window) {
+            return Err(DisputeError::AppealWindowOpen);
+        }
+
+        if dispute.status == DisputeStatus::ResolutionFailed {
+            let resolution: DisputeResolution = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingResolution(dispute_id))
+                .ok_or(DisputeError::ResolutionNotFound)?;
+            let escrow_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::EscrowContract)
+                .ok_or(DisputeError::NotInitialized)?;
+            if !notify_escrow(&env, &escrow_addr, &dispute, &resolution) {
+                bump_pending_resolution_ttl(&env, dispute_id);
+                env.events().publish(
+                    (symbol_short!("dispute"), Symbol::new(&env, "escrow_fail")),
+                    (dispute_id, dispute.job_id),
+                );
+                return Ok(DisputeStatus::ResolutionFailed);
+            }
+            dispute.status = resolution_to_status(&resolution);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::PendingResolution(dispute_id));
+            env.storage()
+                .persistent()
+                .set(&DataKey::Dispute(dispute_id), &dispute);
+            bump_dispute_ttl(&env, dispute_id);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&final_key, &env.ledger().timestamp());
+        env.storage()
+            .persistent()
+            .extend_ttl(&final_key, MIN_TTL_THRESHOLD, MIN_TTL_EXTEND_TO);
+        settle_held_funds(&env, &dispute);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("finalized")),
+            (dispute_id, dispute.job_id, dispute.status.clone()),
+        );
+        Ok(dispute.status)
+    }
+
+    /// When `finalize_after_appeal_window` made the dispute final, if it has.
+    pub fn get_finalized_at(env: Env, dispute_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FinalizedAt(dispute_id))
+    }
 }
 
 /// Returns the median client_bps from all SplitAward votes using insertion sort.
@@ -2176,12 +4866,101 @@ fn resolution_to_status(resolution: &DisputeResolution) -> DisputeStatus {
     }
 }
 
+/// Outcome derived from a dispute's current tallies, shared by
+/// `internal_resolve` and `preview_resolution`. Reads storage only.
+struct ResolutionOutcome {
+    status: DisputeStatus,
+    malicious: bool,
+    tie_break_applied: bool,
+    /// Client and freelancer weights tied exactly (recorded as a 50/50 split).
+    even_split: bool,
+}
+
+fn compute_outcome(env: &Env, dispute: &Dispute) -> ResolutionOutcome {
+    let sa = dispute.votes_for_split_award;
+    let total_votes = dispute.votes_for_client
+        + dispute.votes_for_freelancer
+        + dispute.votes_for_refund_split
+        + dispute.votes_for_malicious
+        + sa;
+
+    // ── Supermajority check: MaliciousFiling requires `mal_bps` of all votes ─────
+    // (4 out of every 5 by default): votes_for_malicious * 10_000 >= total_votes * mal_bps
+    let malicious_bps = known_parameter(env, &PARAM_MALICIOUS_BPS);
+    let malicious = total_votes >= 5
+        && dispute.votes_for_malicious as i128 * 10_000 >= total_votes as i128 * malicious_bps;
+    let mut outcome = ResolutionOutcome {
+        status: DisputeStatus::MaliciousDisputeFiling,
+        malicious,
+        tie_break_applied: false,
+        even_split: false,
+    };
+    if malicious {
+        return outcome;
+    }
+
+    // ── Normal resolution path ────────────────────────────────────────────────
+    if dispute.votes_for_client > dispute.votes_for_freelancer
+        && dispute.votes_for_client > dispute.votes_for_refund_split
+        && dispute.votes_for_client > dispute.votes_for_malicious
+    {
+        outcome.status = DisputeStatus::ResolvedForClient;
+    } else if dispute.votes_for_freelancer > dispute.votes_for_client
+        && dispute.votes_for_freelancer > dispute.votes_for_refund_split
+        && dispute.votes_for_freelancer > dispute.votes_for_malicious
+    {
+        outcome.status = DisputeStatus::ResolvedForFreelancer;
+    } else if sa > dispute.votes_for_client
+        && sa > dispute.votes_for_freelancer
+        && sa > dispute.votes_for_refund_split
+    {
+        let stored_votes = dispute_votes(env, dispute);
+        let median = compute_median_bps(env, &stored_votes);
+        outcome.status = DisputeStatus::SplitAward(median);
+    } else if dispute.votes_for_refund_split > dispute.votes_for_client
+        && dispute.votes_for_refund_split > dispute.votes_for_freelancer
+        && dispute.votes_for_refund_split > dispute.votes_for_malicious
+    {
+        let avg = dispute.refund_split_sum / dispute.votes_for_refund_split as u64;
+        outcome.status = DisputeStatus::RefundSplit(avg as u32);
+    } else if dispute.tally.client_weight > 0
+        && dispute.tally.client_weight == dispute.tally.freelancer_weight
+    {
+        // Exact tie between client and freelancer — resolve as 50/50 split.
+        outcome.status = DisputeStatus::RefundSplit(50);
+        outcome.even_split = true;
+    } else {
+        // Tie-break logic (applies if votes are tied OR if total_votes is 0 in force mode)
+        outcome.tie_break_applied = true;
+        outcome.status = match dispute.tie_break_method {
+            TieBreakMethod::FavorClient => DisputeStatus::ResolvedForClient,
+            TieBreakMethod::FavorFreelancer => DisputeStatus::ResolvedForFreelancer,
+            TieBreakMethod::RefundBoth => DisputeStatus::RefundedBoth,
+            TieBreakMethod::Escalate => DisputeStatus::Escalated,
+        };
+    }
+    outcome
+}
+
+fn status_to_resolution(status: &DisputeStatus) -> DisputeResolution {
+    match status {
+        DisputeStatus::ResolvedForClient => DisputeResolution::ClientWins,
+        DisputeStatus::ResolvedForFreelancer => DisputeResolution::FreelancerWins,
+        DisputeStatus::RefundedBoth => DisputeResolution::RefundBoth,
+        DisputeStatus::RefundSplit(pct) => DisputeResolution::RefundSplit(*pct),
+        DisputeStatus::SplitAward(bps) => DisputeResolution::SplitAward(*bps),
+        DisputeStatus::MaliciousDisputeFiling => DisputeResolution::MaliciousFiling,
+        _ => DisputeResolution::Escalate,
+    }
+}
+
 fn internal_resolve(
     env: &Env,
     dispute_id: u64,
     dispute: &mut Dispute,
     escrow_addr: &Address,
     force: bool,
+    resolver: Option<Address>,
 ) -> Result<DisputeStatus, DisputeError> {
     if dispute.status == DisputeStatus::ResolvedForClient
         || dispute.status == DisputeStatus::ResolvedForFreelancer
@@ -2191,6 +4970,7 @@ fn internal_resolve(
         || dispute.status == DisputeStatus::Escalated
         || dispute.status == DisputeStatus::MaliciousDisputeFiling
         || dispute.status == DisputeStatus::ResolutionFailed
+        || dispute.status == DisputeStatus::Expired
     {
         return Err(DisputeError::AlreadyResolved);
     }
@@ -2201,31 +4981,52 @@ fn internal_resolve(
         + dispute.votes_for_refund_split
         + dispute.votes_for_malicious
         + sa;
-    if !force && total_votes < dispute.min_votes {
+    if !force && total_votes < quorum(env, dispute_id, dispute) {
         return Err(DisputeError::NotEnoughVotes);
     }
-
-    // ── Supermajority check: MaliciousFiling requires 4 out of every 5 votes ─────
-    // votes_for_malicious * 5 >= total_votes * 4  ↔  ≥ 80 % of all votes
-    let is_malicious_supermajority = total_votes >= 5
-        && dispute.votes_for_malicious.saturating_mul(5) >= total_votes.saturating_mul(4);
+    if !force {
+        let min_open = known_parameter(env, &PARAM_MIN_VOTING_PERIOD) as u64;
+        let first_vote_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FirstVoteAt(dispute_id))
+            .unwrap_or(0);
+        if env.ledger().timestamp() < first_vote_at.saturating_add(min_open) {
+            return Err(DisputeError::MinVotingPeriodNotElapsed);
+        }
+    }
+    record_juror_participation(env, dispute_id, dispute);
+
+    let outcome = compute_outcome(env, dispute);
+    let is_malicious_supermajority = outcome.malicious;
+
+    let mut record = ResolutionRecord {
+        dispute_id,
+        round: ROUND_ORIGINAL,
+        status: dispute.status.clone(),
+        votes_for_client: dispute.votes_for_client,
+        votes_for_freelancer: dispute.votes_for_freelancer,
+        votes_for_refund_split: dispute.votes_for_refund_split,
+        votes_for_split_award: sa,
+        votes_for_malicious: dispute.votes_for_malicious,
+        client_weight: dispute.tally.client_weight,
+        freelancer_weight: dispute.tally.freelancer_weight,
+        total_weight_cast: dispute.tally.total_weight_cast,
+        malicious: is_malicious_supermajority,
+        tie_break_applied: false,
+        tie_break_method: dispute.tie_break_method.clone(),
+        resolver,
+        resolved_at: env.ledger().timestamp(),
+    };
 
     if is_malicious_supermajority {
         dispute.status = DisputeStatus::MaliciousDisputeFiling;
+        record.status = dispute.status.clone();
+        store_resolution_record(env, &record);
 
         // Notify escrow: slash full stake of initiator to treasury.
-        let escrow_ok = matches!(
-            env.try_invoke_contract::<(), soroban_sdk::Error>(
-                escrow_addr,
-                &Symbol::new(env, "resolve_dispute_callback"),
-                vec![
-                    env,
-                    dispute.job_id.into_val(env),
-                    DisputeResolution::MaliciousFiling.into_val(env),
-                ],
-            ),
-            Ok(Ok(_))
-        );
+        let escrow_ok =
+            notify_escrow(env, escrow_addr, dispute, &DisputeResolution::MaliciousFiling);
 
         if !escrow_ok {
             dispute.status = DisputeStatus::ResolutionFailed;
@@ -2276,10 +5077,7 @@ fn internal_resolve(
         }
 
         // Persist state before emitting events.
-        env.storage()
-            .persistent()
-            .set(&DataKey::LastDisputeClosedAt(dispute.job_id), &env.ledger().timestamp());
-        bump_last_dispute_closed_ttl(env, dispute.job_id);
+        mark_jobs_closed(env, dispute);
 
         env.storage().persistent().set(
             &DataKey::LastDisputeLedger(dispute.client.clone(), dispute.freelancer.clone()),
@@ -2302,74 +5100,23 @@ fn internal_resolve(
     }
 
     // ── Normal resolution path ────────────────────────────────────────────────
-    if dispute.votes_for_client > dispute.votes_for_freelancer
-        && dispute.votes_for_client > dispute.votes_for_refund_split
-        && dispute.votes_for_client > dispute.votes_for_malicious
-    {
-        dispute.status = DisputeStatus::ResolvedForClient;
-    } else if dispute.votes_for_freelancer > dispute.votes_for_client
-        && dispute.votes_for_freelancer > dispute.votes_for_refund_split
-        && dispute.votes_for_freelancer > dispute.votes_for_malicious
-    {
-        dispute.status = DisputeStatus::ResolvedForFreelancer;
-    } else if sa > dispute.votes_for_client
-        && sa > dispute.votes_for_freelancer
-        && sa > dispute.votes_for_refund_split
-    {
-        let stored_votes: Vec<Vote> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Votes(dispute_id))
-            .unwrap_or(Vec::new(env));
-        let median = compute_median_bps(env, &stored_votes);
-        dispute.status = DisputeStatus::SplitAward(median);
-    } else if dispute.votes_for_refund_split > dispute.votes_for_client
-        && dispute.votes_for_refund_split > dispute.votes_for_freelancer
-        && dispute.votes_for_refund_split > dispute.votes_for_malicious
-    {
-        let avg = dispute.refund_split_sum / dispute.votes_for_refund_split as u64;
-        dispute.status = DisputeStatus::RefundSplit(avg as u32);
-    } else if dispute.tally.client_weight > 0
-        && dispute.tally.client_weight == dispute.tally.freelancer_weight
-    {
-        // Exact tie between client and freelancer — resolve as 50/50 split.
-        dispute.status = DisputeStatus::RefundSplit(50);
-
+    dispute.status = outcome.status;
+    record.tie_break_applied = outcome.tie_break_applied;
+    if outcome.even_split {
         env.storage().persistent().set(
             &DataKey::SplitRatio(dispute_id),
             &(50u32, 50u32),
         );
-    } else {
-        // Tie-break logic (applies if votes are tied OR if total_votes is 0 in force mode)
-        match dispute.tie_break_method {
-            TieBreakMethod::FavorClient => dispute.status = DisputeStatus::ResolvedForClient,
-            TieBreakMethod::FavorFreelancer => {
-                dispute.status = DisputeStatus::ResolvedForFreelancer
-            }
-            TieBreakMethod::RefundBoth => dispute.status = DisputeStatus::RefundedBoth,
-            TieBreakMethod::Escalate => dispute.status = DisputeStatus::Escalated,
-        }
     }
 
-    let resolution = match dispute.status {
-        DisputeStatus::ResolvedForClient => DisputeResolution::ClientWins,
-        DisputeStatus::ResolvedForFreelancer => DisputeResolution::FreelancerWins,
-        DisputeStatus::RefundedBoth => DisputeResolution::RefundBoth,
-        DisputeStatus::RefundSplit(pct) => DisputeResolution::RefundSplit(pct),
-        DisputeStatus::SplitAward(bps) => DisputeResolution::SplitAward(bps),
-        _ => DisputeResolution::Escalate,
-    };
+    let resolution = status_to_resolution(&dispute.status);
+
+    record.status = dispute.status.clone();
+    store_resolution_record(env, &record);
 
     // Only invoke the escrow callback if the dispute has a concrete resolution.
     if resolution != DisputeResolution::Escalate {
-        let escrow_ok = matches!(
-            env.try_invoke_contract::<(), soroban_sdk::Error>(
-                escrow_addr,
-                &Symbol::new(env, "resolve_dispute_callback"),
-                vec![env, dispute.job_id.into_val(env), resolution.clone().into_val(env)],
-            ),
-            Ok(Ok(_))
-        );
+        let escrow_ok = notify_escrow(env, escrow_addr, dispute, &resolution);
 
         if !escrow_ok {
             dispute.status = DisputeStatus::ResolutionFailed;
@@ -2447,11 +5194,7 @@ fn internal_resolve(
         }
     }
 
-    env.storage().persistent().set(
-        &DataKey::LastDisputeClosedAt(dispute.job_id),
-        &env.ledger().timestamp(),
-    );
-    bump_last_dispute_closed_ttl(env, dispute.job_id);
+    mark_jobs_closed(env, dispute);
 
     env.storage().persistent().set(
         &DataKey::LastDisputeLedger(dispute.client.clone(), dispute.freelancer.clone()),
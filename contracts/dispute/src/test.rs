@@ -3,21 +3,10 @@
 use super::*;
 use soroban_sdk::{
     contract, contractimpl,
-    testutils::{Address as _, BytesN as _, Events, Ledger},
+    testutils::{Address as _, Events, Ledger},
     BytesN, Env, String,
 };
 
-// Helper macro to add arbitrators and get assigned ones for a dispute
-macro_rules! setup_arbs_and_get_assigned {
-    ($env:expr, $client:expr, $admin:expr, $dispute_id:expr, $count:expr) => {{
-        for _ in 0..$count {
-            let arb = Address::generate(&$env);
-            $client.add_arbitrator(&$admin, &arb);
-        }
-        $client.get_assigned_arbitrators(&$dispute_id)
-    }};
-}
-
 #[contract]
 pub struct DummyEscrow;
 
@@ -44,6 +33,86 @@ mod failing_escrow {
 }
 use failing_escrow::DummyEscrowFailing;
 
+/// Escrow exposing a fixed job (1000 total, 400 already approved) for
+/// `preview_resolution`. The job's token can be pinned with `set_token` and its
+/// parties with `set_parties`; `settled` lists the jobs called back so far.
+mod job_escrow {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, Env};
+
+    #[contract]
+    pub struct JobEscrow;
+
+    #[contractimpl]
+    impl JobEscrow {
+        pub fn set_token(env: Env, token: Address) {
+            env.storage().instance().set(&symbol_short!("token"), &token);
+        }
+
+        pub fn set_parties(env: Env, client: Address, freelancer: Address) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("parties"), &(client, freelancer));
+        }
+
+        pub fn settled(env: Env) -> soroban_sdk::Vec<u64> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("settled"))
+                .unwrap_or(soroban_sdk::Vec::new(&env))
+        }
+
+        pub fn get_job(env: Env, job_id: u64) -> escrow::Job {
+            let milestone = |id: u32, amount: i128, status: escrow::MilestoneStatus| escrow::Milestone {
+                id,
+                description: String::from_str(&env, "m"),
+                amount,
+                status,
+                deadline: 0,
+                token: None,
+                released: 0,
+            };
+            let (client, freelancer) = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("parties"))
+                .unwrap_or_else(|| (Address::generate(&env), Address::generate(&env)));
+            escrow::Job {
+                id: job_id,
+                client,
+                freelancer,
+                token: env
+                    .storage()
+                    .instance()
+                    .get(&symbol_short!("token"))
+                    .unwrap_or_else(|| Address::generate(&env)),
+                total_amount: 1000,
+                funded_amount: 1000,
+                status: escrow::JobStatus::Disputed,
+                milestones: soroban_sdk::vec![
+                    &env,
+                    milestone(0, 400, escrow::MilestoneStatus::Approved),
+                    milestone(1, 600, escrow::MilestoneStatus::Submitted),
+                ],
+                job_deadline: 0,
+                auto_refund_after: 0,
+                expiry_ledger: 0,
+                created_at: None,
+                funded_at: None,
+                completed_at: None,
+                cancelled_at: None,
+            }
+        }
+
+        pub fn resolve_dispute_callback(env: Env, job_id: u64, _resolution: DisputeResolution) {
+            let mut settled = Self::settled(env.clone());
+            settled.push_back(job_id);
+            env.storage().instance().set(&symbol_short!("settled"), &settled);
+        }
+    }
+}
+use job_escrow::JobEscrow;
+
 // Mock reputation contract for testing
 #[contract]
 pub struct MockReputationContract;
@@ -72,6 +141,11 @@ impl MockReputationContract {
         Ok(())
     }
 
+    /// Every user sits in the Gold tier.
+    pub fn get_tier(_env: Env, _user: Address) -> reputation::ReputationTier {
+        reputation::ReputationTier::Gold
+    }
+
     /// Mock for the MaliciousFiling cross-contract call.
     pub fn apply_dispute_outcome(
         _env: Env,
@@ -82,6 +156,13 @@ impl MockReputationContract {
     }
 }
 
+/// Hash of an off-chain vote rationale, as passed to `cast_vote`.
+fn reason_hash(env: &Env, text: &str) -> BytesN<32> {
+    env.crypto()
+        .sha256(&soroban_sdk::Bytes::from_slice(env, text.as_bytes()))
+        .into()
+}
+
 #[test]
 fn test_initialize_contract() {
     let env = Env::default();
@@ -94,7 +175,7 @@ fn test_initialize_contract() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     // Verify initialization by checking if we can set min reputation
     client.set_min_voter_reputation(&admin, &400);
@@ -113,9 +194,9 @@ fn test_initialize_twice_fails() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     // Try to initialize again - should fail
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 }
 
 #[test]
@@ -130,7 +211,7 @@ fn test_set_min_voter_reputation() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     client.set_min_voter_reputation(&admin, &500);
 }
 
@@ -148,7 +229,7 @@ fn test_set_min_voter_reputation_non_admin_fails() {
     let admin = Address::generate(&env);
     let non_admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     // Non-admin tries to set min reputation - should fail
     client.set_min_voter_reputation(&non_admin, &500);
 }
@@ -165,13 +246,13 @@ fn test_is_eligible_voter_high_reputation() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let voter = Address::generate(&env);
 
     let is_eligible = client.is_eligible_voter(&voter);
     // Mock returns high reputation for all users
-    assert_eq!(is_eligible, true);
+    assert!(is_eligible);
 }
 
 #[test]
@@ -186,7 +267,7 @@ fn test_vote_with_reputation_check() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -202,6 +283,8 @@ fn test_vote_with_reputation_check() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -214,7 +297,7 @@ fn test_vote_with_reputation_check() {
         &dispute_id,
         &voter,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote"), &0);
+        &reason_hash(&env, "Vote"), &None, &0);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.votes_for_client, 1);
@@ -237,6 +320,8 @@ fn test_raise_dispute() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Work not delivered"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -249,6 +334,60 @@ fn test_raise_dispute() {
     assert_eq!(dispute.min_votes, 3);
 }
 
+#[test]
+fn test_dispute_counts_by_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, DisputeContract);
+    let client = DisputeContractClient::new(&env, &contract_id);
+    for (code, count) in client.get_dispute_counts_by_reason().iter() {
+        assert_eq!(count, 0, "{:?}", code);
+    }
+
+    let party = Address::generate(&env);
+    let statement = BytesN::from_array(&env, &[7; 32]);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &party,
+        &Address::generate(&env),
+        &party,
+        &String::from_str(&env, "Nothing delivered"),
+        &ReasonCode::NonDelivery,
+        &Some(statement.clone()),
+        &3u32,
+        &None,
+    );
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.reason_code, ReasonCode::NonDelivery);
+    assert_eq!(client.get_dispute_reason_hash(&dispute_id), Some(statement));
+
+    for job_id in [2u64, 3] {
+        client.raise_dispute(
+            &job_id,
+            &party,
+            &Address::generate(&env),
+            &party,
+            &String::from_str(&env, "Late"),
+            &ReasonCode::MissedDeadline,
+            &None,
+            &3u32,
+            &None,
+        );
+    }
+
+    let counts = client.get_dispute_counts_by_reason();
+    assert_eq!(counts.len(), REASON_CODES.len() as u32);
+    for (code, count) in counts.iter() {
+        let expected = match code {
+            ReasonCode::NonDelivery => 1,
+            ReasonCode::MissedDeadline => 2,
+            _ => 0,
+        };
+        assert_eq!(count, expected, "{:?}", code);
+    }
+}
+
 #[test]
 fn test_vote_and_resolve() {
     let env = Env::default();
@@ -264,7 +403,7 @@ fn test_vote_and_resolve() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     
     // Add arbitrators
     for _ in 0..5 {
@@ -278,6 +417,8 @@ fn test_vote_and_resolve() {
         &freelancer,
         &freelancer,
         &String::from_str(&env, "Payment not released"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -288,17 +429,17 @@ fn test_vote_and_resolve() {
         &dispute_id,
         &assigned.get(0).unwrap(),
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Work was done"), &0);
+        &reason_hash(&env, "Work was done"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &assigned.get(1).unwrap(),
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Agree with freelancer"), &0);
+        &reason_hash(&env, "Agree with freelancer"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &assigned.get(2).unwrap(),
         &VoteChoice::Client,
-        &String::from_str(&env, "Incomplete work"), &0);
+        &reason_hash(&env, "Incomplete work"), &None, &0);
 
     let result = client.resolve_dispute(&dispute_id);
     assert_eq!(result, DisputeStatus::ResolvedForFreelancer);
@@ -320,7 +461,7 @@ fn test_resolve_without_enough_votes() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -333,6 +474,8 @@ fn test_resolve_without_enough_votes() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -343,7 +486,7 @@ fn test_resolve_without_enough_votes() {
         &dispute_id,
         &voter,
         &VoteChoice::Client,
-        &String::from_str(&env, "Reason"), &0);
+        &reason_hash(&env, "Reason"), &None, &0);
 
     client.resolve_dispute(&dispute_id);
 }
@@ -362,7 +505,7 @@ fn test_tie_break_favor_client() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     
     // Add arbitrators
     for _ in 0..10 {
@@ -375,6 +518,8 @@ fn test_tie_break_favor_client() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &4u32, // min_votes set to 4
         &Some(TieBreakMethod::FavorClient),
     );
@@ -385,22 +530,22 @@ fn test_tie_break_favor_client() {
         &dispute_id,
         &assigned.get(0).unwrap(),
         &VoteChoice::Client,
-        &String::from_str(&env, "C1"), &0);
+        &reason_hash(&env, "C1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &assigned.get(1).unwrap(),
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F1"), &0);
+        &reason_hash(&env, "F1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &assigned.get(2).unwrap(),
         &VoteChoice::Client,
-        &String::from_str(&env, "C2"), &0);
+        &reason_hash(&env, "C2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &assigned.get(3).unwrap(),
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F2"), &0);
+        &reason_hash(&env, "F2"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     // Exact client/freelancer tie now resolves as 50/50 split (Issue #702)
@@ -421,7 +566,7 @@ fn test_tie_break_favor_freelancer() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     for _ in 0..5 {
         let arb = Address::generate(&env);
         client.add_arbitrator(&admin, &arb);
@@ -433,6 +578,8 @@ fn test_tie_break_favor_freelancer() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &4u32,
         &Some(TieBreakMethod::FavorFreelancer),
     );
@@ -447,22 +594,22 @@ fn test_tie_break_favor_freelancer() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "C1"), &0);
+        &reason_hash(&env, "C1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F1"), &0);
+        &reason_hash(&env, "F1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "C2"), &0);
+        &reason_hash(&env, "C2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter4,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F2"), &0);
+        &reason_hash(&env, "F2"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     // Exact client/freelancer tie now resolves as 50/50 split (Issue #702)
@@ -483,7 +630,7 @@ fn test_tie_break_refund_both() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     for _ in 0..5 {
         let arb = Address::generate(&env);
         client.add_arbitrator(&admin, &arb);
@@ -495,6 +642,8 @@ fn test_tie_break_refund_both() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &4u32,
         &Some(TieBreakMethod::RefundBoth),
     );
@@ -509,22 +658,22 @@ fn test_tie_break_refund_both() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "C1"), &0);
+        &reason_hash(&env, "C1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F1"), &0);
+        &reason_hash(&env, "F1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "C2"), &0);
+        &reason_hash(&env, "C2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter4,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F2"), &0);
+        &reason_hash(&env, "F2"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     // Exact client/freelancer tie now resolves as 50/50 split (Issue #702)
@@ -545,7 +694,7 @@ fn test_tie_break_escalate() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     for _ in 0..5 {
         let arb = Address::generate(&env);
         client.add_arbitrator(&admin, &arb);
@@ -557,6 +706,8 @@ fn test_tie_break_escalate() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &4u32,
         &Some(TieBreakMethod::Escalate),
     );
@@ -571,22 +722,22 @@ fn test_tie_break_escalate() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "C1"), &0);
+        &reason_hash(&env, "C1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F1"), &0);
+        &reason_hash(&env, "F1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "C2"), &0);
+        &reason_hash(&env, "C2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter4,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F2"), &0);
+        &reason_hash(&env, "F2"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     // Exact client/freelancer tie now resolves as 50/50 split (Issue #702)
@@ -607,7 +758,7 @@ fn test_tie_break_default_refund_both() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     for _ in 0..5 {
         let arb = Address::generate(&env);
         client.add_arbitrator(&admin, &arb);
@@ -619,6 +770,8 @@ fn test_tie_break_default_refund_both() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &4u32,
         &None, // Should default to RefundBoth
     );
@@ -633,22 +786,22 @@ fn test_tie_break_default_refund_both() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "C1"), &0);
+        &reason_hash(&env, "C1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F1"), &0);
+        &reason_hash(&env, "F1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "C2"), &0);
+        &reason_hash(&env, "C2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter4,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F2"), &0);
+        &reason_hash(&env, "F2"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     // Exact client/freelancer tie now resolves as 50/50 split (Issue #702)
@@ -669,7 +822,7 @@ fn test_vote_without_reputation_contract() {
     let admin = Address::generate(&env);
 
     // MockReputationContract returns score=500 which satisfies min_voter_reputation=300.
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -685,6 +838,8 @@ fn test_vote_without_reputation_contract() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -695,7 +850,7 @@ fn test_vote_without_reputation_contract() {
         &dispute_id,
         &voter,
         &VoteChoice::Client,
-        &String::from_str(&env, "Reason"), &0);
+        &reason_hash(&env, "Reason"), &None, &0);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.votes_for_client, 1);
@@ -715,7 +870,7 @@ fn test_pause_and_unpause() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     // Create a dispute first
     let user_client = Address::generate(&env);
@@ -727,6 +882,8 @@ fn test_pause_and_unpause() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -745,6 +902,8 @@ fn test_pause_and_unpause() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue 2"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -765,7 +924,7 @@ fn test_pause_unauthorized() {
     let admin = Address::generate(&env);
     let non_admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     // Try to pause with non-admin address
     client.pause(&non_admin);
@@ -784,7 +943,7 @@ fn test_raise_dispute_when_paused() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     client.pause(&admin);
 
     let user_client = Address::generate(&env);
@@ -796,6 +955,8 @@ fn test_raise_dispute_when_paused() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -814,7 +975,7 @@ fn test_cast_vote_when_paused() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -825,6 +986,8 @@ fn test_cast_vote_when_paused() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -836,7 +999,7 @@ fn test_cast_vote_when_paused() {
         &dispute_id,
         &voter,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote"), &0);
+        &reason_hash(&env, "Vote"), &None, &0);
 }
 
 #[test]
@@ -852,7 +1015,7 @@ fn test_resolve_dispute_when_paused() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -869,6 +1032,8 @@ fn test_resolve_dispute_when_paused() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &5u32,
         &None,
     );
@@ -882,17 +1047,17 @@ fn test_resolve_dispute_when_paused() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote 1"), &0);
+        &reason_hash(&env, "Vote 1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Vote 2"), &0);
+        &reason_hash(&env, "Vote 2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote 3"), &0);
+        &reason_hash(&env, "Vote 3"), &None, &0);
 
     client.pause(&admin);
 
@@ -911,7 +1076,7 @@ fn test_read_only_functions_when_paused() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -922,6 +1087,8 @@ fn test_read_only_functions_when_paused() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -939,7 +1106,7 @@ fn test_read_only_functions_when_paused() {
     assert_eq!(votes.len(), 0);
 
     let is_excluded = client.is_excluded_voter(&dispute_id, &Address::generate(&env));
-    assert_eq!(is_excluded, false);
+    assert!(!is_excluded);
 }
 
 // ── Stake slashing tests (issue #221) ────────────────────────────────────────
@@ -949,7 +1116,7 @@ fn setup_dispute_with_votes(
     client_votes: u32,
     freelancer_votes: u32,
 ) -> (
-    DisputeContractClient,
+    DisputeContractClient<'_>,
     Address, // dispute contract id
     Address, // escrow contract id
     Address, // user_client
@@ -966,7 +1133,7 @@ fn setup_dispute_with_votes(
     let user_client = Address::generate(env);
     let freelancer = Address::generate(env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     // Add arbitrators to pool
     let mut arbitrators = Vec::new(env);
@@ -982,6 +1149,8 @@ fn setup_dispute_with_votes(
         &freelancer,
         &user_client,
         &String::from_str(env, "Dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -989,13 +1158,13 @@ fn setup_dispute_with_votes(
     let assigned = client.get_assigned_arbitrators(&dispute_id);
     
     for i in 0..client_votes {
-        if (i as u32) < assigned.len() {
-            let voter = assigned.get(i as u32).unwrap();
+        if i < assigned.len() {
+            let voter = assigned.get(i).unwrap();
             client.cast_vote(
                 &dispute_id,
                 &voter,
                 &VoteChoice::Client,
-                &String::from_str(env, "For client"), &0);
+                &reason_hash(env, "For client"), &None, &0);
         }
     }
     for i in 0..freelancer_votes {
@@ -1006,7 +1175,7 @@ fn setup_dispute_with_votes(
                 &dispute_id,
                 &voter,
                 &VoteChoice::Freelancer,
-                &String::from_str(env, "For freelancer"), &0);
+                &reason_hash(env, "For freelancer"), &None, &0);
         }
     }
 
@@ -1086,7 +1255,7 @@ fn test_no_slash_on_escalated_dispute() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -1099,6 +1268,8 @@ fn test_no_slash_on_escalated_dispute() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &4u32,
         &Some(TieBreakMethod::Escalate),
     );
@@ -1113,22 +1284,22 @@ fn test_no_slash_on_escalated_dispute() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "C"), &0);
+        &reason_hash(&env, "C"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F"), &0);
+        &reason_hash(&env, "F"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "C"), &0);
+        &reason_hash(&env, "C"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter4,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "F"), &0);
+        &reason_hash(&env, "F"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     // Exact client/freelancer tie now resolves as 50/50 split (Issue #702)
@@ -1151,7 +1322,7 @@ fn test_raise_dispute_blocked_by_job_cooldown() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -1164,6 +1335,8 @@ fn test_raise_dispute_blocked_by_job_cooldown() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Initial dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -1177,17 +1350,17 @@ fn test_raise_dispute_blocked_by_job_cooldown() {
         &dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "V1"), &0);
+        &reason_hash(&env, "V1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Client,
-        &String::from_str(&env, "V2"), &0);
+        &reason_hash(&env, "V2"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter3,
         &VoteChoice::Client,
-        &String::from_str(&env, "V3"), &0);
+        &reason_hash(&env, "V3"), &None, &0);
 
     // 3 votes for Client → auto-resolve fires; re-raise immediately must fail with DisputeCooldown.
     client.raise_dispute(
@@ -1196,6 +1369,8 @@ fn test_raise_dispute_blocked_by_job_cooldown() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Retry too soon"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -1216,7 +1391,7 @@ fn test_raise_dispute_allowed_after_cooldown() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -1229,6 +1404,8 @@ fn test_raise_dispute_allowed_after_cooldown() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Initial dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -1242,17 +1419,17 @@ fn test_raise_dispute_allowed_after_cooldown() {
         &first_dispute_id,
         &voter1,
         &VoteChoice::Client,
-        &String::from_str(&env, "V1"), &0);
+        &reason_hash(&env, "V1"), &None, &0);
     client.cast_vote(
         &first_dispute_id,
         &voter2,
         &VoteChoice::Client,
-        &String::from_str(&env, "V2"), &0);
+        &reason_hash(&env, "V2"), &None, &0);
     client.cast_vote(
         &first_dispute_id,
         &voter3,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "V3"), &0);
+        &reason_hash(&env, "V3"), &None, &0);
 
     // Dispute auto-resolved on 3rd vote; advance past both cooldowns.
     env.ledger().with_mut(|l| l.timestamp = 1000 + 1_209_601);
@@ -1263,6 +1440,8 @@ fn test_raise_dispute_allowed_after_cooldown() {
         &freelancer,
         &freelancer,
         &String::from_str(&env, "Retry after cooldown"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -1283,7 +1462,7 @@ fn test_force_resolve_timeout_not_expired_fails() {
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -1294,6 +1473,8 @@ fn test_force_resolve_timeout_not_expired_fails() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &10u32,
         &None,
     );
@@ -1315,7 +1496,7 @@ fn test_force_resolve_timeout_expired_success() {
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -1331,6 +1512,8 @@ fn test_force_resolve_timeout_expired_success() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &10u32,
         &None,
     );
@@ -1342,7 +1525,7 @@ fn test_force_resolve_timeout_expired_success() {
         &dispute_id,
         &voter,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Reason"), &0);
+        &reason_hash(&env, "Reason"), &None, &0);
 
     // Advance past deadline (1000 + 604_800 = 605_800)
     env.ledger().with_mut(|l| l.timestamp = 605_801);
@@ -1363,7 +1546,7 @@ fn test_force_resolve_timeout_tie_break_success() {
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -1374,6 +1557,8 @@ fn test_force_resolve_timeout_tie_break_success() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &10u32,
         &Some(TieBreakMethod::FavorClient),
     );
@@ -1402,7 +1587,7 @@ fn test_party_cooldown_blocks_same_parties_on_different_job() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -1412,21 +1597,21 @@ fn test_party_cooldown_blocks_same_parties_on_different_job() {
     // Raise and resolve a dispute on job 1.
     let d1 = client.raise_dispute(
         &1u64, &user_client, &freelancer, &user_client,
-        &String::from_str(&env, "First dispute"), &3u32, &None,
+        &String::from_str(&env, "First dispute"), &ReasonCode::Other, &None, &3u32, &None,
     );
     let assigned = client.get_assigned_arbitrators(&d1);
     let v1 = assigned.get(0).unwrap();
     let v2 = assigned.get(1).unwrap();
     let v3 = assigned.get(2).unwrap();
-    client.cast_vote(&d1, &v1, &VoteChoice::Client, &String::from_str(&env, "v1"), &0);
-    client.cast_vote(&d1, &v2, &VoteChoice::Client, &String::from_str(&env, "v2"), &0);
-    client.cast_vote(&d1, &v3, &VoteChoice::Client, &String::from_str(&env, "v3"), &0);
+    client.cast_vote(&d1, &v1, &VoteChoice::Client, &reason_hash(&env, "v1"), &None, &0);
+    client.cast_vote(&d1, &v2, &VoteChoice::Client, &reason_hash(&env, "v2"), &None, &0);
+    client.cast_vote(&d1, &v3, &VoteChoice::Client, &reason_hash(&env, "v3"), &None, &0);
 
     // 3 votes for Client → auto-resolve fires and sets party cooldown.
     // Immediately raising a dispute on a different job between the same parties must fail.
     client.raise_dispute(
         &2u64, &user_client, &freelancer, &freelancer,
-        &String::from_str(&env, "Too soon"), &3u32, &None,
+        &String::from_str(&env, "Too soon"), &ReasonCode::Other, &None, &3u32, &None,
     );
 }
 
@@ -1444,7 +1629,7 @@ fn test_party_cooldown_allows_after_expiry() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -1453,22 +1638,22 @@ fn test_party_cooldown_allows_after_expiry() {
 
     let d1 = client.raise_dispute(
         &1u64, &user_client, &freelancer, &user_client,
-        &String::from_str(&env, "First dispute"), &3u32, &None,
+        &String::from_str(&env, "First dispute"), &ReasonCode::Other, &None, &3u32, &None,
     );
     let assigned = client.get_assigned_arbitrators(&d1);
     let v1 = assigned.get(0).unwrap();
     let v2 = assigned.get(1).unwrap();
     let v3 = assigned.get(2).unwrap();
-    client.cast_vote(&d1, &v1, &VoteChoice::Client, &String::from_str(&env, "v1"), &0);
-    client.cast_vote(&d1, &v2, &VoteChoice::Client, &String::from_str(&env, "v2"), &0);
-    client.cast_vote(&d1, &v3, &VoteChoice::Freelancer, &String::from_str(&env, "v3"), &0);
+    client.cast_vote(&d1, &v1, &VoteChoice::Client, &reason_hash(&env, "v1"), &None, &0);
+    client.cast_vote(&d1, &v2, &VoteChoice::Client, &reason_hash(&env, "v2"), &None, &0);
+    client.cast_vote(&d1, &v3, &VoteChoice::Freelancer, &reason_hash(&env, "v3"), &None, &0);
 
     // Advance past the 14-day per-party cooldown (1_209_600 s) and per-job cooldown (86_400 s).
     env.ledger().with_mut(|l| l.timestamp = 1000 + 1_209_601);
 
     let d2 = client.raise_dispute(
         &2u64, &user_client, &freelancer, &freelancer,
-        &String::from_str(&env, "After cooldown"), &3u32, &None,
+        &String::from_str(&env, "After cooldown"), &ReasonCode::Other, &None, &3u32, &None,
     );
     assert_eq!(d2, 2);
 }
@@ -1492,7 +1677,7 @@ fn test_party_cooldown_does_not_affect_different_party_pairs() {
     let user_client_b = Address::generate(&env);
     let freelancer_b = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(&env);
@@ -1502,20 +1687,20 @@ fn test_party_cooldown_does_not_affect_different_party_pairs() {
     // Resolve a dispute between pair A.
     let d1 = client.raise_dispute(
         &1u64, &user_client_a, &freelancer_a, &user_client_a,
-        &String::from_str(&env, "Pair A"), &3u32, &None,
+        &String::from_str(&env, "Pair A"), &ReasonCode::Other, &None, &3u32, &None,
     );
     let assigned = client.get_assigned_arbitrators(&d1);
     let v1 = assigned.get(0).unwrap();
     let v2 = assigned.get(1).unwrap();
     let v3 = assigned.get(2).unwrap();
-    client.cast_vote(&d1, &v1, &VoteChoice::Client, &String::from_str(&env, "v1"), &0);
-    client.cast_vote(&d1, &v2, &VoteChoice::Client, &String::from_str(&env, "v2"), &0);
-    client.cast_vote(&d1, &v3, &VoteChoice::Freelancer, &String::from_str(&env, "v3"), &0);
+    client.cast_vote(&d1, &v1, &VoteChoice::Client, &reason_hash(&env, "v1"), &None, &0);
+    client.cast_vote(&d1, &v2, &VoteChoice::Client, &reason_hash(&env, "v2"), &None, &0);
+    client.cast_vote(&d1, &v3, &VoteChoice::Freelancer, &reason_hash(&env, "v3"), &None, &0);
 
     // Different pair B should be unaffected.
     let d2 = client.raise_dispute(
         &2u64, &user_client_b, &freelancer_b, &user_client_b,
-        &String::from_str(&env, "Pair B"), &3u32, &None,
+        &String::from_str(&env, "Pair B"), &ReasonCode::Other, &None, &3u32, &None,
     );
     assert_eq!(d2, 2);
 }
@@ -1531,7 +1716,7 @@ fn test_set_cooldown_duration() {
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     // Admin can update the cooldown duration.
     client.set_cooldown_duration(&admin, &100_000u64);
@@ -1550,7 +1735,7 @@ fn test_set_cooldown_duration_non_admin_fails() {
     let admin = Address::generate(&env);
     let non_admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     client.set_cooldown_duration(&non_admin, &100_000u64);
 }
 
@@ -1558,13 +1743,13 @@ fn test_set_cooldown_duration_non_admin_fails() {
 
 fn setup_initialized_dispute_contract(
     env: &Env,
-) -> (DisputeContractClient, Address, Address, Address) {
+) -> (DisputeContractClient<'_>, Address, Address, Address) {
     let dispute_contract_id = env.register_contract(None, DisputeContract);
     let client = DisputeContractClient::new(env, &dispute_contract_id);
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(env);
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     
     // Add arbitrators to pool
     for _ in 0..10 {
@@ -1608,6 +1793,8 @@ fn test_delegate_can_cast_vote_on_behalf_of_owner() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &1u32,
         &None,
     );
@@ -1624,7 +1811,7 @@ fn test_delegate_can_cast_vote_on_behalf_of_owner() {
         &dispute_id,
         &delegate,
         &VoteChoice::Client,
-        &String::from_str(&env, "Voting on behalf of owner"), &0);
+        &reason_hash(&env, "Voting on behalf of owner"), &None, &0);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.votes_for_client, 1);
@@ -1665,6 +1852,8 @@ fn test_owner_cannot_vote_directly_after_delegate_voted() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &1u32,
         &None,
     );
@@ -1680,14 +1869,14 @@ fn test_owner_cannot_vote_directly_after_delegate_voted() {
         &dispute_id,
         &delegate,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Delegate vote"), &0);
+        &reason_hash(&env, "Delegate vote"), &None, &0);
 
     // Owner tries to vote directly — must fail with AlreadyVoted (#3).
     client.cast_vote(
         &dispute_id,
         &owner,
         &VoteChoice::Client,
-        &String::from_str(&env, "Direct vote after delegate"), &0);
+        &reason_hash(&env, "Direct vote after delegate"), &None, &0);
 }
 
 #[test]
@@ -1708,6 +1897,8 @@ fn test_delegate_cannot_vote_if_owner_voted_directly() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &1u32,
         &None,
     );
@@ -1721,7 +1912,7 @@ fn test_delegate_cannot_vote_if_owner_voted_directly() {
         &dispute_id,
         &owner,
         &VoteChoice::Client,
-        &String::from_str(&env, "Direct owner vote"), &0);
+        &reason_hash(&env, "Direct owner vote"), &None, &0);
 
     // Owner tries to set up a delegation after already voting — must fail with AlreadyVoted (#3).
     client.delegate_vote(&owner, &delegate, &1u64);
@@ -1745,6 +1936,8 @@ fn test_revoke_fails_after_delegate_voted() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &1u32,
         &None,
     );
@@ -1759,7 +1952,7 @@ fn test_revoke_fails_after_delegate_voted() {
         &dispute_id,
         &delegate,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Delegate vote"), &0);
+        &reason_hash(&env, "Delegate vote"), &None, &0);
 
     // Attempting to revoke after delegate has voted must fail with DelegateAlreadyVoted (#17).
     client.revoke_delegation(&owner, &1u64);
@@ -1798,6 +1991,8 @@ fn test_delegated_vote_counts_same_as_direct_vote_in_resolution() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -1813,12 +2008,12 @@ fn test_delegated_vote_counts_same_as_direct_vote_in_resolution() {
         &dispute_id,
         &voter1,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "v1"), &0);
+        &reason_hash(&env, "v1"), &None, &0);
     client.cast_vote(
         &dispute_id,
         &voter2,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "v2"), &0);
+        &reason_hash(&env, "v2"), &None, &0);
 
     // One delegated vote for freelancer (owner is an assigned arbitrator who delegates).
     client.delegate_vote(&owner, &delegate, &1u64);
@@ -1826,7 +2021,7 @@ fn test_delegated_vote_counts_same_as_direct_vote_in_resolution() {
         &dispute_id,
         &delegate,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "delegated"), &0);
+        &reason_hash(&env, "delegated"), &None, &0);
 
     // 3 votes for freelancer — dispute auto-resolved on 3rd vote.
     let dispute = client.get_dispute(&dispute_id);
@@ -1846,7 +2041,7 @@ fn test_conflict_of_interest_voter_is_party() {
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -1857,6 +2052,8 @@ fn test_conflict_of_interest_voter_is_party() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -1866,7 +2063,7 @@ fn test_conflict_of_interest_voter_is_party() {
         &dispute_id,
         &user_client,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote"), &0);
+        &reason_hash(&env, "Vote"), &None, &0);
 }
 
 // ── Malicious dispute filing tests ────────────────────────────────────────────
@@ -1875,7 +2072,7 @@ fn test_conflict_of_interest_voter_is_party() {
 fn setup_malicious_test(
     env: &Env,
 ) -> (
-    DisputeContractClient,
+    DisputeContractClient<'_>,
     Address, // job_client
     Address, // freelancer
     u64,     // dispute_id
@@ -1885,7 +2082,7 @@ fn setup_malicious_test(
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(env);
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     // Add arbitrators to pool
     for _ in 0..10 {
@@ -1901,6 +2098,8 @@ fn setup_malicious_test(
         &freelancer,
         &job_client, // initiator = client (the one allegedly filing in bad faith)
         &String::from_str(env, "Frivolous claim"),
+        &ReasonCode::Other,
+        &None,
         &5u32, // min_votes = 5 so we can reach supermajority
         &None,
     );
@@ -1923,11 +2122,11 @@ fn test_malicious_filing_supermajority_resolves() {
     let v5 = assigned.get(4).unwrap();
 
     // 4 malicious votes + 1 dissenting vote = supermajority (auto-resolves on 5th vote)
-    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v4, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v5, &VoteChoice::Client,           &String::from_str(&env, "disagree"), &0);
+    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v4, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v5, &VoteChoice::Client,           &reason_hash(&env, "disagree"), &None, &0);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.status, DisputeStatus::MaliciousDisputeFiling);
@@ -1950,11 +2149,11 @@ fn test_malicious_filing_below_supermajority_resolves_normally() {
     let v5 = assigned.get(4).unwrap();
 
     // 3 malicious + 2 for client = 60 % malicious, not ≥ 80 % (auto-resolves on 5th vote via tie-break)
-    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v4, &VoteChoice::Client,           &String::from_str(&env, "for client"), &0);
-    client.cast_vote(&dispute_id, &v5, &VoteChoice::Client,           &String::from_str(&env, "for client"), &0);
+    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v4, &VoteChoice::Client,           &reason_hash(&env, "for client"), &None, &0);
+    client.cast_vote(&dispute_id, &v5, &VoteChoice::Client,           &reason_hash(&env, "for client"), &None, &0);
 
     // Should NOT resolve as MaliciousDisputeFiling — normal resolution applies.
     let dispute = client.get_dispute(&dispute_id);
@@ -1977,11 +2176,11 @@ fn test_malicious_filing_cannot_be_re_resolved() {
     let v4 = assigned.get(3).unwrap();
     let v5 = assigned.get(4).unwrap();
 
-    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v4, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v5, &VoteChoice::Freelancer,       &String::from_str(&env, "dissent"), &0);
+    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v4, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v5, &VoteChoice::Freelancer,       &reason_hash(&env, "dissent"), &None, &0);
 
     // Dispute auto-resolved on 5th vote; any subsequent resolve attempt must fail with AlreadyResolved (#7).
     client.resolve_dispute(&dispute_id);
@@ -2002,11 +2201,11 @@ fn test_malicious_filing_event_emitted() {
     let v4 = assigned.get(3).unwrap();
     let v5 = assigned.get(4).unwrap();
 
-    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v4, &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0);
-    client.cast_vote(&dispute_id, &v5, &VoteChoice::Freelancer,       &String::from_str(&env, "dissent"), &0);
+    client.cast_vote(&dispute_id, &v1, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v2, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v3, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v4, &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0);
+    client.cast_vote(&dispute_id, &v5, &VoteChoice::Freelancer,       &reason_hash(&env, "dissent"), &None, &0);
 
     // Dispute auto-resolves on the 5th vote; event is emitted during auto-resolve.
 
@@ -2038,7 +2237,7 @@ fn test_add_arbitrator_to_pool() {
     let escrow_id = env.register_contract(None, DummyEscrow);
     let rep_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
-    client.initialize(&admin, &rep_id, &300, &escrow_id);
+    client.initialize(&admin, &rep_id, &300, &escrow_id, &false);
 
     let arbitrator = Address::generate(&env);
     client.add_arbitrator(&admin, &arbitrator);
@@ -2058,7 +2257,7 @@ fn test_remove_arbitrator_from_pool() {
     let escrow_id = env.register_contract(None, DummyEscrow);
     let rep_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
-    client.initialize(&admin, &rep_id, &300, &escrow_id);
+    client.initialize(&admin, &rep_id, &300, &escrow_id, &false);
 
     let arbitrator1 = Address::generate(&env);
     let arbitrator2 = Address::generate(&env);
@@ -2113,6 +2312,8 @@ fn test_dispute_assigns_arbitrators() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2121,7 +2322,7 @@ fn test_dispute_assigns_arbitrators() {
     
     // Should assign up to 5 arbitrators
     assert!(assigned.len() <= 5);
-    assert!(assigned.len() > 0);
+    assert!(!assigned.is_empty());
     
     // Assigned arbitrators should not include client or freelancer
     assert!(!assigned.contains(&job_client));
@@ -2151,6 +2352,8 @@ fn test_non_assigned_arbitrator_cannot_vote() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2161,7 +2364,7 @@ fn test_non_assigned_arbitrator_cannot_vote() {
         &dispute_id,
         &non_assigned,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote"), &0);
+        &reason_hash(&env, "Vote"), &None, &0);
 }
 
 #[test]
@@ -2186,12 +2389,14 @@ fn test_assigned_arbitrator_can_vote() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
 
     let assigned = client.get_assigned_arbitrators(&dispute_id);
-    assert!(assigned.len() > 0);
+    assert!(!assigned.is_empty());
 
     // First assigned arbitrator should be able to vote
     let arbitrator = assigned.get(0).unwrap();
@@ -2199,7 +2404,7 @@ fn test_assigned_arbitrator_can_vote() {
         &dispute_id,
         &arbitrator,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote"), &0);
+        &reason_hash(&env, "Vote"), &None, &0);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.votes_for_client, 1);
@@ -2227,6 +2432,8 @@ fn test_auto_resolve_at_3_vote_majority() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2240,7 +2447,7 @@ fn test_auto_resolve_at_3_vote_majority() {
             &dispute_id,
             &arbitrator,
             &VoteChoice::Freelancer,
-            &String::from_str(&env, "Vote for freelancer"), &0);
+            &reason_hash(&env, "Vote for freelancer"), &None, &0);
     }
 
     // Check that dispute was auto-resolved
@@ -2270,6 +2477,8 @@ fn test_unanimous_vote_5_0() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &5u32,
         &None,
     );
@@ -2283,7 +2492,7 @@ fn test_unanimous_vote_5_0() {
             &dispute_id,
             &arbitrator,
             &VoteChoice::Client,
-            &String::from_str(&env, "Vote for client"), &0);
+            &reason_hash(&env, "Vote for client"), &None, &0);
     }
 
     let dispute = client.get_dispute(&dispute_id);
@@ -2313,6 +2522,8 @@ fn test_split_vote_3_2_client_wins() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2326,7 +2537,7 @@ fn test_split_vote_3_2_client_wins() {
             &dispute_id,
             &arbitrator,
             &VoteChoice::Client,
-            &String::from_str(&env, "Vote for client"), &0);
+            &reason_hash(&env, "Vote for client"), &None, &0);
     }
 
     let dispute = client.get_dispute(&dispute_id);
@@ -2356,6 +2567,8 @@ fn test_split_vote_3_2_freelancer_wins() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2369,7 +2582,7 @@ fn test_split_vote_3_2_freelancer_wins() {
             &dispute_id,
             &arbitrator,
             &VoteChoice::Freelancer,
-            &String::from_str(&env, "Vote for freelancer"), &0);
+            &reason_hash(&env, "Vote for freelancer"), &None, &0);
     }
 
     let dispute = client.get_dispute(&dispute_id);
@@ -2399,6 +2612,8 @@ fn test_vote_cast_event_emitted() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &5u32,
         &None,
     );
@@ -2410,7 +2625,7 @@ fn test_vote_cast_event_emitted() {
         &dispute_id,
         &arbitrator,
         &VoteChoice::Client,
-        &String::from_str(&env, "Vote"), &0);
+        &reason_hash(&env, "Vote"), &None, &0);
 
     // Verify VoteCast event was emitted
     let events = env.events().all();
@@ -2447,6 +2662,8 @@ fn test_dispute_resolved_event_emitted_on_auto_resolve() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2460,7 +2677,7 @@ fn test_dispute_resolved_event_emitted_on_auto_resolve() {
             &dispute_id,
             &arbitrator,
             &VoteChoice::Client,
-            &String::from_str(&env, "Vote"), &0);
+            &reason_hash(&env, "Vote"), &None, &0);
     }
 
     // Verify DisputeResolved event was emitted
@@ -2499,6 +2716,8 @@ fn test_arbitrator_cannot_vote_twice() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &5u32,
         &None,
     );
@@ -2511,14 +2730,14 @@ fn test_arbitrator_cannot_vote_twice() {
         &dispute_id,
         &arbitrator,
         &VoteChoice::Client,
-        &String::from_str(&env, "First vote"), &0);
+        &reason_hash(&env, "First vote"), &None, &0);
 
     // Second vote - should fail with AlreadyVoted
     client.cast_vote(
         &dispute_id,
         &arbitrator,
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "Second vote"), &1);
+        &reason_hash(&env, "Second vote"), &None, &1);
 }
 
 #[test]
@@ -2531,7 +2750,7 @@ fn test_dispute_with_empty_arbitrator_pool() {
     let escrow_id = env.register_contract(None, DummyEscrow);
     let rep_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
-    client.initialize(&admin, &rep_id, &300, &escrow_id);
+    client.initialize(&admin, &rep_id, &300, &escrow_id, &false);
 
     let job_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -2543,6 +2762,8 @@ fn test_dispute_with_empty_arbitrator_pool() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2571,7 +2792,7 @@ fn test_exact_tie_resolves_as_5050_split() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     for _ in 0..7 {
         let arb = Address::generate(&env);
         client.add_arbitrator(&admin, &arb);
@@ -2583,6 +2804,8 @@ fn test_exact_tie_resolves_as_5050_split() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Tie dispute"),
+        &ReasonCode::Other,
+        &None,
         &4u32,
         &None,
     );
@@ -2590,10 +2813,10 @@ fn test_exact_tie_resolves_as_5050_split() {
     let assigned = client.get_assigned_arbitrators(&dispute_id);
 
     // 2 votes for client, 2 for freelancer — exact tie
-    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &String::from_str(&env, "c1"), &0);
-    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Freelancer, &String::from_str(&env, "f1"), &0);
-    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &String::from_str(&env, "c2"), &0);
-    client.cast_vote(&dispute_id, &assigned.get(3).unwrap(), &VoteChoice::Freelancer, &String::from_str(&env, "f2"), &0);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &reason_hash(&env, "c1"), &None, &0);
+    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Freelancer, &reason_hash(&env, "f1"), &None, &0);
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason_hash(&env, "c2"), &None, &0);
+    client.cast_vote(&dispute_id, &assigned.get(3).unwrap(), &VoteChoice::Freelancer, &reason_hash(&env, "f2"), &None, &0);
 
     let status = client.resolve_dispute(&dispute_id);
     assert_eq!(status, DisputeStatus::RefundSplit(50));
@@ -2618,7 +2841,7 @@ fn test_cast_vote_nonce_replay_rejected() {
     let user_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
     for _ in 0..5 {
         let arb = Address::generate(&env);
         client.add_arbitrator(&admin, &arb);
@@ -2630,6 +2853,8 @@ fn test_cast_vote_nonce_replay_rejected() {
         &freelancer,
         &user_client,
         &String::from_str(&env, "Replay test"),
+        &ReasonCode::Other,
+        &None,
         &5u32,
         &None,
     );
@@ -2639,13 +2864,13 @@ fn test_cast_vote_nonce_replay_rejected() {
     let voter2 = assigned.get(1).unwrap();
 
     // First vote with nonce 99 succeeds
-    client.cast_vote(&dispute_id, &voter1, &VoteChoice::Client, &String::from_str(&env, "v1"), &99);
+    client.cast_vote(&dispute_id, &voter1, &VoteChoice::Client, &reason_hash(&env, "v1"), &None, &99);
 
     // Different voter with same nonce 99 succeeds (different caller)
-    client.cast_vote(&dispute_id, &voter2, &VoteChoice::Freelancer, &String::from_str(&env, "v2"), &99);
+    client.cast_vote(&dispute_id, &voter2, &VoteChoice::Freelancer, &reason_hash(&env, "v2"), &None, &99);
 
     // voter1 tries to replay nonce 99 — should fail with NonceReplay
-    client.cast_vote(&dispute_id, &voter1, &VoteChoice::Client, &String::from_str(&env, "replay"), &99);
+    client.cast_vote(&dispute_id, &voter1, &VoteChoice::Client, &reason_hash(&env, "replay"), &None, &99);
 }
 
 // ── Issue #770: submit_evidence emits on-chain event ────────────────────────
@@ -2667,11 +2892,13 @@ fn test_submit_evidence_emits_event() {
         &freelancer,
         &party_client,
         &String::from_str(&env, "Work not delivered"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
 
-    let hash: BytesN<32> = BytesN::random(&env);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1u8; 32]);
     client.submit_evidence(&dispute_id, &party_client, &hash);
 
     let events = env.events().all();
@@ -2695,12 +2922,14 @@ fn test_submit_evidence_stores_and_retrieves() {
         &freelancer,
         &party_client,
         &String::from_str(&env, "Scope creep"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
 
-    let hash1: BytesN<32> = BytesN::random(&env);
-    let hash2: BytesN<32> = BytesN::random(&env);
+    let hash1: BytesN<32> = BytesN::from_array(&env, &[1u8; 32]);
+    let hash2: BytesN<32> = BytesN::from_array(&env, &[2u8; 32]);
 
     client.submit_evidence(&dispute_id, &party_client, &hash1);
     client.submit_evidence(&dispute_id, &freelancer, &hash2);
@@ -2730,11 +2959,13 @@ fn test_submit_evidence_rejects_non_party() {
         &freelancer,
         &party_client,
         &String::from_str(&env, "Breach"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
 
-    let hash: BytesN<32> = BytesN::random(&env);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1u8; 32]);
     client.submit_evidence(&dispute_id, &outsider, &hash);
 }
 
@@ -2748,7 +2979,7 @@ fn test_submit_evidence_rejects_nonexistent_dispute() {
     let client = DisputeContractClient::new(&env, &contract_id);
 
     let submitter = Address::generate(&env);
-    let hash: BytesN<32> = BytesN::random(&env);
+    let hash: BytesN<32> = BytesN::from_array(&env, &[1u8; 32]);
     client.submit_evidence(&999u64, &submitter, &hash);
 }
 
@@ -2765,7 +2996,7 @@ fn test_cast_vote_valid_choices_accepted() {
     let reputation_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_id, &0, &escrow_id);
+    client.initialize(&admin, &reputation_id, &0, &escrow_id, &false);
 
     let party_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -2780,6 +3011,8 @@ fn test_cast_vote_valid_choices_accepted() {
         &freelancer,
         &party_client,
         &String::from_str(&env, "dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2791,7 +3024,7 @@ fn test_cast_vote_valid_choices_accepted() {
         &dispute_id,
         &assigned.get(0).unwrap(),
         &VoteChoice::Client,
-        &String::from_str(&env, "for client"),
+        &reason_hash(&env, "for client"), &None,
         &1u64,
     );
     let dispute = client.get_dispute(&dispute_id);
@@ -2802,7 +3035,7 @@ fn test_cast_vote_valid_choices_accepted() {
         &dispute_id,
         &assigned.get(1).unwrap(),
         &VoteChoice::Freelancer,
-        &String::from_str(&env, "for freelancer"),
+        &reason_hash(&env, "for freelancer"), &None,
         &2u64,
     );
     let dispute = client.get_dispute(&dispute_id);
@@ -2813,7 +3046,7 @@ fn test_cast_vote_valid_choices_accepted() {
         &dispute_id,
         &assigned.get(2).unwrap(),
         &VoteChoice::RefundSplit(50u32),
-        &String::from_str(&env, "refund split"),
+        &reason_hash(&env, "refund split"), &None,
         &3u64,
     );
     let dispute = client.get_dispute(&dispute_id);
@@ -2838,7 +3071,7 @@ fn test_cast_vote_split_award_bps_validation() {
     let reputation_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_id, &0, &escrow_id);
+    client.initialize(&admin, &reputation_id, &0, &escrow_id, &false);
 
     let party_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -2853,6 +3086,8 @@ fn test_cast_vote_split_award_bps_validation() {
         &freelancer,
         &party_client,
         &String::from_str(&env, "dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2864,7 +3099,7 @@ fn test_cast_vote_split_award_bps_validation() {
         &dispute_id,
         &assigned.get(0).unwrap(),
         &VoteChoice::SplitAward(6000u32, 4000u32),
-        &String::from_str(&env, "split"),
+        &reason_hash(&env, "split"), &None,
         &1u64,
     );
     let dispute = client.get_dispute(&dispute_id);
@@ -2883,7 +3118,7 @@ fn test_cast_vote_split_award_invalid_bps_rejected() {
     let reputation_id = env.register_contract(None, MockReputationContract);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &reputation_id, &0, &escrow_id);
+    client.initialize(&admin, &reputation_id, &0, &escrow_id, &false);
 
     let party_client = Address::generate(&env);
     let freelancer = Address::generate(&env);
@@ -2898,6 +3133,8 @@ fn test_cast_vote_split_award_invalid_bps_rejected() {
         &freelancer,
         &party_client,
         &String::from_str(&env, "dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2909,7 +3146,7 @@ fn test_cast_vote_split_award_invalid_bps_rejected() {
         &dispute_id,
         &assigned.get(0).unwrap(),
         &VoteChoice::SplitAward(9999u32, 0u32),
-        &String::from_str(&env, "bad split"),
+        &reason_hash(&env, "bad split"), &None,
         &1u64,
     );
 }
@@ -2918,14 +3155,14 @@ fn test_cast_vote_split_award_invalid_bps_rejected() {
 
 fn setup_dispute_with_failing_escrow(
     env: &Env,
-) -> (DisputeContractClient, Address, Address, u64) {
+) -> (DisputeContractClient<'_>, Address, Address, u64) {
     let dispute_contract_id = env.register_contract(None, DisputeContract);
     let client = DisputeContractClient::new(env, &dispute_contract_id);
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     // Register the *failing* escrow so every resolve_dispute_callback panics.
     let escrow_contract_id = env.register_contract(None, DummyEscrowFailing);
     let admin = Address::generate(env);
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..5 {
         let arb = Address::generate(env);
@@ -2940,6 +3177,8 @@ fn setup_dispute_with_failing_escrow(
         &freelancer,
         &job_client,
         &String::from_str(env, "test dispute"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
@@ -2957,9 +3196,9 @@ fn test_resolve_dispute_escrow_fail_enters_resolution_failed() {
         setup_dispute_with_failing_escrow(&env);
 
     let assigned = client.get_assigned_arbitrators(&dispute_id);
-    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &0u64);
-    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &1u64);
-    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &2u64);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &0u64);
+    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &1u64);
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &2u64);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.status, DisputeStatus::ResolutionFailed);
@@ -2977,9 +3216,9 @@ fn test_retry_escrow_callback_succeeds() {
         setup_dispute_with_failing_escrow(&env);
 
     let assigned = client.get_assigned_arbitrators(&dispute_id);
-    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &0u64);
-    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &1u64);
-    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &2u64);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &0u64);
+    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &1u64);
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &2u64);
 
     assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolutionFailed);
 
@@ -3010,9 +3249,9 @@ fn test_retry_escrow_callback_idempotent_on_still_failing_escrow() {
         setup_dispute_with_failing_escrow(&env);
 
     let assigned = client.get_assigned_arbitrators(&dispute_id);
-    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &0u64);
-    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &1u64);
-    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &2u64);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &0u64);
+    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &1u64);
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &2u64);
 
     // First retry — escrow still broken.
     let status1 = client.retry_escrow_callback(&dispute_id);
@@ -3038,7 +3277,7 @@ fn test_malicious_filing_escrow_fail_and_retry() {
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let escrow_contract_id = env.register_contract(None, DummyEscrowFailing);
     let admin = Address::generate(&env);
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..10 {
         let arb = Address::generate(&env);
@@ -3053,16 +3292,18 @@ fn test_malicious_filing_escrow_fail_and_retry() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "bad faith"),
+        &ReasonCode::Other,
+        &None,
         &5u32,
         &None,
     );
 
     let assigned = client.get_assigned_arbitrators(&dispute_id);
-    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &0u64);
-    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &1u64);
-    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &2u64);
-    client.cast_vote(&dispute_id, &assigned.get(3).unwrap(), &VoteChoice::MaliciousFiling, &String::from_str(&env, "bad faith"), &3u64);
-    client.cast_vote(&dispute_id, &assigned.get(4).unwrap(), &VoteChoice::Client,           &String::from_str(&env, "disagree"), &4u64);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &0u64);
+    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &1u64);
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &2u64);
+    client.cast_vote(&dispute_id, &assigned.get(3).unwrap(), &VoteChoice::MaliciousFiling, &reason_hash(&env, "bad faith"), &None, &3u64);
+    client.cast_vote(&dispute_id, &assigned.get(4).unwrap(), &VoteChoice::Client,           &reason_hash(&env, "disagree"), &None, &4u64);
 
     assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolutionFailed);
 
@@ -3090,7 +3331,7 @@ fn test_retry_on_already_resolved_dispute_fails() {
     let reputation_contract_id = env.register_contract(None, MockReputationContract);
     let escrow_contract_id = env.register_contract(None, DummyEscrow);
     let admin = Address::generate(&env);
-    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
 
     for _ in 0..3 {
         let arb = Address::generate(&env);
@@ -3105,15 +3346,1521 @@ fn test_retry_on_already_resolved_dispute_fails() {
         &freelancer,
         &job_client,
         &String::from_str(&env, "test"),
+        &ReasonCode::Other,
+        &None,
         &3u32,
         &None,
     );
 
     let assigned = client.get_assigned_arbitrators(&dispute_id);
-    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &0u64);
-    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &1u64);
-    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &String::from_str(&env, "r"), &2u64);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &0u64);
+    client.cast_vote(&dispute_id, &assigned.get(1).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &1u64);
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &2u64);
 
     // Dispute is now ResolvedForClient — retry should return AlreadyResolved (#7).
     client.retry_escrow_callback(&dispute_id);
 }
+
+// ── Resolution records ───────────────────────────────────────────────────────
+
+#[test]
+fn test_auto_resolve_stores_resolution_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, _, dispute_id) = setup_dispute_with_votes(&env, 3, 0);
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+
+    let record = client.get_resolution(&dispute_id, &ROUND_ORIGINAL);
+    assert_eq!(record.dispute_id, dispute_id);
+    assert_eq!(record.round, ROUND_ORIGINAL);
+    assert_eq!(record.status, DisputeStatus::ResolvedForClient);
+    assert_eq!(record.votes_for_client, 3);
+    assert_eq!(record.votes_for_freelancer, 0);
+    assert_eq!(record.client_weight, 3);
+    assert_eq!(record.total_weight_cast, 3);
+    assert!(!record.malicious);
+    assert!(!record.tie_break_applied);
+    // The third vote triggered auto-resolution, so its caster is the resolver.
+    assert_eq!(record.resolver, Some(assigned.get(2).unwrap()));
+    assert_eq!(record.resolved_at, env.ledger().timestamp());
+}
+
+#[test]
+fn test_force_resolve_records_tie_break_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let dispute_contract_id = env.register_contract(None, DisputeContract);
+    let client = DisputeContractClient::new(&env, &dispute_contract_id);
+    let escrow_contract_id = env.register_contract(None, DummyEscrow);
+    let reputation_contract_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+
+    let user_client = Address::generate(&env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &user_client,
+        &Address::generate(&env),
+        &user_client,
+        &String::from_str(&env, "No votes"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &Some(TieBreakMethod::Escalate),
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp += VOTING_PERIOD_SECS + 1);
+    client.force_resolve_timeout(&dispute_id);
+
+    let record = client.get_resolution(&dispute_id, &ROUND_ORIGINAL);
+    assert_eq!(record.status, DisputeStatus::Escalated);
+    assert!(record.tie_break_applied);
+    assert_eq!(record.tie_break_method, TieBreakMethod::Escalate);
+    assert_eq!(record.resolver, None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_get_resolution_missing_round_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _, _, _, _, dispute_id) = setup_dispute_with_votes(&env, 3, 0);
+    client.get_resolution(&dispute_id, &ROUND_APPEAL);
+}
+
+// ── Per-dispute reward pool accounting ──────────────────────────────────────
+
+fn setup_pool_dispute(env: &Env) -> (DisputeContractClient<'_>, Address, u64, Address, Address) {
+    let (client, _, _, admin) = setup_initialized_dispute_contract(env);
+    let user_client = Address::generate(env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &user_client,
+        &Address::generate(env),
+        &user_client,
+        &String::from_str(env, "Pool"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(env, &token).mint(&user_client, &1_000);
+    client.set_pool_token(&admin, &token, &true);
+    (client, admin, dispute_id, token, user_client)
+}
+
+#[test]
+fn test_pool_balance_tracks_deposits_and_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dispute_id, token, depositor) = setup_pool_dispute(&env);
+
+    assert_eq!(client.get_pool_balance(&dispute_id).len(), 0);
+    client.fund_pool(&dispute_id, &depositor, &token, &600);
+    assert_eq!(
+        client.get_pool_balance(&dispute_id),
+        vec![&env, (token.clone(), 600)]
+    );
+
+    let recipient = Address::generate(&env);
+    client.payout_from_pool(&admin, &dispute_id, &token, &recipient, &250);
+    assert_eq!(
+        client.get_pool_balance(&dispute_id),
+        vec![&env, (token.clone(), 350)]
+    );
+    assert_eq!(
+        soroban_sdk::token::TokenClient::new(&env, &token).balance(&recipient),
+        250
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_pool_payout_cannot_spend_other_disputes_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dispute_id, token, depositor) = setup_pool_dispute(&env);
+
+    // A second dispute's funds sit in the same contract balance...
+    let other_party = Address::generate(&env);
+    let other_id = client.raise_dispute(
+        &2u64,
+        &other_party,
+        &Address::generate(&env),
+        &other_party,
+        &String::from_str(&env, "Other"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    client.fund_pool(&other_id, &depositor, &token, &900);
+    client.fund_pool(&dispute_id, &depositor, &token, &100);
+
+    // ...but the first dispute can only spend what was credited to it.
+    client.payout_from_pool(&admin, &dispute_id, &token, &Address::generate(&env), &101);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #100)")]
+fn test_fund_pool_rejects_tokens_not_allowlisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dispute_id, token, depositor) = setup_pool_dispute(&env);
+    client.set_pool_token(&admin, &token, &false);
+    client.fund_pool(&dispute_id, &depositor, &token, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")]
+fn test_pool_refund_requires_expired_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, dispute_id, token, depositor) = setup_pool_dispute(&env);
+    client.fund_pool(&dispute_id, &depositor, &token, &100);
+    client.claim_pool_refund(&dispute_id, &depositor, &token);
+}
+
+// ── Voter privacy mode ───────────────────────────────────────────────────────
+
+fn setup_private_resolved_dispute(
+    env: &Env,
+) -> (DisputeContractClient<'_>, u64, Vec<Address>, Address, Address) {
+    let contract_id = env.register_contract(None, DisputeContract);
+    let client = DisputeContractClient::new(env, &contract_id);
+    let escrow_id = env.register_contract(None, DummyEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &true);
+    for _ in 0..10 {
+        client.add_arbitrator(&admin, &Address::generate(env));
+    }
+
+    let user_client = Address::generate(env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &user_client,
+        &Address::generate(env),
+        &user_client,
+        &String::from_str(env, "Private"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    // The panel is not public: each juror checks their own seat.
+    let mut assigned = Vec::new(env);
+    for juror in client.get_arbitrator_pool().iter() {
+        if client.is_on_panel(&dispute_id, &juror) {
+            assigned.push_back(juror);
+        }
+    }
+    let reason = reason_hash(env, "r");
+    // One losing vote, then three client votes trigger auto-resolution.
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Freelancer, &reason, &None, &0);
+    for i in 1..4 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(env, &token).mint(&user_client, &300);
+    client.set_pool_token(&admin, &token, &true);
+    client.fund_pool(&dispute_id, &user_client, &token, &300);
+    (client, dispute_id, assigned, token, admin)
+}
+
+#[test]
+fn test_private_mode_hides_voter_addresses() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, dispute_id, _, _, _) = setup_private_resolved_dispute(&env);
+
+    assert!(client.is_voter_privacy_enabled());
+    assert_eq!(client.try_get_votes(&dispute_id), Err(Ok(DisputeError::VotesPrivate)));
+    assert_eq!(client.try_get_arbitrators(&dispute_id), Err(Ok(DisputeError::VotesPrivate)));
+    assert_eq!(client.try_get_assigned_arbitrators(&dispute_id), Err(Ok(DisputeError::VotesPrivate)));
+    assert!(client.get_dispute(&dispute_id).assigned_arbitrators.is_empty());
+
+    env.ledger().with_mut(|l| l.timestamp += 172_800 + 1);
+    let public = client.get_public_votes(&dispute_id);
+    assert_eq!(public.len(), 4);
+    for vote in public.iter() {
+        assert_eq!(vote.voter, None);
+    }
+    assert_ne!(public.get(0).unwrap().voter_commitment, public.get(1).unwrap().voter_commitment);
+    assert!(client.get_resolution(&dispute_id, &ROUND_ORIGINAL).resolver.is_none());
+}
+
+#[test]
+fn test_private_mode_reward_claim_verifies_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, dispute_id, assigned, token, _) = setup_private_resolved_dispute(&env);
+    let winner = assigned.get(1).unwrap();
+    env.ledger().with_mut(|l| l.timestamp += 172_800 + 1);
+
+    assert_eq!(
+        client.claim_voter_reward(&dispute_id, &winner),
+        vec![&env, (token.clone(), 100_i128)]
+    );
+    assert_eq!(soroban_sdk::token::TokenClient::new(&env, &token).balance(&winner), 100);
+    assert_eq!(
+        client.try_claim_voter_reward(&dispute_id, &winner),
+        Err(Ok(DisputeError::RewardAlreadyClaimed))
+    );
+    // The losing voter and a non-voter are both rejected.
+    assert_eq!(
+        client.try_claim_voter_reward(&dispute_id, &assigned.get(0).unwrap()),
+        Err(Ok(DisputeError::NotEligibleForReward))
+    );
+    assert_eq!(
+        client.try_claim_voter_reward(&dispute_id, &Address::generate(&env)),
+        Err(Ok(DisputeError::NotEligibleForReward))
+    );
+    // Remaining winners still get the share fixed at the first claim.
+    assert_eq!(
+        client.claim_voter_reward(&dispute_id, &assigned.get(3).unwrap()),
+        vec![&env, (token, 100_i128)]
+    );
+}
+
+#[test]
+fn test_private_mode_delegate_excluded_from_appeal_panel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, DisputeContract);
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let escrow_id = env.register_contract(None, DummyEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &true);
+    for _ in 0..10 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+
+    let user_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &user_client,
+        &freelancer,
+        &user_client,
+        &String::from_str(&env, "Private"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let mut assigned = Vec::new(&env);
+    let mut off_panel = Vec::new(&env);
+    for juror in client.get_arbitrator_pool().iter() {
+        if client.is_on_panel(&dispute_id, &juror) {
+            assigned.push_back(juror);
+        } else {
+            off_panel.push_back(juror);
+        }
+    }
+    // A juror hands their vote to an arbitrator from outside the panel.
+    let owner = assigned.get(0).unwrap();
+    let delegate = off_panel.get(0).unwrap();
+    client.delegate_vote(&owner, &delegate, &1u64);
+
+    let reason = reason_hash(&env, "r");
+    client.cast_vote(&dispute_id, &delegate, &VoteChoice::Client, &reason, &None, &0);
+    for i in 1..3 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForClient);
+
+    let appeal_id = client.appeal(&dispute_id, &freelancer);
+    let excluded = client.get_appeal(&appeal_id).excluded_arbitrators;
+    assert_eq!(excluded.len(), 3);
+    assert!(excluded.contains(&delegate));
+    assert_eq!(
+        client.try_cast_appeal_vote(&appeal_id, &delegate, &VoteChoice::Freelancer, &reason, &None),
+        Err(Ok(DisputeError::ConflictOfInterest))
+    );
+}
+
+#[test]
+fn test_reward_claim_pays_every_pool_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, dispute_id, assigned, fee_token, admin) = setup_private_resolved_dispute(&env);
+    let winner = assigned.get(1).unwrap();
+    let depositor = Address::generate(&env);
+
+    // An appeal bond posted in a second token.
+    let bond_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &bond_token).mint(&depositor, &90);
+    client.set_pool_token(&admin, &bond_token, &true);
+    client.fund_pool(&dispute_id, &depositor, &bond_token, &90);
+    env.ledger().with_mut(|l| l.timestamp += 172_800 + 1);
+
+    assert_eq!(
+        client.claim_voter_reward(&dispute_id, &winner),
+        vec![&env, (fee_token.clone(), 100_i128), (bond_token.clone(), 30_i128)]
+    );
+    assert_eq!(soroban_sdk::token::TokenClient::new(&env, &bond_token).balance(&winner), 30);
+
+    // A token credited after the first claim is claimable on its own.
+    let late_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &late_token).mint(&depositor, &60);
+    client.set_pool_token(&admin, &late_token, &true);
+    client.fund_pool(&dispute_id, &depositor, &late_token, &60);
+    assert_eq!(
+        client.claim_voter_reward(&dispute_id, &winner),
+        vec![&env, (late_token, 20_i128)]
+    );
+    assert_eq!(
+        client.try_claim_voter_reward(&dispute_id, &winner),
+        Err(Ok(DisputeError::RewardAlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_rewards_and_vote_choices_locked_until_appeal_window_closes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, dispute_id, assigned, token, _) = setup_private_resolved_dispute(&env);
+    let winner = assigned.get(1).unwrap();
+
+    assert_eq!(
+        client.try_claim_voter_reward(&dispute_id, &winner),
+        Err(Ok(DisputeError::RewardsLocked))
+    );
+    assert_eq!(client.get_public_votes(&dispute_id).len(), 0);
+    assert_eq!(client.get_dispute(&dispute_id).votes_for_client, 3);
+
+    env.ledger().with_mut(|l| l.timestamp += 172_800 + 1);
+    assert_eq!(client.get_public_votes(&dispute_id).get(1).unwrap().choice, VoteChoice::Client);
+    assert_eq!(
+        client.claim_voter_reward(&dispute_id, &winner),
+        vec![&env, (token, 100_i128)]
+    );
+}
+
+#[test]
+fn test_public_mode_exposes_voter_in_public_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _, _, dispute_id) = setup_dispute_with_votes(&env, 1, 0);
+
+    assert!(!client.is_voter_privacy_enabled());
+    let votes = client.get_votes(&dispute_id);
+    let public = client.get_public_votes(&dispute_id);
+    assert_eq!(public.get(0).unwrap().voter, votes.get(0).unwrap().voter);
+}
+
+// ── Governance parameters ────────────────────────────────────────────────────
+
+#[test]
+fn test_set_parameter_validates_name_bounds_and_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+
+    assert_eq!(client.get_parameter(&symbol_short!("mal_bps")), 8_000);
+    assert_eq!(client.get_parameter(&symbol_short!("min_rep")), 300);
+
+    client.set_parameter(&admin, &symbol_short!("mal_bps"), &6_000);
+    assert_eq!(client.get_parameter(&symbol_short!("mal_bps")), 6_000);
+    // Parameters with a dedicated setter share its storage.
+    client.set_parameter(&admin, &symbol_short!("cooldown"), &3_600);
+    assert_eq!(client.get_parameter(&symbol_short!("cooldown")), 3_600);
+    client.set_cooldown_duration(&admin, &7_200);
+    assert_eq!(client.get_parameter(&symbol_short!("cooldown")), 7_200);
+
+    assert_eq!(
+        client.try_set_parameter(&admin, &symbol_short!("mal_bps"), &5_000),
+        Err(Ok(DisputeError::ParameterOutOfBounds))
+    );
+    assert_eq!(
+        client.try_set_parameter(&admin, &symbol_short!("bogus"), &1),
+        Err(Ok(DisputeError::UnknownParameter))
+    );
+    assert_eq!(
+        client.try_get_parameter(&symbol_short!("bogus")),
+        Err(Ok(DisputeError::UnknownParameter))
+    );
+    assert_eq!(
+        client.try_set_parameter(&Address::generate(&env), &symbol_short!("min_votes"), &4),
+        Err(Ok(DisputeError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_parameters_apply_to_new_disputes_and_appeals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+
+    // Resolve one dispute under the defaults.
+    let party = Address::generate(&env);
+    let resolved_id = client.raise_dispute(
+        &1u64,
+        &party,
+        &Address::generate(&env),
+        &party,
+        &String::from_str(&env, "Defaults"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let assigned = client.get_assigned_arbitrators(&resolved_id);
+    let reason = reason_hash(&env, "r");
+    for i in 0..3 {
+        client.cast_vote(&resolved_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+    assert_eq!(client.get_dispute(&resolved_id).status, DisputeStatus::ResolvedForClient);
+
+    client.set_parameter(&admin, &symbol_short!("min_votes"), &5);
+    client.set_parameter(&admin, &symbol_short!("tie_pol"), &1);
+    client.set_parameter(&admin, &symbol_short!("max_appl"), &0);
+
+    // Appeals are disabled for the resolved dispute...
+    assert_eq!(client.try_appeal(&resolved_id, &party), Err(Ok(DisputeError::AlreadyAppealed)));
+
+    // ...and new disputes pick up the raised floor and default tie policy.
+    let other = Address::generate(&env);
+    let dispute_id = client.raise_dispute(
+        &2u64,
+        &other,
+        &Address::generate(&env),
+        &other,
+        &String::from_str(&env, "Params"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.min_votes, 5);
+    assert_eq!(dispute.tie_break_method, TieBreakMethod::FavorClient);
+}
+
+fn resolve_and_appeal(env: &Env, client: &DisputeContractClient<'_>) -> (u64, u64) {
+    let party = Address::generate(env);
+    let freelancer = Address::generate(env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &party,
+        &freelancer,
+        &party,
+        &String::from_str(env, "Appeal"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    let reason = reason_hash(env, "r");
+    for i in 0..3 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForClient);
+
+    // Only the losing freelancer may appeal a verdict for the client.
+    let appeal_id = client.appeal(&dispute_id, &freelancer);
+    let choices = [VoteChoice::Freelancer, VoteChoice::Freelancer, VoteChoice::Client];
+    for choice in choices.iter() {
+        client.cast_appeal_vote(&appeal_id, &Address::generate(env), choice, &reason, &None);
+    }
+    (dispute_id, appeal_id)
+}
+
+#[test]
+fn test_appeal_supermajority_overturns_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _admin) = setup_initialized_dispute_contract(&env);
+    assert_eq!(client.get_parameter(&symbol_short!("ovrt_bps")), 6_000);
+
+    // 2 of 3 votes (66%) clears the default 60% threshold.
+    let (dispute_id, appeal_id) = resolve_and_appeal(&env, &client);
+    assert_eq!(client.resolve_appeal(&appeal_id), AppealStatus::ResolvedForFreelancer);
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForFreelancer);
+}
+
+#[test]
+fn test_appeal_below_overturn_threshold_upholds_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    client.set_parameter(&admin, &symbol_short!("ovrt_bps"), &7_000);
+
+    let (dispute_id, appeal_id) = resolve_and_appeal(&env, &client);
+    assert_eq!(client.resolve_appeal(&appeal_id), AppealStatus::Upheld);
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForClient);
+    assert_eq!(client.get_appeal(&appeal_id).status, AppealStatus::Upheld);
+
+    // The upheld round still counts toward the appeal limit.
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(
+        client.try_appeal(&dispute_id, &dispute.client),
+        Err(Ok(DisputeError::AlreadyAppealed))
+    );
+    assert_eq!(
+        client.try_resolve_appeal(&appeal_id),
+        Err(Ok(DisputeError::AlreadyResolved))
+    );
+}
+
+#[test]
+fn test_preview_resolution_matches_resolve_without_mutating() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+
+    let party = Address::generate(&env);
+    let other = Address::generate(&env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &party,
+        &other,
+        &party,
+        &String::from_str(&env, "Preview"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    let reason = reason_hash(&env, "r");
+    for i in 0..2 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+
+    let preview = client.preview_resolution(&dispute_id);
+    assert!(!preview.resolvable);
+    assert_eq!(preview.status, DisputeStatus::ResolvedForClient);
+    assert_eq!(preview.resolution, DisputeResolution::ClientWins);
+    assert_eq!(preview.winner, Some(party.clone()));
+    assert!(!preview.malicious);
+    assert_eq!(preview.amount_at_stake, 600);
+    assert_eq!((preview.to_client, preview.to_freelancer, preview.to_treasury), (600, 0, 0));
+    // Nothing was written.
+    assert!(matches!(client.get_dispute(&dispute_id).status, DisputeStatus::Open | DisputeStatus::Voting));
+    assert_eq!(
+        client.try_get_resolution(&dispute_id, &ROUND_ORIGINAL),
+        Err(Ok(DisputeError::ResolutionNotFound))
+    );
+
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    assert_eq!(client.get_dispute(&dispute_id).status, preview.status);
+    assert_eq!(
+        client.try_preview_resolution(&dispute_id),
+        Err(Ok(DisputeError::AlreadyResolved))
+    );
+}
+
+#[test]
+fn test_only_losing_party_may_appeal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    client.set_parameter(&admin, &symbol_short!("max_appl"), &2);
+
+    let party = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let dispute_id = client.raise_dispute(&1u64, &party, &freelancer, &party, &String::from_str(&env, "Loser"), &ReasonCode::Other, &None, &3u32, &None);
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    let reason = reason_hash(&env, "r");
+    for i in 0..3 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+
+    // The winning client cannot appeal its own verdict.
+    assert_eq!(client.try_appeal(&dispute_id, &party), Err(Ok(DisputeError::NotLosingParty)));
+
+    let appeal_id = client.appeal(&dispute_id, &freelancer);
+    assert_eq!(client.get_appeal(&appeal_id).round, 1);
+    // A second round cannot open while the first is still running.
+    assert_eq!(client.try_appeal(&dispute_id, &freelancer), Err(Ok(DisputeError::AppealInProgress)));
+
+    let rounds = client.get_appeal_rounds(&dispute_id);
+    assert_eq!(rounds.len(), 1);
+    let round = rounds.get(0).unwrap();
+    assert_eq!(round.appeal_id, appeal_id);
+    assert_eq!(round.appellant, freelancer);
+    assert_eq!(round.appealed_outcome, DisputeStatus::ResolvedForClient);
+}
+
+#[test]
+fn test_upheld_outcome_cannot_be_appealed_twice_by_same_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    client.set_parameter(&admin, &symbol_short!("max_appl"), &2);
+    client.set_parameter(&admin, &symbol_short!("ovrt_bps"), &7_000);
+
+    let (dispute_id, appeal_id) = resolve_and_appeal(&env, &client);
+    assert_eq!(client.resolve_appeal(&appeal_id), AppealStatus::Upheld);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(client.try_appeal(&dispute_id, &dispute.freelancer), Err(Ok(DisputeError::OutcomeAlreadyAppealed)));
+    assert_eq!(client.try_appeal(&dispute_id, &dispute.client), Err(Ok(DisputeError::NotLosingParty)));
+}
+
+#[test]
+fn test_overturned_outcome_can_be_appealed_by_new_loser() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    client.set_parameter(&admin, &symbol_short!("max_appl"), &2);
+
+    let (dispute_id, appeal_id) = resolve_and_appeal(&env, &client);
+    assert_eq!(client.resolve_appeal(&appeal_id), AppealStatus::ResolvedForFreelancer);
+
+    // The verdict now favours the freelancer, so only the client may appeal.
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(client.try_appeal(&dispute_id, &dispute.freelancer), Err(Ok(DisputeError::NotLosingParty)));
+    let second = client.appeal(&dispute_id, &dispute.client);
+    assert_eq!(client.get_appeal(&second).round, 2);
+
+    let rounds = client.get_appeal_rounds(&dispute_id);
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds.get(1).unwrap().appellant, dispute.client);
+    assert_eq!(rounds.get(1).unwrap().appealed_outcome, DisputeStatus::ResolvedForFreelancer);
+}
+
+#[test]
+fn test_juror_pruned_after_missed_disputes_until_reactivated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    assert_eq!(client.get_parameter(&symbol_short!("max_miss")), 3);
+    client.set_parameter(&admin, &symbol_short!("max_miss"), &1);
+    assert_eq!(client.get_active_juror_count(), 10);
+
+    let party = Address::generate(&env);
+    let dispute_id = client.raise_dispute(&1u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Idle"), &ReasonCode::Other, &None, &3u32, &None);
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    assert!(assigned.len() > 3);
+    let reason = reason_hash(&env, "r");
+    for i in 0..2 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+    env.ledger().with_mut(|l| l.timestamp += 604_800 + 1);
+    assert_eq!(client.force_resolve_timeout(&dispute_id), DisputeStatus::ResolvedForClient);
+
+    // Voters stay active; every assigned juror who let the period run out is
+    // pruned.
+    let idle = assigned.get(2).unwrap();
+    assert!(!client.is_juror_inactive(&assigned.get(0).unwrap()));
+    for i in 2..assigned.len() {
+        assert!(client.is_juror_inactive(&assigned.get(i).unwrap()));
+    }
+    let active = 10 - (assigned.len() - 2);
+    assert_eq!(client.get_active_juror_count(), active);
+
+    // Inactive jurors are left out of new selections.
+    let other = Address::generate(&env);
+    let next_id = client.raise_dispute(&2u64, &other, &Address::generate(&env), &other, &String::from_str(&env, "Next"), &ReasonCode::Other, &None, &3u32, &None);
+    for juror in client.get_assigned_arbitrators(&next_id).iter() {
+        assert!(!client.is_juror_inactive(&juror));
+    }
+
+    client.reactivate_juror(&idle);
+    assert!(!client.is_juror_inactive(&idle));
+    assert_eq!(client.get_active_juror_count(), active + 1);
+    assert_eq!(client.try_reactivate_juror(&idle), Err(Ok(DisputeError::JurorNotInactive)));
+}
+
+#[test]
+fn test_inactive_jurors_leave_the_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &env.register_contract(None, MockReputationContract), &300, &env.register_contract(None, DummyEscrow), &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    client.set_parameter(&admin, &symbol_short!("max_miss"), &1);
+    let reason = reason_hash(&env, "r");
+
+    // Both panels are the whole pool of five.
+    let party = Address::generate(&env);
+    let first = client.raise_dispute(&1u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "First"), &ReasonCode::Other, &None, &3u32, &None);
+    env.ledger().with_mut(|l| l.timestamp += 1_000);
+    let other = Address::generate(&env);
+    let second = client.raise_dispute(&2u64, &other, &Address::generate(&env), &other, &String::from_str(&env, "Second"), &ReasonCode::Other, &None, &3u32, &None);
+    let panel = client.get_assigned_arbitrators(&first);
+    assert_eq!(panel.len(), 5);
+
+    // Only two jurors vote on the first dispute; the other three are pruned.
+    for i in 0..2 {
+        client.cast_vote(&first, &panel.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+    env.ledger().with_mut(|l| l.timestamp += 604_800 - 500);
+    client.force_resolve_timeout(&first);
+    assert_eq!(client.get_active_juror_count(), 2);
+
+    // The second dispute needs 3 votes, but only two of its jurors are left
+    // to cast them: two votes make the quorum.
+    client.cast_vote(&second, &panel.get(0).unwrap(), &VoteChoice::Freelancer, &reason, &None, &1);
+    assert!(!client.preview_resolution(&second).resolvable);
+    assert_eq!(client.try_resolve_dispute(&second), Err(Ok(DisputeError::NotEnoughVotes)));
+    client.cast_vote(&second, &panel.get(1).unwrap(), &VoteChoice::Freelancer, &reason, &None, &1);
+    assert!(client.preview_resolution(&second).resolvable);
+    assert_eq!(client.resolve_dispute(&second), DisputeStatus::ResolvedForFreelancer);
+}
+
+#[test]
+fn test_job_snapshot_recorded_at_dispute_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    let party = Address::generate(&env);
+    let dispute_id = client.raise_dispute(&7u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Snapshot"), &ReasonCode::Other, &None, &3u32, &None);
+
+    let snapshot = client.get_job_snapshot(&dispute_id).unwrap();
+    assert_eq!(snapshot.job_id, 7);
+    assert_eq!(snapshot.total_amount, 1000);
+    assert_eq!(snapshot.taken_at, 5_000);
+    assert_eq!(snapshot.milestones.len(), 2);
+    let second = snapshot.milestones.get(1).unwrap();
+    assert_eq!((second.id, second.amount, second.status), (1, 600, escrow::MilestoneStatus::Submitted));
+    assert_eq!(client.get_job_snapshot(&(dispute_id + 1)), None);
+
+    // An escrow that cannot serve the job leaves the dispute without a snapshot.
+    let (other_client, _, _, _) = setup_initialized_dispute_contract(&env);
+    let other_id = other_client.raise_dispute(&1u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "None"), &ReasonCode::Other, &None, &3u32, &None);
+    assert_eq!(other_client.get_job_snapshot(&other_id), None);
+}
+
+#[test]
+fn test_initiator_stakes_share_of_disputed_value() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    job_escrow::JobEscrowClient::new(&env, &escrow_id).set_token(&token);
+    assert_eq!(client.get_required_stake(&7u64), 0);
+
+    // 5% of the 600 still unapproved.
+    client.set_parameter(&admin, &symbol_short!("stake_bps"), &500);
+    assert_eq!(client.get_required_stake(&7u64), 30);
+
+    let party = Address::generate(&env);
+    let reason = String::from_str(&env, "Stake");
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&party, &29);
+    assert_eq!(
+        client.try_raise_dispute(&7u64, &party, &Address::generate(&env), &party, &reason, &ReasonCode::Other, &None, &3u32, &None),
+        Err(Ok(DisputeError::InsufficientStake))
+    );
+
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&party, &1);
+    let dispute_id = client.raise_dispute(&7u64, &party, &Address::generate(&env), &party, &reason, &ReasonCode::Other, &None, &3u32, &None);
+    assert_eq!(soroban_sdk::token::TokenClient::new(&env, &token).balance(&party), 0);
+    assert_eq!(client.get_pool_balance(&dispute_id), soroban_sdk::vec![&env, (token, 30)]);
+}
+
+#[test]
+fn test_bundled_dispute_covers_every_job() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    let escrow = job_escrow::JobEscrowClient::new(&env, &escrow_id);
+    let job_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let reason = String::from_str(&env, "Bundle");
+
+    assert_eq!(
+        client.try_raise_bundled_dispute(&soroban_sdk::vec![&env], &job_client, &freelancer, &job_client, &reason, &ReasonCode::Other, &None, &3u32, &None),
+        Err(Ok(DisputeError::InvalidBundle))
+    );
+    assert_eq!(
+        client.try_raise_bundled_dispute(&soroban_sdk::vec![&env, 3u64, 4, 3], &job_client, &freelancer, &job_client, &reason, &ReasonCode::Other, &None, &3u32, &None),
+        Err(Ok(DisputeError::InvalidBundle))
+    );
+    // The escrow reports other parties until they are pinned.
+    assert_eq!(
+        client.try_raise_bundled_dispute(&soroban_sdk::vec![&env, 3u64, 4], &job_client, &freelancer, &job_client, &reason, &ReasonCode::Other, &None, &3u32, &None),
+        Err(Ok(DisputeError::BundlePartyMismatch))
+    );
+
+    escrow.set_parties(&job_client, &freelancer);
+    let jobs = soroban_sdk::vec![&env, 3u64, 4, 5];
+    let dispute_id = client.raise_bundled_dispute(&jobs, &job_client, &freelancer, &job_client, &reason, &ReasonCode::Other, &None, &3u32, &None);
+    assert_eq!(client.get_dispute_jobs(&dispute_id), jobs);
+    assert_eq!(client.get_dispute(&dispute_id).job_id, 3);
+    assert_eq!(client.get_dispute_by_job(&5u64).unwrap().id, dispute_id);
+    assert_eq!(client.get_bundled_job_snapshot(&dispute_id, &3u64).unwrap().job_id, 3);
+    assert_eq!(client.get_bundled_job_snapshot(&dispute_id, &5u64).unwrap().job_id, 5);
+    assert_eq!(client.get_bundled_job_snapshot(&dispute_id, &6u64), None);
+    // Each job still has 600 unapproved.
+    assert_eq!(client.preview_resolution(&dispute_id).amount_at_stake, 1800);
+
+    // One vote decides every job.
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    for i in 0..3 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Freelancer, &reason_hash(&env, "Bundle"), &None, &0);
+    }
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForFreelancer);
+    assert_eq!(escrow.settled(), jobs);
+}
+
+#[test]
+fn test_juror_applications_are_queued_for_admin_review() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_client = soroban_sdk::token::TokenClient::new(&env, &token);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    for applicant in [&alice, &bob] {
+        soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(applicant, &500);
+    }
+    let statement = BytesN::from_array(&env, &[7; 32]);
+
+    assert_eq!(
+        client.try_apply_as_juror(&alice, &statement, &100),
+        Err(Ok(DisputeError::NotInitialized))
+    );
+    client.set_juror_stake(&admin, &token, &100);
+    assert_eq!(
+        client.try_apply_as_juror(&alice, &statement, &99),
+        Err(Ok(DisputeError::InsufficientStake))
+    );
+
+    client.apply_as_juror(&alice, &statement, &100);
+    client.apply_as_juror(&bob, &statement, &200);
+    assert_eq!(
+        client.try_apply_as_juror(&alice, &statement, &100),
+        Err(Ok(DisputeError::ApplicationPending))
+    );
+    assert_eq!(token_client.balance(&client.address), 300);
+    let pending = client.get_pending_jurors(&0, &10);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(1).unwrap().applicant, bob);
+    assert_eq!(client.get_pending_jurors(&1, &10).len(), 1);
+
+    // Approval moves the applicant into the pool and keeps the stake.
+    client.approve_juror(&admin, &alice);
+    assert!(client.get_arbitrator_pool().contains(&alice));
+    assert_eq!(client.get_active_jurors(&0, &100).len(), 11);
+    assert_eq!(client.get_active_jurors(&10, &10), soroban_sdk::vec![&env, alice.clone()]);
+    assert_eq!(
+        client.try_apply_as_juror(&alice, &statement, &100),
+        Err(Ok(DisputeError::DuplicateArbitrator))
+    );
+
+    // Rejection refunds the stake.
+    client.reject_juror(&admin, &bob);
+    assert_eq!(token_client.balance(&bob), 500);
+    assert_eq!(client.get_pending_jurors(&0, &10).len(), 0);
+    assert_eq!(
+        client.try_reject_juror(&admin, &bob),
+        Err(Ok(DisputeError::ApplicationNotFound))
+    );
+
+    // Leaving the pool returns an approved juror's stake.
+    client.remove_arbitrator(&admin, &alice);
+    assert_eq!(token_client.balance(&alice), 500);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_execute_resolution_pays_executor_bounty_after_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, dispute_id, token, depositor) = setup_pool_dispute(&env);
+    client.fund_pool(&dispute_id, &depositor, &token, &600);
+
+    let exec_bps = Symbol::new(&env, "exec_bps");
+    assert_eq!(client.get_parameter(&exec_bps), 0);
+    assert_eq!(
+        client.try_set_parameter(&admin, &exec_bps, &1_001),
+        Err(Ok(DisputeError::ParameterOutOfBounds))
+    );
+    client.set_parameter(&admin, &exec_bps, &500);
+
+    let executor = Address::generate(&env);
+    assert_eq!(
+        client.try_execute_resolution(&dispute_id, &executor),
+        Err(Ok(DisputeError::VotingPeriodNotExpired))
+    );
+
+    let deadline = client.get_dispute(&dispute_id).voting_deadline;
+    env.ledger().with_mut(|l| l.timestamp = deadline);
+    let status = client.execute_resolution(&dispute_id, &executor);
+    assert_eq!(client.get_dispute(&dispute_id).status, status);
+    assert_eq!(
+        client.try_execute_resolution(&dispute_id, &executor),
+        Err(Ok(DisputeError::AlreadyResolved))
+    );
+
+    // The bounty is held in the pool until the outcome can no longer be appealed.
+    let token_client = soroban_sdk::token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&executor), 0);
+    assert_eq!(client.get_pool_balance(&dispute_id), vec![&env, (token.clone(), 600)]);
+
+    // 5% of the pool goes to the executor; the rest stays for voter rewards.
+    env.ledger().with_mut(|l| l.timestamp += 172_801);
+    client.finalize_after_appeal_window(&dispute_id);
+    assert_eq!(token_client.balance(&executor), 30);
+    assert_eq!(client.get_pool_balance(&dispute_id), vec![&env, (token.clone(), 570)]);
+}
+
+#[test]
+fn test_initiator_stake_follows_final_outcome_after_appeal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    job_escrow::JobEscrowClient::new(&env, &escrow_id).set_token(&token);
+    client.set_parameter(&admin, &symbol_short!("stake_bps"), &500);
+    let token_client = soroban_sdk::token::Client::new(&env, &token);
+
+    // The freelancer stakes 30 and loses the first round.
+    let job_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&freelancer, &30);
+    let dispute_id = client.raise_dispute(
+        &7u64,
+        &job_client,
+        &freelancer,
+        &freelancer,
+        &String::from_str(&env, "Flip"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    let reason = reason_hash(&env, "r");
+    for i in 0..3 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    }
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForClient);
+    // Nothing is slashed or returned while the outcome can still flip.
+    assert_eq!(token_client.balance(&freelancer), 0);
+    assert_eq!(client.get_pool_balance(&dispute_id), vec![&env, (token.clone(), 30)]);
+
+    // The appeal overturns the verdict in the freelancer's favour.
+    let appeal_id = client.appeal(&dispute_id, &freelancer);
+    let choices = [VoteChoice::Freelancer, VoteChoice::Freelancer, VoteChoice::Client];
+    for choice in choices.iter() {
+        client.cast_appeal_vote(&appeal_id, &Address::generate(&env), choice, &reason, &None);
+    }
+    assert_eq!(client.resolve_appeal(&appeal_id), AppealStatus::ResolvedForFreelancer);
+    assert_eq!(token_client.balance(&freelancer), 0);
+
+    env.ledger().with_mut(|l| l.timestamp += 172_801);
+    assert_eq!(
+        client.finalize_after_appeal_window(&dispute_id),
+        DisputeStatus::ResolvedForFreelancer
+    );
+    assert_eq!(token_client.balance(&freelancer), 30);
+    assert_eq!(client.get_pool_balance(&dispute_id), vec![&env, (token.clone(), 0)]);
+}
+
+#[test]
+fn test_vote_stores_reason_hash_and_bounded_summary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let reputation_contract_id = env.register_contract(None, MockReputationContract);
+    let escrow_contract_id = env.register_contract(None, DummyEscrow);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_contract_id, &300, &escrow_contract_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+
+    let party = Address::generate(&env);
+    let dispute_id = client.raise_dispute(
+        &1u64,
+        &party,
+        &Address::generate(&env),
+        &party,
+        &String::from_str(&env, "Issue"),
+        &ReasonCode::Other,
+        &None,
+        &3u32,
+        &None,
+    );
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+
+    let long = String::from_str(&env, &"x".repeat(MAX_VOTE_SUMMARY_LEN as usize + 1));
+    assert_eq!(
+        client.try_cast_vote(
+            &dispute_id,
+            &assigned.get(0).unwrap(),
+            &VoteChoice::Client,
+            &reason_hash(&env, "Deliverable missing"),
+            &Some(long),
+            &0,
+        ),
+        Err(Ok(DisputeError::SummaryTooLong))
+    );
+
+    let summary = String::from_str(&env, "Deliverable missing");
+    client.cast_vote(
+        &dispute_id,
+        &assigned.get(0).unwrap(),
+        &VoteChoice::Client,
+        &reason_hash(&env, "Deliverable missing; see the client's screenshots."),
+        &Some(summary.clone()),
+        &0,
+    );
+    client.cast_vote(
+        &dispute_id,
+        &assigned.get(1).unwrap(),
+        &VoteChoice::Client,
+        &reason_hash(&env, "Agree."),
+        &None,
+        &0,
+    );
+
+    let votes = client.get_votes(&dispute_id);
+    let first = votes.get(0).unwrap();
+    assert_eq!(
+        first.reason_hash,
+        reason_hash(&env, "Deliverable missing; see the client's screenshots.")
+    );
+    assert_eq!(first.summary, Some(summary));
+    assert_eq!(votes.get(1).unwrap().summary, None);
+}
+
+#[test]
+fn test_initiator_tier_discounts_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let reputation_id = env.register_contract(None, MockReputationContract);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &reputation_id, &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    job_escrow::JobEscrowClient::new(&env, &escrow_id).set_token(&token);
+
+    // 5% of the 600 still unapproved is 30; Gold initiators get half off.
+    client.set_parameter(&admin, &symbol_short!("stake_bps"), &500);
+    client.set_parameter(&admin, &symbol_short!("disc_slv"), &2_000);
+    client.set_parameter(&admin, &symbol_short!("disc_gld"), &5_000);
+    assert_eq!(
+        client.try_set_parameter(&admin, &symbol_short!("disc_plt"), &10_001),
+        Err(Ok(DisputeError::ParameterOutOfBounds))
+    );
+
+    let party = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token).mint(&party, &30);
+    let dispute_id = client.raise_dispute(&7u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Stake"), &ReasonCode::Other, &None, &3u32, &None);
+    assert_eq!(client.get_dispute(&dispute_id).stake_discount_bps, 5_000);
+    assert_eq!(soroban_sdk::token::TokenClient::new(&env, &token).balance(&party), 15);
+    assert_eq!(client.get_pool_balance(&dispute_id), soroban_sdk::vec![&env, (token, 15)]);
+}
+
+#[test]
+fn test_expire_dispute_returns_deposits_and_settles_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let escrow_id = env.register_contract(None, JobEscrow);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &300, &escrow_id, &false);
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let escrow = job_escrow::JobEscrowClient::new(&env, &escrow_id);
+    escrow.set_token(&token);
+    let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token);
+    let balance = soroban_sdk::token::TokenClient::new(&env, &token);
+
+    assert_eq!(
+        client.try_set_parameter(&admin, &symbol_short!("max_dur"), &86_400),
+        Err(Ok(DisputeError::ParameterOutOfBounds))
+    );
+    client.set_parameter(&admin, &symbol_short!("max_dur"), &(30 * 86_400));
+    client.set_parameter(&admin, &symbol_short!("exp_res"), &1);
+    client.set_parameter(&admin, &symbol_short!("stake_bps"), &500);
+
+    let party = Address::generate(&env);
+    let funder = Address::generate(&env);
+    token_admin.mint(&party, &30);
+    token_admin.mint(&funder, &50);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let dispute_id = client.raise_dispute(&7u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Stale"), &ReasonCode::Other, &None, &3u32, &None);
+    client.fund_pool(&dispute_id, &funder, &token, &50);
+    assert_eq!(balance.balance(&party), 0);
+
+    let expires_at = client.get_dispute_expiry(&dispute_id);
+    assert_eq!(expires_at, 1_000 + 30 * 86_400);
+    env.ledger().with_mut(|l| l.timestamp = expires_at - 1);
+    assert_eq!(
+        client.try_expire_dispute(&dispute_id),
+        Err(Ok(DisputeError::DisputeNotExpired))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = expires_at);
+    assert_eq!(client.expire_dispute(&dispute_id), DisputeResolution::ClientWins);
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::Expired);
+    // Deposits, the stake included, are taken back by each depositor.
+    assert_eq!(balance.balance(&party), 0);
+    assert_eq!(client.get_pool_deposit(&dispute_id, &funder, &token), 50);
+    assert_eq!(client.claim_pool_refund(&dispute_id, &party, &token), 30);
+    assert_eq!(client.claim_pool_refund(&dispute_id, &funder, &token), 50);
+    assert_eq!(balance.balance(&party), 30);
+    assert_eq!(balance.balance(&funder), 50);
+    assert_eq!(client.get_pool_deposit(&dispute_id, &funder, &token), 0);
+    assert_eq!(client.get_pool_balance(&dispute_id), soroban_sdk::vec![&env, (token, 0)]);
+    assert_eq!(escrow.settled(), soroban_sdk::vec![&env, 7u64]);
+
+    assert_eq!(
+        client.try_expire_dispute(&dispute_id),
+        Err(Ok(DisputeError::AlreadyResolved))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&dispute_id),
+        Err(Ok(DisputeError::AlreadyResolved))
+    );
+}
+
+#[test]
+fn test_roles_split_admin_duties_across_keys() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    let params = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+
+    assert!(client.has_role(&admin, &Role::Moderator));
+    assert!(!client.has_role(&params, &Role::ParameterManager));
+    assert_eq!(
+        client.try_grant_role(&params, &params, &Role::ParameterManager),
+        Err(Ok(DisputeError::NotAdmin))
+    );
+
+    client.grant_role(&admin, &params, &Role::ParameterManager);
+    client.grant_role(&admin, &moderator, &Role::Moderator);
+    client.set_parameter(&params, &symbol_short!("mal_bps"), &6_000);
+    assert_eq!(client.get_parameter(&symbol_short!("mal_bps")), 6_000);
+    assert_eq!(
+        client.try_add_arbitrator(&params, &arbitrator),
+        Err(Ok(DisputeError::NotAdmin))
+    );
+    client.add_arbitrator(&moderator, &arbitrator);
+    assert_eq!(
+        client.try_set_parameter(&moderator, &symbol_short!("mal_bps"), &7_000),
+        Err(Ok(DisputeError::NotAdmin))
+    );
+    assert_eq!(client.try_pause(&moderator), Err(Ok(DisputeError::NotAdmin)));
+
+    // A granted Admin holds every role and may manage the others.
+    let deputy = Address::generate(&env);
+    client.grant_role(&admin, &deputy, &Role::Admin);
+    assert!(client.has_role(&deputy, &Role::Resolver));
+    client.revoke_role(&deputy, &params, &Role::ParameterManager);
+    assert!(!client.has_role(&params, &Role::ParameterManager));
+    assert_eq!(
+        client.try_set_parameter(&params, &symbol_short!("mal_bps"), &7_000),
+        Err(Ok(DisputeError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_recused_juror_is_replaced_and_cannot_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, admin) = setup_initialized_dispute_contract(&env);
+    client.set_parameter(&admin, &symbol_short!("max_miss"), &1);
+    let party = Address::generate(&env);
+    let dispute_id = client.raise_dispute(&1u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Recusal"), &ReasonCode::Other, &None, &3u32, &None);
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    let reason = reason_hash(&env, "r");
+
+    let voter = assigned.get(0).unwrap();
+    client.cast_vote(&dispute_id, &voter, &VoteChoice::Client, &reason, &None, &0);
+    assert_eq!(client.try_recuse(&dispute_id, &voter, &1), Err(Ok(DisputeError::AlreadyVoted)));
+    assert_eq!(
+        client.try_recuse(&dispute_id, &Address::generate(&env), &1),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+
+    let juror = assigned.get(1).unwrap();
+    let replacement = client.recuse(&dispute_id, &juror, &7).unwrap();
+    assert!(!assigned.contains(&replacement));
+    let panel = client.get_assigned_arbitrators(&dispute_id);
+    assert_eq!(panel.len(), assigned.len());
+    assert!(!panel.contains(&juror));
+    assert!(panel.contains(&replacement));
+    assert_eq!(client.get_dispute(&dispute_id).assigned_arbitrators, panel);
+    assert_eq!(client.get_recusal(&dispute_id, &juror), Some(7));
+    assert_eq!(client.get_juror_recusal_count(&juror), 1);
+
+    assert_eq!(
+        client.try_cast_vote(&dispute_id, &juror, &VoteChoice::Client, &reason, &None, &1),
+        Err(Ok(DisputeError::Unauthorized))
+    );
+    assert_eq!(client.try_recuse(&dispute_id, &juror, &7), Err(Ok(DisputeError::Unauthorized)));
+    client.cast_vote(&dispute_id, &replacement, &VoteChoice::Client, &reason, &None, &0);
+
+    // The recusal is not a missed dispute. Nor is staying silent on a panel
+    // that resolved before its voting deadline.
+    client.cast_vote(&dispute_id, &assigned.get(2).unwrap(), &VoteChoice::Client, &reason, &None, &0);
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolvedForClient);
+    assert!(!client.is_juror_inactive(&juror));
+    assert!(!client.is_juror_inactive(&panel.get(3).unwrap()));
+}
+
+/// An unappealed resolution becomes final once the appeal window closes,
+/// retrying a failed escrow callback on the way; afterwards it cannot be
+/// appealed or finalized again.
+#[test]
+fn test_finalize_after_appeal_window_settles_and_locks_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _job_client, freelancer, dispute_id) =
+        setup_dispute_with_failing_escrow(&env);
+    assert_eq!(
+        client.try_finalize_after_appeal_window(&dispute_id),
+        Err(Ok(DisputeError::NotResolved))
+    );
+
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    for i in 0..3u32 {
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason_hash(&env, "r"), &None, &(i as u64));
+    }
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::ResolutionFailed);
+    assert_eq!(
+        client.try_finalize_after_appeal_window(&dispute_id),
+        Err(Ok(DisputeError::AppealWindowOpen))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 172_801);
+    // The escrow still rejects the callback: nothing becomes final.
+    assert_eq!(
+        client.finalize_after_appeal_window(&dispute_id),
+        DisputeStatus::ResolutionFailed
+    );
+    assert_eq!(client.get_finalized_at(&dispute_id), None);
+
+    let working_escrow_id = env.register_contract(None, DummyEscrow);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowContract, &working_escrow_id);
+    });
+    assert_eq!(
+        client.finalize_after_appeal_window(&dispute_id),
+        DisputeStatus::ResolvedForClient
+    );
+    assert_eq!(client.get_finalized_at(&dispute_id), Some(env.ledger().timestamp()));
+
+    assert_eq!(
+        client.try_finalize_after_appeal_window(&dispute_id),
+        Err(Ok(DisputeError::AlreadyFinalized))
+    );
+    assert_eq!(
+        client.try_appeal(&dispute_id, &freelancer),
+        Err(Ok(DisputeError::AlreadyFinalized))
+    );
+}
+
+#[test]
+fn test_min_voting_period_holds_resolution_after_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &env.register_contract(None, MockReputationContract),
+        &300,
+        &env.register_contract(None, DummyEscrow),
+        &false,
+    );
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+
+    assert_eq!(
+        client.try_set_parameter(&admin, &symbol_short!("min_open"), &(8 * 86_400)),
+        Err(Ok(DisputeError::ParameterOutOfBounds))
+    );
+    client.set_parameter(&admin, &symbol_short!("min_open"), &86_400);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let party = Address::generate(&env);
+    let dispute_id = client.raise_dispute(&1u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Slow down"), &ReasonCode::Other, &None, &3u32, &None);
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    for i in 0..3 {
+        env.ledger().with_mut(|l| l.timestamp += 10);
+        client.cast_vote(&dispute_id, &assigned.get(i).unwrap(), &VoteChoice::Client, &reason_hash(&env, "Client"), &None, &0);
+    }
+
+    // Quorum and the auto-resolve majority are met, but the dispute stays
+    // open until a day has passed since the first vote.
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::Voting);
+    env.ledger().with_mut(|l| l.timestamp = 5_010 + 86_399);
+    assert_eq!(
+        client.try_resolve_dispute(&dispute_id),
+        Err(Ok(DisputeError::MinVotingPeriodNotElapsed))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 5_010 + 86_400);
+    assert_eq!(client.resolve_dispute(&dispute_id), DisputeStatus::ResolvedForClient);
+}
+
+#[test]
+fn test_evidence_rejected_after_cutoff_and_phase_close_announced() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DisputeContractClient::new(&env, &env.register_contract(None, DisputeContract));
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &env.register_contract(None, MockReputationContract),
+        &300,
+        &env.register_contract(None, DummyEscrow),
+        &false,
+    );
+    for _ in 0..5 {
+        client.add_arbitrator(&admin, &Address::generate(&env));
+    }
+    client.set_parameter(&admin, &symbol_short!("ev_cutoff"), &86_400);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let party = Address::generate(&env);
+    let dispute_id = client.raise_dispute(&1u64, &party, &Address::generate(&env), &party, &String::from_str(&env, "Late files"), &ReasonCode::Other, &None, &3u32, &None);
+    let cutoff = client.get_dispute(&dispute_id).voting_deadline - 86_400;
+    assert_eq!(client.get_evidence_cutoff(&dispute_id), cutoff);
+
+    env.ledger().with_mut(|l| l.timestamp = cutoff - 1);
+    client.submit_evidence(&dispute_id, &party, &BytesN::from_array(&env, &[1u8; 32]));
+    assert!(!client.close_evidence_phase(&dispute_id));
+
+    // From the cutoff on, evidence is refused and the first vote announces
+    // the close exactly once.
+    env.ledger().with_mut(|l| l.timestamp = cutoff);
+    assert_eq!(
+        client.try_submit_evidence(&dispute_id, &party, &BytesN::from_array(&env, &[2u8; 32])),
+        Err(Ok(DisputeError::EvidencePhaseClosed))
+    );
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    client.cast_vote(&dispute_id, &assigned.get(0).unwrap(), &VoteChoice::Client, &reason_hash(&env, "Client"), &None, &0);
+    let closed = env.events().all().iter().any(|(_, topics, _)| {
+        if topics.len() >= 2 {
+            let t1: Symbol = topics.get(1).unwrap().into_val(&env);
+            return t1 == symbol_short!("ev_closed");
+        }
+        false
+    });
+    assert!(closed);
+    assert!(!client.close_evidence_phase(&dispute_id));
+    assert_eq!(client.get_evidence(&dispute_id).len(), 1);
+}
+
+#[test]
+fn test_votes_stored_individually_and_read_in_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _, _, _, _, dispute_id) = setup_dispute_with_votes(&env, 1, 1);
+    let assigned = client.get_assigned_arbitrators(&dispute_id);
+    let first = assigned.get(0).unwrap();
+    let second = assigned.get(1).unwrap();
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.tally.vote_count, 2);
+    assert_eq!(dispute.votes_for_client, 1);
+    assert_eq!(dispute.votes_for_freelancer, 1);
+
+    let page = client.get_votes_page(&dispute_id, &0, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().voter, Some(first.clone()));
+    let page = client.get_votes_page(&dispute_id, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().voter, Some(second.clone()));
+    assert_eq!(page.get(0).unwrap().choice, VoteChoice::Freelancer);
+    assert!(client.get_votes_page(&dispute_id, &2, &10).is_empty());
+    assert_eq!(client.get_votes(&dispute_id).len(), 2);
+    assert_eq!(client.get_arbitrators(&dispute_id), vec![&env, first.clone(), second]);
+
+    assert_eq!(
+        client.try_cast_vote(
+            &dispute_id,
+            &first,
+            &VoteChoice::Client,
+            &reason_hash(&env, "Again"),
+            &None,
+            &1,
+        ),
+        Err(Ok(DisputeError::AlreadyVoted))
+    );
+}
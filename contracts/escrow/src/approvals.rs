@@ -0,0 +1,79 @@
+//! Milestone approvals guarded against revision races.
+//!
+//! A revision accepted between `submit_milestone` and `approve_milestone` can
+//! change a milestone's amount after the client reviewed it, so a plain
+//! approval may release a sum the client never saw. With
+//! [`EscrowContract::approve_milestone_checked`] the client passes the amount
+//! they reviewed; if the milestone no longer carries it, the approval fails
+//! with `StaleState` and nothing changes.
+
+use soroban_sdk::{contracterror, contractimpl, Address, Env};
+
+use crate::{EscrowContract, EscrowError, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Checked approval errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1400, clear of `PayoutError`
+/// (1300..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ApprovalError {
+    /// The contract is paused.
+    ContractPaused = 1400,
+    /// No job exists with the given ID.
+    JobNotFound = 1401,
+    /// Caller is not the job's client.
+    Unauthorized = 1402,
+    /// The job is disputed, or the milestone is not awaiting review.
+    InvalidStatus = 1403,
+    /// The job has no milestone with the given ID.
+    MilestoneNotFound = 1404,
+    /// The milestone amount changed since the client reviewed it.
+    StaleState = 1405,
+}
+
+impl From<EscrowError> for ApprovalError {
+    fn from(err: EscrowError) -> Self {
+        match err {
+            EscrowError::ContractPaused => ApprovalError::ContractPaused,
+            EscrowError::JobNotFound => ApprovalError::JobNotFound,
+            EscrowError::Unauthorized => ApprovalError::Unauthorized,
+            EscrowError::MilestoneNotFound => ApprovalError::MilestoneNotFound,
+            _ => ApprovalError::InvalidStatus,
+        }
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Approve a submitted milestone, provided it still carries
+    /// `expected_amount`, the amount the client reviewed. Otherwise fails with
+    /// `StaleState`. Behaves like `approve_milestone` in every other respect.
+    pub fn approve_milestone_checked(
+        env: Env,
+        job_id: u64,
+        milestone_id: u32,
+        client: Address,
+        expected_amount: i128,
+    ) -> Result<(), ApprovalError> {
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(ApprovalError::JobNotFound)?;
+        let milestone = job
+            .milestones
+            .get(milestone_id)
+            .ok_or(ApprovalError::MilestoneNotFound)?;
+        if milestone.amount != expected_amount {
+            return Err(ApprovalError::StaleState);
+        }
+        Ok(Self::approve_milestone(env, job_id, milestone_id, client)?)
+    }
+}
@@ -0,0 +1,249 @@
+//! Archival of finished jobs to compact summaries.
+//!
+//! A finished job keeps its full `Job` record — milestone descriptions and all —
+//! in persistent storage, paying rent for data nobody needs once the work is
+//! settled. After a retention window has passed since the job closed, either
+//! party can call [`EscrowContract::archive_job`] to replace the record with a
+//! [`JobSummary`]: parties, totals, final status and the SHA-256 of the full
+//! record's XDR, so an off-chain copy can still be checked against the chain.
+//!
+//! [`EscrowContract::get_job_summary`] serves live and archived jobs alike;
+//! `get_job` no longer finds an archived job. The retention window should stay
+//! long enough for reviews and other reads of the full record to happen first.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, Env, Symbol,
+};
+
+use crate::{EscrowContract, Job, JobStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Default time a closed job must stay intact before it can be archived.
+pub const DEFAULT_ARCHIVE_RETENTION_SECS: u64 = 30 * 86_400;
+
+/// Archival errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 700, clear of `BondError` (600..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ArchiveError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 700,
+    /// The contract is paused.
+    ContractPaused = 701,
+    /// No live or archived job exists with the given ID.
+    JobNotFound = 702,
+    /// Caller is neither the job's client nor its freelancer.
+    Unauthorized = 703,
+    /// Only completed, cancelled or expired jobs can be archived.
+    InvalidStatus = 704,
+    /// The retention window since the job closed has not passed yet.
+    RetentionNotElapsed = 705,
+    /// The freelancer still has a performance bond to reclaim on this job.
+    BondOutstanding = 706,
+    /// The escrow still holds funds of the job for one of the parties.
+    BalanceOutstanding = 707,
+}
+
+/// Compact, immutable record of a job; shared with the reputation contract.
+pub use stellar_market_interface::JobSummary;
+
+/// Storage keys for archival.
+#[contracttype]
+#[derive(Clone)]
+pub enum ArchiveKey {
+    /// Retention window in seconds (`u64`, instance).
+    Retention,
+    /// When a job reached a terminal status (`u64`).
+    ClosedAt(u64),
+    /// Summary that replaced an archived job.
+    Summary(u64),
+}
+
+fn retention(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ArchiveKey::Retention)
+        .unwrap_or(DEFAULT_ARCHIVE_RETENTION_SECS)
+}
+
+fn closed_at(env: &Env, job_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&ArchiveKey::ClosedAt(job_id))
+}
+
+fn summarize(env: &Env, job: &Job, closed_at: u64, archived_at: u64) -> JobSummary {
+    JobSummary {
+        id: job.id,
+        client: job.client.clone(),
+        freelancer: job.freelancer.clone(),
+        token: job.token.clone(),
+        total_amount: job.total_amount,
+        funded_amount: job.funded_amount,
+        status: job.status.clone(),
+        milestone_count: job.milestones.len(),
+        closed_at,
+        archived_at,
+        record_hash: env.crypto().sha256(&job.clone().to_xdr(env)).into(),
+    }
+}
+
+/// Record when `job` reached a terminal status.
+pub(crate) fn record_closed(env: &Env, job: &Job) {
+    let key = ArchiveKey::ClosedAt(job.id);
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Set how long a closed job must stay intact before it can be archived.
+    /// Registered multisig signers only.
+    pub fn set_archive_retention(
+        env: Env,
+        admin: Address,
+        retention_secs: u64,
+    ) -> Result<(), ArchiveError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(ArchiveError::NotAdmin);
+        }
+        env.storage()
+            .instance()
+            .set(&ArchiveKey::Retention, &retention_secs);
+
+        env.events().publish(
+            (
+                symbol_short!("escrow"),
+                Symbol::new(&env, "archive_retention_set"),
+            ),
+            (retention_secs, admin),
+        );
+        Ok(())
+    }
+
+    /// Retention window in seconds before a closed job can be archived.
+    pub fn get_archive_retention(env: Env) -> u64 {
+        retention(&env)
+    }
+
+    /// Replace a finished job's full record with its [`JobSummary`]. Client or
+    /// freelancer only, once the retention window has passed since the job
+    /// closed (measured from its deadline for jobs closed before closing times
+    /// were recorded).
+    pub fn archive_job(env: Env, job_id: u64, caller: Address) -> Result<JobSummary, ArchiveError> {
+        crate::require_not_paused(&env).map_err(|_| ArchiveError::ContractPaused)?;
+        caller.require_auth();
+        let job_key = crate::DataKey::Job(job_id);
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&job_key)
+            .ok_or(ArchiveError::JobNotFound)?;
+        if caller != job.client && caller != job.freelancer {
+            return Err(ArchiveError::Unauthorized);
+        }
+        if !matches!(
+            job.status,
+            JobStatus::Completed | JobStatus::Cancelled | JobStatus::Expired
+        ) {
+            return Err(ArchiveError::InvalidStatus);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&crate::bonds::BondKey::Bond(job_id))
+        {
+            return Err(ArchiveError::BondOutstanding);
+        }
+        let expense_balance: i128 = env
+            .storage()
+            .persistent()
+            .get(&crate::expenses::ExpenseKey::Balance(job_id))
+            .unwrap_or(0);
+        if crate::funding_auth::held(&env, job_id) > 0
+            || crate::early_bonus::held(&env, job_id) > 0
+            || crate::owed::is_owed(&env, &job)
+            || expense_balance > 0
+        {
+            return Err(ArchiveError::BalanceOutstanding);
+        }
+        let closed = closed_at(&env, job_id);
+        let now = env.ledger().timestamp();
+        let archivable_at = closed
+            .unwrap_or(job.job_deadline)
+            .saturating_add(retention(&env));
+        if now < archivable_at {
+            return Err(ArchiveError::RetentionNotElapsed);
+        }
+
+        let summary = summarize(&env, &job, closed.unwrap_or(0), now);
+        let summary_key = ArchiveKey::Summary(job_id);
+        env.storage().persistent().set(&summary_key, &summary);
+        env.storage().persistent().extend_ttl(
+            &summary_key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+        env.storage().persistent().remove(&job_key);
+        env.storage()
+            .persistent()
+            .remove(&ArchiveKey::ClosedAt(job_id));
+        crate::descriptions::remove(&env, job_id);
+        crate::display::remove(&env, job_id);
+        crate::rejections::remove(&env, &job);
+        crate::payouts::remove(&env, job_id);
+        crate::review_window::remove(&env, job_id);
+        crate::bonds::remove(&env, job_id);
+        crate::tips::remove(&env, job_id);
+        crate::hourly::remove(&env, job_id);
+        crate::recurring::remove(&env, job_id);
+        crate::pairs::on_archive(&env, &job);
+        crate::status_index::on_archive(&env, &job);
+        crate::visibility::remove(&env, job_id);
+        crate::early_bonus::remove(&env, job_id);
+        crate::notes::remove(&env, job_id);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("archived")),
+            (job_id, caller, summary.record_hash.clone()),
+        );
+        Ok(summary)
+    }
+
+    /// Summary of a job, live or archived.
+    pub fn get_job_summary(env: Env, job_id: u64) -> Result<JobSummary, ArchiveError> {
+        if let Some(job) = env
+            .storage()
+            .persistent()
+            .get::<_, Job>(&crate::DataKey::Job(job_id))
+        {
+            let closed = closed_at(&env, job_id).unwrap_or(0);
+            return Ok(summarize(&env, &job, closed, 0));
+        }
+        let summary_key = ArchiveKey::Summary(job_id);
+        let summary = env
+            .storage()
+            .persistent()
+            .get(&summary_key)
+            .ok_or(ArchiveError::JobNotFound)?;
+        env.storage().persistent().extend_ttl(
+            &summary_key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+        Ok(summary)
+    }
+}
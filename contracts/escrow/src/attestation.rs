@@ -0,0 +1,166 @@
+//! Attestation gate for high-value jobs.
+//!
+//! Regulated deployments need both parties of a large job to have passed KYC
+//! (or a similar check) before money moves. Signers register the one
+//! attestation contract the escrow trusts through a time-locked
+//! [`AdminAction::SetAttestationContract`](crate::AdminAction::SetAttestationContract)
+//! proposal, and a per-token value threshold with
+//! [`EscrowContract::set_attestation_threshold`]. A job whose total is at or
+//! above its token's threshold can only be created, funded or grown while the
+//! attestation contract vouches for both the client and the freelancer;
+//! otherwise the call fails with [`AttestationError::AttestationRequired`].
+//!
+//! The attestation contract is expected to expose:
+//! `is_attested(subject: Address) -> bool`
+//!
+//! The check runs again at `fund_job`, `top_up_escrow`, `accept_revision` and
+//! `recurring::renew`, so an attestation revoked after creation blocks any
+//! further deposit, and a job cannot be grown past the threshold unattested. Jobs below the threshold, and every job while no
+//! attestation contract or threshold is set, are not affected.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, vec, Address,
+    Env, IntoVal, Symbol,
+};
+
+use crate::EscrowContract;
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Attestation errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1900, clear of `PostingError`
+/// (1800..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AttestationError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 1900,
+    /// The threshold is negative.
+    InvalidThreshold = 1901,
+    /// The job is at or above its token's threshold and the client or the
+    /// freelancer holds no valid attestation.
+    AttestationRequired = 1902,
+}
+
+/// Storage keys for the attestation gate.
+#[contracttype]
+#[derive(Clone)]
+pub enum AttestationKey {
+    /// The allowlisted attestation contract (`Address`, instance).
+    Contract,
+    /// Job total (`i128`, token base units) from which attestations are
+    /// required for a token (instance).
+    Threshold(Address),
+}
+
+fn threshold(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&AttestationKey::Threshold(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether the attestation contract vouches for `subject`. A call that traps
+/// or returns something other than a bool counts as no attestation.
+fn is_attested(env: &Env, attester: &Address, subject: &Address) -> bool {
+    let result = env.try_invoke_contract::<bool, soroban_sdk::Error>(
+        attester,
+        &Symbol::new(env, "is_attested"),
+        vec![env, subject.clone().into_val(env)],
+    );
+    matches!(result, Ok(Ok(true)))
+}
+
+/// Abort with [`AttestationError::AttestationRequired`] if a job of `total` in
+/// `token` needs attestations that `client` or `freelancer` lacks. Used from
+/// entry points that return `EscrowError`, which has no room for the new
+/// variant.
+pub(crate) fn enforce_attestation(
+    env: &Env,
+    token: &Address,
+    total: i128,
+    client: &Address,
+    freelancer: &Address,
+) {
+    let Some(attester) = env
+        .storage()
+        .instance()
+        .get::<_, Address>(&AttestationKey::Contract)
+    else {
+        return;
+    };
+    let threshold = threshold(env, token);
+    if threshold == 0 || total < threshold {
+        return;
+    }
+    if !is_attested(env, &attester, client) || !is_attested(env, &attester, freelancer) {
+        panic_with_error!(env, AttestationError::AttestationRequired);
+    }
+}
+
+/// Register `attester`, or remove the gate with `None`. Reached only through
+/// the time-locked [`AdminAction::SetAttestationContract`](crate::AdminAction::SetAttestationContract)
+/// proposal, since swapping the attester decides who may move large sums.
+pub(crate) fn set_contract(env: &Env, attester: Option<Address>, proposer: &Address) {
+    match &attester {
+        Some(attester) => env
+            .storage()
+            .instance()
+            .set(&AttestationKey::Contract, attester),
+        None => env.storage().instance().remove(&AttestationKey::Contract),
+    }
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("attester")),
+        (attester, proposer.clone()),
+    );
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Return the registered attestation contract, if any.
+    pub fn get_attestation_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&AttestationKey::Contract)
+    }
+
+    /// Require attestations for jobs in `token` whose total is at least
+    /// `threshold`, in the token's base units. Registered multisig signers
+    /// only. A `threshold` of `0` removes the requirement for the token.
+    pub fn set_attestation_threshold(
+        env: Env,
+        admin: Address,
+        token: Address,
+        threshold: i128,
+    ) -> Result<(), AttestationError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(AttestationError::NotAdmin);
+        }
+        if threshold < 0 {
+            return Err(AttestationError::InvalidThreshold);
+        }
+
+        let key = AttestationKey::Threshold(token.clone());
+        if threshold == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &threshold);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "attest_threshold")),
+            (token, threshold, admin),
+        );
+        Ok(())
+    }
+
+    /// Return the attestation threshold for `token` (`0` when unset).
+    pub fn get_attestation_threshold(env: Env, token: Address) -> i128 {
+        threshold(&env, &token)
+    }
+}
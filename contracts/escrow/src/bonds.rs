@@ -0,0 +1,346 @@
+//! Optional freelancer performance bonds.
+//!
+//! High-value clients may ask the freelancer to put skin in the game. The
+//! freelancer posts a bond with [`EscrowContract::post_performance_bond`], in
+//! the job's token, before or during the work. The bond is held by the escrow
+//! contract as a balance of its own: it never touches `Job::total_amount`,
+//! `Job::funded_amount` or the milestone payout path.
+//!
+//! A client can also make the bond a condition of the job: while the job is
+//! still `Created`, [`EscrowContract::set_required_bond`] fixes the least the
+//! freelancer must have posted, and `fund_job` fails with `BondRequired` until
+//! they have.
+//!
+//! The bond is settled when the job ends:
+//!
+//! - **Completed** — returned to the freelancer in full;
+//! - **dispute resolved `ClientWins`**, or **abandoned** (the client reclaims
+//!   a funded job with `claim_refund`, or it is expired with `expire_job`,
+//!   while some milestone is still pending or in progress past its deadline) —
+//!   the forfeit portion (basis points, snapshotted when the bond is first
+//!   posted) goes to the client and the rest back to the freelancer;
+//! - **any other terminal state** — the freelancer reclaims it with
+//!   [`EscrowContract::withdraw_performance_bond`].
+//!
+//! Whatever goes back to the freelancer when the job ends is paid like a
+//! milestone: if their trustline cannot take it, it is owed to them (see
+//! `owed`) and the completion, release or resolution still goes through.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address,
+    Env, Symbol,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, JobStatus, MilestoneStatus, PauseScope};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Default share of a bond forfeited to the client on a `ClientWins` verdict.
+pub const DEFAULT_BOND_FORFEIT_BPS: u32 = 10_000;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Performance bond errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 600, clear of `ExpenseError`
+/// (400..) and `FeeError` (500..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BondError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 600,
+    /// The contract is paused.
+    ContractPaused = 601,
+    /// No job exists with the given ID.
+    JobNotFound = 602,
+    /// Caller is not the job's freelancer.
+    Unauthorized = 603,
+    /// The job is not in a state that allows this operation.
+    InvalidStatus = 604,
+    /// Amount must be strictly positive.
+    InvalidAmount = 605,
+    /// The job has no outstanding bond.
+    NoBond = 606,
+    /// Forfeit share must be between 0 and 10 000 basis points.
+    InvalidForfeitBps = 607,
+    /// The job requires a bond the freelancer has not fully posted yet.
+    BondRequired = 608,
+}
+
+/// A freelancer's outstanding bond on a job.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PerformanceBond {
+    pub amount: i128,
+    /// Share forfeited to the client on a `ClientWins` verdict, fixed when the
+    /// bond is first posted.
+    pub forfeit_bps: u32,
+    pub posted_at: u64,
+}
+
+/// Storage keys for performance bonds.
+#[contracttype]
+#[derive(Clone)]
+pub enum BondKey {
+    /// Forfeit share for newly posted bonds (`u32`, instance).
+    ForfeitBps,
+    /// Outstanding bond of a job; removed once settled.
+    Bond(u64),
+    /// Least bond the freelancer must post before the job can be funded:
+    /// job_id → `i128`.
+    Required(u64),
+}
+
+fn forfeit_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&BondKey::ForfeitBps)
+        .unwrap_or(DEFAULT_BOND_FORFEIT_BPS)
+}
+
+fn require_not_paused(env: &Env) -> Result<(), BondError> {
+    crate::require_not_paused(env).map_err(|_| BondError::ContractPaused)
+}
+
+fn require_scope_open(env: &Env, scope: PauseScope) -> Result<(), BondError> {
+    crate::pause::require_scope_open(env, scope).map_err(|_| BondError::ContractPaused)
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, BondError> {
+    env.storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(BondError::JobNotFound)
+}
+
+fn load_bond(env: &Env, job_id: u64) -> Option<PerformanceBond> {
+    env.storage().persistent().get(&BondKey::Bond(job_id))
+}
+
+fn required_bond(env: &Env, job_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&BondKey::Required(job_id))
+        .unwrap_or(0)
+}
+
+/// Panic with `BondRequired` unless the freelancer has posted the bond the
+/// job requires.
+pub(crate) fn enforce_required_bond(env: &Env, job: &Job) {
+    let posted = load_bond(env, job.id).map_or(0, |bond| bond.amount);
+    if posted < required_bond(env, job.id) {
+        panic_with_error!(env, BondError::BondRequired);
+    }
+}
+
+/// Drop the bond requirement of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage().persistent().remove(&BondKey::Required(job_id));
+}
+
+/// Pay out and clear the bond of `job`: `to_client` to the client, the rest to
+/// the freelancer, owed to them if their transfer fails so a frozen trustline
+/// cannot block the completion or release that settles the bond. Returns the
+/// amount paid to the freelancer.
+fn settle_bond(env: &Env, job: &Job, bond: &PerformanceBond, to_client: i128) -> i128 {
+    env.storage().persistent().remove(&BondKey::Bond(job.id));
+    let to_freelancer = bond.amount - to_client;
+    if to_client > 0 {
+        token::Client::new(env, &job.token).transfer(
+            &env.current_contract_address(),
+            &job.client,
+            &to_client,
+        );
+    }
+    crate::owed::pay_or_owe(env, job.id, &job.token, &job.freelancer, to_freelancer);
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("bond_stl")),
+        (job.id, to_client, to_freelancer),
+    );
+    to_freelancer
+}
+
+/// Return the bond of a completed job to the freelancer. No-op without a bond.
+pub(crate) fn return_bond(env: &Env, job: &Job) {
+    if let Some(bond) = load_bond(env, job.id) {
+        settle_bond(env, job, &bond, 0);
+    }
+}
+
+/// Forfeit the bond's configured share to the client after a `ClientWins`
+/// verdict or an abandoned job; the rest goes back to the freelancer. No-op
+/// without a bond.
+pub(crate) fn forfeit_bond(env: &Env, job: &Job) {
+    if let Some(bond) = load_bond(env, job.id) {
+        let to_client = bond.amount * bond.forfeit_bps as i128 / BPS_DENOMINATOR;
+        settle_bond(env, job, &bond, to_client);
+    }
+}
+
+/// Forfeit the bond of a job being reclaimed or expired if the freelancer
+/// abandoned it: some milestone is still pending or in progress past its
+/// deadline. A freelancer who delivered everything keeps the bond, to be
+/// withdrawn with `withdraw_performance_bond`.
+pub(crate) fn forfeit_if_abandoned(env: &Env, job: &Job) {
+    let now = env.ledger().timestamp();
+    let abandoned = job.milestones.iter().any(|m| {
+        matches!(m.status, MilestoneStatus::Pending | MilestoneStatus::InProgress)
+            && m.deadline < now
+    });
+    if abandoned {
+        forfeit_bond(env, job);
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Set the share of a bond forfeited to the client on a `ClientWins`
+    /// verdict, in basis points. Registered multisig signers only. Applies to
+    /// bonds posted afterwards.
+    pub fn set_bond_forfeit_bps(env: Env, admin: Address, bps: u32) -> Result<(), BondError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(BondError::NotAdmin);
+        }
+        if bps as i128 > BPS_DENOMINATOR {
+            return Err(BondError::InvalidForfeitBps);
+        }
+        env.storage().instance().set(&BondKey::ForfeitBps, &bps);
+
+        env.events().publish(
+            (
+                symbol_short!("escrow"),
+                Symbol::new(&env, "bond_forfeit_set"),
+            ),
+            (bps, admin),
+        );
+        Ok(())
+    }
+
+    /// Share of a bond forfeited to the client on a `ClientWins` verdict.
+    pub fn get_bond_forfeit_bps(env: Env) -> u32 {
+        forfeit_bps(&env)
+    }
+
+    /// Require the freelancer to post a bond of at least `amount` before the
+    /// job can be funded. Client only, while the job is `Created`; zero drops
+    /// the requirement.
+    pub fn set_required_bond(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        amount: i128,
+    ) -> Result<(), BondError> {
+        require_not_paused(&env)?;
+        client.require_auth();
+        if amount < 0 {
+            return Err(BondError::InvalidAmount);
+        }
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(BondError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Configure)
+            .map_err(|_| BondError::InvalidStatus)?;
+
+        let key = BondKey::Required(job_id);
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+            env.storage().persistent().extend_ttl(
+                &key,
+                crate::TTL_THRESHOLD_LEDGERS,
+                crate::TTL_EXTEND_TO_LEDGERS,
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bond_req")),
+            (job_id, amount),
+        );
+        Ok(())
+    }
+
+    /// Least bond the freelancer must post before the job can be funded; zero
+    /// if the job requires none.
+    pub fn get_required_bond(env: Env, job_id: u64) -> i128 {
+        required_bond(&env, job_id)
+    }
+
+    /// Post (or top up) a performance bond of `amount` in the job's token.
+    /// Freelancer only, while the job is not yet terminal or disputed.
+    pub fn post_performance_bond(
+        env: Env,
+        job_id: u64,
+        freelancer: Address,
+        amount: i128,
+    ) -> Result<(), BondError> {
+        require_scope_open(&env, PauseScope::Create)?;
+        freelancer.require_auth();
+        if amount <= 0 {
+            return Err(BondError::InvalidAmount);
+        }
+        let job = load_job(&env, job_id)?;
+        if job.freelancer != freelancer {
+            return Err(BondError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Amend)
+            .map_err(|_| BondError::InvalidStatus)?;
+
+        token::Client::new(&env, &job.token).transfer(
+            &freelancer,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let mut bond = load_bond(&env, job_id).unwrap_or(PerformanceBond {
+            amount: 0,
+            forfeit_bps: forfeit_bps(&env),
+            posted_at: env.ledger().timestamp(),
+        });
+        bond.amount += amount;
+        let key = BondKey::Bond(job_id);
+        env.storage().persistent().set(&key, &bond);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bond_post")),
+            (job_id, freelancer, amount, bond.amount),
+        );
+        Ok(())
+    }
+
+    /// Reclaim the bond of a job that was cancelled or expired without a
+    /// `ClientWins` verdict or being abandoned. Freelancer only. Returns the amount reclaimed.
+    pub fn withdraw_performance_bond(
+        env: Env,
+        job_id: u64,
+        freelancer: Address,
+    ) -> Result<i128, BondError> {
+        require_scope_open(&env, PauseScope::Payouts)?;
+        freelancer.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.freelancer != freelancer {
+            return Err(BondError::Unauthorized);
+        }
+        if !matches!(job.status, JobStatus::Cancelled | JobStatus::Expired) {
+            return Err(BondError::InvalidStatus);
+        }
+        let bond = load_bond(&env, job_id).ok_or(BondError::NoBond)?;
+        Ok(settle_bond(&env, &job, &bond, 0))
+    }
+
+    /// Outstanding performance bond of a job, if any.
+    pub fn get_performance_bond(env: Env, job_id: u64) -> Option<PerformanceBond> {
+        load_bond(&env, job_id)
+    }
+}
@@ -0,0 +1,118 @@
+//! Recent job state changes, for light-weight pollers.
+//!
+//! Every job state change appends a [`ChangeRecord`] to a fixed-size ring
+//! buffer of [`RECENT_CHANGES_CAPACITY`] slots in temporary storage; the next
+//! write position lives in instance storage. A poller remembers the highest
+//! `seq` it has seen and calls [`EscrowContract::get_recent_changes`] to pick
+//! up what changed since, then re-reads just those jobs. Events remain the
+//! source of truth: a gap in `seq` (the poller fell more than a buffer behind,
+//! or slots expired) means a full event-RPC sync is due.
+
+use soroban_sdk::{contractimpl, contracttype, Env, Vec};
+
+use crate::{EscrowContract, Job, JobStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Number of slots in the change ring buffer.
+pub const RECENT_CHANGES_CAPACITY: u32 = 64;
+
+/// What happened to a job.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChangeKind {
+    Created,
+    Funded,
+    ToppedUp,
+    MilestoneSubmitted,
+    MilestoneApproved,
+    MilestoneRejected,
+    PaymentReleased,
+    DeadlineExtended,
+    Revised,
+    Disputed,
+    Completed,
+    Cancelled,
+    Expired,
+    TokenMigrated,
+    Renewed,
+}
+
+/// One entry of the change feed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangeRecord {
+    /// Position in the feed, starting at 1 and never reused.
+    pub seq: u64,
+    pub job_id: u64,
+    pub kind: ChangeKind,
+    pub ledger: u32,
+}
+
+/// Storage keys for the change feed.
+#[contracttype]
+#[derive(Clone)]
+pub enum ChangeKey {
+    /// Number of changes recorded so far (`u64`, instance).
+    Seq,
+    /// Ring buffer slot (temporary).
+    Slot(u32),
+}
+
+fn seq(env: &Env) -> u64 {
+    env.storage().instance().get(&ChangeKey::Seq).unwrap_or(0)
+}
+
+/// Append a change for `job_id` to the feed, overwriting the oldest slot.
+pub(crate) fn record(env: &Env, job_id: u64, kind: ChangeKind) {
+    let seq = seq(env) + 1;
+    let key = ChangeKey::Slot((seq % RECENT_CHANGES_CAPACITY as u64) as u32);
+    let record = ChangeRecord {
+        seq,
+        job_id,
+        kind,
+        ledger: env.ledger().sequence(),
+    };
+    env.storage().temporary().set(&key, &record);
+    env.storage().temporary().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+    env.storage().instance().set(&ChangeKey::Seq, &seq);
+    crate::stats::count(env, kind);
+}
+
+/// Record the terminal status `job` just reached.
+pub(crate) fn record_closed(env: &Env, job: &Job) {
+    let kind = match job.status {
+        JobStatus::Completed => ChangeKind::Completed,
+        JobStatus::Expired => ChangeKind::Expired,
+        _ => ChangeKind::Cancelled,
+    };
+    record(env, job.id, kind);
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Up to `limit` most recent job changes, newest first. At most
+    /// [`RECENT_CHANGES_CAPACITY`] are retained; expired slots are skipped.
+    pub fn get_recent_changes(env: Env, limit: u32) -> Vec<ChangeRecord> {
+        let mut changes = Vec::new(&env);
+        let mut seq = seq(&env);
+        let mut remaining = limit.min(RECENT_CHANGES_CAPACITY);
+        while seq > 0 && remaining > 0 {
+            let key = ChangeKey::Slot((seq % RECENT_CHANGES_CAPACITY as u64) as u32);
+            if let Some(record) = env.storage().temporary().get::<_, ChangeRecord>(&key) {
+                if record.seq == seq {
+                    changes.push_back(record);
+                }
+            }
+            seq -= 1;
+            remaining -= 1;
+        }
+        changes
+    }
+}
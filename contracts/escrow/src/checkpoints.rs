@@ -0,0 +1,299 @@
+//! Optional client satisfaction checkpoint halfway through a job.
+//!
+//! A client can opt a job in with [`EscrowContract::enable_checkpoint`] before
+//! any milestone has been approved. Once approvals cross half of the job's
+//! budget the checkpoint falls due: the freelancer cannot submit further
+//! milestones until the client calls [`EscrowContract::confirm_continue`].
+//!
+//! If the client has not confirmed by the end of the window, the freelancer may
+//! leave with [`EscrowContract::exit_after_checkpoint`]. The exit pays out the
+//! work delivered so far (approved but unpaid milestones, submitted milestones
+//! and the unpaid balance of partially paid ones), refunds the rest to the
+//! client and cancels the job. A client may still confirm late as long as the
+//! freelancer has not exited.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address, Env,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, JobStatus, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Checkpoint errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 900, clear of `DescriptionError`
+/// (800..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CheckpointError {
+    /// The contract is paused.
+    ContractPaused = 900,
+    /// No job exists with the given ID.
+    JobNotFound = 901,
+    /// Caller is not the job's client (or freelancer, for exits).
+    Unauthorized = 902,
+    /// The job is not in a state that allows this operation.
+    InvalidStatus = 903,
+    /// The confirmation window must be longer than zero seconds.
+    InvalidWindow = 904,
+    /// The checkpoint is due and the client has not confirmed yet.
+    CheckpointPending = 905,
+    /// The job has no checkpoint awaiting confirmation.
+    NoCheckpointPending = 906,
+    /// The confirmation window has not ended yet.
+    WindowNotElapsed = 907,
+}
+
+/// Checkpoint settings and progress of a job.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    /// Seconds the client has to confirm once the checkpoint falls due.
+    pub window_secs: u64,
+    /// When the confirmation window ends; `0` until approvals cross half the
+    /// budget.
+    pub due_at: u64,
+    pub confirmed: bool,
+}
+
+/// Storage keys for checkpoints.
+#[contracttype]
+#[derive(Clone)]
+pub enum CheckpointKey {
+    Checkpoint(u64),
+}
+
+fn load_checkpoint(env: &Env, job_id: u64) -> Option<Checkpoint> {
+    env.storage()
+        .persistent()
+        .get(&CheckpointKey::Checkpoint(job_id))
+}
+
+fn save_checkpoint(env: &Env, job_id: u64, checkpoint: &Checkpoint) {
+    let key = CheckpointKey::Checkpoint(job_id);
+    env.storage().persistent().set(&key, checkpoint);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn is_pending(checkpoint: &Checkpoint) -> bool {
+    checkpoint.due_at > 0 && !checkpoint.confirmed
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, CheckpointError> {
+    env.storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(CheckpointError::JobNotFound)
+}
+
+/// Budget value already approved: the total minus what is still owed on
+/// milestones that are not approved.
+fn approved_value(job: &Job) -> i128 {
+    let open: i128 = job
+        .milestones
+        .iter()
+        .filter(|m| m.status != MilestoneStatus::Approved && crate::milestone_tokens::in_job_token(m))
+        .map(|m| m.amount - m.released)
+        .sum();
+    job.total_amount - open
+}
+
+/// Start the confirmation window once approvals on `job` cross half of its
+/// budget. Called after every approval or payment.
+pub(crate) fn on_approval(env: &Env, job: &Job) {
+    let Some(mut checkpoint) = load_checkpoint(env, job.id) else {
+        return;
+    };
+    if checkpoint.due_at > 0
+        || !matches!(job.status, JobStatus::Funded | JobStatus::InProgress)
+        || approved_value(job) * 2 < job.total_amount
+    {
+        return;
+    }
+    checkpoint.due_at = env
+        .ledger()
+        .timestamp()
+        .saturating_add(checkpoint.window_secs);
+    save_checkpoint(env, job.id, &checkpoint);
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("ckpt_due")),
+        (job.id, checkpoint.due_at),
+    );
+}
+
+/// Abort with [`CheckpointError::CheckpointPending`] while a checkpoint awaits
+/// the client. Used from `submit_milestone`, which returns `EscrowError`.
+pub(crate) fn enforce_submission_allowed(env: &Env, job_id: u64) {
+    if load_checkpoint(env, job_id).is_some_and(|c| is_pending(&c)) {
+        panic_with_error!(env, CheckpointError::CheckpointPending);
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Opt a job into the halfway checkpoint, giving the client `window_secs`
+    /// to confirm once it falls due. Client only, before any milestone has
+    /// been approved.
+    pub fn enable_checkpoint(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        window_secs: u64,
+    ) -> Result<(), CheckpointError> {
+        crate::require_not_paused(&env).map_err(|_| CheckpointError::ContractPaused)?;
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(CheckpointError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Amend)
+            .map_err(|_| CheckpointError::InvalidStatus)?;
+        if job.status == JobStatus::InProgress || approved_value(&job) > 0 {
+            return Err(CheckpointError::InvalidStatus);
+        }
+        if window_secs == 0 {
+            return Err(CheckpointError::InvalidWindow);
+        }
+        let checkpoint = Checkpoint {
+            window_secs,
+            due_at: 0,
+            confirmed: false,
+        };
+        save_checkpoint(&env, job_id, &checkpoint);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ckpt_set")),
+            (job_id, window_secs),
+        );
+        Ok(())
+    }
+
+    /// Confirm the job should continue past its checkpoint. Client only, until
+    /// the freelancer exits.
+    pub fn confirm_continue(env: Env, job_id: u64, client: Address) -> Result<(), CheckpointError> {
+        crate::require_not_paused(&env).map_err(|_| CheckpointError::ContractPaused)?;
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(CheckpointError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Work)
+            .map_err(|_| CheckpointError::InvalidStatus)?;
+        let mut checkpoint = load_checkpoint(&env, job_id)
+            .filter(is_pending)
+            .ok_or(CheckpointError::NoCheckpointPending)?;
+        checkpoint.confirmed = true;
+        save_checkpoint(&env, job_id, &checkpoint);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ckpt_ok")),
+            (job_id, client),
+        );
+        Ok(())
+    }
+
+    /// Leave a job whose checkpoint the client failed to confirm in time.
+    /// Freelancer only. Pays out the work delivered so far, refunds the rest
+    /// to the client and cancels the job. Returns the amount paid to the
+    /// freelancer.
+    pub fn exit_after_checkpoint(
+        env: Env,
+        job_id: u64,
+        freelancer: Address,
+    ) -> Result<i128, CheckpointError> {
+        crate::pause::require_scope_open(&env, crate::PauseScope::Payouts)
+            .map_err(|_| CheckpointError::ContractPaused)?;
+        freelancer.require_auth();
+        let mut job = load_job(&env, job_id)?;
+        if job.freelancer != freelancer {
+            return Err(CheckpointError::Unauthorized);
+        }
+        let next = crate::state::transition(&job.status, JobEvent::Cancel)
+            .map_err(|_| CheckpointError::InvalidStatus)?;
+        let checkpoint = load_checkpoint(&env, job_id)
+            .filter(is_pending)
+            .ok_or(CheckpointError::NoCheckpointPending)?;
+        if env.ledger().timestamp() <= checkpoint.due_at {
+            return Err(CheckpointError::WindowNotElapsed);
+        }
+
+        // Delivered work still held in escrow goes to the freelancer; budget
+        // for milestones never submitted goes back to the client.
+        let mut delivered: i128 = 0;
+        let mut undelivered: i128 = 0;
+        let mut milestones = job.milestones.clone();
+        for (i, mut m) in job.milestones.iter().enumerate() {
+            // Milestones paid in another token are settled by the closing
+            // hook, after the status updates below.
+            if !crate::milestone_tokens::in_job_token(&m) {
+                if matches!(m.status, MilestoneStatus::Submitted | MilestoneStatus::PartiallyPaid) {
+                    m.status = MilestoneStatus::Approved;
+                    milestones.set(i as u32, m);
+                }
+                continue;
+            }
+            match m.status {
+                MilestoneStatus::Approved => {
+                    delivered += m.amount - m.released;
+                }
+                MilestoneStatus::Submitted | MilestoneStatus::PartiallyPaid => {
+                    delivered += m.amount - m.released;
+                    m.status = MilestoneStatus::Approved;
+                    milestones.set(i as u32, m);
+                }
+                MilestoneStatus::Pending | MilestoneStatus::InProgress => {
+                    undelivered += m.amount;
+                }
+            }
+        }
+
+        let token_client = token::Client::new(&env, &job.token);
+        let (fee_amount, fee_deducted) =
+            crate::fees::release_fee(&env, job_id, delivered, crate::fees::current_fee_bps(&env));
+        if fee_amount > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &crate::fees::treasury(&env),
+                &fee_amount,
+            );
+        }
+        let to_freelancer = delivered - fee_deducted;
+        if to_freelancer > 0 {
+            crate::owed::pay_freelancer(&env, &job, to_freelancer);
+        }
+        if undelivered > 0 {
+            token_client.transfer(&env.current_contract_address(), &job.client, &undelivered);
+        }
+
+        job.milestones = milestones;
+        job.status = next;
+        job.cancelled_at = Some(env.ledger().timestamp());
+        crate::fees::settle_fee(&env, &job, job.total_amount - undelivered);
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::Job(job_id), &job);
+        crate::on_job_closed(&env, &job);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ckpt_exit")),
+            (job_id, freelancer, to_freelancer, undelivered),
+        );
+        Ok(to_freelancer)
+    }
+
+    /// Checkpoint settings and progress of a job, if it opted in.
+    pub fn get_checkpoint(env: Env, job_id: u64) -> Option<Checkpoint> {
+        load_checkpoint(&env, job_id)
+    }
+}
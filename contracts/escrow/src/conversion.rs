@@ -0,0 +1,277 @@
+//! Payout conversion into the freelancer's preferred token.
+//!
+//! A freelancer paid in the job's token may rather hold another asset. With
+//! [`EscrowContract::set_payout_conversion`] they name a preferred token, one
+//! of the allowlisted swap adapters (see `set_swap_adapter`) and the most
+//! slippage they accept. From then on every payout of a job's token to them
+//! goes through [`pay_converted`] first: the escrow prices both tokens with
+//! the TWAP oracle, lets the adapter pull the payout through a one-off
+//! allowance with `swap_from`, and has the output sent straight to the payout
+//! address.
+//!
+//! Conversion is best effort and never blocks the payout it is part of. If
+//! either token has no usable price, the adapter is no longer allowlisted, or
+//! the swap fails, nothing moves, `("escrow", "conv_fail")` is published and
+//! the payout is sent in the job's token as usual. The escrow does not trust
+//! the adapter to honour the slippage floor: the output comes back to the
+//! escrow first and is judged by balances. An adapter that takes the payout
+//! but returns less than the floor cannot be rolled back without undoing the
+//! approval, cancel or resolution around it. Whatever it did return is passed
+//! on and `conv_fail` is published with
+//! [`ConversionError::InsufficientOutput`], but the adapter is dropped from
+//! the allowlist and the freelancer's conversion is cleared in the same call,
+//! so a short swap can happen at most once; any part of the payout it left in
+//! escrow is sent in the job's token. Converted output goes through the
+//! owed-payout path like every other payout. Milestones paid in another token
+//! (see `milestone_tokens`) are not converted.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env, IntoVal,
+    Symbol,
+};
+
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Largest slippage, in basis points, a freelancer may accept.
+pub const MAX_CONVERSION_SLIPPAGE_BPS: u32 = 2_000;
+
+/// Payout conversion errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2300, clear of `ReviewError`
+/// (2200..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConversionError {
+    /// The preferred token is not on the escrow's token allowlist.
+    TokenNotAllowed = 2300,
+    /// The adapter is not on the swap adapter allowlist.
+    AdapterNotAllowed = 2301,
+    /// Slippage is above `MAX_CONVERSION_SLIPPAGE_BPS`.
+    InvalidSlippage = 2302,
+    /// The freelancer has no payout conversion to clear.
+    NoConversion = 2303,
+    /// The swap failed or returned less than the slippage floor.
+    InsufficientOutput = 2304,
+}
+
+/// How a freelancer wants their payouts converted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutConversion {
+    /// Token the freelancer wants to receive.
+    pub token: Address,
+    /// Allowlisted swap adapter that performs the conversion.
+    pub adapter: Address,
+    /// Most the output may fall short of the oracle price, in bps.
+    pub max_slippage_bps: u32,
+}
+
+/// Storage keys for payout conversions.
+#[contracttype]
+#[derive(Clone)]
+pub enum ConversionKey {
+    /// A freelancer's `PayoutConversion`.
+    Pref(Address),
+}
+
+fn conversion(env: &Env, freelancer: &Address) -> Option<PayoutConversion> {
+    env.storage()
+        .persistent()
+        .get(&ConversionKey::Pref(freelancer.clone()))
+}
+
+/// Oracle price of `token` in XLM stroops, scaled by `PRICE_SCALE`; native
+/// XLM is valued at par.
+fn price(env: &Env, token: &Address) -> Option<i128> {
+    if crate::native::is_native(env, token) {
+        return Some(crate::PRICE_SCALE);
+    }
+    crate::fetch_twap_price(env, token).ok().map(|(price, _)| price)
+}
+
+/// Drop `freelancer`'s conversion and publish `conv_clr`.
+fn clear(env: &Env, freelancer: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&ConversionKey::Pref(freelancer.clone()));
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("conv_clr")),
+        freelancer.clone(),
+    );
+}
+
+/// Least `token_out` that `amount` of `token_in` may be swapped for.
+fn min_output(
+    env: &Env,
+    token_in: &Address,
+    token_out: &Address,
+    amount: i128,
+    max_slippage_bps: u32,
+) -> Option<i128> {
+    let expected = amount
+        .checked_mul(price(env, token_in)?)?
+        .checked_div(price(env, token_out)?)?;
+    expected
+        .checked_mul(10_000 - max_slippage_bps as i128)?
+        .checked_div(10_000)
+}
+
+/// Pay `amount` of the job's token to `recipient` converted into the
+/// freelancer's preferred token. Returns the part of `amount` that was not
+/// converted, which the caller pays in the job's token: all of it if the
+/// freelancer has no conversion or it could not be carried out.
+pub(crate) fn pay_converted(env: &Env, job: &Job, recipient: &Address, amount: i128) -> i128 {
+    let Some(pref) = conversion(env, &job.freelancer) else {
+        return amount;
+    };
+    if pref.token == job.token {
+        return amount;
+    }
+    let contract = env.current_contract_address();
+    let token_in = token::Client::new(env, &job.token);
+    let held = token_in.balance(&contract);
+    let min_out = min_output(env, &job.token, &pref.token, amount, pref.max_slippage_bps);
+    let result = match min_out {
+        Some(min_out) if min_out > 0 && crate::migrations::adapters(env).contains(&pref.adapter) => {
+            swap(env, job, &pref, recipient, amount, min_out)
+        }
+        _ => Err(ConversionError::InsufficientOutput),
+    };
+    // Judge by balances rather than the adapter's return value: whatever left
+    // escrow must not be paid a second time.
+    let taken = (held - token_in.balance(&contract)).clamp(0, amount);
+
+    match result {
+        Ok(received) => env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("converted")),
+            (job.id, taken, pref.token, received, recipient.clone()),
+        ),
+        Err(err) => {
+            env.events().publish(
+                (symbol_short!("escrow"), symbol_short!("conv_fail")),
+                (job.id, amount, pref.token.clone(), err as u32),
+            );
+            // The adapter kept the payout and came back short: never route
+            // another payout through it or convert for this freelancer again
+            // until they opt back in.
+            if taken > 0 {
+                crate::migrations::drop_adapter(env, &pref.adapter);
+                clear(env, &job.freelancer);
+            }
+        }
+    }
+    amount - taken
+}
+
+/// Let the adapter pull `amount` and swap it for at least `min_out` of the
+/// preferred token, then pass the output on to `recipient`, owed to them if
+/// the transfer fails. Returns how much of the preferred token was passed
+/// on. A swap that fails is rolled back by the host; one that comes back
+/// short is not, and what it returned is passed on before
+/// `InsufficientOutput` is returned for `pay_converted` to stop trusting the
+/// adapter. The allowance is cleared either way.
+fn swap(
+    env: &Env,
+    job: &Job,
+    pref: &PayoutConversion,
+    recipient: &Address,
+    amount: i128,
+    min_out: i128,
+) -> Result<i128, ConversionError> {
+    let contract = env.current_contract_address();
+    let token_in = token::Client::new(env, &job.token);
+    let token_out = token::Client::new(env, &pref.token);
+    let before = token_out.balance(&contract);
+    let expiration = env.ledger().sequence();
+    token_in.approve(&contract, &pref.adapter, &amount, &expiration);
+    let _ = env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        &pref.adapter,
+        &Symbol::new(env, "swap_from"),
+        vec![
+            env,
+            job.token.into_val(env),
+            pref.token.into_val(env),
+            amount.into_val(env),
+            min_out.into_val(env),
+            contract.into_val(env),
+            contract.into_val(env),
+        ],
+    );
+    token_in.approve(&contract, &pref.adapter, &0, &expiration);
+    let received = (token_out.balance(&contract) - before).max(0);
+    crate::owed::pay_or_owe(env, job.id, &pref.token, recipient, received);
+    if received < min_out {
+        return Err(ConversionError::InsufficientOutput);
+    }
+    Ok(received)
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Have future payouts to `freelancer` converted into `token` through the
+    /// allowlisted `adapter`, accepting up to `max_slippage_bps` below the
+    /// oracle price. Applies to every job the caller freelances on and
+    /// replaces any earlier setting.
+    pub fn set_payout_conversion(
+        env: Env,
+        freelancer: Address,
+        token: Address,
+        adapter: Address,
+        max_slippage_bps: u32,
+    ) -> Result<(), ConversionError> {
+        freelancer.require_auth();
+        if !crate::is_token_allowed(&env, &token) {
+            return Err(ConversionError::TokenNotAllowed);
+        }
+        if !crate::migrations::adapters(&env).contains(&adapter) {
+            return Err(ConversionError::AdapterNotAllowed);
+        }
+        if max_slippage_bps > MAX_CONVERSION_SLIPPAGE_BPS {
+            return Err(ConversionError::InvalidSlippage);
+        }
+
+        let pref = PayoutConversion {
+            token,
+            adapter,
+            max_slippage_bps,
+        };
+        let key = ConversionKey::Pref(freelancer.clone());
+        env.storage().persistent().set(&key, &pref);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("conv_set")),
+            (freelancer, pref),
+        );
+        Ok(())
+    }
+
+    /// Stop converting payouts to `freelancer`.
+    pub fn clear_payout_conversion(env: Env, freelancer: Address) -> Result<(), ConversionError> {
+        freelancer.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&ConversionKey::Pref(freelancer.clone()))
+        {
+            return Err(ConversionError::NoConversion);
+        }
+        clear(&env, &freelancer);
+        Ok(())
+    }
+
+    /// The freelancer's payout conversion, if any.
+    pub fn get_payout_conversion(env: Env, freelancer: Address) -> Option<PayoutConversion> {
+        conversion(&env, &freelancer)
+    }
+}
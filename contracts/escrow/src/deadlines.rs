@@ -0,0 +1,266 @@
+//! At-risk milestone queries backed by a deadline-bucketed index.
+//!
+//! Notification services want to warn freelancers about approaching milestone
+//! deadlines without scanning every job. Whenever a milestone deadline is set
+//! (`create_job`, `extend_deadline`, `accept_revision`) the job ID is added to
+//! the bucket covering that deadline, one bucket per [`DEADLINE_BUCKET_SECS`].
+//!
+//! The index is append-only: extended deadlines and approved milestones leave
+//! stale entries behind, which the queries filter out by re-reading the job.
+//! A bucket therefore only narrows the search; the job itself stays the source
+//! of truth.
+//!
+//! Milestone deadlines are also enforced here. Signers may configure a short
+//! submission grace window after each deadline so that a submission landing a
+//! few seconds late (timezone slips, ledger close timing) still goes through;
+//! such submissions are flagged late rather than rejected.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{EscrowContract, EscrowError, Job, JobStatus, Milestone, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Width of one deadline bucket (one day).
+pub const DEADLINE_BUCKET_SECS: u64 = 86_400;
+/// Maximum number of buckets a single global query scans (~90 days ahead).
+pub const MAX_DEADLINE_SCAN_BUCKETS: u64 = 90;
+/// Maximum page size for `get_jobs_with_milestones_due`.
+pub const MAX_DUE_PAGE_SIZE: u32 = 50;
+/// Upper bound for the submission grace window (one day).
+pub const MAX_SUBMISSION_GRACE_SECS: u64 = 86_400;
+
+/// Storage keys for the deadline index.
+#[contracttype]
+#[derive(Clone)]
+pub enum DeadlineKey {
+    /// Job IDs with a milestone deadline in bucket `deadline / DEADLINE_BUCKET_SECS`.
+    Bucket(u64),
+    /// Seconds after a milestone deadline during which submissions are still
+    /// accepted (instance, u64). Unset means no grace.
+    SubmissionGrace,
+    /// Set when a milestone's latest submission arrived within the grace window.
+    LateSubmission(u64, u32),
+}
+
+/// Add `job_id` to the bucket covering `deadline`, if not already present.
+pub(crate) fn index_deadline(env: &Env, job_id: u64, deadline: u64) {
+    let key = DeadlineKey::Bucket(deadline / DEADLINE_BUCKET_SECS);
+    let mut jobs: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !jobs.contains(job_id) {
+        jobs.push_back(job_id);
+        env.storage().persistent().set(&key, &jobs);
+    }
+    // A bucket is shared by every job due that day and has to outlive the
+    // deadlines it indexes, so it is kept alive for as long as the network
+    // allows rather than the 30 days used for per-job entries.
+    let max_ttl = env.storage().max_ttl();
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, max_ttl.min(crate::TTL_THRESHOLD_LEDGERS), max_ttl);
+}
+
+/// Index every milestone deadline of `job`.
+pub(crate) fn index_job_deadlines(env: &Env, job: &Job) {
+    for milestone in job.milestones.iter() {
+        index_deadline(env, job.id, milestone.deadline);
+    }
+}
+
+fn submission_grace(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DeadlineKey::SubmissionGrace)
+        .unwrap_or(0)
+}
+
+/// Check a submission against `milestone`'s deadline. Returns whether the
+/// submission is late (past the deadline but within the grace window).
+pub(crate) fn check_submission_deadline(
+    env: &Env,
+    milestone: &Milestone,
+) -> Result<bool, EscrowError> {
+    let now = env.ledger().timestamp();
+    if now <= milestone.deadline {
+        return Ok(false);
+    }
+    if now > milestone.deadline.saturating_add(submission_grace(env)) {
+        return Err(EscrowError::MilestoneDeadlineExceeded);
+    }
+    Ok(true)
+}
+
+/// Record whether the latest submission of a milestone was late. An on-time
+/// resubmission clears an earlier flag.
+pub(crate) fn record_submission(
+    env: &Env,
+    job_id: u64,
+    milestone_id: u32,
+    milestone: &Milestone,
+    late: bool,
+) {
+    let key = DeadlineKey::LateSubmission(job_id, milestone_id);
+    if !late {
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+        }
+        return;
+    }
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("ms_late")),
+        (
+            job_id,
+            milestone_id,
+            env.ledger().timestamp() - milestone.deadline,
+        ),
+    );
+}
+
+/// Unapproved milestones of `job` whose deadline falls in `[now, now + within]`.
+fn milestones_due(env: &Env, job: &Job, within_seconds: u64) -> Vec<Milestone> {
+    let now = env.ledger().timestamp();
+    let horizon = now.saturating_add(within_seconds);
+    let mut due = Vec::new(env);
+    for milestone in job.milestones.iter() {
+        if milestone.status != MilestoneStatus::Approved
+            && milestone.deadline >= now
+            && milestone.deadline <= horizon
+        {
+            due.push_back(milestone);
+        }
+    }
+    due
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Set how many seconds after a milestone deadline submissions are still
+    /// accepted (flagged late). Registered multisig signers only; capped at
+    /// [`MAX_SUBMISSION_GRACE_SECS`].
+    pub fn set_submission_grace(env: Env, admin: Address, seconds: u64) -> Result<(), EscrowError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(EscrowError::NotAdmin);
+        }
+        if seconds > MAX_SUBMISSION_GRACE_SECS {
+            return Err(EscrowError::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DeadlineKey::SubmissionGrace, &seconds);
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "sub_grace_set")),
+            (seconds, admin),
+        );
+        Ok(())
+    }
+
+    /// Current submission grace window in seconds.
+    pub fn get_submission_grace(env: Env) -> u64 {
+        submission_grace(&env)
+    }
+
+    /// Whether the latest submission of a milestone landed after its deadline
+    /// (within the grace window).
+    pub fn is_submission_late(env: Env, job_id: u64, milestone_id: u32) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DeadlineKey::LateSubmission(job_id, milestone_id))
+    }
+
+    /// Milestones of `job_id` that are not yet approved and fall due within the
+    /// next `seconds`. Overdue milestones are not included.
+    pub fn get_milestones_due_within(
+        env: Env,
+        job_id: u64,
+        seconds: u64,
+    ) -> Result<Vec<Milestone>, EscrowError> {
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::get_job_key(job_id))
+            .ok_or(EscrowError::JobNotFound)?;
+        Ok(milestones_due(&env, &job, seconds))
+    }
+
+    /// Page through active (funded or in-progress) jobs that have at least one
+    /// unapproved milestone due within `within_seconds`. The lookahead is
+    /// capped at [`MAX_DEADLINE_SCAN_BUCKETS`] days and `limit` at
+    /// [`MAX_DUE_PAGE_SIZE`]. Results follow index order (buckets ascending),
+    /// which approximates but does not guarantee deadline order. Private jobs
+    /// are left out.
+    pub fn get_jobs_with_milestones_due(
+        env: Env,
+        within_seconds: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let limit = limit.min(MAX_DUE_PAGE_SIZE);
+        let mut page = Vec::new(&env);
+        if limit == 0 {
+            return page;
+        }
+
+        let now = env.ledger().timestamp();
+        let first = now / DEADLINE_BUCKET_SECS;
+        let last = (now.saturating_add(within_seconds) / DEADLINE_BUCKET_SECS)
+            .min(first + MAX_DEADLINE_SCAN_BUCKETS - 1);
+
+        let mut seen: Vec<u64> = Vec::new(&env);
+        let mut skipped: u32 = 0;
+        for bucket in first..=last {
+            let jobs: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&DeadlineKey::Bucket(bucket))
+                .unwrap_or(Vec::new(&env));
+            for job_id in jobs.iter() {
+                if seen.contains(job_id) {
+                    continue;
+                }
+                seen.push_back(job_id);
+
+                let Some(job) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, Job>(&crate::get_job_key(job_id))
+                else {
+                    continue;
+                };
+                if job.status != JobStatus::Funded && job.status != JobStatus::InProgress {
+                    continue;
+                }
+                if crate::visibility::is_private(&env, job_id) {
+                    continue;
+                }
+                if milestones_due(&env, &job, within_seconds).is_empty() {
+                    continue;
+                }
+
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                page.push_back(job_id);
+                if page.len() >= limit {
+                    return page;
+                }
+            }
+        }
+        page
+    }
+}
@@ -0,0 +1,178 @@
+//! Milestone descriptions kept off-chain.
+//!
+//! A big statement of work written inline into every `Milestone::description`
+//! inflates the `Job` entry and the rent paid on it for the life of the job.
+//! [`EscrowContract::create_job_with_content`] takes a [`MilestoneContent`]
+//! per milestone instead: either the usual inline text, or the SHA-256 of the
+//! full description plus a URI where it is hosted. For a hashed milestone only
+//! the URI is stored in `Milestone::description`; the hashes of a job live in a
+//! single side entry, so the `Job` layout and plain `create_job` are unchanged.
+//!
+//! [`EscrowContract::get_milestone_content`] tells the two apart again. A
+//! revision that rewrites a hashed milestone's description turns it back into
+//! an inline one.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env, Map, String,
+    Vec,
+};
+
+use crate::{EscrowContract, EscrowError, Job, Milestone};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Longest accepted description URI, in bytes.
+pub const MAX_DESCRIPTION_URI_LEN: u32 = 256;
+
+/// Off-chain description errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 800, clear of `ArchiveError`
+/// (700..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DescriptionError {
+    /// A hashed milestone was given an empty URI.
+    EmptyUri = 800,
+    /// A description URI is longer than [`MAX_DESCRIPTION_URI_LEN`].
+    UriTooLong = 801,
+}
+
+/// How a milestone's description is provided.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MilestoneContent {
+    /// Full description text, stored on-chain.
+    Inline(String),
+    /// SHA-256 of the full description and the URI it is hosted at.
+    Hashed(BytesN<32>, String),
+}
+
+/// Storage keys for off-chain descriptions.
+#[contracttype]
+#[derive(Clone)]
+pub enum DescriptionKey {
+    /// Description hashes of a job's hashed milestones, by milestone id.
+    Hashes(u64),
+}
+
+fn load_hashes(env: &Env, job_id: u64) -> Map<u32, BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DescriptionKey::Hashes(job_id))
+        .unwrap_or(Map::new(env))
+}
+
+fn store_hashes(env: &Env, job_id: u64, hashes: &Map<u32, BytesN<32>>) {
+    let key = DescriptionKey::Hashes(job_id);
+    if hashes.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, hashes);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Drop the hash of every hashed milestone whose description a revision is
+/// about to change from `old` to `new`.
+pub(crate) fn retain_unchanged(env: &Env, job_id: u64, old: &Vec<Milestone>, new: &Vec<Milestone>) {
+    let mut hashes = load_hashes(env, job_id);
+    if hashes.is_empty() {
+        return;
+    }
+    for id in hashes.keys().iter() {
+        let before = old.iter().find(|m| m.id == id).map(|m| m.description);
+        let after = new.iter().find(|m| m.id == id).map(|m| m.description);
+        if after.is_none() || before != after {
+            hashes.remove(id);
+        }
+    }
+    store_hashes(env, job_id, &hashes);
+}
+
+/// Drop the description hashes of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DescriptionKey::Hashes(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// [`EscrowContract::create_job`] with each milestone's description given
+    /// as [`MilestoneContent`]. Hashed milestones keep only their URI in the
+    /// job record.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_job_with_content(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        token: Address,
+        milestones: Vec<(MilestoneContent, i128, u64)>,
+        job_deadline: u64,
+        auto_refund_after: u64,
+        expiry_ledger: u32,
+    ) -> Result<u64, EscrowError> {
+        let mut plain = Vec::new(&env);
+        let mut hashes = Map::new(&env);
+        for (i, (content, amount, deadline)) in milestones.iter().enumerate() {
+            let description = match content {
+                MilestoneContent::Inline(text) => text,
+                MilestoneContent::Hashed(hash, uri) => {
+                    if uri.is_empty() {
+                        panic_with_error!(&env, DescriptionError::EmptyUri);
+                    }
+                    if uri.len() > MAX_DESCRIPTION_URI_LEN {
+                        panic_with_error!(&env, DescriptionError::UriTooLong);
+                    }
+                    hashes.set(i as u32, hash);
+                    uri
+                }
+            };
+            plain.push_back((description, amount, deadline));
+        }
+
+        let job_id = Self::create_job(
+            env.clone(),
+            client,
+            freelancer,
+            token,
+            plain,
+            job_deadline,
+            auto_refund_after,
+            expiry_ledger,
+        )?;
+        store_hashes(&env, job_id, &hashes);
+        Ok(job_id)
+    }
+
+    /// Description of a milestone as it was provided: inline text, or the
+    /// description hash and URI.
+    pub fn get_milestone_content(
+        env: Env,
+        job_id: u64,
+        milestone_id: u32,
+    ) -> Result<MilestoneContent, EscrowError> {
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(EscrowError::JobNotFound)?;
+        let milestone = job
+            .milestones
+            .iter()
+            .find(|m| m.id == milestone_id)
+            .ok_or(EscrowError::MilestoneNotFound)?;
+        Ok(match load_hashes(&env, job_id).get(milestone_id) {
+            Some(hash) => MilestoneContent::Hashed(hash, milestone.description),
+            None => MilestoneContent::Inline(milestone.description),
+        })
+    }
+}
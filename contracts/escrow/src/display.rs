@@ -0,0 +1,191 @@
+//! Reference-currency value of a job, locked in at funding.
+//!
+//! Parties often account for a job in a fiat or other reference currency while
+//! the escrow only knows token amounts. When signers configure a reference
+//! oracle with [`EscrowContract::set_reference_oracle`], `fund_job` reads the
+//! token's price in the configured reference symbol (for example `USD`) and
+//! stores it alongside the job. [`EscrowContract::get_job_display_info`] serves
+//! it back with the job's value at that price, giving both parties an agreed
+//! reference point if the value delivered is disputed later.
+//!
+//! The read is best effort: without a configured oracle, or if the oracle call
+//! fails, the job is funded without a reference price.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, vec, Address, Env, IntoVal, Symbol,
+};
+
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Reference price errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1100, clear of `MigrationError`
+/// (1000..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DisplayError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 1100,
+    /// No job exists with the given ID.
+    JobNotFound = 1101,
+}
+
+/// Oracle and currency used for reference prices.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceOracle {
+    pub oracle: Address,
+    pub symbol: Symbol,
+}
+
+/// Price of a job's token in the reference currency, recorded at funding.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferencePrice {
+    pub symbol: Symbol,
+    /// Reference units per token unit, scaled by `PRICE_SCALE` (1e7).
+    pub price: i128,
+    pub recorded_at: u64,
+}
+
+/// A job's amounts alongside their reference-currency value.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JobDisplayInfo {
+    pub job_id: u64,
+    pub token: Address,
+    pub total_amount: i128,
+    pub funded_amount: i128,
+    /// `None` if the job was funded without a reference price.
+    pub reference_symbol: Option<Symbol>,
+    /// Recorded [`ReferencePrice::price`]; `0` without a reference price.
+    pub reference_price: i128,
+    /// `total_amount * reference_price / 1e7`: the job's value in the
+    /// reference currency, at the token's precision.
+    pub reference_value: i128,
+    /// When the reference price was recorded; `0` without one.
+    pub priced_at: u64,
+}
+
+/// Storage keys for reference prices.
+#[contracttype]
+#[derive(Clone)]
+pub enum DisplayKey {
+    /// Configured [`ReferenceOracle`] (instance).
+    Oracle,
+    /// Reference price recorded when a job was funded.
+    Price(u64),
+}
+
+/// Record the reference price of `job`'s token. No-op without a configured
+/// oracle, or if the oracle fails or reports a non-positive price.
+pub(crate) fn record_reference_price(env: &Env, job: &Job) {
+    let Some(config) = env
+        .storage()
+        .instance()
+        .get::<_, ReferenceOracle>(&DisplayKey::Oracle)
+    else {
+        return;
+    };
+    let result = env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        &config.oracle,
+        &Symbol::new(env, "reference_price"),
+        vec![
+            env,
+            job.token.clone().into_val(env),
+            config.symbol.clone().into_val(env),
+        ],
+    );
+    let price = match result {
+        Ok(Ok(price)) if price > 0 => price,
+        _ => return,
+    };
+
+    let key = DisplayKey::Price(job.id);
+    let reference = ReferencePrice {
+        symbol: config.symbol,
+        price,
+        recorded_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&key, &reference);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("ref_price")),
+        (job.id, reference.symbol, price),
+    );
+}
+
+/// Drop the reference price of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DisplayKey::Price(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Configure the oracle consulted for reference prices at funding, and the
+    /// reference currency `symbol`. Registered multisig signers only. The oracle
+    /// must expose `reference_price(token: Address, symbol: Symbol) -> i128`,
+    /// returning reference units per token unit scaled by 1e7.
+    pub fn set_reference_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Address,
+        symbol: Symbol,
+    ) -> Result<(), DisplayError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(DisplayError::NotAdmin);
+        }
+        let config = ReferenceOracle { oracle, symbol };
+        env.storage().instance().set(&DisplayKey::Oracle, &config);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ref_orcl")),
+            (config.oracle, config.symbol, admin),
+        );
+        Ok(())
+    }
+
+    /// Configured reference oracle, if any.
+    pub fn get_reference_oracle(env: Env) -> Option<ReferenceOracle> {
+        env.storage().instance().get(&DisplayKey::Oracle)
+    }
+
+    /// A job's amounts with the reference price recorded at funding.
+    pub fn get_job_display_info(env: Env, job_id: u64) -> Result<JobDisplayInfo, DisplayError> {
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(DisplayError::JobNotFound)?;
+        let reference: Option<ReferencePrice> =
+            env.storage().persistent().get(&DisplayKey::Price(job_id));
+        let (reference_symbol, reference_price, priced_at) = match reference {
+            Some(r) => (Some(r.symbol), r.price, r.recorded_at),
+            None => (None, 0, 0),
+        };
+        Ok(JobDisplayInfo {
+            job_id,
+            token: job.token,
+            total_amount: job.total_amount,
+            funded_amount: job.funded_amount,
+            reference_symbol,
+            reference_price,
+            reference_value: job.total_amount.saturating_mul(reference_price) / crate::PRICE_SCALE,
+            priced_at,
+        })
+    }
+}
@@ -0,0 +1,260 @@
+//! Client-funded early-delivery bonuses.
+//!
+//! A client can attach a bonus to a milestone with
+//! [`EscrowContract::add_early_bonus`]: an amount in the job's token,
+//! deposited up front, and a target timestamp. Like a performance bond the
+//! bonus is held as a balance of its own and never touches
+//! `Job::total_amount`, `Job::funded_amount` or the fee path.
+//!
+//! - **approved by the target** — paid to the freelancer's payout address as
+//!   part of the approval;
+//! - **approved late, or never** — returned to the client when the job reaches
+//!   any terminal status.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol, Vec,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Early-delivery bonus errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1500, clear of `ApprovalError`
+/// (1400..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BonusError {
+    /// The contract is paused.
+    ContractPaused = 1500,
+    /// No job exists with the given ID.
+    JobNotFound = 1501,
+    /// Caller is not the job's client.
+    Unauthorized = 1502,
+    /// The job is terminal or disputed, or the milestone is already approved.
+    InvalidStatus = 1503,
+    /// The job has no milestone with the given ID.
+    MilestoneNotFound = 1504,
+    /// Amount must be strictly positive.
+    InvalidAmount = 1505,
+    /// The target timestamp has already passed.
+    InvalidTarget = 1506,
+    /// The milestone already carries a bonus.
+    BonusExists = 1507,
+}
+
+/// Where an early-delivery bonus stands.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BonusStatus {
+    Held,
+    Paid,
+    Refunded,
+}
+
+/// An early-delivery bonus on one milestone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyBonus {
+    pub amount: i128,
+    /// Latest approval timestamp that still earns the bonus.
+    pub target: u64,
+    pub status: BonusStatus,
+}
+
+/// Storage keys for early-delivery bonuses.
+#[contracttype]
+#[derive(Clone)]
+pub enum BonusKey {
+    /// Bonus on a milestone: (job_id, milestone_id).
+    Bonus(u64, u32),
+    /// Milestone IDs of a job that carry a bonus.
+    Milestones(u64),
+}
+
+fn load_bonus(env: &Env, job_id: u64, milestone_id: u32) -> Option<EarlyBonus> {
+    env.storage()
+        .persistent()
+        .get(&BonusKey::Bonus(job_id, milestone_id))
+}
+
+fn save_bonus(env: &Env, job_id: u64, milestone_id: u32, bonus: &EarlyBonus) {
+    let key = BonusKey::Bonus(job_id, milestone_id);
+    env.storage().persistent().set(&key, bonus);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn bonus_milestones(env: &Env, job_id: u64) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&BonusKey::Milestones(job_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Pay out the held bonuses of milestones on `job` approved by their target.
+/// Called after every approval or payment; bonuses approved late stay held
+/// until the job closes.
+pub(crate) fn on_approval(env: &Env, job: &Job) {
+    let now = env.ledger().timestamp();
+    for milestone_id in bonus_milestones(env, job.id).iter() {
+        let Some(mut bonus) = load_bonus(env, job.id, milestone_id) else {
+            continue;
+        };
+        let approved = job
+            .milestones
+            .get(milestone_id)
+            .is_some_and(|m| m.status == MilestoneStatus::Approved);
+        if bonus.status != BonusStatus::Held || !approved || now > bonus.target {
+            continue;
+        }
+        crate::owed::pay_freelancer(env, job, bonus.amount);
+        bonus.status = BonusStatus::Paid;
+        save_bonus(env, job.id, milestone_id, &bonus);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bonus_pd")),
+            (job.id, milestone_id, crate::payouts::recipient(env, job), bonus.amount),
+        );
+    }
+}
+
+/// Return every bonus still held on a closing job to the client.
+pub(crate) fn refund_held(env: &Env, job: &Job) {
+    for milestone_id in bonus_milestones(env, job.id).iter() {
+        let Some(mut bonus) = load_bonus(env, job.id, milestone_id) else {
+            continue;
+        };
+        if bonus.status != BonusStatus::Held {
+            continue;
+        }
+        token::Client::new(env, &job.token).transfer(
+            &env.current_contract_address(),
+            &job.client,
+            &bonus.amount,
+        );
+        bonus.status = BonusStatus::Refunded;
+        save_bonus(env, job.id, milestone_id, &bonus);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bonus_rf")),
+            (job.id, milestone_id, bonus.amount),
+        );
+    }
+}
+
+/// Total of the bonuses of `job_id` still held in the job's token.
+pub(crate) fn held(env: &Env, job_id: u64) -> i128 {
+    let mut total = 0;
+    for milestone_id in bonus_milestones(env, job_id).iter() {
+        if let Some(bonus) = load_bonus(env, job_id, milestone_id) {
+            if bonus.status == BonusStatus::Held {
+                total += bonus.amount;
+            }
+        }
+    }
+    total
+}
+
+/// Drop the bonus records of an archived job; all are settled by then.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    for milestone_id in bonus_milestones(env, job_id).iter() {
+        env.storage()
+            .persistent()
+            .remove(&BonusKey::Bonus(job_id, milestone_id));
+    }
+    env.storage()
+        .persistent()
+        .remove(&BonusKey::Milestones(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Deposit a bonus of `amount` in the job's token, paid to the freelancer
+    /// if `milestone_id` is approved no later than `target`. Client only, one
+    /// bonus per milestone, while the job is open and the milestone not yet
+    /// approved.
+    pub fn add_early_bonus(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        milestone_id: u32,
+        amount: i128,
+        target: u64,
+    ) -> Result<(), BonusError> {
+        crate::pause::require_scope_open(&env, crate::PauseScope::Create)
+            .map_err(|_| BonusError::ContractPaused)?;
+        client.require_auth();
+        if amount <= 0 {
+            return Err(BonusError::InvalidAmount);
+        }
+        if target <= env.ledger().timestamp() {
+            return Err(BonusError::InvalidTarget);
+        }
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(BonusError::JobNotFound)?;
+        if job.client != client {
+            return Err(BonusError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Amend)
+            .map_err(|_| BonusError::InvalidStatus)?;
+        let milestone = job
+            .milestones
+            .get(milestone_id)
+            .ok_or(BonusError::MilestoneNotFound)?;
+        if milestone.status == MilestoneStatus::Approved {
+            return Err(BonusError::InvalidStatus);
+        }
+        if load_bonus(&env, job_id, milestone_id).is_some() {
+            return Err(BonusError::BonusExists);
+        }
+
+        token::Client::new(&env, &job.token).transfer(
+            &client,
+            &env.current_contract_address(),
+            &amount,
+        );
+        save_bonus(
+            &env,
+            job_id,
+            milestone_id,
+            &EarlyBonus {
+                amount,
+                target,
+                status: BonusStatus::Held,
+            },
+        );
+        let mut milestones = bonus_milestones(&env, job_id);
+        milestones.push_back(milestone_id);
+        let key = BonusKey::Milestones(job_id);
+        env.storage().persistent().set(&key, &milestones);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "bonus_added")),
+            (job_id, milestone_id, amount, target),
+        );
+        Ok(())
+    }
+
+    /// Early-delivery bonus on a milestone, if any.
+    pub fn get_early_bonus(env: Env, job_id: u64, milestone_id: u32) -> Option<EarlyBonus> {
+        load_bonus(&env, job_id, milestone_id)
+    }
+}
@@ -0,0 +1,411 @@
+//! Freelancer-initiated invoicing for off-milestone expenses.
+//!
+//! Jobs regularly incur costs nobody priced into a milestone (licences, travel,
+//! hosting). Instead of renegotiating the whole milestone plan through a
+//! revision, the freelancer can [`EscrowContract::submit_expense`] with an
+//! amount and the hash of an off-chain receipt. The client then either
+//! [`EscrowContract::approve_expense`] or [`EscrowContract::reject_expense`].
+//!
+//! Expenses are tracked entirely apart from milestones: they never touch
+//! `Job::total_amount`, `Job::funded_amount` or the milestone payout path, and
+//! no platform fee is taken on them. An approved expense is paid either from a
+//! dedicated per-job expense balance the client pre-deposits with
+//! [`EscrowContract::fund_expenses`], or by a direct client → freelancer
+//! transfer at approval time. Unspent expense balance is returned to the client
+//! with [`EscrowContract::withdraw_expense_balance`] once the job is terminal.
+//!
+//! The sum of pending and approved expenses on a job is capped at a percentage
+//! of the job total (basis points, [`DEFAULT_EXPENSE_CAP_BPS`] unless signers
+//! configure another value), so expenses cannot be used to quietly double a
+//! job's price.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, Symbol,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, JobStatus, PauseScope};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Default cap on a job's pending + approved expenses: 10% of the job total.
+pub const DEFAULT_EXPENSE_CAP_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Expense errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 400, clear of `EscrowError`
+/// (1..=51), `GovError` (200..) and `LimitsError` (300..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExpenseError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 400,
+    /// The contract is paused.
+    ContractPaused = 401,
+    /// No job exists with the given ID.
+    JobNotFound = 402,
+    /// Caller is not the job's client / freelancer for this operation.
+    Unauthorized = 403,
+    /// The job is not in a state that allows this operation.
+    InvalidStatus = 404,
+    /// Amount must be strictly positive.
+    InvalidAmount = 405,
+    /// The expense would push the job's expenses past the configured cap.
+    CapExceeded = 406,
+    /// No expense exists with the given ID.
+    ExpenseNotFound = 407,
+    /// The expense has already been approved or rejected.
+    NotPending = 408,
+    /// The job's expense balance cannot cover the approved expense.
+    InsufficientExpenseBalance = 409,
+    /// Cap must be between 0 and 10 000 basis points.
+    InvalidCap = 410,
+}
+
+/// Lifecycle of a submitted expense.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpenseStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// An off-milestone expense invoiced by the freelancer.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Expense {
+    pub id: u32,
+    pub job_id: u64,
+    pub amount: i128,
+    /// Hash of the off-chain receipt / description.
+    pub description_hash: BytesN<32>,
+    pub status: ExpenseStatus,
+    pub submitted_at: u64,
+    /// Timestamp of approval or rejection; `0` while pending.
+    pub resolved_at: u64,
+}
+
+/// Storage keys for expenses.
+#[contracttype]
+#[derive(Clone)]
+pub enum ExpenseKey {
+    /// Expense cap in basis points of the job total (`u32`, instance).
+    CapBps,
+    /// A single expense: (job_id, expense_id) → `Expense`.
+    Expense(u64, u32),
+    /// Number of expenses ever submitted for a job (`u32`).
+    Count(u64),
+    /// Sum of pending + approved expense amounts for a job (`i128`).
+    Committed(u64),
+    /// Client-deposited balance reserved for paying expenses (`i128`).
+    Balance(u64),
+}
+
+fn cap_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ExpenseKey::CapBps)
+        .unwrap_or(DEFAULT_EXPENSE_CAP_BPS)
+}
+
+fn require_not_paused(env: &Env) -> Result<(), ExpenseError> {
+    crate::require_not_paused(env).map_err(|_| ExpenseError::ContractPaused)
+}
+
+fn require_scope_open(env: &Env, scope: PauseScope) -> Result<(), ExpenseError> {
+    crate::pause::require_scope_open(env, scope).map_err(|_| ExpenseError::ContractPaused)
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, ExpenseError> {
+    env.storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(ExpenseError::JobNotFound)
+}
+
+fn load_expense(env: &Env, job_id: u64, expense_id: u32) -> Result<Expense, ExpenseError> {
+    env.storage()
+        .persistent()
+        .get(&ExpenseKey::Expense(job_id, expense_id))
+        .ok_or(ExpenseError::ExpenseNotFound)
+}
+
+fn get_i128(env: &Env, key: &ExpenseKey) -> i128 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+fn set_persistent<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(
+    env: &Env,
+    key: &ExpenseKey,
+    value: &V,
+) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(
+        key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn is_terminal(status: &JobStatus) -> bool {
+    matches!(
+        status,
+        JobStatus::Completed | JobStatus::Cancelled | JobStatus::Expired
+    )
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Set the expense cap as basis points of the job total. Registered
+    /// multisig signers only. Applies to expenses submitted afterwards.
+    pub fn set_expense_cap_bps(env: Env, admin: Address, bps: u32) -> Result<(), ExpenseError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(ExpenseError::NotAdmin);
+        }
+        if bps as i128 > BPS_DENOMINATOR {
+            return Err(ExpenseError::InvalidCap);
+        }
+        env.storage().instance().set(&ExpenseKey::CapBps, &bps);
+
+        env.events().publish(
+            (
+                symbol_short!("escrow"),
+                Symbol::new(&env, "expense_cap_set"),
+            ),
+            (bps, admin),
+        );
+        Ok(())
+    }
+
+    /// Return the expense cap in basis points of the job total.
+    pub fn get_expense_cap_bps(env: Env) -> u32 {
+        cap_bps(&env)
+    }
+
+    /// Deposit `amount` into the job's expense balance so that approved
+    /// expenses can be paid without a transfer at approval time. Client only.
+    pub fn fund_expenses(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        amount: i128,
+    ) -> Result<(), ExpenseError> {
+        require_scope_open(&env, PauseScope::Create)?;
+        client.require_auth();
+        if amount <= 0 {
+            return Err(ExpenseError::InvalidAmount);
+        }
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(ExpenseError::Unauthorized);
+        }
+        if is_terminal(&job.status) {
+            return Err(ExpenseError::InvalidStatus);
+        }
+
+        token::Client::new(&env, &job.token).transfer(
+            &client,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let key = ExpenseKey::Balance(job_id);
+        let balance = get_i128(&env, &key).saturating_add(amount);
+        set_persistent(&env, &key, &balance);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("exp_fund")),
+            (job_id, client, amount, balance),
+        );
+        Ok(())
+    }
+
+    /// Invoice an off-milestone expense against a funded job. Freelancer only.
+    /// Returns the new expense ID.
+    pub fn submit_expense(
+        env: Env,
+        job_id: u64,
+        freelancer: Address,
+        amount: i128,
+        description_hash: BytesN<32>,
+    ) -> Result<u32, ExpenseError> {
+        require_not_paused(&env)?;
+        freelancer.require_auth();
+        if amount <= 0 {
+            return Err(ExpenseError::InvalidAmount);
+        }
+        let job = load_job(&env, job_id)?;
+        if job.freelancer != freelancer {
+            return Err(ExpenseError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Work)
+            .map_err(|_| ExpenseError::InvalidStatus)?;
+
+        let committed_key = ExpenseKey::Committed(job_id);
+        let committed = get_i128(&env, &committed_key)
+            .checked_add(amount)
+            .ok_or(ExpenseError::CapExceeded)?;
+        let cap = job.total_amount.saturating_mul(cap_bps(&env) as i128) / BPS_DENOMINATOR;
+        if committed > cap {
+            return Err(ExpenseError::CapExceeded);
+        }
+
+        let count_key = ExpenseKey::Count(job_id);
+        let expense_id: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let expense = Expense {
+            id: expense_id,
+            job_id,
+            amount,
+            description_hash: description_hash.clone(),
+            status: ExpenseStatus::Pending,
+            submitted_at: env.ledger().timestamp(),
+            resolved_at: 0,
+        };
+        set_persistent(&env, &ExpenseKey::Expense(job_id, expense_id), &expense);
+        set_persistent(&env, &count_key, &(expense_id + 1));
+        set_persistent(&env, &committed_key, &committed);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("exp_sub")),
+            (job_id, expense_id, freelancer, amount, description_hash),
+        );
+        Ok(expense_id)
+    }
+
+    /// Approve a pending expense and pay the freelancer. With `from_balance`
+    /// the amount is drawn from the job's expense balance; otherwise it is
+    /// transferred directly from the client. Client only.
+    pub fn approve_expense(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        expense_id: u32,
+        from_balance: bool,
+    ) -> Result<(), ExpenseError> {
+        require_scope_open(&env, PauseScope::Payouts)?;
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(ExpenseError::Unauthorized);
+        }
+        let mut expense = load_expense(&env, job_id, expense_id)?;
+        if expense.status != ExpenseStatus::Pending {
+            return Err(ExpenseError::NotPending);
+        }
+
+        let token_client = token::Client::new(&env, &job.token);
+        if from_balance {
+            let key = ExpenseKey::Balance(job_id);
+            let balance = get_i128(&env, &key);
+            if balance < expense.amount {
+                return Err(ExpenseError::InsufficientExpenseBalance);
+            }
+            set_persistent(&env, &key, &(balance - expense.amount));
+            crate::owed::pay_freelancer(&env, &job, expense.amount);
+        } else {
+            token_client.transfer(&client, &crate::payouts::recipient(&env, &job), &expense.amount);
+        }
+
+        expense.status = ExpenseStatus::Approved;
+        expense.resolved_at = env.ledger().timestamp();
+        set_persistent(&env, &ExpenseKey::Expense(job_id, expense_id), &expense);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("exp_appr")),
+            (job_id, expense_id, expense.amount, from_balance),
+        );
+        Ok(())
+    }
+
+    /// Reject a pending expense, freeing its share of the job's expense cap.
+    /// Client only.
+    pub fn reject_expense(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        expense_id: u32,
+    ) -> Result<(), ExpenseError> {
+        require_not_paused(&env)?;
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(ExpenseError::Unauthorized);
+        }
+        let mut expense = load_expense(&env, job_id, expense_id)?;
+        if expense.status != ExpenseStatus::Pending {
+            return Err(ExpenseError::NotPending);
+        }
+
+        let committed_key = ExpenseKey::Committed(job_id);
+        let committed = get_i128(&env, &committed_key) - expense.amount;
+        set_persistent(&env, &committed_key, &committed);
+
+        expense.status = ExpenseStatus::Rejected;
+        expense.resolved_at = env.ledger().timestamp();
+        set_persistent(&env, &ExpenseKey::Expense(job_id, expense_id), &expense);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("exp_rej")),
+            (job_id, expense_id, expense.amount),
+        );
+        Ok(())
+    }
+
+    /// Return the unspent expense balance to the client once the job has
+    /// reached a terminal state. Client only. Returns the amount withdrawn.
+    pub fn withdraw_expense_balance(
+        env: Env,
+        job_id: u64,
+        client: Address,
+    ) -> Result<i128, ExpenseError> {
+        require_scope_open(&env, PauseScope::Payouts)?;
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(ExpenseError::Unauthorized);
+        }
+        if !is_terminal(&job.status) {
+            return Err(ExpenseError::InvalidStatus);
+        }
+
+        let key = ExpenseKey::Balance(job_id);
+        let balance = get_i128(&env, &key);
+        if balance > 0 {
+            set_persistent(&env, &key, &0i128);
+            token::Client::new(&env, &job.token).transfer(
+                &env.current_contract_address(),
+                &client,
+                &balance,
+            );
+            env.events().publish(
+                (symbol_short!("escrow"), symbol_short!("exp_wdr")),
+                (job_id, client, balance),
+            );
+        }
+        Ok(balance)
+    }
+
+    /// Get a single expense.
+    pub fn get_expense(env: Env, job_id: u64, expense_id: u32) -> Result<Expense, ExpenseError> {
+        load_expense(&env, job_id, expense_id)
+    }
+
+    /// Number of expenses ever submitted for a job (expense IDs are `0..count`).
+    pub fn get_expense_count(env: Env, job_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ExpenseKey::Count(job_id))
+            .unwrap_or(0)
+    }
+
+    /// Client-deposited balance still available for paying a job's expenses.
+    pub fn get_expense_balance(env: Env, job_id: u64) -> i128 {
+        get_i128(&env, &ExpenseKey::Balance(job_id))
+    }
+}
@@ -0,0 +1,100 @@
+//! Client-supplied external job IDs.
+//!
+//! Backends that create jobs on behalf of their users retry requests that time
+//! out, and a retry must not create a second escrow. A job created with
+//! [`EscrowContract::create_job_with_external_id`] is indexed under
+//! `(client, external_id)`, where `external_id` is whatever 32-byte
+//! idempotency key the caller's system uses. A second creation with the same
+//! pair fails with [`ExternalIdError::ExternalIdTaken`], and
+//! [`EscrowContract::get_job_by_external_id`] returns the job the first call
+//! created. Keys are namespaced per client, so two clients may use the same
+//! value.
+//!
+//! The index outlives archival of the job, so a late retry can never create a
+//! second one.
+
+use soroban_sdk::{
+    contracterror, contractimpl, panic_with_error, symbol_short, Address, BytesN, Env, String,
+    Vec,
+};
+
+use crate::{DataKey, EscrowContract, EscrowError};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// External ID errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2400, clear of `ConversionError`
+/// (2300..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExternalIdError {
+    /// The client already created a job with this external ID.
+    ExternalIdTaken = 2400,
+}
+
+fn job_by_external_id(env: &Env, client: &Address, external_id: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ExternalId(client.clone(), external_id.clone()))
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// [`EscrowContract::create_job`] indexed under the client's
+    /// `external_id`. Fails with `ExternalIdError::ExternalIdTaken` if the
+    /// client already created a job with that ID.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_job_with_external_id(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        token: Address,
+        milestones: Vec<(String, i128, u64)>,
+        job_deadline: u64,
+        auto_refund_after: u64,
+        expiry_ledger: u32,
+        external_id: BytesN<32>,
+    ) -> Result<u64, EscrowError> {
+        // Checked before creation so a retry has no side effects.
+        if job_by_external_id(&env, &client, &external_id).is_some() {
+            panic_with_error!(&env, ExternalIdError::ExternalIdTaken);
+        }
+        let job_id = Self::create_job(
+            env.clone(),
+            client.clone(),
+            freelancer,
+            token,
+            milestones,
+            job_deadline,
+            auto_refund_after,
+            expiry_ledger,
+        )?;
+        let key = DataKey::ExternalId(client.clone(), external_id.clone());
+        env.storage().persistent().set(&key, &job_id);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ext_id")),
+            (job_id, client, external_id),
+        );
+        Ok(job_id)
+    }
+
+    /// ID of the job `client` created with `external_id`, if any.
+    pub fn get_job_by_external_id(
+        env: Env,
+        client: Address,
+        external_id: BytesN<32>,
+    ) -> Option<u64> {
+        job_by_external_id(&env, &client, &external_id)
+    }
+}
@@ -0,0 +1,572 @@
+//! Per-job platform fee accounting (accrued vs. earned).
+//!
+//! By default the platform fee is deducted from each payout at release time, so
+//! nothing is collected for work that never happens. Signers can switch on
+//! *up-front fee mode* with [`EscrowContract::set_upfront_fee`]: `fund_job` then
+//! collects the fee on the whole job from the client on top of the escrowed
+//! amount, and the freelancer's payouts are no longer reduced.
+//!
+//! Each up-front fee is tracked in a [`JobFeeLedger`]:
+//!
+//! - `accrued` — collected at funding, held by the contract;
+//! - `earned`  — moved to the treasury as milestone value is released;
+//! - `refunded` — returned to the client when the job is cancelled or refunded.
+//!
+//! The fee rate is snapshotted at funding so later fee changes never reprice a
+//! funded job. `earned + refunded` never exceeds `accrued`.
+//!
+//! A client can also choose who bears the fee on a single job with
+//! [`EscrowContract::set_fee_payer`] before funding it:
+//!
+//! - [`FeePayer::Freelancer`] — deducted from each payout (the default);
+//! - [`FeePayer::Client`] — collected on top at funding, as in up-front mode;
+//! - [`FeePayer::Split`] — half collected at funding, half deducted from payouts.
+//!
+//! Jobs without a choice follow the global mode: `Client` when up-front fee
+//! mode is on, `Freelancer` otherwise.
+//!
+//! Every job's fee rate is snapshotted at funding, whoever pays it, and its
+//! payouts use that snapshot. Signers can also put global fee changes behind
+//! a delay with an [`AdminAction::SetFeeChangeDelay`](crate::AdminAction::SetFeeChangeDelay)
+//! proposal: a new rate is then scheduled rather than applied, and anyone can
+//! enact it with [`EscrowContract::apply_fee_change`] once the delay has
+//! passed. Only one change can be scheduled at a time, and a new delay itself
+//! waits out the current one, so it cannot be shortened to rush a change
+//! through.
+//!
+//! Every milestone approval records a [`PayoutRecord`] of the rate it was
+//! priced at, readable with [`EscrowContract::get_payout_record`] and echoed in
+//! the approval event, so historical payouts stay reconcilable after `FEE`
+//! changes.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address,
+    Env, Symbol,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Fee accounting errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 500, clear of `LimitsError` (300..)
+/// and `ExpenseError` (400..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FeeError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 500,
+    /// No job exists with the given ID.
+    JobNotFound = 501,
+    /// Caller is not the job's client.
+    Unauthorized = 502,
+    /// The fee payer can only be chosen before the job is funded.
+    InvalidStatus = 503,
+    /// No fee change is scheduled.
+    NoFeeChangeScheduled = 504,
+    /// The scheduled fee change is not effective yet.
+    FeeChangeTimelocked = 505,
+    /// A fee change is already scheduled.
+    FeeChangePending = 506,
+}
+
+/// Who bears the platform fee on a job.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeePayer {
+    Freelancer,
+    Client,
+    Split,
+}
+
+/// Up-front fee balance of a single job.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct JobFeeLedger {
+    /// Fee rate prepaid by the client, in basis points, snapshotted at funding.
+    pub fee_bps: u32,
+    pub accrued: i128,
+    pub earned: i128,
+    pub refunded: i128,
+    /// Rest of the fee rate still deducted from the freelancer's payouts
+    /// (non-zero for [`FeePayer::Split`] only).
+    pub freelancer_bps: u32,
+}
+
+/// Fee split of a job's full value between client and freelancer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeEstimate {
+    pub payer: FeePayer,
+    /// Paid by the client on top of the escrowed amount at funding.
+    pub client_fee: i128,
+    /// Deducted from the freelancer's payouts as milestones are released.
+    pub freelancer_fee: i128,
+}
+
+/// A global fee change waiting out the fee change delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledFeeChange {
+    pub fee_bps: u32,
+    /// Timestamp from which `apply_fee_change` may enact it.
+    pub effective_at: u64,
+}
+
+/// Fee snapshot of one approved milestone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutRecord {
+    /// Fee rate the milestone was priced at (the job's funding-time rate).
+    pub fee_bps: u32,
+    /// Fee on the milestone's value, whoever pays it.
+    pub fee_amount: i128,
+    /// What the freelancer receives after their share of the fee.
+    pub net_amount: i128,
+    pub recorded_at: u64,
+}
+
+impl JobFeeLedger {
+    fn unsettled(&self) -> i128 {
+        self.accrued - self.earned - self.refunded
+    }
+}
+
+/// Storage keys for fee accounting.
+#[contracttype]
+#[derive(Clone)]
+pub enum FeeKey {
+    /// Whether `fund_job` collects the platform fee up front (instance, bool).
+    UpfrontMode,
+    /// Fee ledger for a job funded in up-front mode.
+    Ledger(u64),
+    /// Fee payer chosen for a job; fixed once the job is funded.
+    Payer(u64),
+    /// Fee rate in force when a job was funded (bps).
+    Rate(u64),
+    /// Seconds a global fee change waits before it can be applied (instance).
+    ChangeDelay,
+    /// Pending `ScheduledFeeChange` (instance).
+    Scheduled,
+    /// `PayoutRecord` of an approved milestone: (job_id, milestone_id).
+    Payout(u64, u32),
+}
+
+fn load_ledger(env: &Env, job_id: u64) -> Option<JobFeeLedger> {
+    env.storage().persistent().get(&FeeKey::Ledger(job_id))
+}
+
+fn save_ledger(env: &Env, job_id: u64, ledger: &JobFeeLedger) {
+    let key = FeeKey::Ledger(job_id);
+    env.storage().persistent().set(&key, ledger);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+pub(crate) fn treasury(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("TRE"))
+        .unwrap_or(env.current_contract_address())
+}
+
+pub(crate) fn current_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("FEE"))
+        .unwrap_or(0)
+}
+
+/// Fee rate snapshotted when `job_id` was funded, or the current rate for
+/// jobs not funded yet.
+pub(crate) fn job_fee_bps(env: &Env, job_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&FeeKey::Rate(job_id))
+        .unwrap_or_else(|| current_fee_bps(env))
+}
+
+pub(crate) fn fee_change_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&FeeKey::ChangeDelay)
+        .unwrap_or(0)
+}
+
+/// Change the global fee rate, or schedule the change when a fee change
+/// delay is configured. Used by every path that sets the fee after
+/// `initialize`; `fee_bps` must already be validated against `MAX_FEE_BPS`.
+/// Aborts with [`FeeError::FeeChangePending`] while a change is scheduled, as
+/// the multisig and governance paths return errors of their own.
+pub(crate) fn set_fee_bps(env: &Env, fee_bps: u32) {
+    if env.storage().instance().has(&FeeKey::Scheduled) {
+        panic_with_error!(env, FeeError::FeeChangePending);
+    }
+    let delay = fee_change_delay(env);
+    if delay == 0 {
+        env.storage().instance().set(&symbol_short!("FEE"), &fee_bps);
+        return;
+    }
+    let change = ScheduledFeeChange {
+        fee_bps,
+        effective_at: env.ledger().timestamp().saturating_add(delay),
+    };
+    env.storage().instance().set(&FeeKey::Scheduled, &change);
+    env.events().publish(
+        (symbol_short!("escrow"), Symbol::new(env, "fee_scheduled")),
+        (change.fee_bps, change.effective_at),
+    );
+}
+
+/// Make global fee changes wait `delay_secs`; zero applies them immediately.
+/// Reached only through the [`AdminAction::SetFeeChangeDelay`](crate::AdminAction::SetFeeChangeDelay)
+/// proposal, which waits out the current delay first.
+pub(crate) fn set_fee_change_delay(env: &Env, delay_secs: u64, proposer: &Address) {
+    env.storage()
+        .instance()
+        .set(&FeeKey::ChangeDelay, &delay_secs);
+
+    env.events().publish(
+        (symbol_short!("escrow"), Symbol::new(env, "fee_delay_set")),
+        (delay_secs, proposer.clone()),
+    );
+}
+
+/// Payer chosen for `job_id`, or the one implied by the global mode.
+fn fee_payer(env: &Env, job_id: u64) -> FeePayer {
+    if let Some(payer) = env.storage().persistent().get(&FeeKey::Payer(job_id)) {
+        return payer;
+    }
+    let upfront: bool = env
+        .storage()
+        .instance()
+        .get(&FeeKey::UpfrontMode)
+        .unwrap_or(false);
+    if upfront {
+        FeePayer::Client
+    } else {
+        FeePayer::Freelancer
+    }
+}
+
+fn save_payer(env: &Env, job_id: u64, payer: FeePayer) {
+    let key = FeeKey::Payer(job_id);
+    env.storage().persistent().set(&key, &payer);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// `(client_bps, freelancer_bps)` shares of `fee_bps` for `payer`.
+fn split_rate(payer: FeePayer, fee_bps: u32) -> (u32, u32) {
+    match payer {
+        FeePayer::Freelancer => (0, fee_bps),
+        FeePayer::Client => (fee_bps, 0),
+        FeePayer::Split => (fee_bps - fee_bps / 2, fee_bps / 2),
+    }
+}
+
+/// Client's share of the fee `accrue_upfront_fee` would collect on `job` if
+/// it were funded now.
+pub(crate) fn upfront_fee(env: &Env, job: &Job) -> i128 {
+    let (fee_bps, _) = split_rate(fee_payer(env, job.id), current_fee_bps(env));
+    job.total_amount * fee_bps as i128 / 10_000
+}
+
+/// Called from `fund_job` after the escrow deposit. Fixes the job's fee payer
+/// and, when the client bears any of the fee, collects the client's share on
+/// `total_amount` and opens the job's fee ledger. `prefunded` collects it as
+/// `funding_auth::pull` does.
+pub(crate) fn accrue_upfront_fee(env: &Env, job: &Job, prefunded: bool) {
+    let payer = fee_payer(env, job.id);
+    save_payer(env, job.id, payer);
+    let rate = current_fee_bps(env);
+    let rate_key = FeeKey::Rate(job.id);
+    env.storage().persistent().set(&rate_key, &rate);
+    env.storage().persistent().extend_ttl(
+        &rate_key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+    if payer == FeePayer::Freelancer {
+        return;
+    }
+    let (fee_bps, freelancer_bps) = split_rate(payer, rate);
+    let fee = job.total_amount * fee_bps as i128 / 10_000;
+    if fee > 0 {
+        crate::funding_auth::pull(env, &job.token, &job.client, fee, prefunded);
+    }
+    save_ledger(
+        env,
+        job.id,
+        &JobFeeLedger {
+            fee_bps,
+            accrued: fee,
+            earned: 0,
+            refunded: 0,
+            freelancer_bps,
+        },
+    );
+    env.events().publish(
+        (symbol_short!("escrow"), Symbol::new(env, "fee_accrued")),
+        (job.id, fee, fee_bps),
+    );
+}
+
+/// Client's share of the fee on `amount` of milestone value added to a funded
+/// job, booked to the job's fee ledger. The caller collects it from the
+/// client. Zero for jobs without a fee ledger.
+pub(crate) fn accrue_added_value(env: &Env, job_id: u64, amount: i128) -> i128 {
+    let Some(mut ledger) = load_ledger(env, job_id) else {
+        return 0;
+    };
+    let fee = amount * ledger.fee_bps as i128 / 10_000;
+    ledger.accrued += fee;
+    save_ledger(env, job_id, &ledger);
+    fee
+}
+
+/// Fee due when `amount` of milestone value is released for `job_id`, as
+/// `(fee_to_treasury, deducted_from_freelancer)`. Jobs without a fee ledger
+/// keep the release-time deduction at their funding-time rate (`fee_bps` for
+/// jobs funded before rates were snapshotted); jobs with one draw the
+/// client's share from the pre-paid balance and deduct only the freelancer's
+/// snapshotted share (zero unless the fee is split).
+pub(crate) fn release_fee(env: &Env, job_id: u64, amount: i128, fee_bps: u32) -> (i128, i128) {
+    match load_ledger(env, job_id) {
+        None => {
+            let fee_bps = env
+                .storage()
+                .persistent()
+                .get(&FeeKey::Rate(job_id))
+                .unwrap_or(fee_bps);
+            let fee = amount * fee_bps as i128 / 10_000;
+            (fee, fee)
+        }
+        Some(mut ledger) => {
+            let slice = (amount * ledger.fee_bps as i128 / 10_000).min(ledger.unsettled());
+            ledger.earned += slice;
+            save_ledger(env, job_id, &ledger);
+            let deducted = amount * ledger.freelancer_bps as i128 / 10_000;
+            (slice + deducted, deducted)
+        }
+    }
+}
+
+/// Fee rate, fee and freelancer's net on `amount` of `job_id`'s milestone
+/// value, as `(fee_bps, fee_amount, net_amount)`, without settling anything.
+pub(crate) fn quote_fee(env: &Env, job_id: u64, amount: i128) -> (u32, i128, i128) {
+    let fee_bps = job_fee_bps(env, job_id);
+    let fee = amount * fee_bps as i128 / 10_000;
+    let deducted = match load_ledger(env, job_id) {
+        Some(ledger) => amount * ledger.freelancer_bps as i128 / 10_000,
+        None => fee,
+    };
+    (fee_bps, fee, amount - deducted)
+}
+
+/// Persist the fee snapshot of an approved milestone.
+pub(crate) fn record_payout(
+    env: &Env,
+    job_id: u64,
+    milestone_id: u32,
+    fee_bps: u32,
+    fee_amount: i128,
+    net_amount: i128,
+) {
+    let key = FeeKey::Payout(job_id, milestone_id);
+    let record = PayoutRecord {
+        fee_bps,
+        fee_amount,
+        net_amount,
+        recorded_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&key, &record);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Close out the fee ledger of a job that is ending. The fee on
+/// `earned_value` (milestone value actually delivered) is moved to the
+/// treasury; the rest of the pre-paid fee is refunded to the client. Returns
+/// the refunded amount. No-op for jobs without a fee ledger.
+pub(crate) fn settle_fee(env: &Env, job: &Job, earned_value: i128) -> i128 {
+    let Some(mut ledger) = load_ledger(env, job.id) else {
+        return 0;
+    };
+    let earned_target =
+        (earned_value * ledger.fee_bps as i128 / 10_000).min(ledger.accrued - ledger.refunded);
+    let to_treasury = (earned_target - ledger.earned).max(0);
+    let token_client = token::Client::new(env, &job.token);
+    if to_treasury > 0 {
+        token_client.transfer(
+            &env.current_contract_address(),
+            &treasury(env),
+            &to_treasury,
+        );
+        ledger.earned += to_treasury;
+    }
+    let refund = ledger.unsettled();
+    if refund > 0 {
+        token_client.transfer(&env.current_contract_address(), &job.client, &refund);
+        ledger.refunded += refund;
+    }
+    save_ledger(env, job.id, &ledger);
+
+    env.events().publish(
+        (symbol_short!("escrow"), Symbol::new(env, "fee_settled")),
+        (job.id, ledger.earned, refund),
+    );
+    refund
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Enable or disable up-front fee collection for jobs funded from now on.
+    /// Registered multisig signers only. Already-funded jobs keep the mode
+    /// they were funded under.
+    pub fn set_upfront_fee(env: Env, admin: Address, enabled: bool) -> Result<(), FeeError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(FeeError::NotAdmin);
+        }
+        env.storage().instance().set(&FeeKey::UpfrontMode, &enabled);
+
+        env.events().publish(
+            (
+                symbol_short!("escrow"),
+                Symbol::new(&env, "upfront_fee_set"),
+            ),
+            (enabled, admin),
+        );
+        Ok(())
+    }
+
+    /// Whether `fund_job` currently collects the platform fee up front.
+    pub fn is_upfront_fee_enabled(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&FeeKey::UpfrontMode)
+            .unwrap_or(false)
+    }
+
+    /// Fee ledger of a job. All zeros for jobs not funded in up-front mode.
+    pub fn get_fee_ledger(env: Env, job_id: u64) -> JobFeeLedger {
+        load_ledger(&env, job_id).unwrap_or_default()
+    }
+
+    /// Choose who bears the platform fee on a job. Client only, before the job
+    /// is funded.
+    pub fn set_fee_payer(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        payer: FeePayer,
+    ) -> Result<(), FeeError> {
+        client.require_auth();
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(FeeError::JobNotFound)?;
+        if job.client != client {
+            return Err(FeeError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Configure)
+            .map_err(|_| FeeError::InvalidStatus)?;
+        save_payer(&env, job_id, payer);
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "fee_payer_set")),
+            (job_id, payer),
+        );
+        Ok(())
+    }
+
+    /// Fee payer of a job: fixed at funding, otherwise the client's choice or
+    /// the global default.
+    pub fn get_fee_payer(env: Env, job_id: u64) -> FeePayer {
+        fee_payer(&env, job_id)
+    }
+
+    /// Fee rate applied to a job's payouts: snapshotted at funding, or the
+    /// current rate while the job is unfunded.
+    pub fn get_job_fee_bps(env: Env, job_id: u64) -> u32 {
+        job_fee_bps(&env, job_id)
+    }
+
+    /// Fee snapshot recorded when `milestone_id` of `job_id` was approved.
+    pub fn get_payout_record(env: Env, job_id: u64, milestone_id: u32) -> Option<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&FeeKey::Payout(job_id, milestone_id))
+    }
+
+    /// Seconds a global fee change waits before it can be applied.
+    pub fn get_fee_change_delay(env: Env) -> u64 {
+        fee_change_delay(&env)
+    }
+
+    /// The global fee change waiting out the delay, if any.
+    pub fn get_scheduled_fee_change(env: Env) -> Option<ScheduledFeeChange> {
+        env.storage().instance().get(&FeeKey::Scheduled)
+    }
+
+    /// Enact the scheduled fee change once its delay has passed. Anyone may
+    /// call this. Returns the new fee rate.
+    pub fn apply_fee_change(env: Env) -> Result<u32, FeeError> {
+        let change: ScheduledFeeChange = env
+            .storage()
+            .instance()
+            .get(&FeeKey::Scheduled)
+            .ok_or(FeeError::NoFeeChangeScheduled)?;
+        if env.ledger().timestamp() < change.effective_at {
+            return Err(FeeError::FeeChangeTimelocked);
+        }
+        env.storage().instance().remove(&FeeKey::Scheduled);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("FEE"), &change.fee_bps);
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "fee_applied")),
+            change.fee_bps,
+        );
+        Ok(change.fee_bps)
+    }
+
+    /// Fee on the job's full value and how it splits between client and
+    /// freelancer. Funded jobs use the rates snapshotted at funding; unfunded
+    /// jobs the current rate.
+    pub fn estimate_job_fees(env: Env, job_id: u64) -> Result<FeeEstimate, FeeError> {
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(FeeError::JobNotFound)?;
+        let payer = fee_payer(&env, job_id);
+        let (client_bps, freelancer_bps) = match load_ledger(&env, job_id) {
+            Some(ledger) => (ledger.fee_bps, ledger.freelancer_bps),
+            None => split_rate(payer, job_fee_bps(&env, job_id)),
+        };
+        Ok(FeeEstimate {
+            payer,
+            client_fee: job.total_amount * client_bps as i128 / 10_000,
+            freelancer_fee: job.total_amount * freelancer_bps as i128 / 10_000,
+        })
+    }
+}
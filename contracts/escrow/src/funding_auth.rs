@@ -0,0 +1,249 @@
+//! Pre-authorized funding for relayers.
+//!
+//! Wallets that use session keys, or sponsor their users' fees, want the
+//! client to approve funding once and let a relayer submit it later. The
+//! client signs [`EscrowContract::prefund_authorization`] for a job they
+//! created, naming the most funding may cost them. That call moves the amount
+//! into the escrow, where it is held for the job alone and never mixed with
+//! the client's token allowance. Until the `expiry` ledger, any relayer can
+//! then call [`EscrowContract::fund_job_with_authorization`]. It funds the job
+//! exactly like `fund_job`, drawing the deposit and the client's share of the
+//! fee from the held amount, so the client does not have to sign again. If the
+//! job's total grew past what was authorized, relayed funding fails. The
+//! intent is used up by the first successful call and anything left over goes
+//! back to the client, who can also take the whole amount back with
+//! [`EscrowContract::revoke_funding_authorization`] at any time before.
+//!
+//! Relayed funding skips the oracle parity check, which needs the client's
+//! own slippage bounds, and is not available for jobs with milestones paid in
+//! another token, since those pull further tokens from the client.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address, Env,
+};
+
+use crate::state::{self, JobEvent};
+use crate::{DataKey, EscrowContract, EscrowError, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Funding authorization errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2800, clear of `RecurringError`
+/// (2700..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FundingAuthError {
+    /// No job exists with the given ID.
+    JobNotFound = 2800,
+    /// Caller is not the job's client.
+    Unauthorized = 2801,
+    /// The job is no longer awaiting funding.
+    InvalidStatus = 2802,
+    /// `expiry` is not a future ledger.
+    InvalidExpiry = 2803,
+    /// The job has milestones paid in another token.
+    Unsupported = 2804,
+    /// The client has not authorized funding of the job.
+    NotAuthorized = 2805,
+    /// The authorization's expiry ledger has passed.
+    Expired = 2806,
+    /// `amount` must be strictly positive.
+    InvalidAmount = 2807,
+    /// The job already has an unused funding authorization.
+    AlreadyAuthorized = 2808,
+    /// Funding the job would cost more than the client authorized.
+    AmountExceeded = 2809,
+    /// The contract, or job creation and funding, are paused.
+    ContractPaused = 2810,
+}
+
+/// A client's standing authorization to fund a job through a relayer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingAuthorization {
+    /// Most the deposit and the client's share of the fee may come to. Held
+    /// by the escrow until the job is funded or the authorization revoked.
+    pub amount: i128,
+    /// Last ledger at which a relayer may fund the job.
+    pub expiry: u32,
+}
+
+/// Storage keys for funding authorizations.
+#[contracttype]
+#[derive(Clone)]
+pub enum FundingAuthKey {
+    /// Unused authorization of a job: job_id → `FundingAuthorization`.
+    FundingAuthorization(u64),
+}
+
+fn authorization(env: &Env, job_id: u64) -> Option<FundingAuthorization> {
+    env.storage()
+        .persistent()
+        .get(&FundingAuthKey::FundingAuthorization(job_id))
+}
+
+/// Amount held for an unused funding authorization of `job_id`.
+pub(crate) fn held(env: &Env, job_id: u64) -> i128 {
+    authorization(env, job_id).map_or(0, |auth| auth.amount)
+}
+
+/// Move `amount` of `token` from `from` into the escrow. With `prefunded` the
+/// tokens are already held under a funding authorization and nothing moves.
+pub(crate) fn pull(env: &Env, token: &Address, from: &Address, amount: i128, prefunded: bool) {
+    if !prefunded {
+        token::Client::new(env, token).transfer(from, &env.current_contract_address(), &amount);
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Authorize a relayer to fund `job_id` until ledger `expiry`, for at
+    /// most `amount` including the client's share of the fee. Client only,
+    /// while the job awaits funding. `amount` moves into the escrow now.
+    pub fn prefund_authorization(
+        env: Env,
+        client: Address,
+        job_id: u64,
+        amount: i128,
+        expiry: u32,
+    ) -> Result<(), FundingAuthError> {
+        client.require_auth();
+        crate::pause::require_scope_open(&env, crate::pause::PauseScope::Create)
+            .map_err(|_| FundingAuthError::ContractPaused)?;
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Job(job_id))
+            .ok_or(FundingAuthError::JobNotFound)?;
+        if job.client != client {
+            return Err(FundingAuthError::Unauthorized);
+        }
+        state::transition(&job.status, JobEvent::Fund)
+            .map_err(|_| FundingAuthError::InvalidStatus)?;
+        if expiry <= env.ledger().sequence() {
+            return Err(FundingAuthError::InvalidExpiry);
+        }
+        if !job.milestones.iter().all(|m| crate::milestone_tokens::in_job_token(&m)) {
+            return Err(FundingAuthError::Unsupported);
+        }
+        if amount <= 0 {
+            return Err(FundingAuthError::InvalidAmount);
+        }
+        if authorization(&env, job_id).is_some() {
+            return Err(FundingAuthError::AlreadyAuthorized);
+        }
+
+        token::Client::new(&env, &job.token).transfer(
+            &client,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let key = FundingAuthKey::FundingAuthorization(job_id);
+        env.storage()
+            .persistent()
+            .set(&key, &FundingAuthorization { amount, expiry });
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("fund_auth")),
+            (job_id, client, amount, expiry),
+        );
+        Ok(())
+    }
+
+    /// Withdraw an unused funding authorization. Client only. The amount held
+    /// for it goes back to the client.
+    pub fn revoke_funding_authorization(
+        env: Env,
+        client: Address,
+        job_id: u64,
+    ) -> Result<(), FundingAuthError> {
+        client.require_auth();
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Job(job_id))
+            .ok_or(FundingAuthError::JobNotFound)?;
+        if job.client != client {
+            return Err(FundingAuthError::Unauthorized);
+        }
+        let auth = authorization(&env, job_id).ok_or(FundingAuthError::NotAuthorized)?;
+        env.storage()
+            .persistent()
+            .remove(&FundingAuthKey::FundingAuthorization(job_id));
+        token::Client::new(&env, &job.token).transfer(
+            &env.current_contract_address(),
+            &client,
+            &auth.amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("fund_rvk")),
+            (job_id, client, auth.amount),
+        );
+        Ok(())
+    }
+
+    /// Fund `job_id` on its client's behalf under an unexpired
+    /// `prefund_authorization`. Callable by any relayer; fails with
+    /// `FundingAuthError::NotAuthorized`, `Expired` or `AmountExceeded`
+    /// otherwise, and as `fund_job` does if funding itself fails. What the
+    /// authorization held beyond the cost of funding goes back to the client.
+    pub fn fund_job_with_authorization(
+        env: Env,
+        job_id: u64,
+        relayer: Address,
+    ) -> Result<(), EscrowError> {
+        relayer.require_auth();
+        let Some(auth) = authorization(&env, job_id) else {
+            panic_with_error!(&env, FundingAuthError::NotAuthorized);
+        };
+        if env.ledger().sequence() > auth.expiry {
+            panic_with_error!(&env, FundingAuthError::Expired);
+        }
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Job(job_id))
+            .ok_or(EscrowError::JobNotFound)?;
+        if crate::milestone_tokens::has_overrides(&job) {
+            panic_with_error!(&env, FundingAuthError::Unsupported);
+        }
+        let cost = job.total_amount + crate::fees::upfront_fee(&env, &job);
+        if cost > auth.amount {
+            panic_with_error!(&env, FundingAuthError::AmountExceeded);
+        }
+        env.storage()
+            .persistent()
+            .remove(&FundingAuthKey::FundingAuthorization(job_id));
+        crate::fund(&env, job_id, job.client.clone(), 0, 0, true)?;
+        if auth.amount > cost {
+            token::Client::new(&env, &job.token).transfer(
+                &env.current_contract_address(),
+                &job.client,
+                &(auth.amount - cost),
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("fund_rly")),
+            (job_id, relayer),
+        );
+        Ok(())
+    }
+
+    /// Unused funding authorization of `job_id`, if its client has authorized
+    /// relayed funding.
+    pub fn get_funding_authorization(env: Env, job_id: u64) -> Option<FundingAuthorization> {
+        authorization(&env, job_id)
+    }
+}
@@ -21,10 +21,10 @@ fn random_i128(seed: &mut u64, max: i128) -> i128 {
 }
 
 fn random_bool(seed: &mut u64) -> bool {
-    random_u32(seed) % 2 == 0
+    random_u32(seed).is_multiple_of(2)
 }
 
-fn setup_fuzz_test(env: &Env, num_tokens: usize) -> (EscrowContractClient, Vec<Address>) {
+fn setup_fuzz_test(env: &Env, num_tokens: usize) -> (EscrowContractClient<'_>, Vec<Address>) {
     let contract_id = env.register_contract(None, EscrowContract);
     let client = EscrowContractClient::new(env, &contract_id);
 
@@ -35,13 +35,13 @@ fn setup_fuzz_test(env: &Env, num_tokens: usize) -> (EscrowContractClient, Vec<A
     client.initialize(&signers, &1, &treasury, &0, &604800);
 
     let mut tokens = Vec::new(env);
-    for i in 0..num_tokens {
+    for _i in 0..num_tokens {
         let token_address = env
             .register_stellar_asset_contract_v2(Address::generate(env))
             .address();
         let token_admin = StellarAssetClient::new(env, &token_address);
 
-        for j in 0..10 {
+        for _j in 0..10 {
             let user = Address::generate(env);
             token_admin.mint(&user, &1_000_000_000_000_i128);
         }
@@ -65,7 +65,7 @@ fn fuzz_deposit_and_release_basic() {
     let mut seed: u64 = 12345;
     let num_runs = 100;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -88,7 +88,7 @@ fn fuzz_deposit_and_release_basic() {
             total_amount = total_amount.checked_add(amount).unwrap_or(total_amount);
         }
 
-        if milestones.len() == 0 || total_amount <= 0 {
+        if milestones.is_empty() || total_amount <= 0 {
             continue;
         }
 
@@ -128,7 +128,7 @@ fn fuzz_partial_payments() {
     let mut seed: u64 = 54321;
     let num_runs = 100;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -245,7 +245,7 @@ fn fuzz_refund_flows() {
     let mut seed: u64 = 99999;
     let num_runs = 50;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -268,7 +268,7 @@ fn fuzz_refund_flows() {
             total_amount = total_amount.checked_add(amount).unwrap_or(total_amount);
         }
 
-        if milestones.len() == 0 || total_amount <= 0 {
+        if milestones.is_empty() || total_amount <= 0 {
             continue;
         }
 
@@ -302,7 +302,7 @@ fn fuzz_claim_refund_after_expiry() {
     let mut seed: u64 = 77777;
     let num_runs = 50;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -350,9 +350,9 @@ fn fuzz_multi_token_scenarios() {
 
     let num_runs = 50;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
-        let token_idx = (random_u32(&mut seed) % 2) as u32;
+        let token_idx = random_u32(&mut seed) % 2;
         let token = tokens.get(token_idx).unwrap();
 
         let client = Address::generate(&env);
@@ -376,7 +376,7 @@ fn fuzz_multi_token_scenarios() {
             total_amount = total_amount.checked_add(amount).unwrap_or(total_amount);
         }
 
-        if milestones.len() == 0 || total_amount <= 0 {
+        if milestones.is_empty() || total_amount <= 0 {
             continue;
         }
 
@@ -410,7 +410,7 @@ fn fuzz_balance_invariants() {
     let mut seed: u64 = 11111;
     let num_runs = 50;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -458,7 +458,7 @@ fn fuzz_approve_milestones_batch() {
     let mut seed: u64 = 44444;
     let num_runs = 30;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -481,7 +481,7 @@ fn fuzz_approve_milestones_batch() {
             total_amount = total_amount.checked_add(amount).unwrap_or(total_amount);
         }
 
-        if milestones.len() == 0 || total_amount <= 0 {
+        if milestones.is_empty() || total_amount <= 0 {
             continue;
         }
 
@@ -494,8 +494,8 @@ fn fuzz_approve_milestones_batch() {
         let mut indices = Vec::new(&env);
         for m in 0..milestones.len() {
             contract
-                .submit_milestone(&job_id, &(m as u32), &freelancer);
-            indices.push_back(m as u32);
+                .submit_milestone(&job_id, &m, &freelancer);
+            indices.push_back(m);
         }
 
         contract
@@ -520,7 +520,7 @@ fn fuzz_top_up_escrow() {
     let mut seed: u64 = 33333;
     let num_runs = 30;
 
-    for run in 0..num_runs {
+    for _run in 0..num_runs {
         env.budget().reset_unlimited();
         let client = Address::generate(&env);
         let freelancer = Address::generate(&env);
@@ -557,7 +557,7 @@ fn fuzz_no_panic_on_edge_cases() {
     let (contract, tokens) = setup_fuzz_test(&env, 1);
     let token = tokens.get(0).unwrap();
 
-    let mut seed: u64 = 88888;
+    let _seed: u64 = 88888;
 
     let client = Address::generate(&env);
     let freelancer = Address::generate(&env);
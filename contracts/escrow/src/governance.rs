@@ -347,8 +347,8 @@ fn enact(env: &Env, action: &AdminAction) -> Result<(), GovError> {
             if *fee > MAX_FEE_BPS {
                 return Err(GovError::InvalidFee);
             }
-            // Same storage slot the multisig path and `initialize` use.
-            env.storage().instance().set(&symbol_short!("FEE"), fee);
+            // Same path as the multisig, so the fee change delay applies.
+            crate::fees::set_fee_bps(env, *fee);
             Ok(())
         }
         AdminAction::SetTreasury(treasury) => {
@@ -440,7 +440,8 @@ impl EscrowContract {
 
     /// Return the current platform fee in basis points. Reads the same `FEE`
     /// slot that `initialize`, the multisig, and governance all write, so it
-    /// reflects changes made through any path.
+    /// reflects changes made through any path. A change still waiting out the
+    /// fee change delay shows in `get_scheduled_fee_change` instead.
     pub fn get_fee_bps(env: Env) -> u32 {
         env.storage()
             .instance()
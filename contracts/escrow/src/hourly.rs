@@ -0,0 +1,509 @@
+//! Hourly jobs billed through work logs.
+//!
+//! An hourly job is created with [`EscrowContract::create_hourly_job`], which
+//! sets an hourly rate and a budget. The budget becomes the job's single
+//! milestone and is funded with the normal `fund_job`. The freelancer does not
+//! submit that milestone. Instead they bill time with
+//! [`EscrowContract::submit_work_log`], and each log is worth
+//! `rate_per_hour * minutes / 60`. Pending logs together can never exceed the
+//! unspent budget, and a job holds at most [`MAX_WORK_LOGS`] logs. The budget
+//! milestone cannot be revised.
+//!
+//! The client approves or rejects each log. A log the client has not reviewed
+//! within [`WORK_LOG_REVIEW_SECS`] can be approved by anyone with
+//! [`EscrowContract::finalize_work_log`]. An approved log is paid from the
+//! budget straight away, at the job's fee rate. Once no logs are pending,
+//! either party can close the job with [`EscrowContract::close_hourly_job`].
+//! The unspent budget then goes back to the client. A job that billed any time
+//! completes, and one that billed none is cancelled.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, vec,
+    Address, BytesN, Env, String,
+};
+
+use crate::pause::PauseScope;
+use crate::state::{self, JobEvent};
+use crate::{DataKey, EscrowContract, EscrowError, Job, JobStatus, Milestone, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// How long the client has to review a work log before anyone may approve it.
+pub const WORK_LOG_REVIEW_SECS: u64 = 7 * 24 * 3600;
+
+/// Most work logs one job may hold, so archiving can drop them all in one call.
+pub const MAX_WORK_LOGS: u32 = 200;
+
+/// Hourly billing errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2600, clear of `TipError`
+/// (2500..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum HourlyError {
+    /// The contract, or its payouts, are paused.
+    ContractPaused = 2600,
+    /// No job exists with the given ID.
+    JobNotFound = 2601,
+    /// Caller is not the party allowed to do this.
+    Unauthorized = 2602,
+    /// The job's status does not allow this.
+    InvalidStatus = 2603,
+    /// The job is not an hourly job.
+    NotHourly = 2604,
+    /// Hourly jobs are billed and closed through work logs, not milestones.
+    MilestonesDisabled = 2605,
+    /// Rate must be strictly positive.
+    InvalidRate = 2606,
+    /// The log must bill a strictly positive amount.
+    InvalidMinutes = 2607,
+    /// Pending logs would exceed the unspent budget.
+    BudgetExceeded = 2608,
+    /// No work log exists with the given ID.
+    LogNotFound = 2609,
+    /// The work log has already been approved or rejected.
+    LogNotPending = 2610,
+    /// The client's review window for the log is still open.
+    ReviewWindowOpen = 2611,
+    /// Logs are still awaiting review.
+    LogsPending = 2612,
+    /// The job already holds `MAX_WORK_LOGS` logs.
+    LogLimit = 2613,
+}
+
+/// How a job is billed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JobKind {
+    /// Paid per milestone.
+    Fixed,
+    /// Paid per approved work log, out of a budget.
+    Hourly,
+}
+
+/// Review state of a work log.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WorkLogStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// Time billed by the freelancer on an hourly job.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkLog {
+    pub id: u32,
+    pub minutes: u32,
+    /// `rate_per_hour * minutes / 60`, fixed when the log is submitted.
+    pub amount: i128,
+    /// Hash of the off-chain description of the work.
+    pub description_hash: BytesN<32>,
+    pub submitted_at: u64,
+    pub status: WorkLogStatus,
+}
+
+/// Storage keys for hourly jobs.
+#[contracttype]
+#[derive(Clone)]
+pub enum HourlyKey {
+    /// Hourly rate of a job: job_id → `i128`. Present only on hourly jobs.
+    HourlyRate(u64),
+    /// A work log: (job_id, log_id) → `WorkLog`.
+    WorkLog(u64, u32),
+    /// Number of logs submitted on a job: job_id → `u32`.
+    WorkLogCount(u64),
+    /// Total amount of the job's pending logs: job_id → `i128`.
+    PendingLogTotal(u64),
+}
+
+fn bump(env: &Env, key: &HourlyKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn set<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(env: &Env, key: &HourlyKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    bump(env, key);
+}
+
+fn rate(env: &Env, job_id: u64) -> Option<i128> {
+    env.storage().persistent().get(&HourlyKey::HourlyRate(job_id))
+}
+
+fn log_count(env: &Env, job_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&HourlyKey::WorkLogCount(job_id))
+        .unwrap_or(0)
+}
+
+fn pending(env: &Env, job_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&HourlyKey::PendingLogTotal(job_id))
+        .unwrap_or(0)
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, HourlyError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Job(job_id))
+        .ok_or(HourlyError::JobNotFound)
+}
+
+fn load_hourly_job(env: &Env, job_id: u64) -> Result<Job, HourlyError> {
+    let job = load_job(env, job_id)?;
+    if rate(env, job_id).is_none() {
+        return Err(HourlyError::NotHourly);
+    }
+    Ok(job)
+}
+
+fn load_log(env: &Env, job_id: u64, log_id: u32) -> Result<WorkLog, HourlyError> {
+    env.storage()
+        .persistent()
+        .get(&HourlyKey::WorkLog(job_id, log_id))
+        .ok_or(HourlyError::LogNotFound)
+}
+
+fn save_job(env: &Env, job: &Job) {
+    env.storage().persistent().set(&DataKey::Job(job.id), job);
+    crate::bump_job_ttl(env, job.id);
+    crate::status_index::sync(env, job);
+}
+
+/// Reject milestone submission, completion and revisions on hourly jobs, whose
+/// budget milestone is only drawn down by work logs and closed by
+/// `close_hourly_job`.
+pub(crate) fn enforce_milestone_flow(env: &Env, job_id: u64) {
+    if rate(env, job_id).is_some() {
+        panic_with_error!(env, HourlyError::MilestonesDisabled);
+    }
+}
+
+/// Drop the hourly terms and work logs of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    for log_id in 0..log_count(env, job_id) {
+        env.storage().persistent().remove(&HourlyKey::WorkLog(job_id, log_id));
+    }
+    env.storage().persistent().remove(&HourlyKey::HourlyRate(job_id));
+    env.storage().persistent().remove(&HourlyKey::WorkLogCount(job_id));
+    env.storage().persistent().remove(&HourlyKey::PendingLogTotal(job_id));
+}
+
+/// Mark a pending log approved and pay it out of the budget milestone.
+fn approve_log(env: &Env, job_id: u64, mut log: WorkLog) -> Result<(), HourlyError> {
+    crate::pause::require_scope_open(env, PauseScope::Payouts)
+        .map_err(|_| HourlyError::ContractPaused)?;
+    let mut job = load_hourly_job(env, job_id)?;
+    let next = state::transition(&job.status, JobEvent::Progress)
+        .map_err(|_| HourlyError::InvalidStatus)?;
+
+    let budget = job.milestones.get(0).ok_or(HourlyError::NotHourly)?;
+    if log.amount > budget.amount - budget.released {
+        return Err(HourlyError::BudgetExceeded);
+    }
+
+    let (fee_amount, fee_deducted) =
+        crate::fees::release_fee(env, job_id, log.amount, crate::fees::current_fee_bps(env));
+    if fee_amount > 0 {
+        let treasury = crate::fees::treasury(env);
+        token::Client::new(env, &job.token).transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &fee_amount,
+        );
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("fee")),
+            (job_id, 0u32, fee_amount, treasury),
+        );
+    }
+    let net = log.amount - fee_deducted;
+    crate::owed::pay_freelancer(env, &job, net);
+    crate::record_milestone_disbursed(env, job_id, 0, log.amount);
+
+    let released = budget.released + log.amount;
+    job.milestones.set(
+        0,
+        Milestone {
+            status: if released == budget.amount {
+                MilestoneStatus::Approved
+            } else {
+                MilestoneStatus::InProgress
+            },
+            released,
+            ..budget
+        },
+    );
+    job.status = next;
+    save_job(env, &job);
+
+    log.status = WorkLogStatus::Approved;
+    set(env, &HourlyKey::WorkLog(job_id, log.id), &log);
+    set(env, &HourlyKey::PendingLogTotal(job_id), &(pending(env, job_id) - log.amount));
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("log_paid")),
+        (job_id, log.id, log.amount, fee_amount, net),
+    );
+    Ok(())
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Create an hourly job billing `rate_per_hour` up to `budget`. The budget
+    /// becomes a single milestone due at `job_deadline`; fund it with
+    /// `fund_job` as usual.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_hourly_job(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        token: Address,
+        rate_per_hour: i128,
+        budget: i128,
+        job_deadline: u64,
+        auto_refund_after: u64,
+        expiry_ledger: u32,
+    ) -> Result<u64, EscrowError> {
+        if rate_per_hour <= 0 {
+            panic_with_error!(&env, HourlyError::InvalidRate);
+        }
+        let milestones = vec![
+            &env,
+            (String::from_str(&env, "Hourly budget"), budget, job_deadline),
+        ];
+        let job_id = Self::create_job(
+            env.clone(),
+            client.clone(),
+            freelancer,
+            token,
+            milestones,
+            job_deadline,
+            auto_refund_after,
+            expiry_ledger,
+        )?;
+        set(&env, &HourlyKey::HourlyRate(job_id), &rate_per_hour);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("hourly")),
+            (job_id, client, rate_per_hour, budget),
+        );
+        Ok(job_id)
+    }
+
+    /// How `job_id` is billed.
+    pub fn get_job_kind(env: Env, job_id: u64) -> JobKind {
+        match rate(&env, job_id) {
+            Some(_) => JobKind::Hourly,
+            None => JobKind::Fixed,
+        }
+    }
+
+    /// Hourly rate of `job_id`, or `None` if it is not an hourly job.
+    pub fn get_hourly_rate(env: Env, job_id: u64) -> Option<i128> {
+        rate(&env, job_id)
+    }
+
+    /// Freelancer bills `minutes` of work described off-chain by
+    /// `description_hash`. Returns the log's ID. The log is worth
+    /// `rate_per_hour * minutes / 60` and, with the other pending logs, must
+    /// fit in the unspent budget.
+    pub fn submit_work_log(
+        env: Env,
+        job_id: u64,
+        freelancer: Address,
+        minutes: u32,
+        description_hash: BytesN<32>,
+    ) -> Result<u32, HourlyError> {
+        freelancer.require_auth();
+        crate::pause::require_scope_open(&env, PauseScope::Payouts)
+            .map_err(|_| HourlyError::ContractPaused)?;
+        let job = load_hourly_job(&env, job_id)?;
+        if job.freelancer != freelancer {
+            return Err(HourlyError::Unauthorized);
+        }
+        state::transition(&job.status, JobEvent::Work).map_err(|_| HourlyError::InvalidStatus)?;
+
+        let rate_per_hour = rate(&env, job_id).ok_or(HourlyError::NotHourly)?;
+        let amount = rate_per_hour * minutes as i128 / 60;
+        if amount <= 0 {
+            return Err(HourlyError::InvalidMinutes);
+        }
+        let unspent = job
+            .milestones
+            .get(0)
+            .map(|m| m.amount - m.released)
+            .unwrap_or(0);
+        let pending_total = pending(&env, job_id) + amount;
+        if pending_total > unspent {
+            return Err(HourlyError::BudgetExceeded);
+        }
+
+        let log_id = log_count(&env, job_id);
+        if log_id >= MAX_WORK_LOGS {
+            return Err(HourlyError::LogLimit);
+        }
+        let log = WorkLog {
+            id: log_id,
+            minutes,
+            amount,
+            description_hash,
+            submitted_at: env.ledger().timestamp(),
+            status: WorkLogStatus::Pending,
+        };
+        set(&env, &HourlyKey::WorkLog(job_id, log_id), &log);
+        set(&env, &HourlyKey::WorkLogCount(job_id), &(log_id + 1));
+        set(&env, &HourlyKey::PendingLogTotal(job_id), &pending_total);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("log_sub")),
+            (job_id, log_id, minutes, amount),
+        );
+        Ok(log_id)
+    }
+
+    /// Client approves a pending work log, paying it from the budget.
+    pub fn approve_work_log(
+        env: Env,
+        job_id: u64,
+        log_id: u32,
+        client: Address,
+    ) -> Result<(), HourlyError> {
+        client.require_auth();
+        let job = load_hourly_job(&env, job_id)?;
+        if job.client != client {
+            return Err(HourlyError::Unauthorized);
+        }
+        let log = load_log(&env, job_id, log_id)?;
+        if log.status != WorkLogStatus::Pending {
+            return Err(HourlyError::LogNotPending);
+        }
+        approve_log(&env, job_id, log)
+    }
+
+    /// Client rejects a pending work log, releasing its share of the budget.
+    pub fn reject_work_log(
+        env: Env,
+        job_id: u64,
+        log_id: u32,
+        client: Address,
+    ) -> Result<(), HourlyError> {
+        client.require_auth();
+        let job = load_hourly_job(&env, job_id)?;
+        if job.client != client {
+            return Err(HourlyError::Unauthorized);
+        }
+        let mut log = load_log(&env, job_id, log_id)?;
+        if log.status != WorkLogStatus::Pending {
+            return Err(HourlyError::LogNotPending);
+        }
+        log.status = WorkLogStatus::Rejected;
+        set(&env, &HourlyKey::WorkLog(job_id, log_id), &log);
+        set(&env, &HourlyKey::PendingLogTotal(job_id), &(pending(&env, job_id) - log.amount));
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("log_rej")),
+            (job_id, log_id),
+        );
+        Ok(())
+    }
+
+    /// Approve a work log the client left unreviewed for
+    /// `WORK_LOG_REVIEW_SECS`. Callable by anyone.
+    pub fn finalize_work_log(env: Env, job_id: u64, log_id: u32) -> Result<(), HourlyError> {
+        load_hourly_job(&env, job_id)?;
+        let log = load_log(&env, job_id, log_id)?;
+        if log.status != WorkLogStatus::Pending {
+            return Err(HourlyError::LogNotPending);
+        }
+        if env.ledger().timestamp() < log.submitted_at + WORK_LOG_REVIEW_SECS {
+            return Err(HourlyError::ReviewWindowOpen);
+        }
+        approve_log(&env, job_id, log)
+    }
+
+    /// A work log of `job_id`.
+    pub fn get_work_log(env: Env, job_id: u64, log_id: u32) -> Option<WorkLog> {
+        env.storage().persistent().get(&HourlyKey::WorkLog(job_id, log_id))
+    }
+
+    /// Number of work logs submitted on `job_id`.
+    pub fn get_work_log_count(env: Env, job_id: u64) -> u32 {
+        log_count(&env, job_id)
+    }
+
+    /// Close an hourly job with no pending logs. Client or freelancer. The
+    /// unspent budget is refunded to the client; the job completes if any
+    /// time was paid and is cancelled otherwise.
+    pub fn close_hourly_job(env: Env, job_id: u64, caller: Address) -> Result<(), HourlyError> {
+        caller.require_auth();
+        crate::pause::require_scope_open(&env, PauseScope::Payouts)
+            .map_err(|_| HourlyError::ContractPaused)?;
+        let mut job = load_hourly_job(&env, job_id)?;
+        if caller != job.client && caller != job.freelancer {
+            return Err(HourlyError::Unauthorized);
+        }
+        if pending(&env, job_id) > 0 {
+            return Err(HourlyError::LogsPending);
+        }
+        let completes = job.status == JobStatus::InProgress;
+        let event = if completes {
+            JobEvent::Complete
+        } else {
+            JobEvent::Cancel
+        };
+        let next = state::transition(&job.status, event).map_err(|_| HourlyError::InvalidStatus)?;
+
+        let budget = job.milestones.get(0).ok_or(HourlyError::NotHourly)?;
+        let (refund, released) = (budget.amount - budget.released, budget.released);
+        if refund > 0 {
+            token::Client::new(&env, &job.token).transfer(
+                &env.current_contract_address(),
+                &job.client,
+                &refund,
+            );
+        }
+        crate::fees::settle_fee(&env, &job, released);
+
+        job.milestones.set(
+            0,
+            // The unspent budget went back to the client.
+            Milestone {
+                amount: released,
+                status: if completes {
+                    MilestoneStatus::Approved
+                } else {
+                    budget.status
+                },
+                ..budget
+            },
+        );
+        job.status = next;
+        if completes {
+            job.completed_at = Some(env.ledger().timestamp());
+        } else {
+            job.cancelled_at = Some(env.ledger().timestamp());
+        }
+        save_job(&env, &job);
+        if completes {
+            crate::bonds::return_bond(&env, &job);
+        }
+        crate::on_job_closed(&env, &job);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("hr_closed")),
+            (job_id, caller, released, refund),
+        );
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env,
-    IntoVal, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    Address, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
 
+pub use pause::PauseScope;
+use state::JobEvent;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -122,6 +125,17 @@ pub enum AdminAction {
     /// Emergency withdrawal: recover escrowed funds from a specific job to a recipient address.
     /// Only executable when the contract is paused. Requires multi-sig approval.
     EmergencyWithdraw(u64, Address),
+    /// Pause (`true`) or resume (`false`) one `PauseScope` without touching
+    /// the others.
+    SetPauseScope(PauseScope, bool),
+    /// Register the attestation contract consulted for high-value jobs, or
+    /// remove it with `None`, which turns the gate off.
+    SetAttestationContract(Option<Address>),
+    /// Make global fee changes wait this many seconds before they apply.
+    /// Executable only once the current delay has passed.
+    SetFeeChangeDelay(u64),
+    /// Register the network's native XLM Stellar Asset Contract.
+    SetNativeToken(Address),
 }
 
 /// A pending multi-sig proposal. Executed when `approvals.len() >= threshold`.
@@ -136,90 +150,9 @@ pub struct MultiSigProposal {
     pub created_at: u64,
 }
 
-/// # Escrow State Machine
-///
-/// The escrow contract enforces a strict state machine to ensure valid transitions
-/// and prevent invalid operations. Each state has specific allowed transitions:
-///
-/// ```text
-/// ┌─────────┐
-/// │ Created │ ──fund_job──> ┌────────┐
-/// └─────────┘               │ Funded │
-///                           └────────┘
-///                                │
-///                                │ submit_milestone
-///                                ▼
-///                          ┌────────────┐
-///                          │ InProgress │
-///                          └────────────┘
-///                                │
-///                    ┌───────────┼───────────┐
-///                    │           │           │
-///         approve_milestone   dispute    expire_job
-///                    │           │           │
-///                    ▼           ▼           ▼
-///              ┌───────────┐ ┌──────────┐ ┌─────────┐
-///              │ Completed │ │ Disputed │ │ Expired │
-///              └───────────┘ └──────────┘ └─────────┘
-///                                │
-///                    resolve_dispute_callback
-///                                │
-///                    ┌───────────┴───────────┐
-///                    ▼                       ▼
-///              ┌───────────┐           ┌───────────┐
-///              │ Completed │           │ Cancelled │
-///              └───────────┘           └───────────┘
-/// ```
-///
-/// ## State Descriptions
-///
-/// - **Created**: Job has been created but not yet funded. Only `fund_job` is allowed.
-/// - **Funded**: Escrow is funded. Freelancer can start work via `submit_milestone`.
-/// - **InProgress**: Work has begun. Milestones can be submitted, approved, or disputed.
-/// - **Completed**: All milestones approved and payments released. Terminal state.
-/// - **Disputed**: A dispute has been raised. Only dispute resolution can change state.
-/// - **Cancelled**: Job was cancelled or refunded. Terminal state.
-/// - **Expired**: Job deadline passed without completion. Terminal state.
-///
-/// ## Transition Rules
-///
-/// | From State  | To State    | Trigger Function              | Conditions                          |
-/// |-------------|-------------|-------------------------------|-------------------------------------|
-/// | Created     | Funded      | `fund_job`                    | Client transfers full amount        |
-/// | Funded      | InProgress  | `submit_milestone`            | Freelancer submits first milestone  |
-/// | Funded      | Cancelled   | `cancel_job`                  | No work started, client cancels     |
-/// | Funded      | Expired     | `expire_job`                  | Deadline passed                     |
-/// | InProgress  | Completed   | `approve_milestone`           | All milestones approved             |
-/// | InProgress  | Disputed    | External dispute contract     | Either party raises dispute         |
-/// | InProgress  | Cancelled   | `cancel_job`                  | No active work, client cancels      |
-/// | InProgress  | Expired     | `expire_job`                  | Deadline passed                     |
-/// | Disputed    | Completed   | `resolve_dispute_callback`    | Resolution favors freelancer        |
-/// | Disputed    | Cancelled   | `resolve_dispute_callback`    | Resolution favors client            |
-///
-/// Terminal states (Completed, Cancelled, Expired) cannot transition to any other state.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum JobStatus {
-    Created,
-    Funded,
-    InProgress,
-    Completed,
-    Disputed,
-    Cancelled,
-    Expired,
-}
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum DisputeResolution {
-    ClientWins,
-    FreelancerWins,
-    RefundBoth,
-    RefundSplit(u32),
-    Escalate,
-    /// Dispute was filed in bad faith; initiator's full stake is sent to treasury.
-    MaliciousFiling,
-}
+// Job types are shared with the dispute and reputation contracts through
+// `stellar-market-interface`; re-exported so `crate::Job` etc. keep working.
+pub use stellar_market_interface::{DisputeResolution, Job, JobStatus, Milestone, MilestoneStatus};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -230,17 +163,6 @@ pub struct PayoutBreakdown {
     pub arbitrators: i128,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum MilestoneStatus {
-    Pending,
-    InProgress,
-    Submitted,
-    Approved,
-    /// Milestone has been partially paid; `amount` now holds the REMAINING unpaid balance.
-    PartiallyPaid,
-}
-
 /// Represents the lifecycle state of a revision proposal.
 /// A proposal begins as Pending and transitions to either Accepted or Rejected.
 /// Only one transition is permitted — a resolved proposal cannot be re-opened.
@@ -255,37 +177,6 @@ pub enum ProposalStatus {
     Rejected,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Milestone {
-    pub id: u32,
-    pub description: String,
-    /// For a `PartiallyPaid` milestone this is the REMAINING unpaid balance.
-    pub amount: i128,
-    pub status: MilestoneStatus,
-    pub deadline: u64,
-}
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Job {
-    pub id: u64,
-    pub client: Address,
-    pub freelancer: Address,
-    pub token: Address,
-    pub total_amount: i128,
-    /// Total tokens actually deposited into this contract for this job.
-    /// Starts at 0, set to `total_amount` by `fund_job`, and updated by
-    /// `top_up_escrow` and `accept_revision` budget adjustments.
-    pub funded_amount: i128,
-    pub status: JobStatus,
-    pub milestones: Vec<Milestone>,
-    pub job_deadline: u64,
-    pub auto_refund_after: u64,
-    /// Ledger number at which the escrow expires and funds can be auto-released.
-    pub expiry_ledger: u32,
-}
-
 pub(crate) const MAX_FEE_BPS: u32 = 500; // 5%
 const MAX_MILESTONES: u32 = 50;
 
@@ -364,6 +255,9 @@ enum DataKey {
     /// Used by `accept_revision` to ensure a revision never shrinks a milestone's
     /// value below funds that have already left escrow for it.
     MilestoneDisbursed(u64, u32),
+    /// Job a client created under an external idempotency key:
+    /// (client, external_id) → `u64`.
+    ExternalId(Address, BytesN<32>),
 }
 
 /// Fixed-point scale for oracle prices: prices are quoted in XLM stroops per token
@@ -409,6 +303,22 @@ fn record_milestone_disbursed(env: &Env, job_id: u64, milestone_id: u32, amount:
         .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
 }
 
+/// Job-token milestone value that has left escrow, and value approved but
+/// still held for the freelancer, as `(released, approved_unpaid)`.
+fn settled_value(job: &Job) -> (i128, i128) {
+    job.milestones
+        .iter()
+        .filter(milestone_tokens::in_job_token)
+        .fold((0, 0), |(released, approved), m| {
+            let unpaid = if m.status == MilestoneStatus::Approved {
+                m.amount - m.released
+            } else {
+                0
+            };
+            (released + m.released, approved + unpaid)
+        })
+}
+
 fn consume_nonce(env: &Env, caller: &Address, function: &Symbol, nonce: u64) -> Result<(), EscrowError> {
     let key = DataKey::Nonce(caller.clone(), function.clone(), nonce);
     if env.storage().temporary().has(&key) {
@@ -434,6 +344,17 @@ fn require_not_paused(env: &Env) -> Result<(), EscrowError> {
     Ok(())
 }
 
+/// Whether new jobs may be denominated in `token`. An empty allowlist admits
+/// every token.
+fn is_token_allowed(env: &Env, token: &Address) -> bool {
+    let allowed: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(env));
+    allowed.is_empty() || allowed.contains(token)
+}
+
 /// Validates that every address in `callers` is a registered signer.
 fn is_signer(env: &Env, address: &Address) -> bool {
     if let Some(signers) = env
@@ -447,6 +368,296 @@ fn is_signer(env: &Env, address: &Address) -> bool {
     }
 }
 
+/// Approve a submitted milestone the client never reviewed and pay it out as
+/// `approve_milestone` followed by a release would. Shared by the inactivity
+/// and review-window auto-approvals. Returns `(fee_bps, fee_amount,
+/// net_amount)`.
+fn approve_unreviewed(
+    env: &Env,
+    job: &mut Job,
+    milestone: &Milestone,
+    next: JobStatus,
+) -> (u32, i128, i128) {
+    let (fee_bps, fee_amount, freelancer_amount) = if milestone_tokens::in_job_token(milestone) {
+        let token_client = token::Client::new(env, &job.token);
+
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("FEE"))
+            .unwrap_or(0);
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("TRE"))
+            .unwrap_or(env.current_contract_address());
+
+        let (fee_amount, fee_deducted) =
+            fees::release_fee(env, job.id, milestone.amount, fee_bps);
+        let freelancer_amount = milestone.amount - fee_deducted;
+
+        if fee_amount > 0 {
+            token_client.transfer(&env.current_contract_address(), &treasury, &fee_amount);
+            env.events().publish(
+                (symbol_short!("escrow"), symbol_short!("fee")),
+                (job.id, milestone.id, fee_amount, treasury.clone()),
+            );
+        }
+
+        owed::pay_freelancer(env, job, freelancer_amount);
+        let fee_bps = fees::quote_fee(env, job.id, milestone.amount).0;
+        (fee_bps, fee_amount, freelancer_amount)
+    } else {
+        milestone_tokens::release(env, job, milestone)
+    };
+    fees::record_payout(env, job.id, milestone.id, fee_bps, fee_amount, freelancer_amount);
+    record_milestone_disbursed(env, job.id, milestone.id, milestone.amount);
+
+    let updated = Milestone {
+        id: milestone.id,
+        description: milestone.description.clone(),
+        amount: milestone.amount,
+        status: MilestoneStatus::Approved,
+        deadline: milestone.deadline,
+        token: milestone.token.clone(),
+        released: milestone.released + milestone.amount,
+    };
+    job.milestones.set(milestone.id, updated);
+
+    // Keep the job in InProgress; complete_job will finalize payment and transition to Completed.
+    job.status = next;
+
+    env.storage().persistent().set(&get_job_key(job.id), job);
+    status_index::sync(env, job);
+    bump_job_ttl(env, job.id);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MilestoneSubmittedAt(job.id, milestone.id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InactivityAutoApproveAt(job.id, milestone.id));
+    changes::record(env, job.id, changes::ChangeKind::MilestoneApproved);
+    checkpoints::on_approval(env, job);
+    early_bonus::on_approval(env, job);
+
+    (fee_bps, fee_amount, freelancer_amount)
+}
+
+/// Body of `fund_job`. With `prefunded` the deposit and any up-front fee come
+/// out of the amount the escrow holds under the client's funding
+/// authorization, for `fund_job_with_authorization`.
+fn fund(
+    env: &Env,
+    job_id: u64,
+    client: Address,
+    agreed_value_stroops: i128,
+    max_slippage_bps: u32,
+    prefunded: bool,
+) -> Result<(), EscrowError> {
+    bump_escrow_ttl(env, job_id);
+    pause::require_scope_open(env, PauseScope::Create)?;
+
+    let mut job: Job = env
+        .storage()
+        .persistent()
+        .get(&get_job_key(job_id))
+        .ok_or(EscrowError::JobNotFound)?;
+    bump_job_ttl(env, job_id);
+
+    // Require auth from the persisted job owner to prevent third-party funding.
+    if !prefunded {
+        job.client.require_auth();
+    }
+
+    if job.client != client {
+        return Err(EscrowError::Unauthorized);
+    }
+
+    // STATE VALIDATION: Job must be in Created state to be funded
+    let next = state::transition(&job.status, JobEvent::Fund)?;
+
+    // Validate that total_amount matches the sum of stored milestone amounts.
+    // Guards against any inconsistency between the two fields (e.g. from a
+    // revision path bug) that would leave milestones unpayable or trap surplus funds.
+    let milestone_sum: i128 = job
+        .milestones
+        .iter()
+        .filter(milestone_tokens::in_job_token)
+        .try_fold(0i128, |acc, m| acc.checked_add(m.amount))
+        .ok_or(EscrowError::InvalidAmount)?;
+    if job.total_amount != milestone_sum {
+        return Err(EscrowError::InvalidAmount);
+    }
+
+    // Exchange-rate parity check (no-op when agreed_value_stroops == 0).
+    let snapshot = validate_deposit_value(
+        env,
+        &job.token,
+        job.total_amount,
+        agreed_value_stroops,
+        max_slippage_bps,
+    )?;
+
+    attestation::enforce_attestation(
+        env,
+        &job.token,
+        job.total_amount,
+        &job.client,
+        &job.freelancer,
+    );
+    bonds::enforce_required_bond(env, &job);
+
+    if !prefunded {
+        native::require_balance(env, &job.token, &client, job.total_amount)?;
+    }
+    funding_auth::pull(env, &job.token, &client, job.total_amount, prefunded);
+    milestone_tokens::fund(env, &job, &client)?;
+
+    job.funded_amount = job.total_amount;
+    job.status = next;
+    job.funded_at = Some(env.ledger().timestamp());
+    limits::track_job_funded(env, &job);
+    env.storage().persistent().set(&get_job_key(job_id), &job);
+    status_index::sync(env, &job);
+    bump_job_ttl(env, job_id);
+    fees::accrue_upfront_fee(env, &job, prefunded);
+    display::record_reference_price(env, &job);
+
+    // Persist the parity snapshot for audit / UI when the oracle was consulted.
+    if agreed_value_stroops != 0 {
+        env.storage()
+            .persistent()
+            .set(&DataKey::RateSnapshot(job_id), &snapshot);
+        env.storage().persistent().extend_ttl(
+            &DataKey::RateSnapshot(job_id),
+            TTL_THRESHOLD_LEDGERS,
+            TTL_EXTEND_TO_LEDGERS,
+        );
+    }
+
+    changes::record(env, job_id, changes::ChangeKind::Funded);
+
+    // Emit event
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("funded")),
+        (job_id, client, job.freelancer, job.token, job.total_amount),
+    );
+
+    Ok(())
+}
+
+/// Pay the client-chosen part of a submitted or partially paid milestone;
+/// shared by `release_partial_payment` and `approve_milestone_partial`.
+/// `amount_of` picks the amount to release from the milestone.
+fn release_partial(
+    env: &Env,
+    job_id: u64,
+    milestone_index: u32,
+    client: Address,
+    amount_of: impl FnOnce(&Milestone) -> i128,
+) -> Result<(), EscrowError> {
+    bump_escrow_ttl(env, job_id);
+    client.require_auth();
+    pause::require_scope_open(env, PauseScope::Payouts)?;
+
+    let mut job: Job = env
+        .storage()
+        .persistent()
+        .get(&get_job_key(job_id))
+        .ok_or(EscrowError::JobNotFound)?;
+    bump_job_ttl(env, job_id);
+
+    if job.client != client {
+        return Err(EscrowError::Unauthorized);
+    }
+
+    // STATE VALIDATION: Only Funded or InProgress jobs take payments
+    let next = state::transition(&job.status, JobEvent::Progress)?;
+
+    let mut milestones = job.milestones.clone();
+    let milestone = milestones
+        .get(milestone_index)
+        .ok_or(EscrowError::MilestoneNotFound)?;
+
+    // Only allow partial payment on a Submitted or already-PartiallyPaid milestone.
+    if milestone.status != MilestoneStatus::Submitted
+        && milestone.status != MilestoneStatus::PartiallyPaid
+    {
+        return Err(EscrowError::InvalidStatus);
+    }
+
+    if !milestone_tokens::in_job_token(&milestone) {
+        panic_with_error!(env, milestone_tokens::MilestoneTokenError::Unsupported);
+    }
+
+    // Validate the requested amount.
+    let amount = amount_of(&milestone);
+    if amount <= 0 || amount > milestone.amount - milestone.released {
+        return Err(EscrowError::InvalidPartialAmount);
+    }
+
+    // Release partial payment to freelancer (no fee deducted per-milestone;
+    // the protocol fee is taken from remaining escrow on complete_job).
+    owed::pay_freelancer(env, &job, amount);
+
+    // Track cumulative disbursement so a later revision can't undercut what's
+    // already been paid out for this milestone.
+    record_milestone_disbursed(env, job_id, milestone.id, amount);
+
+    // Add to what the milestone has paid out; transition status accordingly.
+    let released = milestone.released + amount;
+    let new_status = if released == milestone.amount {
+        MilestoneStatus::Approved
+    } else {
+        MilestoneStatus::PartiallyPaid
+    };
+
+    let updated = Milestone {
+        id: milestone.id,
+        description: milestone.description.clone(),
+        amount: milestone.amount,
+        status: new_status,
+        deadline: milestone.deadline,
+        token: milestone.token.clone(),
+        released,
+    };
+    milestones.set(milestone_index, updated);
+    job.milestones = milestones.clone();
+
+    // Keep the job in InProgress; complete_job will finalize payment and transition to Completed.
+    job.status = next;
+
+    env.storage().persistent().set(&get_job_key(job_id), &job);
+    status_index::sync(env, &job);
+    bump_job_ttl(env, job_id);
+
+    changes::record(env, job_id, changes::ChangeKind::PaymentReleased);
+    early_bonus::on_approval(env, &job);
+
+    // Emit PartialPaymentReleased event.
+    let client = job.client.clone();
+    let freelancer = job.freelancer.clone();
+    env.events().publish(
+        (symbol_short!("escrow"), Symbol::new(env, "partial_pmt")),
+        (job_id, milestone_index, amount, client, freelancer),
+    );
+
+    Ok(())
+}
+
+/// Require the call to come from the registered dispute contract. Fails
+/// with `Unauthorized` while none is registered.
+fn require_dispute_contract(env: &Env) -> Result<(), EscrowError> {
+    let dispute_contract: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::DisputeContract)
+        .ok_or(EscrowError::Unauthorized)?;
+    dispute_contract.require_auth();
+    Ok(())
+}
+
 // Production TTL constants based on Stellar's ~5-second ledger close time
 const LEDGERS_PER_DAY: u32 = 17_280; // 86,400 seconds/day ÷ 5 seconds/ledger
 const TTL_THRESHOLD_LEDGERS: u32 = LEDGERS_PER_DAY * 15; // 15 days = 259,200 ledgers
@@ -491,91 +702,22 @@ fn bump_job_ttl(env: &Env, job_id: u64) {
     bump_escrow_ttl(env, job_id);
 }
 
+/// Bookkeeping shared by every transition into a terminal status.
+fn on_job_closed(env: &Env, job: &Job) {
+    milestone_tokens::on_job_closed(env, job);
+    limits::track_job_closed(env, job);
+    archive::record_closed(env, job);
+    changes::record_closed(env, job);
+    early_bonus::refund_held(env, job);
+    status_index::sync(env, job);
+}
+
 fn bump_job_count_ttl(env: &Env) {
     env.storage()
         .instance()
         .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND_TO);
 }
 
-// ============================================================
-// STATE MACHINE VALIDATION
-// ============================================================
-// These functions enforce the escrow state machine by validating
-// that state transitions are legal before any mutation occurs.
-// Each function returns EscrowError::InvalidState if the current
-// state does not permit the requested operation.
-// ============================================================
-
-/// Validates that the job is in Created state (ready to be funded).
-fn require_state_created(job: &Job) -> Result<(), EscrowError> {
-    if job.status != JobStatus::Created {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
-/// Validates that the job is in Funded or InProgress state (work can proceed).
-fn require_state_funded_or_in_progress(job: &Job) -> Result<(), EscrowError> {
-    if job.status != JobStatus::Funded && job.status != JobStatus::InProgress {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
-/// Validates that the job is NOT in a terminal state (Completed, Cancelled, Expired).
-/// Terminal states cannot transition to any other state.
-fn require_state_not_terminal(job: &Job) -> Result<(), EscrowError> {
-    if job.status == JobStatus::Completed
-        || job.status == JobStatus::Cancelled
-        || job.status == JobStatus::Expired
-    {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
-/// Validates that the job is NOT disputed.
-/// Most operations are blocked during active disputes.
-fn require_state_not_disputed(job: &Job) -> Result<(), EscrowError> {
-    if job.status == JobStatus::Disputed {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
-/// Validates that the job is in a state that can be disputed.
-/// Only Funded, InProgress, or already Disputed jobs can have dispute operations.
-fn require_state_disputable(job: &Job) -> Result<(), EscrowError> {
-    if job.status != JobStatus::Funded
-        && job.status != JobStatus::InProgress
-        && job.status != JobStatus::Disputed
-    {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
-/// Validates that the job is in a state that can be cancelled.
-/// Only Funded or InProgress jobs can be cancelled (and only if no work is in progress).
-fn require_state_cancellable(job: &Job) -> Result<(), EscrowError> {
-    if job.status != JobStatus::Funded && job.status != JobStatus::InProgress {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
-/// Validates that the job is in a state that can expire.
-/// Jobs in Created, Completed, Cancelled, or Expired states cannot expire.
-fn require_state_expirable(job: &Job) -> Result<(), EscrowError> {
-    if job.status == JobStatus::Completed
-        || job.status == JobStatus::Cancelled
-        || job.status == JobStatus::Expired
-    {
-        return Err(EscrowError::InvalidStatus);
-    }
-    Ok(())
-}
-
 /// Query the configured price oracle for the TWAP of `token` quoted in XLM
 /// stroops (scaled by [`PRICE_SCALE`]) over the last [`TWAP_SAMPLE_LEDGERS`]
 /// ledgers.
@@ -638,8 +780,9 @@ fn compute_deposited_value(amount: i128, twap_price: i128) -> Result<i128, Escro
 /// `agreed_value_stroops`, within `max_slippage_bps` downside tolerance, using a
 /// TWAP from the oracle. Returns the audit [`RateSnapshot`] on success.
 ///
-/// When `agreed_value_stroops == 0` the check is bypassed (e.g. native XLM jobs)
-/// and an empty snapshot is returned without contacting the oracle.
+/// When `agreed_value_stroops == 0` the check is bypassed (e.g. the legacy
+/// migration path) and an empty snapshot is returned without contacting the
+/// oracle. Native XLM is valued at par, also without the oracle.
 fn validate_deposit_value(
     env: &Env,
     token: &Address,
@@ -658,7 +801,11 @@ fn validate_deposit_value(
         });
     }
 
-    let (twap_price, samples) = fetch_twap_price(env, token)?;
+    let (twap_price, samples) = if native::is_native(env, token) {
+        (PRICE_SCALE, 0)
+    } else {
+        fetch_twap_price(env, token)?
+    };
 
     // deposited_value = amount * twap_price / PRICE_SCALE, with overflow checks
     // so adversarial amount/price values can never wrap i128.
@@ -852,12 +999,7 @@ impl EscrowContract {
         job_id: u64,
         dispute_id: u64,
     ) -> Result<(), EscrowError> {
-        let dispute_contract: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::DisputeContract)
-            .ok_or(EscrowError::Unauthorized)?;
-        dispute_contract.require_auth();
+        require_dispute_contract(&env)?;
 
         let mut job: Job = env
             .storage()
@@ -866,10 +1008,10 @@ impl EscrowContract {
             .ok_or(EscrowError::JobNotFound)?;
         bump_job_ttl(&env, job_id);
 
-        require_state_disputable(&job)?;
-
-        job.status = JobStatus::Disputed;
+        job.status = state::transition(&job.status, JobEvent::Dispute)?;
         env.storage().persistent().set(&get_job_key(job_id), &job);
+        status_index::sync(&env, &job);
+        changes::record(&env, job_id, changes::ChangeKind::Disputed);
 
         env.events().publish(
             (symbol_short!("escrow"), Symbol::new(&env, "disputed")),
@@ -888,6 +1030,25 @@ impl EscrowContract {
             .get(&DataKey::RateSnapshot(job_id))
     }
 
+    /// Pause the contract at once on the word of any single signer: an
+    /// emergency stop must not wait for other signers or the `Pause` time lock.
+    /// Lifting the pause, and fee or treasury changes, still need
+    /// `threshold` approvals through `propose_admin_action`.
+    pub fn emergency_pause(env: Env, signer: Address) -> Result<(), EscrowError> {
+        signer.require_auth();
+        if !is_signer(&env, &signer) {
+            return Err(EscrowError::SignerNotFound);
+        }
+        require_not_paused(&env)?;
+
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("paused")),
+            Paused { paused_by: signer },
+        );
+        Ok(())
+    }
+
     pub fn propose_admin_action(
         env: Env,
         proposer: Address,
@@ -917,8 +1078,12 @@ impl EscrowContract {
 
         let now = env.ledger().timestamp();
         let execution_not_before = match action {
-            AdminAction::Pause | AdminAction::SetTreasury(_) => {
-                now.saturating_add(MULTISIG_TIME_LOCK_SECS)
+            AdminAction::Pause
+            | AdminAction::SetPauseScope(_, true)
+            | AdminAction::SetTreasury(_)
+            | AdminAction::SetAttestationContract(_) => now.saturating_add(MULTISIG_TIME_LOCK_SECS),
+            AdminAction::SetFeeChangeDelay(_) => {
+                now.saturating_add(fees::fee_change_delay(&env))
             }
             _ => now,
         };
@@ -1094,17 +1259,33 @@ impl EscrowContract {
                     },
                 );
             }
+            AdminAction::SetPauseScope(scope, paused) => {
+                pause::set_scope(env, scope, paused);
+                env.events().publish(
+                    (symbol_short!("escrow"), symbol_short!("pause_scp")),
+                    (scope, paused, proposal.proposer.clone()),
+                );
+            }
             AdminAction::SetFeeBps(fee) => {
                 if fee > MAX_FEE_BPS {
                     return Err(EscrowError::InvalidFee);
                 }
-                env.storage().instance().set(&symbol_short!("FEE"), &fee);
+                fees::set_fee_bps(env, fee);
             }
             AdminAction::SetTreasury(treasury) => {
                 env.storage()
                     .instance()
                     .set(&symbol_short!("TRE"), &treasury);
             }
+            AdminAction::SetFeeChangeDelay(delay_secs) => {
+                fees::set_fee_change_delay(env, delay_secs, &proposal.proposer);
+            }
+            AdminAction::SetAttestationContract(attester) => {
+                attestation::set_contract(env, attester, &proposal.proposer);
+            }
+            AdminAction::SetNativeToken(token) => {
+                native::set_native_token(env, token, &proposal.proposer);
+            }
             AdminAction::AddSigner(signer) => {
                 let mut signers: Vec<Address> = env
                     .storage()
@@ -1184,42 +1365,30 @@ impl EscrowContract {
                     .persistent()
                     .get(&get_job_key(job_id))
                     .ok_or(EscrowError::JobNotFound)?;
-                bump_job_ttl(&env, job_id);
+                bump_job_ttl(env, job_id);
 
                 // Compute the remaining escrowed balance (total minus already-approved milestones).
-                let approved_amount: i128 = job
-                    .milestones
-                    .iter()
-                    .filter(|m| m.status == MilestoneStatus::Approved)
-                    .map(|m| m.amount)
-                    .sum();
-                let withdrawable = job.total_amount - approved_amount;
+                let (released, approved_amount) = settled_value(&job);
+                let withdrawable = job.total_amount - released - approved_amount;
 
                 if withdrawable <= 0 {
                     return Err(EscrowError::NoFundsToWithdraw);
                 }
 
                 // Only transfer if the job held funded escrow.
-                if job.status == JobStatus::Funded
-                    || job.status == JobStatus::InProgress
-                    || job.status == JobStatus::Disputed
-                {
-                    let token_client = token::Client::new(&env, &job.token);
-                    token_client.transfer(
-                        &env.current_contract_address(),
-                        &recipient,
-                        &withdrawable,
-                    );
-                } else {
-                    return Err(EscrowError::NoFundsToWithdraw);
-                }
+                let next = state::transition(&job.status, JobEvent::Withdraw)
+                    .map_err(|_| EscrowError::NoFundsToWithdraw)?;
+                let token_client = token::Client::new(env, &job.token);
+                token_client.transfer(&env.current_contract_address(), &recipient, &withdrawable);
 
-                job.status = JobStatus::Cancelled;
+                job.status = next;
+                job.cancelled_at = Some(env.ledger().timestamp());
                 env.storage().persistent().set(&get_job_key(job_id), &job);
-                bump_job_ttl(&env, job_id);
+                bump_job_ttl(env, job_id);
+                on_job_closed(env, &job);
 
                 env.events().publish(
-                    (symbol_short!("escrow"), Symbol::new(&env, "emrg_wdrw")),
+                    (symbol_short!("escrow"), Symbol::new(env, "emrg_wdrw")),
                     (job_id, recipient, withdrawable, job.client, job.freelancer),
                 );
             }
@@ -1248,6 +1417,7 @@ impl EscrowContract {
     }
 
     /// Creates a new job with milestones. Client specifies the freelancer and token for payment.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_job(
         env: Env,
         client: Address,
@@ -1258,14 +1428,9 @@ impl EscrowContract {
         auto_refund_after: u64,
         expiry_ledger: u32,
     ) -> Result<u64, EscrowError> {
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Create)?;
 
-        let allowed_tokens = Self::get_allowed_tokens(env.clone());
-        if !allowed_tokens.is_empty()
-            && !allowed_tokens
-                .iter()
-                .any(|allowed| allowed == token.clone())
-        {
+        if !is_token_allowed(&env, &token) {
             return Err(EscrowError::TokenNotAllowed);
         }
 
@@ -1302,6 +1467,7 @@ impl EscrowContract {
             if amount <= 0 {
                 return Err(EscrowError::InvalidMilestone);
             }
+            limits::enforce_min_amount(&env, &token, amount);
             if deadline <= env.ledger().timestamp() {
                 return Err(EscrowError::MilestoneDeadlineInPast);
             }
@@ -1322,8 +1488,11 @@ impl EscrowContract {
                 amount,
                 status: MilestoneStatus::Pending,
                 deadline,
+                token: None,
+                released: 0,
             });
         }
+        attestation::enforce_attestation(&env, &token, total, &client, &freelancer);
 
         let job = Job {
             id: job_count,
@@ -1337,14 +1506,22 @@ impl EscrowContract {
             job_deadline,
             auto_refund_after,
             expiry_ledger,
+            created_at: Some(env.ledger().timestamp()),
+            funded_at: None,
+            completed_at: None,
+            cancelled_at: None,
         };
 
         env.storage()
             .persistent()
             .set(&get_job_key(job_count), &job);
         bump_job_ttl(&env, job_count);
+        deadlines::index_job_deadlines(&env, &job);
+        pairs::index_job(&env, &job);
+        status_index::sync(&env, &job);
         env.storage().instance().set(&DataKey::JobCount, &job_count);
         bump_job_count_ttl(&env);
+        changes::record(&env, job_count, changes::ChangeKind::Created);
 
         // Emit event
         env.events().publish(
@@ -1362,7 +1539,11 @@ impl EscrowContract {
     /// oracle for a TWAP of `job.token` and rejects the deposit with
     /// [`EscrowError::InsufficientValue`] if its value falls below the agreed
     /// value minus `max_slippage_bps`. Passing `agreed_value_stroops = 0` bypasses
-    /// the oracle check (e.g. for native-XLM jobs and the legacy migration path).
+    /// the oracle check (e.g. for the legacy migration path). Native XLM jobs
+    /// are valued at par without the oracle, and fail with `InsufficientFunds`
+    /// when the client's XLM balance is short. Jobs with a `set_required_bond`
+    /// requirement fail with `BondError::BondRequired` until the freelancer
+    /// has posted it.
     pub fn fund_job(
         env: Env,
         job_id: u64,
@@ -1370,74 +1551,7 @@ impl EscrowContract {
         agreed_value_stroops: i128,
         max_slippage_bps: u32,
     ) -> Result<(), EscrowError> {
-        bump_escrow_ttl(&env, job_id);
-        require_not_paused(&env)?;
-
-        let mut job: Job = env
-            .storage()
-            .persistent()
-            .get(&get_job_key(job_id))
-            .ok_or(EscrowError::JobNotFound)?;
-        bump_job_ttl(&env, job_id);
-
-        // Require auth from the persisted job owner to prevent third-party funding.
-        job.client.require_auth();
-
-        if job.client != client {
-            return Err(EscrowError::Unauthorized);
-        }
-
-        // STATE VALIDATION: Job must be in Created state to be funded
-        require_state_created(&job)?;
-
-        // Validate that total_amount matches the sum of stored milestone amounts.
-        // Guards against any inconsistency between the two fields (e.g. from a
-        // revision path bug) that would leave milestones unpayable or trap surplus funds.
-        let milestone_sum: i128 = job
-            .milestones
-            .iter()
-            .try_fold(0i128, |acc, m| acc.checked_add(m.amount))
-            .ok_or(EscrowError::InvalidAmount)?;
-        if job.total_amount != milestone_sum {
-            return Err(EscrowError::InvalidAmount);
-        }
-
-        // Exchange-rate parity check (no-op when agreed_value_stroops == 0).
-        let snapshot = validate_deposit_value(
-            &env,
-            &job.token,
-            job.total_amount,
-            agreed_value_stroops,
-            max_slippage_bps,
-        )?;
-
-        let token_client = token::Client::new(&env, &job.token);
-        token_client.transfer(&client, &env.current_contract_address(), &job.total_amount);
-
-        job.funded_amount = job.total_amount;
-        job.status = JobStatus::Funded;
-        env.storage().persistent().set(&get_job_key(job_id), &job);
-        bump_job_ttl(&env, job_id);
-
-        // Persist the parity snapshot for audit / UI when the oracle was consulted.
-        if agreed_value_stroops != 0 {
-            env.storage()
-                .persistent()
-                .set(&DataKey::RateSnapshot(job_id), &snapshot);
-            env.storage().persistent().extend_ttl(
-                &DataKey::RateSnapshot(job_id),
-                TTL_THRESHOLD_LEDGERS,
-                TTL_EXTEND_TO_LEDGERS,
-            );
-        }
-
-        // Emit event
-        env.events().publish(
-            (symbol_short!("escrow"), symbol_short!("funded")),
-            (job_id, client, job.freelancer, job.token, job.total_amount),
-        );
-
-        Ok(())
+        fund(&env, job_id, client, agreed_value_stroops, max_slippage_bps, false)
     }
 
     /// Incrementally top up the escrow balance for an already-funded job.
@@ -1452,6 +1566,7 @@ impl EscrowContract {
     /// - `InvalidStatus`    — job is not Funded or InProgress
     /// - `AlreadyFunded`    — adding `amount` would exceed `total_amount`
     /// - `ContractPaused`   — the contract is paused
+    /// - `InsufficientFunds` — the client's native XLM balance is below `amount`
     pub fn top_up_escrow(
         env: Env,
         client: Address,
@@ -1459,7 +1574,7 @@ impl EscrowContract {
         amount: i128,
     ) -> Result<(), EscrowError> {
         bump_escrow_ttl(&env, job_id);
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Create)?;
 
         client.require_auth();
 
@@ -1475,7 +1590,7 @@ impl EscrowContract {
         }
 
         // STATE VALIDATION: Job must be Funded or InProgress to top up
-        require_state_funded_or_in_progress(&job)?;
+        state::transition(&job.status, JobEvent::Work)?;
 
         let new_funded = job
             .funded_amount
@@ -1485,13 +1600,22 @@ impl EscrowContract {
         if new_funded > job.total_amount {
             return Err(EscrowError::AlreadyFunded);
         }
+        attestation::enforce_attestation(
+            &env,
+            &job.token,
+            job.total_amount,
+            &job.client,
+            &job.freelancer,
+        );
 
+        native::require_balance(&env, &job.token, &client, amount)?;
         let token_client = token::Client::new(&env, &job.token);
         token_client.transfer(&client, &env.current_contract_address(), &amount);
 
         job.funded_amount = new_funded;
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        changes::record(&env, job_id, changes::ChangeKind::ToppedUp);
 
         env.events().publish(
             (symbol_short!("escrow"), symbol_short!("top_up")),
@@ -1501,7 +1625,7 @@ impl EscrowContract {
         Ok(())
     }
 
-    /// Called by the dispute contract to resolve a disputed job and distribute funds.
+    /// Called by the registered dispute contract to resolve a disputed job and distribute funds.
     /// Uses the full DisputeResolution enum to correctly handle all four outcomes,
     /// including the zero-remaining edge case where only the job status needs updating.
     pub fn resolve_dispute_callback(
@@ -1509,8 +1633,9 @@ impl EscrowContract {
         job_id: u64,
         resolution: DisputeResolution,
     ) -> Result<(), EscrowError> {
+        require_dispute_contract(&env)?;
         bump_escrow_ttl(&env, job_id);
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Payouts)?;
 
         let mut job: Job = env
             .storage()
@@ -1519,16 +1644,16 @@ impl EscrowContract {
             .ok_or(EscrowError::JobNotFound)?;
 
         // STATE VALIDATION: Job must be in a disputable state
-        require_state_disputable(&job)?;
+        let event = match resolution {
+            DisputeResolution::FreelancerWins => JobEvent::AwardFreelancer,
+            DisputeResolution::Escalate => JobEvent::Escalate,
+            _ => JobEvent::AwardClient,
+        };
+        let next = state::transition(&job.status, event)?;
 
-        let approved_amount: i128 = job
-            .milestones
-            .iter()
-            .filter(|m| m.status == MilestoneStatus::Approved)
-            .map(|m| m.amount)
-            .sum();
+        let (released, approved_amount) = settled_value(&job);
 
-        let remaining = job.total_amount - approved_amount;
+        let remaining = job.total_amount - released - approved_amount;
 
         if remaining > 0 {
             // Funds remain — transfer them according to the resolution outcome.
@@ -1536,27 +1661,22 @@ impl EscrowContract {
             match resolution {
                 DisputeResolution::ClientWins => {
                     token_client.transfer(&env.current_contract_address(), &job.client, &remaining);
-                    job.status = JobStatus::Cancelled;
+                    job.status = next.clone();
+                    job.cancelled_at = Some(env.ledger().timestamp());
                 }
                 DisputeResolution::FreelancerWins => {
-                    token_client.transfer(
-                        &env.current_contract_address(),
-                        &job.freelancer,
-                        &remaining,
-                    );
-                    job.status = JobStatus::Completed;
+                    owed::pay_freelancer(&env, &job, remaining);
+                    job.status = next.clone();
+                    job.completed_at = Some(env.ledger().timestamp());
                 }
                 DisputeResolution::RefundBoth => {
                     let half = remaining / 2;
                     if half > 0 {
                         token_client.transfer(&env.current_contract_address(), &job.client, &half);
-                        token_client.transfer(
-                            &env.current_contract_address(),
-                            &job.freelancer,
-                            &(remaining - half),
-                        );
+                        owed::pay_freelancer(&env, &job, remaining - half);
                     }
-                    job.status = JobStatus::Cancelled;
+                    job.status = next.clone();
+                    job.cancelled_at = Some(env.ledger().timestamp());
                 }
                 DisputeResolution::RefundSplit(pct_client) => {
                     let pct = if pct_client > 100 { 100 } else { pct_client } as i128;
@@ -1570,13 +1690,24 @@ impl EscrowContract {
                         );
                     }
                     if freelancer_amount > 0 {
+                        owed::pay_freelancer(&env, &job, freelancer_amount);
+                    }
+                    job.status = next.clone();
+                    job.cancelled_at = Some(env.ledger().timestamp());
+                }
+                DisputeResolution::SplitAward(bps_client) => {
+                    let client_amount = remaining * bps_client.min(10_000) as i128 / 10_000;
+                    let freelancer_amount = remaining - client_amount;
+                    if client_amount > 0 {
                         token_client.transfer(
                             &env.current_contract_address(),
-                            &job.freelancer,
-                            &freelancer_amount,
+                            &job.client,
+                            &client_amount,
                         );
                     }
-                    job.status = JobStatus::Cancelled;
+                    owed::pay_freelancer(&env, &job, freelancer_amount);
+                    job.status = next.clone();
+                    job.cancelled_at = Some(env.ledger().timestamp());
                 }
                 DisputeResolution::Escalate => {
                     // No funds transferred; job remains in its current disputed state
@@ -1590,7 +1721,8 @@ impl EscrowContract {
                         .get(&symbol_short!("TRE"))
                         .unwrap_or(job.client.clone());
                     token_client.transfer(&env.current_contract_address(), &treasury, &remaining);
-                    job.status = JobStatus::Cancelled;
+                    job.status = next.clone();
+                    job.cancelled_at = Some(env.ledger().timestamp());
                 }
             }
         } else {
@@ -1600,11 +1732,14 @@ impl EscrowContract {
                 DisputeResolution::ClientWins
                 | DisputeResolution::RefundBoth
                 | DisputeResolution::RefundSplit(_)
+                | DisputeResolution::SplitAward(_)
                 | DisputeResolution::MaliciousFiling => {
-                    job.status = JobStatus::Cancelled;
+                    job.status = next.clone();
+                    job.cancelled_at = Some(env.ledger().timestamp());
                 }
                 DisputeResolution::FreelancerWins => {
-                    job.status = JobStatus::Completed;
+                    job.status = next.clone();
+                    job.completed_at = Some(env.ledger().timestamp());
                 }
                 DisputeResolution::Escalate => {
                     // Leave status unchanged, same as above.
@@ -1612,7 +1747,35 @@ impl EscrowContract {
             }
         }
 
+        // Milestones paid in other tokens follow the resolution before the
+        // closing hook would refund them.
+        if let Some(remainder) = milestone_tokens::dispute_remainder(&resolution) {
+            milestone_tokens::settle(&env, &job, remainder);
+        }
+
+        // Settle any up-front fee: earned in full if the freelancer was awarded the
+        // remaining work, otherwise only on already-approved milestones.
+        match job.status {
+            JobStatus::Completed => {
+                fees::settle_fee(&env, &job, job.total_amount);
+                on_job_closed(&env, &job);
+            }
+            JobStatus::Cancelled => {
+                fees::settle_fee(&env, &job, released + approved_amount);
+                on_job_closed(&env, &job);
+            }
+            _ => {}
+        }
+
+        // Settle the freelancer's performance bond, if one was posted.
+        if resolution == DisputeResolution::ClientWins {
+            bonds::forfeit_bond(&env, &job);
+        } else if job.status == JobStatus::Completed {
+            bonds::return_bond(&env, &job);
+        }
+
         env.storage().persistent().set(&get_job_key(job_id), &job);
+        status_index::sync(&env, &job);
         bump_job_ttl(&env, job_id);
 
         env.events().publish(
@@ -1669,6 +1832,15 @@ impl EscrowContract {
                     arbitrators: arbitrator_fee,
                 }
             }
+            DisputeResolution::SplitAward(bps_client) => {
+                let client_amount = remaining * bps_client.min(10_000) as i128 / 10_000;
+                PayoutBreakdown {
+                    client: client_amount,
+                    freelancer: remaining - client_amount,
+                    platform: platform_fee,
+                    arbitrators: arbitrator_fee,
+                }
+            }
             DisputeResolution::Escalate => PayoutBreakdown {
                 client: 0,
                 freelancer: 0,
@@ -1694,6 +1866,8 @@ impl EscrowContract {
         bump_escrow_ttl(&env, job_id);
         freelancer.require_auth();
         require_not_paused(&env)?;
+        checkpoints::enforce_submission_allowed(&env, job_id);
+        hourly::enforce_milestone_flow(&env, job_id);
 
         let mut job: Job = env
             .storage()
@@ -1707,8 +1881,7 @@ impl EscrowContract {
         }
         
         // STATE VALIDATION: Job must be Funded or InProgress, and not disputed
-        require_state_funded_or_in_progress(&job)?;
-        require_state_not_disputed(&job)?;
+        let next = state::transition(&job.status, JobEvent::Progress)?;
 
         let mut milestones = job.milestones.clone();
         let milestone = milestones
@@ -1721,9 +1894,7 @@ impl EscrowContract {
             return Err(EscrowError::InvalidStatus);
         }
 
-        if env.ledger().timestamp() > milestone.deadline {
-            return Err(EscrowError::MilestoneDeadlineExceeded);
-        }
+        let late = deadlines::check_submission_deadline(&env, &milestone)?;
 
         let updated = Milestone {
             id: milestone.id,
@@ -1731,12 +1902,15 @@ impl EscrowContract {
             amount: milestone.amount,
             status: MilestoneStatus::Submitted,
             deadline: milestone.deadline,
+            token: milestone.token.clone(),
+            released: milestone.released,
         };
         milestones.set(milestone_id, updated);
 
         job.milestones = milestones;
-        job.status = JobStatus::InProgress;
+        job.status = next;
         env.storage().persistent().set(&get_job_key(job_id), &job);
+        status_index::sync(&env, &job);
         bump_job_ttl(&env, job_id);
 
         let submitted_key = DataKey::MilestoneSubmittedAt(job_id, milestone_id);
@@ -1753,6 +1927,9 @@ impl EscrowContract {
         if env.storage().persistent().has(&auto_key) {
             env.storage().persistent().remove(&auto_key);
         }
+        deadlines::record_submission(&env, job_id, milestone_id, &milestone, late);
+        rejections::on_submitted(&env, job_id, milestone_id);
+        changes::record(&env, job_id, changes::ChangeKind::MilestoneSubmitted);
 
         Ok(())
     }
@@ -1766,7 +1943,7 @@ impl EscrowContract {
     ) -> Result<(), EscrowError> {
         bump_escrow_ttl(&env, job_id);
         client.require_auth();
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Payouts)?;
 
         let mut job: Job = env
             .storage()
@@ -1779,8 +1956,8 @@ impl EscrowContract {
             return Err(EscrowError::Unauthorized);
         }
 
-        // STATE VALIDATION: Cannot approve milestones while disputed
-        require_state_not_disputed(&job)?;
+        // STATE VALIDATION: Only Funded or InProgress jobs take approvals
+        let next = state::transition(&job.status, JobEvent::Progress)?;
 
         let mut milestones = job.milestones.clone();
         let milestone = milestones
@@ -1797,16 +1974,17 @@ impl EscrowContract {
             amount: milestone.amount,
             status: MilestoneStatus::Approved,
             deadline: milestone.deadline,
+            token: milestone.token.clone(),
+            released: milestone.released,
         };
         milestones.set(milestone_id, updated);
         job.milestones = milestones.clone();
 
         // Keep the job in InProgress; complete_job will finalize payment and transition to Completed.
-        if job.status != JobStatus::InProgress {
-            job.status = JobStatus::InProgress;
-        }
+        job.status = next;
 
         env.storage().persistent().set(&get_job_key(job_id), &job);
+        status_index::sync(&env, &job);
         bump_job_ttl(&env, job_id);
 
         let submitted_key = DataKey::MilestoneSubmittedAt(job_id, milestone_id);
@@ -1818,6 +1996,13 @@ impl EscrowContract {
             env.storage().persistent().remove(&auto_key);
         }
 
+        changes::record(&env, job_id, changes::ChangeKind::MilestoneApproved);
+        checkpoints::on_approval(&env, &job);
+        early_bonus::on_approval(&env, &job);
+
+        let (fee_bps, fee_amount, net_amount) = fees::quote_fee(&env, job_id, milestone.amount);
+        fees::record_payout(&env, job_id, milestone_id, fee_bps, fee_amount, net_amount);
+
         // Emit milestone approved event
         env.events().publish(
             (symbol_short!("escrow"), symbol_short!("milestone")),
@@ -1827,6 +2012,9 @@ impl EscrowContract {
                 client,
                 job.freelancer.clone(),
                 milestone.amount,
+                fee_bps,
+                fee_amount,
+                net_amount,
             ),
         );
 
@@ -1844,7 +2032,7 @@ impl EscrowContract {
     ) -> Result<i128, EscrowError> {
         bump_escrow_ttl(&env, job_id);
         client.require_auth();
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Payouts)?;
 
         let mut job: Job = env
             .storage()
@@ -1857,12 +2045,15 @@ impl EscrowContract {
             return Err(EscrowError::Unauthorized);
         }
 
-        // STATE VALIDATION: Cannot approve milestones while disputed
-        require_state_not_disputed(&job)?;
+        // STATE VALIDATION: Only Funded or InProgress jobs take approvals
+        let next = state::transition(&job.status, JobEvent::Progress)?;
 
         // Validate all milestone indices before making any state changes
         let mut milestones = job.milestones.clone();
         let mut total_released: i128 = 0;
+        let mut total_fee: i128 = 0;
+        let mut total_net: i128 = 0;
+        let mut fee_bps = 0;
 
         for i in milestone_indices.iter() {
             let index = i;
@@ -1882,6 +2073,11 @@ impl EscrowContract {
 
             // Release payment for this milestone
             total_released += milestone.amount;
+            let (bps, fee_amount, net_amount) = fees::quote_fee(&env, job_id, milestone.amount);
+            fees::record_payout(&env, job_id, index, bps, fee_amount, net_amount);
+            fee_bps = bps;
+            total_fee += fee_amount;
+            total_net += net_amount;
 
             let updated = Milestone {
                 id: milestone.id,
@@ -1889,6 +2085,8 @@ impl EscrowContract {
                 amount: milestone.amount,
                 status: MilestoneStatus::Approved,
                 deadline: milestone.deadline,
+                token: milestone.token.clone(),
+                released: milestone.released,
             };
             milestones.set(index, updated);
 
@@ -1905,13 +2103,16 @@ impl EscrowContract {
         job.milestones = milestones.clone();
 
         // Keep the job in InProgress; complete_job will finalize payment and transition to Completed.
-        if job.status != JobStatus::InProgress {
-            job.status = JobStatus::InProgress;
-        }
+        job.status = next;
 
         env.storage().persistent().set(&get_job_key(job_id), &job);
+        status_index::sync(&env, &job);
         bump_job_ttl(&env, job_id);
 
+        changes::record(&env, job_id, changes::ChangeKind::MilestoneApproved);
+        checkpoints::on_approval(&env, &job);
+        early_bonus::on_approval(&env, &job);
+
         // Emit batch approval event
         env.events().publish(
             (symbol_short!("escrow"), symbol_short!("batch")),
@@ -1921,6 +2122,9 @@ impl EscrowContract {
                 total_released,
                 job.client.clone(),
                 job.freelancer.clone(),
+                fee_bps,
+                total_fee,
+                total_net,
             ),
         );
 
@@ -1949,6 +2153,7 @@ impl EscrowContract {
         if caller != job.client && caller != job.freelancer {
             return Err(EscrowError::Unauthorized);
         }
+        state::transition(&job.status, JobEvent::Work)?;
 
         let milestone = job
             .milestones
@@ -1997,7 +2202,7 @@ impl EscrowContract {
     ) -> Result<(), EscrowError> {
         bump_escrow_ttl(&env, job_id);
         caller.require_auth();
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Payouts)?;
 
         let mut job: Job = env
             .storage()
@@ -2010,11 +2215,11 @@ impl EscrowContract {
             return Err(EscrowError::Unauthorized);
         }
 
-        // STATE VALIDATION: Cannot finalize inactivity approval while disputed
-        require_state_not_disputed(&job)?;
+        // STATE VALIDATION: Only Funded or InProgress jobs take approvals
+        let next = state::transition(&job.status, JobEvent::Progress)?;
 
-        let mut milestones = job.milestones.clone();
-        let milestone = milestones
+        let milestone = job
+            .milestones
             .get(milestone_id)
             .ok_or(EscrowError::MilestoneNotFound)?;
         if milestone.status != MilestoneStatus::Submitted {
@@ -2043,61 +2248,12 @@ impl EscrowContract {
             return Err(EscrowError::InvalidStatus);
         }
 
-        // Release payment for this milestone (same logic as client approval).
-        let token_client = token::Client::new(&env, &job.token);
-
-        let fee_bps: u32 = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("FEE"))
-            .unwrap_or(0);
-        let treasury: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("TRE"))
-            .unwrap_or(env.current_contract_address());
-
-        let fee_amount = (milestone.amount * fee_bps as i128) / 10_000;
-        let freelancer_amount = milestone.amount - fee_amount;
-
-        if fee_amount > 0 {
-            token_client.transfer(&env.current_contract_address(), &treasury, &fee_amount);
-            env.events().publish(
-                (symbol_short!("escrow"), symbol_short!("fee")),
-                (job_id, milestone_id, fee_amount, treasury.clone()),
-            );
-        }
-
-        token_client.transfer(
-            &env.current_contract_address(),
-            &job.freelancer,
-            &freelancer_amount,
-        );
-
-        let updated = Milestone {
-            id: milestone.id,
-            description: milestone.description.clone(),
-            amount: milestone.amount,
-            status: MilestoneStatus::Approved,
-            deadline: milestone.deadline,
-        };
-        milestones.set(milestone_id, updated);
-        job.milestones = milestones.clone();
-
-        // Keep the job in InProgress; complete_job will finalize payment and transition to Completed.
-        if job.status != JobStatus::InProgress {
-            job.status = JobStatus::InProgress;
-        }
-
-        env.storage().persistent().set(&get_job_key(job_id), &job);
-        bump_job_ttl(&env, job_id);
-
-        env.storage().persistent().remove(&submitted_key);
-        env.storage().persistent().remove(&auto_key);
+        let (fee_bps, fee_amount, freelancer_amount) =
+            approve_unreviewed(&env, &mut job, &milestone, next);
 
         env.events().publish(
             (symbol_short!("escrow"), Symbol::new(&env, "inact_final")),
-            (job_id, milestone_id, caller),
+            (job_id, milestone_id, caller, fee_bps, fee_amount, freelancer_amount),
         );
 
         Ok(())
@@ -2105,16 +2261,16 @@ impl EscrowContract {
 
     /// Client releases a partial payment for a submitted milestone.
     ///
-    /// `amount` must be > 0 and <= the milestone's current stored amount.
-    /// After the call the milestone's `amount` is reduced by `amount`.
-    /// * If the remaining balance reaches 0 the status transitions to `Approved`.
+    /// `amount` must be > 0 and <= what the milestone has left unpaid.
+    /// After the call the milestone's `released` grows by `amount`.
+    /// * If the whole milestone is now paid the status transitions to `Approved`.
     /// * Otherwise the status is set to `PartiallyPaid` so further payments
     ///   (partial or full) can be made later.
     ///
     /// # Errors
     /// * `Unauthorized`          — caller is not the job's client.
     /// * `InvalidStatus`         — job is disputed, or milestone is not Submitted / PartiallyPaid.
-    /// * `InvalidPartialAmount`  — amount <= 0 or amount > milestone.amount.
+    /// * `InvalidPartialAmount`  — amount <= 0 or amount > milestone.amount - milestone.released.
     pub fn release_partial_payment(
         env: Env,
         job_id: u64,
@@ -2124,89 +2280,32 @@ impl EscrowContract {
         nonce: u64,
     ) -> Result<(), EscrowError> {
         consume_nonce(&env, &client, &Symbol::new(&env, "partial_pmt"), nonce)?;
-        bump_escrow_ttl(&env, job_id);
-        client.require_auth();
-        require_not_paused(&env)?;
-
-        let mut job: Job = env
-            .storage()
-            .persistent()
-            .get(&get_job_key(job_id))
-            .ok_or(EscrowError::JobNotFound)?;
-        bump_job_ttl(&env, job_id);
-
-        if job.client != client {
-            return Err(EscrowError::Unauthorized);
-        }
-        
-        // STATE VALIDATION: Cannot release partial payment while disputed
-        require_state_not_disputed(&job)?;
-
-        let mut milestones = job.milestones.clone();
-        let milestone = milestones
-            .get(milestone_index)
-            .ok_or(EscrowError::MilestoneNotFound)?;
-
-        // Only allow partial payment on a Submitted or already-PartiallyPaid milestone.
-        if milestone.status != MilestoneStatus::Submitted
-            && milestone.status != MilestoneStatus::PartiallyPaid
-        {
-            return Err(EscrowError::InvalidStatus);
-        }
+        release_partial(&env, job_id, milestone_index, client, |_| amount)
+    }
 
-        // Validate the requested amount.
-        if amount <= 0 || amount > milestone.amount {
+    /// Client releases `bps` basis points of a submitted milestone's full
+    /// amount, leaving the rest pending for a later
+    /// approval or a dispute. Works like `release_partial_payment` otherwise;
+    /// the milestone's `released` field shows what has been paid so far.
+    ///
+    /// # Errors
+    /// * `Unauthorized`          — caller is not the job's client.
+    /// * `InvalidStatus`         — job is disputed, or milestone is not Submitted / PartiallyPaid.
+    /// * `InvalidPartialAmount`  — `bps` is zero or above 10_000, rounds to nothing, or
+    ///                             exceeds what is left unpaid.
+    pub fn approve_milestone_partial(
+        env: Env,
+        job_id: u64,
+        milestone_id: u32,
+        bps: u32,
+        client: Address,
+    ) -> Result<(), EscrowError> {
+        if bps == 0 || bps > 10_000 {
             return Err(EscrowError::InvalidPartialAmount);
         }
-
-        // Release partial payment to freelancer (no fee deducted per-milestone;
-        // the protocol fee is taken from remaining escrow on complete_job).
-        let token_client = token::Client::new(&env, &job.token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &job.freelancer,
-            &amount,
-        );
-
-        // Track cumulative disbursement so a later revision can't undercut what's
-        // already been paid out for this milestone.
-        record_milestone_disbursed(&env, job_id, milestone.id, amount);
-
-        // Deduct paid amount from milestone; transition status accordingly.
-        let remaining = milestone.amount - amount;
-        let new_status = if remaining == 0 {
-            MilestoneStatus::Approved
-        } else {
-            MilestoneStatus::PartiallyPaid
-        };
-
-        let updated = Milestone {
-            id: milestone.id,
-            description: milestone.description.clone(),
-            amount: remaining,
-            status: new_status,
-            deadline: milestone.deadline,
-        };
-        milestones.set(milestone_index, updated);
-        job.milestones = milestones.clone();
-
-        // Keep the job in InProgress; complete_job will finalize payment and transition to Completed.
-        if job.status != JobStatus::InProgress {
-            job.status = JobStatus::InProgress;
-        }
-
-        env.storage().persistent().set(&get_job_key(job_id), &job);
-        bump_job_ttl(&env, job_id);
-
-        // Emit PartialPaymentReleased event.
-        let client = job.client.clone();
-        let freelancer = job.freelancer.clone();
-        env.events().publish(
-            (symbol_short!("escrow"), Symbol::new(&env, "partial_pmt")),
-            (job_id, milestone_index, amount, client, freelancer),
-        );
-
-        Ok(())
+        release_partial(&env, job_id, milestone_id, client, |m| {
+            m.amount * bps as i128 / 10_000
+        })
     }
 
     /// Finalize a job when all milestones are approved, deducting the protocol fee
@@ -2226,7 +2325,8 @@ impl EscrowContract {
         caller: Address,
     ) -> Result<(), EscrowError> {
         caller.require_auth();
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Payouts)?;
+        hourly::enforce_milestone_flow(&env, job_id);
 
         let mut job: Job = env
             .storage()
@@ -2239,9 +2339,7 @@ impl EscrowContract {
             return Err(EscrowError::Unauthorized);
         }
 
-        if job.status != JobStatus::InProgress {
-            return Err(EscrowError::InvalidStatus);
-        }
+        let next = state::transition(&job.status, JobEvent::Complete)?;
 
         let all_approved = job
             .milestones
@@ -2262,8 +2360,9 @@ impl EscrowContract {
             .get(&symbol_short!("TRE"))
             .unwrap_or(env.current_contract_address());
 
-        let fee_amount = (job.total_amount * fee_bps as i128) / 10_000;
-        let freelancer_amount = job.total_amount - fee_amount;
+        let (fee_amount, fee_deducted) =
+            fees::release_fee(&env, job_id, job.total_amount, fee_bps);
+        let freelancer_amount = job.total_amount - fee_deducted;
 
         let token_client = token::Client::new(&env, &job.token);
 
@@ -2276,16 +2375,15 @@ impl EscrowContract {
         }
 
         if freelancer_amount > 0 {
-            token_client.transfer(
-                &env.current_contract_address(),
-                &job.freelancer,
-                &freelancer_amount,
-            );
+            owed::pay_freelancer(&env, &job, freelancer_amount);
         }
 
-        job.status = JobStatus::Completed;
+        job.status = next;
+        job.completed_at = Some(env.ledger().timestamp());
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        bonds::return_bond(&env, &job);
+        on_job_closed(&env, &job);
 
         env.events().publish(
             (symbol_short!("escrow"), Symbol::new(&env, "fee_taken")),
@@ -2320,7 +2418,7 @@ impl EscrowContract {
         consume_nonce(&env, &client, &Symbol::new(&env, "release_ms"), nonce)?;
         bump_escrow_ttl(&env, job_id);
         client.require_auth();
-        require_not_paused(&env)?;
+        pause::require_scope_open(&env, PauseScope::Payouts)?;
 
         let mut job: Job = env
             .storage()
@@ -2332,9 +2430,7 @@ impl EscrowContract {
         if job.client != client {
             return Err(EscrowError::Unauthorized);
         }
-        if job.status == JobStatus::Disputed {
-            return Err(EscrowError::InvalidStatus);
-        }
+        let next = state::transition(&job.status, JobEvent::Progress)?;
 
         let mut milestones = job.milestones.clone();
         let milestone = milestones
@@ -2357,7 +2453,7 @@ impl EscrowContract {
                 &Symbol::new(&env, "twap"),
                 soroban_sdk::vec![
                     &env,
-                    job.token.clone().into_val(&env),
+                    milestone_tokens::token_of(&job, &milestone).into_val(&env),
                     TWAP_SAMPLE_LEDGERS.into_val(&env),
                 ],
             );
@@ -2378,34 +2474,38 @@ impl EscrowContract {
         }
 
         // Compute fee and net freelancer amount.
-        let token_client = token::Client::new(&env, &job.token);
-        let fee_bps: u32 = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("FEE"))
-            .unwrap_or(0);
-        let treasury: Address = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("TRE"))
-            .unwrap_or(env.current_contract_address());
+        let (fee_bps, fee_amount, freelancer_amount) = if milestone_tokens::in_job_token(&milestone) {
+            let token_client = token::Client::new(&env, &job.token);
+            let fee_bps: u32 = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("FEE"))
+                .unwrap_or(0);
+            let treasury: Address = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("TRE"))
+                .unwrap_or(env.current_contract_address());
 
-        let fee_amount = (milestone.amount * fee_bps as i128) / 10_000;
-        let freelancer_amount = milestone.amount - fee_amount;
+            let (fee_amount, fee_deducted) =
+                fees::release_fee(&env, job_id, milestone.amount, fee_bps);
+            let freelancer_amount = milestone.amount - fee_deducted;
 
-        if fee_amount > 0 {
-            token_client.transfer(&env.current_contract_address(), &treasury, &fee_amount);
-            env.events().publish(
-                (symbol_short!("escrow"), symbol_short!("fee")),
-                (job_id, milestone_index, fee_amount, treasury.clone()),
-            );
-        }
+            if fee_amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &treasury, &fee_amount);
+                env.events().publish(
+                    (symbol_short!("escrow"), symbol_short!("fee")),
+                    (job_id, milestone_index, fee_amount, treasury.clone()),
+                );
+            }
 
-        token_client.transfer(
-            &env.current_contract_address(),
-            &job.freelancer,
-            &freelancer_amount,
-        );
+            owed::pay_freelancer(&env, &job, freelancer_amount);
+            let fee_bps = fees::quote_fee(&env, job_id, milestone.amount).0;
+            (fee_bps, fee_amount, freelancer_amount)
+        } else {
+            milestone_tokens::release(&env, &job, &milestone)
+        };
+        fees::record_payout(&env, job_id, milestone_index, fee_bps, fee_amount, freelancer_amount);
 
         // Track cumulative disbursement (full nominal milestone amount — fee included —
         // since that value has left escrow for good) so a later revision can't undercut it.
@@ -2417,20 +2517,34 @@ impl EscrowContract {
             amount: milestone.amount,
             status: MilestoneStatus::Approved,
             deadline: milestone.deadline,
+            token: milestone.token.clone(),
+            released: milestone.released + milestone.amount,
         };
         milestones.set(milestone_index, updated);
         job.milestones = milestones.clone();
 
-        // Check if all milestones are now fully paid.
+        // Check if all milestones are now fully paid. A running retainer stays
+        // open for the next period's milestone.
         let all_approved = milestones
             .iter()
-            .all(|m| m.status == MilestoneStatus::Approved);
+            .all(|m| m.status == MilestoneStatus::Approved)
+            && !recurring::is_running(&env, job_id);
+        job.status = next;
         if all_approved {
-            job.status = JobStatus::Completed;
+            job.status = state::transition(&job.status, JobEvent::Complete)?;
+            job.completed_at = Some(env.ledger().timestamp());
         }
 
         env.storage().persistent().set(&get_job_key(job_id), &job);
+        status_index::sync(&env, &job);
         bump_job_ttl(&env, job_id);
+        changes::record(&env, job_id, changes::ChangeKind::PaymentReleased);
+        checkpoints::on_approval(&env, &job);
+        early_bonus::on_approval(&env, &job);
+        if all_approved {
+            bonds::return_bond(&env, &job);
+            on_job_closed(&env, &job);
+        }
 
         // Clean up auxiliary keys.
         let submitted_key = DataKey::MilestoneSubmittedAt(job_id, milestone_index);
@@ -2451,6 +2565,9 @@ impl EscrowContract {
                 client,
                 job.freelancer.clone(),
                 milestone.amount,
+                fee_bps,
+                fee_amount,
+                freelancer_amount,
             ),
         );
 
@@ -2496,9 +2613,7 @@ impl EscrowContract {
         }
 
         // STATE VALIDATION: Job must be in a cancellable state (Funded or InProgress)
-        require_state_cancellable(&job)?;
-        // Additional guard: explicitly reject if Disputed
-        require_state_not_disputed(&job)?;
+        let next = state::transition(&job.status, JobEvent::Cancel)?;
 
         // Guard: reject cancellation if any milestone is actively InProgress or Submitted.
         // The client must open a dispute for in-flight work instead.
@@ -2511,24 +2626,23 @@ impl EscrowContract {
 
         // Refund the remaining escrowed amount (total minus already-approved milestones).
         // Pay already-approved milestones to the freelancer, since payment only happens in complete_job.
-        let approved_amount: i128 = job
-            .milestones
-            .iter()
-            .filter(|m| m.status == MilestoneStatus::Approved)
-            .map(|m| m.amount)
-            .sum();
-        let refund = job.total_amount - approved_amount;
+        let (released, approved_amount) = settled_value(&job);
+        let refund = job.total_amount - released - approved_amount;
         let token_client = token::Client::new(&env, &job.token);
         if approved_amount > 0 {
-            token_client.transfer(&env.current_contract_address(), &job.freelancer, &approved_amount);
+            owed::pay_freelancer(&env, &job, approved_amount);
         }
         if refund > 0 {
             token_client.transfer(&env.current_contract_address(), &client, &refund);
         }
+        // Return the up-front fee on undelivered milestones.
+        fees::settle_fee(&env, &job, released + approved_amount);
 
-        job.status = JobStatus::Cancelled;
+        job.status = next;
+        job.cancelled_at = Some(env.ledger().timestamp());
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        on_job_closed(&env, &job);
 
         // Emit JobCancelled event with job_id, client address, and refund_amount.
         env.events().publish(
@@ -2560,7 +2674,7 @@ impl EscrowContract {
         }
 
         // STATE VALIDATION: Only allow refund for Funded or InProgress jobs
-        require_state_funded_or_in_progress(&job)?;
+        let next = state::transition(&job.status, JobEvent::Cancel)?;
 
         // Ensure the grace period after deadline has elapsed
         let refund_eligible_at = job.job_deadline + job.auto_refund_after;
@@ -2578,31 +2692,30 @@ impl EscrowContract {
         }
 
         // Calculate refund: total minus already-approved milestone amounts
-        let approved_amount: i128 = job
-            .milestones
-            .iter()
-            .filter(|m| m.status == MilestoneStatus::Approved)
-            .map(|m| m.amount)
-            .sum();
+        let (released, approved_amount) = settled_value(&job);
 
-        let refund = job.total_amount - approved_amount;
+        let refund = job.total_amount - released - approved_amount;
 
         // If there's nothing to distribute (all approved, nothing to refund), still
         // pay the approved amount to the freelancer before returning.
         let token_client = token::Client::new(&env, &job.token);
 
         if approved_amount > 0 {
-            token_client.transfer(&env.current_contract_address(), &job.freelancer, &approved_amount);
+            owed::pay_freelancer(&env, &job, approved_amount);
         }
+        // Return the up-front fee on undelivered milestones.
+        fees::settle_fee(&env, &job, released + approved_amount);
 
         if refund <= 0 {
             if approved_amount == 0 {
                 return Err(EscrowError::NoRefundDue);
             }
             // approved_amount paid above — no refund due to client, just update status
-            job.status = JobStatus::Cancelled;
+            job.status = next;
+            job.cancelled_at = Some(env.ledger().timestamp());
             env.storage().persistent().set(&get_job_key(job_id), &job);
             bump_job_ttl(&env, job_id);
+            on_job_closed(&env, &job);
             env.events().publish(
                 (symbol_short!("escrow"), symbol_short!("refund")),
                 (job_id, 0_i128, client, job.freelancer),
@@ -2612,10 +2725,14 @@ impl EscrowContract {
 
         // Transfer refund to client
         token_client.transfer(&env.current_contract_address(), &client, &refund);
+        // The freelancer walked away from undelivered work.
+        bonds::forfeit_if_abandoned(&env, &job);
 
-        job.status = JobStatus::Cancelled;
+        job.status = next;
+        job.cancelled_at = Some(env.ledger().timestamp());
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        on_job_closed(&env, &job);
 
         // Emit event
         env.events().publish(
@@ -2665,21 +2782,11 @@ impl EscrowContract {
         bump_job_ttl(&env, job_id);
 
         // Only allow refund on jobs that actually hold escrowed funds.
-        if job.status != JobStatus::Funded
-            && job.status != JobStatus::InProgress
-            && job.status != JobStatus::Disputed
-        {
-            return Err(EscrowError::InvalidStatus);
-        }
+        state::transition(&job.status, JobEvent::Adjust)?;
 
         // Calculate remaining locked balance (total minus already-approved milestones).
-        let approved_amount: i128 = job
-            .milestones
-            .iter()
-            .filter(|m| m.status == MilestoneStatus::Approved)
-            .map(|m| m.amount)
-            .sum();
-        let remaining = job.total_amount - approved_amount;
+        let (released, approved_amount) = settled_value(&job);
+        let remaining = job.total_amount - released - approved_amount;
 
         if amount <= 0 || amount > remaining {
             return Err(EscrowError::InsufficientFunds);
@@ -2783,14 +2890,19 @@ impl EscrowContract {
         if caller != job.client && caller != job.freelancer {
             return Err(EscrowError::NotAuthorizedForProposalAction);
         }
+        // Revisions re-price milestones in the job's token only.
+        milestone_tokens::reject_overrides(&env, &job);
+        hourly::enforce_milestone_flow(&env, job_id);
+        if new_milestones.iter().any(|m| !milestone_tokens::in_job_token(&m)) {
+            panic_with_error!(&env, milestone_tokens::MilestoneTokenError::Unsupported);
+        }
 
         // Freeze revisions while a dispute is active so later dispute
         // resolution still operates on the original milestone set and total, and
         // reject on any terminal state (Completed, Cancelled, Expired) — the whole
         // revision flow assumes the job is still actively in progress and nothing
         // has been finalized yet.
-        require_state_not_terminal(&job)?;
-        require_state_not_disputed(&job)?;
+        state::transition(&job.status, JobEvent::Amend)?;
 
         // 3. Assert no existing Pending proposal, allowing overwrite of expired ones
         if let Some(existing) = env
@@ -2919,6 +3031,9 @@ impl EscrowContract {
             .ok_or(EscrowError::JobNotFound)?;
         bump_job_ttl(&env, job_id);
 
+        milestone_tokens::reject_overrides(&env, &job);
+        hourly::enforce_milestone_flow(&env, job_id);
+
         // 2. Load proposal — must exist and be Pending
         let mut proposal = env
             .storage()
@@ -2941,8 +3056,7 @@ impl EscrowContract {
         // The job may have moved into a dispute or a terminal state after the proposal
         // was created but before it was accepted — re-check here, right before mutating
         // anything, rather than trusting the state that held at propose time.
-        require_state_not_terminal(&job)?;
-        require_state_not_disputed(&job)?;
+        state::transition(&job.status, JobEvent::Amend)?;
 
         // Guard against a revision silently erasing money already paid to the freelancer:
         // for every existing milestone that has disbursed funds (via release_partial_payment
@@ -2962,6 +3076,21 @@ impl EscrowContract {
             }
         }
 
+        // Milestones with no disbursed funds must respect the token's minimum amount,
+        // the same floor `create_job` applies.
+        for new_milestone in proposal.new_milestones.iter() {
+            if get_milestone_disbursed(&env, job_id, new_milestone.id) <= 0 {
+                limits::enforce_min_amount(&env, &job.token, new_milestone.amount);
+            }
+        }
+        attestation::enforce_attestation(
+            &env,
+            &job.token,
+            proposal.new_total,
+            &job.client,
+            &job.freelancer,
+        );
+
         // 4. Snapshot current milestones to revision history BEFORE overwriting
         let mut history: Vec<MilestoneRevision> = env
             .storage()
@@ -2971,7 +3100,7 @@ impl EscrowContract {
 
         let revision_index = history.len();
         let snapshot = MilestoneRevision {
-            revision_index: revision_index as u32,
+            revision_index,
             milestones: job.milestones.clone(),
             total_amount: job.total_amount,
             revised_at: env.ledger().timestamp(),
@@ -3025,10 +3154,9 @@ impl EscrowContract {
         // delta == 0: no token movement needed
 
         // 7. Update job milestones and total. For any milestone id that already carries
-        // disbursed funds, rebase the incoming entry onto the real remaining balance
-        // (new nominal value minus what's already been paid) instead of trusting the
-        // proposer's raw entry verbatim — otherwise a later release could pay out the
-        // full new amount on top of funds already sent to the freelancer.
+        // disbursed funds, carry what's already been paid into `released` instead of
+        // trusting the proposer's raw entry verbatim — otherwise a later release could
+        // pay out the full new amount on top of funds already sent to the freelancer.
         let mut final_milestones: Vec<Milestone> = Vec::new(&env);
         for new_milestone in proposal.new_milestones.iter() {
             let was_existing = job.milestones.iter().any(|old| old.id == new_milestone.id);
@@ -3038,29 +3166,36 @@ impl EscrowContract {
                 0
             };
             if disbursed > 0 {
-                let remaining = new_milestone.amount - disbursed; // >= 0, enforced above
-                let (status, amount) = if remaining <= 0 {
-                    (MilestoneStatus::Approved, 0)
+                // new_milestone.amount >= disbursed, enforced above
+                let status = if new_milestone.amount <= disbursed {
+                    MilestoneStatus::Approved
                 } else {
-                    (MilestoneStatus::PartiallyPaid, remaining)
+                    MilestoneStatus::PartiallyPaid
                 };
                 final_milestones.push_back(Milestone {
                     id: new_milestone.id,
                     description: new_milestone.description.clone(),
-                    amount,
+                    amount: new_milestone.amount,
                     status,
                     deadline: new_milestone.deadline,
+                    token: None,
+                    released: disbursed,
                 });
             } else {
-                final_milestones.push_back(new_milestone.clone());
+                final_milestones.push_back(Milestone {
+                    released: 0,
+                    ..new_milestone.clone()
+                });
             }
         }
+        descriptions::retain_unchanged(&env, job_id, &job.milestones, &final_milestones);
         job.milestones = final_milestones;
         job.total_amount = new_total;
 
         // 8. Persist updated job
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        deadlines::index_job_deadlines(&env, &job);
 
         // 9. Update proposal status to Accepted
         proposal.status = ProposalStatus::Accepted;
@@ -3073,6 +3208,8 @@ impl EscrowContract {
             TTL_EXTEND_TO_LEDGERS,
         );
 
+        changes::record(&env, job_id, changes::ChangeKind::Revised);
+
         // 10. Emit event
         env.events().publish(
             (Symbol::new(&env, "revision_accepted"),),
@@ -3353,18 +3490,13 @@ impl EscrowContract {
         }
 
         // STATE VALIDATION: Job must be in an expirable state (not terminal)
-        require_state_expirable(&job)?;
+        let next = state::transition(&job.status, JobEvent::Expire)?;
 
         // Refund remaining escrowed balance (total minus already-approved milestones).
         // Also pay the approved milestone amounts to the freelancer, since payment
         // only happens during complete_job.
-        let approved_amount: i128 = job
-            .milestones
-            .iter()
-            .filter(|m| m.status == MilestoneStatus::Approved)
-            .map(|m| m.amount)
-            .sum();
-        let refund = job.total_amount - approved_amount;
+        let (released, approved_amount) = settled_value(&job);
+        let refund = job.total_amount - released - approved_amount;
 
         let token_client = token::Client::new(&env, &job.token);
 
@@ -3373,7 +3505,7 @@ impl EscrowContract {
                 || job.status == JobStatus::InProgress
                 || job.status == JobStatus::Disputed)
         {
-            token_client.transfer(&env.current_contract_address(), &job.freelancer, &approved_amount);
+            owed::pay_freelancer(&env, &job, approved_amount);
         }
 
         // Only transfer if funds are actually held in escrow (job was funded).
@@ -3384,10 +3516,16 @@ impl EscrowContract {
         {
             token_client.transfer(&env.current_contract_address(), &job.client, &refund);
         }
+        // A funded job left with overdue undelivered work counts as abandoned;
+        // a disputed one is settled by the dispute instead.
+        if matches!(job.status, JobStatus::Funded | JobStatus::InProgress) {
+            bonds::forfeit_if_abandoned(&env, &job);
+        }
 
-        job.status = JobStatus::Expired;
+        job.status = next;
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        on_job_closed(&env, &job);
 
         env.events().publish(
             (symbol_short!("escrow"), Symbol::new(&env, "job_expired")),
@@ -3409,6 +3547,41 @@ impl EscrowContract {
         Ok(job)
     }
 
+    /// Whether job `job_id` was completed between exactly `client` and
+    /// `freelancer` and was worth at least `min_amount`, so other contracts can
+    /// check work history with one typed call. Archived jobs are checked
+    /// against their summary.
+    pub fn verify_completion(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        freelancer: Address,
+        min_amount: i128,
+    ) -> bool {
+        let job_key = get_job_key(job_id);
+        let (job_client, job_freelancer, status, total_amount) =
+            match env.storage().persistent().get::<_, Job>(&job_key) {
+                Some(job) => (job.client, job.freelancer, job.status, job.total_amount),
+                None => match env
+                    .storage()
+                    .persistent()
+                    .get::<_, archive::JobSummary>(&archive::ArchiveKey::Summary(job_id))
+                {
+                    Some(summary) => (
+                        summary.client,
+                        summary.freelancer,
+                        summary.status,
+                        summary.total_amount,
+                    ),
+                    None => return false,
+                },
+            };
+        status == JobStatus::Completed
+            && job_client == client
+            && job_freelancer == freelancer
+            && total_amount >= min_amount
+    }
+
     /// Get total number of jobs.
     pub fn get_job_count(env: Env) -> u64 {
         let count: u64 = env
@@ -3453,6 +3626,7 @@ impl EscrowContract {
 
         job.client.require_auth();
         job.freelancer.require_auth();
+        state::transition(&job.status, JobEvent::Amend)?;
 
         if new_deadline <= env.ledger().timestamp() {
             return Err(EscrowError::InvalidDeadline);
@@ -3469,6 +3643,8 @@ impl EscrowContract {
         job.milestones = milestones;
         env.storage().persistent().set(&get_job_key(job_id), &job);
         bump_job_ttl(&env, job_id);
+        deadlines::index_deadline(&env, job_id, new_deadline);
+        changes::record(&env, job_id, changes::ChangeKind::DeadlineExtended);
 
         // Emit deadline extension event
         env.events().publish(
@@ -3552,6 +3728,74 @@ impl EscrowContract {
 
 /// Reputation-weighted on-chain governance for protocol parameters (issue #899).
 mod governance;
+/// Per-token minimum milestone amounts.
+mod limits;
+/// Freelancer-invoiced off-milestone expenses.
+mod expenses;
+/// Deadline-bucketed index for at-risk milestone queries and submission grace.
+mod deadlines;
+/// Up-front platform fee accounting and refunds of unearned fees.
+mod fees;
+/// Optional freelancer performance bonds.
+mod bonds;
+/// Archival of finished jobs to compact summaries.
+mod archive;
+/// Ring buffer of recent job state changes for pollers.
+mod changes;
+/// Milestone descriptions stored as a hash plus off-chain URI.
+mod descriptions;
+/// Optional client satisfaction checkpoint halfway through a job.
+mod checkpoints;
+/// Migration of live jobs off a de-listed or frozen token.
+mod migrations;
+/// Reference-currency prices recorded at funding.
+mod display;
+/// Milestone rejections with structured reasons.
+mod rejections;
+/// Payout routing to a named beneficiary.
+mod payouts;
+/// Milestone approvals guarded against revision races.
+mod approvals;
+/// Private jobs left out of the public index views.
+mod visibility;
+/// Client-funded bonuses for milestones approved early.
+mod early_bonus;
+/// Indexes of jobs by client, by freelancer and by client and freelancer pair.
+mod pairs;
+/// Note hashes the parties anchor on a job and acknowledge.
+mod notes;
+/// Job status transition table consulted by every entry point.
+mod state;
+/// Freelancer payouts recorded as owed when their transfer fails.
+mod owed;
+/// Pauses scoped to job creation or payouts.
+mod pause;
+/// Open job postings that freelancers apply to.
+mod postings;
+/// Attestation gate for high-value jobs.
+mod attestation;
+/// Paged index of jobs by current status.
+mod status_index;
+/// Daily job lifecycle counts over a rolling window.
+mod stats;
+/// First-class native XLM jobs.
+mod native;
+/// Milestones paid in a token other than the job's.
+mod milestone_tokens;
+/// Per-job client review windows with permissionless auto-approval.
+mod review_window;
+/// Opt-in conversion of payouts into the freelancer's preferred token.
+mod conversion;
+/// Jobs indexed by a client-supplied external idempotency key.
+mod external_ids;
+/// Client bonuses paid on top of milestone payouts.
+mod tips;
+/// Hourly jobs billed through client-approved work logs.
+mod hourly;
+/// Retainers that renew an identical milestone every period.
+mod recurring;
+/// Client-signed funding intents executed later by relayers.
+mod funding_auth;
 
 #[cfg(test)]
 mod test;
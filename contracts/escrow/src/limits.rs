@@ -0,0 +1,225 @@
+//! Per-token minimum milestone amounts.
+//!
+//! Dust-sized milestones (a handful of stroops) pollute job indices and make
+//! basis-point fee math round down to zero. Signers can configure a minimum
+//! milestone amount per token with [`EscrowContract::set_min_amount`]. Because
+//! the floor is stored per token in that token's base units, it naturally
+//! accounts for tokens with different decimal precision (e.g. 7-decimal
+//! Stellar assets vs. 6-decimal bridged stablecoins).
+//!
+//! The floor is enforced wherever milestone amounts enter a job: `create_job`
+//! and revision acceptance (`accept_revision`). Tokens without a configured
+//! floor keep the existing behaviour (any positive amount is accepted).
+//!
+//! Signers can also cap how many jobs an address may have active at once, as
+//! client and as freelancer ([`EscrowContract::set_active_job_caps`]), so a
+//! compromised client key cannot lock funds into an unbounded number of jobs.
+//! A job counts as active from `fund_job` until it reaches a terminal state
+//! (disputed jobs still count). The cap is enforced at `fund_job`; jobs funded
+//! before caps were tracked are not counted.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address, Env,
+    Symbol,
+};
+
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Amount-limit errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 300 so they never overlap with
+/// `EscrowError` (1..=51) or `GovError` (200..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LimitsError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 300,
+    /// A milestone amount is below the configured minimum for its token.
+    BelowMinimumAmount = 301,
+    /// A configured limit is out of range (e.g. a negative minimum).
+    InvalidLimit = 302,
+    /// Funding the job would exceed the client's or freelancer's active job cap.
+    ActiveJobCapExceeded = 303,
+}
+
+/// Storage keys for amount limits.
+#[contracttype]
+#[derive(Clone)]
+pub enum LimitsKey {
+    /// Minimum milestone amount (`i128`, token base units) for a token.
+    MinAmount(Address),
+    /// Maximum active jobs per client (`u32`, instance); unset means no cap.
+    ClientJobCap,
+    /// Maximum active jobs per freelancer (`u32`, instance); unset means no cap.
+    FreelancerJobCap,
+    /// Number of active jobs an address is party to (`u32`).
+    ActiveJobs(Address),
+    /// Marks a job counted in `ActiveJobs`; removed when its slot is released.
+    CountedJob(u64),
+}
+
+/// Configured minimum milestone amount for `token`, or `0` when unset.
+pub(crate) fn min_amount(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&LimitsKey::MinAmount(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Abort with [`LimitsError::BelowMinimumAmount`] if `amount` is under the
+/// configured floor for `token`. Used from entry points that return
+/// `EscrowError`, which has no room for the new variant.
+pub(crate) fn enforce_min_amount(env: &Env, token: &Address, amount: i128) {
+    if amount < min_amount(env, token) {
+        panic_with_error!(env, LimitsError::BelowMinimumAmount);
+    }
+}
+
+fn job_cap(env: &Env, key: &LimitsKey) -> u32 {
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+fn active_job_count(env: &Env, address: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&LimitsKey::ActiveJobs(address.clone()))
+        .unwrap_or(0)
+}
+
+// Active job bookkeeping has to survive for as long as the jobs it counts,
+// which are only bumped when touched, so it is kept alive for as long as the
+// network allows rather than the 30 days used for per-job entries.
+fn extend_tracking_ttl(env: &Env, key: &LimitsKey) {
+    let max_ttl = env.storage().max_ttl();
+    env.storage()
+        .persistent()
+        .extend_ttl(key, max_ttl.min(crate::TTL_THRESHOLD_LEDGERS), max_ttl);
+}
+
+fn set_active_job_count(env: &Env, address: &Address, count: u32) {
+    let key = LimitsKey::ActiveJobs(address.clone());
+    env.storage().persistent().set(&key, &count);
+    extend_tracking_ttl(env, &key);
+}
+
+/// Count a newly funded job against its client and freelancer, aborting with
+/// [`LimitsError::ActiveJobCapExceeded`] if either would go over their cap.
+pub(crate) fn track_job_funded(env: &Env, job: &Job) {
+    let client_active = active_job_count(env, &job.client);
+    let freelancer_active = active_job_count(env, &job.freelancer);
+    let client_cap = job_cap(env, &LimitsKey::ClientJobCap);
+    let freelancer_cap = job_cap(env, &LimitsKey::FreelancerJobCap);
+    if (client_cap > 0 && client_active >= client_cap)
+        || (freelancer_cap > 0 && freelancer_active >= freelancer_cap)
+    {
+        panic_with_error!(env, LimitsError::ActiveJobCapExceeded);
+    }
+    set_active_job_count(env, &job.client, client_active + 1);
+    set_active_job_count(env, &job.freelancer, freelancer_active + 1);
+    let key = LimitsKey::CountedJob(job.id);
+    env.storage().persistent().set(&key, &true);
+    extend_tracking_ttl(env, &key);
+}
+
+/// Release a job's slot once it reaches a terminal state. No-op for jobs that
+/// were never counted (unfunded, or funded before tracking existed).
+pub(crate) fn track_job_closed(env: &Env, job: &Job) {
+    let key = LimitsKey::CountedJob(job.id);
+    if !env.storage().persistent().has(&key) {
+        return;
+    }
+    env.storage().persistent().remove(&key);
+    for address in [&job.client, &job.freelancer] {
+        let active = active_job_count(env, address);
+        if active > 0 {
+            set_active_job_count(env, address, active - 1);
+        }
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Set the minimum milestone amount for `token`, in the token's base units.
+    /// Registered multisig signers only. A `min` of `0` removes the floor.
+    pub fn set_min_amount(
+        env: Env,
+        admin: Address,
+        token: Address,
+        min: i128,
+    ) -> Result<(), LimitsError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(LimitsError::NotAdmin);
+        }
+        if min < 0 {
+            return Err(LimitsError::InvalidLimit);
+        }
+
+        let key = LimitsKey::MinAmount(token.clone());
+        if min == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &min);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "min_amount_set")),
+            (token, min, admin),
+        );
+        Ok(())
+    }
+
+    /// Return the minimum milestone amount for `token` (`0` when unset).
+    pub fn get_min_amount(env: Env, token: Address) -> i128 {
+        min_amount(&env, &token)
+    }
+
+    /// Cap the number of concurrently active jobs per client and per
+    /// freelancer. Registered multisig signers only. `0` removes a cap.
+    pub fn set_active_job_caps(
+        env: Env,
+        admin: Address,
+        client_cap: u32,
+        freelancer_cap: u32,
+    ) -> Result<(), LimitsError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(LimitsError::NotAdmin);
+        }
+        for (key, cap) in [
+            (LimitsKey::ClientJobCap, client_cap),
+            (LimitsKey::FreelancerJobCap, freelancer_cap),
+        ] {
+            if cap == 0 {
+                env.storage().instance().remove(&key);
+            } else {
+                env.storage().instance().set(&key, &cap);
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "job_caps_set")),
+            (client_cap, freelancer_cap, admin),
+        );
+        Ok(())
+    }
+
+    /// Return the `(client_cap, freelancer_cap)` pair (`0` when unset).
+    pub fn get_active_job_caps(env: Env) -> (u32, u32) {
+        (
+            job_cap(&env, &LimitsKey::ClientJobCap),
+            job_cap(&env, &LimitsKey::FreelancerJobCap),
+        )
+    }
+
+    /// Number of active jobs `address` is party to, as client or freelancer.
+    pub fn get_active_job_count(env: Env, address: Address) -> u32 {
+        active_job_count(&env, &address)
+    }
+}
@@ -0,0 +1,434 @@
+//! Moving a live job off a de-listed or frozen token.
+//!
+//! A job's escrow is held in one token for its whole life. If that token is
+//! removed from the allowlist or frozen by its issuer, the job can no longer be
+//! paid out. A signer proposes a replacement with
+//! [`EscrowContract::migrate_job_token`]: the new token, an exchange rate and
+//! where the new tokens come from ([`SwapSource`]):
+//!
+//! - **`Adapter`** — an allowlisted AMM adapter swaps the escrowed balance and
+//!   must return at least the agreed rate;
+//! - **`Liquidity`** — a liquidity provider pays in the new tokens at the agreed
+//!   rate and receives the old balance. If the old token is frozen and cannot
+//!   be transferred, the old balance stays stranded in the contract and is
+//!   reported in the `tok_swap` event.
+//!
+//! Nothing moves until both the client and the freelancer have called
+//! [`EscrowContract::accept_token_migration`]. The swap then re-denominates the
+//! job — milestone amounts, totals and disbursement records — at the agreed
+//! rate. Jobs with a performance bond, up-front fee ledger, expense balance,
+//! held early bonus or owed payout must settle those first, as they are held
+//! in the old token as well.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env, IntoVal,
+    Symbol, Vec,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Token migration errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1000, clear of `CheckpointError`
+/// (900..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MigrationError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 1000,
+    /// The contract is paused.
+    ContractPaused = 1001,
+    /// No job exists with the given ID.
+    JobNotFound = 1002,
+    /// Caller is neither the job's client nor its freelancer.
+    Unauthorized = 1003,
+    /// Only jobs that are not yet finished or disputed can be migrated.
+    InvalidStatus = 1004,
+    /// The exchange rate must be strictly positive.
+    InvalidRate = 1005,
+    /// The new token is the job's current token.
+    SameToken = 1006,
+    /// The new token is not on the token allowlist.
+    TokenNotAllowed = 1007,
+    /// The swap adapter is not on the adapter allowlist.
+    AdapterNotAllowed = 1008,
+    /// The job has a bond, fee ledger, expense balance, early bonus or owed
+    /// payout held in the old token.
+    SideBalanceOutstanding = 1009,
+    /// The job has no pending token migration.
+    NoMigration = 1010,
+    /// The adapter returned less than the agreed rate.
+    InsufficientOutput = 1011,
+}
+
+/// Where the new tokens of a migration come from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SwapSource {
+    /// An allowlisted AMM adapter.
+    Adapter(Address),
+    /// A liquidity provider, who receives the old balance in exchange.
+    Liquidity(Address),
+}
+
+/// A proposed token migration awaiting both parties.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMigration {
+    pub new_token: Address,
+    /// New tokens per old token, scaled by `PRICE_SCALE` (1e7).
+    pub rate: i128,
+    pub source: SwapSource,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub client_accepted: bool,
+    pub freelancer_accepted: bool,
+}
+
+/// Storage keys for token migrations.
+#[contracttype]
+#[derive(Clone)]
+pub enum MigrationKey {
+    /// Allowlisted swap adapters (`Vec<Address>`, instance).
+    Adapters,
+    /// Pending migration of a job.
+    Pending(u64),
+}
+
+/// Allowlisted swap adapters; also used by payout conversions.
+pub(crate) fn adapters(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&MigrationKey::Adapters)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Take `adapter` off the allowlist without a signer, for an adapter caught
+/// misbehaving mid-payout. Publishes the same event as `set_swap_adapter`,
+/// with the escrow itself as the admin.
+pub(crate) fn drop_adapter(env: &Env, adapter: &Address) {
+    let mut list = adapters(env);
+    let Some(index) = list.first_index_of(adapter) else {
+        return;
+    };
+    list.remove(index);
+    env.storage().instance().set(&MigrationKey::Adapters, &list);
+
+    env.events().publish(
+        (
+            symbol_short!("escrow"),
+            Symbol::new(env, "swap_adapter_set"),
+        ),
+        (adapter.clone(), false, env.current_contract_address()),
+    );
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, MigrationError> {
+    env.storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(MigrationError::JobNotFound)
+}
+
+fn load_migration(env: &Env, job_id: u64) -> Option<TokenMigration> {
+    env.storage()
+        .persistent()
+        .get(&MigrationKey::Pending(job_id))
+}
+
+fn save_migration(env: &Env, job_id: u64, migration: &TokenMigration) {
+    let key = MigrationKey::Pending(job_id);
+    env.storage().persistent().set(&key, migration);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn require_migratable(env: &Env, job: &Job) -> Result<(), MigrationError> {
+    crate::state::transition(&job.status, JobEvent::Amend)
+        .map_err(|_| MigrationError::InvalidStatus)?;
+    let storage = env.storage().persistent();
+    let expense_balance: i128 = storage
+        .get(&crate::expenses::ExpenseKey::Balance(job.id))
+        .unwrap_or(0);
+    if storage.has(&crate::bonds::BondKey::Bond(job.id))
+        || storage.has(&crate::fees::FeeKey::Ledger(job.id))
+        || expense_balance > 0
+        || crate::early_bonus::held(env, job.id) > 0
+        || crate::owed::is_owed(env, job)
+    {
+        return Err(MigrationError::SideBalanceOutstanding);
+    }
+    Ok(())
+}
+
+fn convert(amount: i128, rate: i128) -> i128 {
+    amount * rate / crate::PRICE_SCALE
+}
+
+/// Swap the job's escrowed balance into the new token and re-denominate the
+/// job at the agreed rate.
+fn execute(env: &Env, job: &mut Job, migration: &TokenMigration) -> Result<(), MigrationError> {
+    let contract = env.current_contract_address();
+    let old_token = job.token.clone();
+    let mut disbursed_total: i128 = 0;
+    for m in job.milestones.iter() {
+        disbursed_total += crate::get_milestone_disbursed(env, job.id, m.id);
+    }
+    let held = (job.funded_amount - disbursed_total).max(0);
+    let expected = convert(held, migration.rate);
+
+    let old_client = token::Client::new(env, &old_token);
+    let new_client = token::Client::new(env, &migration.new_token);
+    let mut received = 0;
+    let mut stranded = 0;
+    if held > 0 {
+        match &migration.source {
+            SwapSource::Adapter(adapter) => {
+                if !adapters(env).contains(adapter) {
+                    return Err(MigrationError::AdapterNotAllowed);
+                }
+                let before = new_client.balance(&contract);
+                old_client.transfer(&contract, adapter, &held);
+                env.invoke_contract::<i128>(
+                    adapter,
+                    &Symbol::new(env, "swap"),
+                    vec![
+                        env,
+                        old_token.into_val(env),
+                        migration.new_token.into_val(env),
+                        held.into_val(env),
+                        expected.into_val(env),
+                        contract.into_val(env),
+                    ],
+                );
+                received = new_client.balance(&contract) - before;
+                if received < expected {
+                    return Err(MigrationError::InsufficientOutput);
+                }
+            }
+            SwapSource::Liquidity(provider) => {
+                provider.require_auth();
+                new_client.transfer(provider, &contract, &expected);
+                received = expected;
+                // A frozen token cannot leave the contract; the provider
+                // accepted that risk when offering liquidity.
+                if old_client.try_transfer(&contract, provider, &held).is_err() {
+                    stranded = held;
+                }
+            }
+        }
+    }
+
+    let mut milestones = Vec::new(env);
+    for mut m in job.milestones.iter() {
+        let key = crate::DataKey::MilestoneDisbursed(job.id, m.id);
+        if let Some(paid) = env.storage().persistent().get::<_, i128>(&key) {
+            env.storage()
+                .persistent()
+                .set(&key, &convert(paid, migration.rate));
+        }
+        // Milestones paid in another token keep their amounts.
+        if crate::milestone_tokens::in_job_token(&m) {
+            m.amount = convert(m.amount, migration.rate);
+            m.released = convert(m.released, migration.rate);
+        }
+        milestones.push_back(m);
+    }
+    job.milestones = milestones;
+    job.total_amount = convert(job.total_amount, migration.rate);
+    job.funded_amount = convert(job.funded_amount, migration.rate);
+    job.token = migration.new_token.clone();
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("tok_swap")),
+        (
+            job.id,
+            old_token,
+            migration.new_token.clone(),
+            migration.rate,
+            held,
+            received,
+            stranded,
+        ),
+    );
+    Ok(())
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Add (`allowed = true`) or remove a swap adapter usable by token
+    /// migrations and payout conversions. Registered multisig signers only.
+    /// An adapter must expose `swap(token_in: Address, token_out: Address,
+    /// amount_in: i128, min_amount_out: i128, to: Address) -> i128`, swapping
+    /// `amount_in` already transferred to it and sending the output to `to`.
+    /// Payout conversions call `swap_from`, which takes the same arguments
+    /// plus a `from: Address` before `to` and pulls `amount_in` from `from`
+    /// through a token allowance.
+    pub fn set_swap_adapter(
+        env: Env,
+        admin: Address,
+        adapter: Address,
+        allowed: bool,
+    ) -> Result<(), MigrationError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(MigrationError::NotAdmin);
+        }
+        let mut list = adapters(&env);
+        let index = list.first_index_of(&adapter);
+        match (allowed, index) {
+            (true, None) => list.push_back(adapter.clone()),
+            (false, Some(i)) => {
+                list.remove(i);
+            }
+            _ => return Ok(()),
+        }
+        env.storage().instance().set(&MigrationKey::Adapters, &list);
+
+        env.events().publish(
+            (
+                symbol_short!("escrow"),
+                Symbol::new(&env, "swap_adapter_set"),
+            ),
+            (adapter, allowed, admin),
+        );
+        Ok(())
+    }
+
+    /// Allowlisted swap adapters.
+    pub fn get_swap_adapters(env: Env) -> Vec<Address> {
+        adapters(&env)
+    }
+
+    /// Propose moving a job to `new_token` at `rate` new tokens per old token
+    /// (scaled by 1e7), funded from `source`. Registered multisig signers only.
+    /// Replaces any earlier proposal for the job; executes once both parties
+    /// accept it.
+    pub fn migrate_job_token(
+        env: Env,
+        admin: Address,
+        job_id: u64,
+        new_token: Address,
+        rate: i128,
+        source: SwapSource,
+    ) -> Result<(), MigrationError> {
+        crate::require_not_paused(&env).map_err(|_| MigrationError::ContractPaused)?;
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(MigrationError::NotAdmin);
+        }
+        let job = load_job(&env, job_id)?;
+        require_migratable(&env, &job)?;
+        if rate <= 0 {
+            return Err(MigrationError::InvalidRate);
+        }
+        if new_token == job.token {
+            return Err(MigrationError::SameToken);
+        }
+        if !crate::is_token_allowed(&env, &new_token) {
+            return Err(MigrationError::TokenNotAllowed);
+        }
+        if let SwapSource::Adapter(adapter) = &source {
+            if !adapters(&env).contains(adapter) {
+                return Err(MigrationError::AdapterNotAllowed);
+            }
+        }
+
+        let migration = TokenMigration {
+            new_token: new_token.clone(),
+            rate,
+            source: source.clone(),
+            proposed_by: admin.clone(),
+            proposed_at: env.ledger().timestamp(),
+            client_accepted: false,
+            freelancer_accepted: false,
+        };
+        save_migration(&env, job_id, &migration);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("tok_prop")),
+            (job_id, job.token, new_token, rate, source, admin),
+        );
+        Ok(())
+    }
+
+    /// Accept a job's pending token migration. Client or freelancer only.
+    /// Returns `true` when this acceptance was the second one and the
+    /// migration has been carried out.
+    pub fn accept_token_migration(
+        env: Env,
+        job_id: u64,
+        caller: Address,
+    ) -> Result<bool, MigrationError> {
+        crate::require_not_paused(&env).map_err(|_| MigrationError::ContractPaused)?;
+        caller.require_auth();
+        let mut job = load_job(&env, job_id)?;
+        let mut migration = load_migration(&env, job_id).ok_or(MigrationError::NoMigration)?;
+        if caller == job.client {
+            migration.client_accepted = true;
+        } else if caller == job.freelancer {
+            migration.freelancer_accepted = true;
+        } else {
+            return Err(MigrationError::Unauthorized);
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("tok_acc")),
+            (job_id, caller),
+        );
+        if !(migration.client_accepted && migration.freelancer_accepted) {
+            save_migration(&env, job_id, &migration);
+            return Ok(false);
+        }
+
+        require_migratable(&env, &job)?;
+        execute(&env, &mut job, &migration)?;
+        env.storage()
+            .persistent()
+            .remove(&MigrationKey::Pending(job_id));
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::Job(job_id), &job);
+        crate::changes::record(&env, job_id, crate::changes::ChangeKind::TokenMigrated);
+        Ok(true)
+    }
+
+    /// Withdraw a job's pending token migration. Registered multisig signers
+    /// only.
+    pub fn cancel_token_migration(
+        env: Env,
+        admin: Address,
+        job_id: u64,
+    ) -> Result<(), MigrationError> {
+        admin.require_auth();
+        if !crate::is_signer(&env, &admin) {
+            return Err(MigrationError::NotAdmin);
+        }
+        let key = MigrationKey::Pending(job_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(MigrationError::NoMigration);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("tok_cncl")),
+            (job_id, admin),
+        );
+        Ok(())
+    }
+
+    /// Pending token migration of a job, if any.
+    pub fn get_token_migration(env: Env, job_id: u64) -> Option<TokenMigration> {
+        load_migration(&env, job_id)
+    }
+}
@@ -0,0 +1,301 @@
+//! Milestones paid in a token other than the job's.
+//!
+//! Some engagements pay one milestone in USDC and another in a project token.
+//! While a job is still `Created`, the client can move a milestone to another
+//! allowlisted token with [`EscrowContract::set_milestone_token`]; the
+//! milestone's `token` then holds the override, and `None` means the job's
+//! own token.
+//!
+//! A job's `total_amount` and `funded_amount`, and everything that works from
+//! them (fee ledger, limits, top-ups, partial refunds), cover only the
+//! milestones in the job's token. Override milestones are accounted for here,
+//! one milestone at a time:
+//!
+//! - `fund_job` also pulls the sum of each override token from the client
+//!   ([`EscrowContract::get_job_token_totals`] lists what a job needs);
+//! - `release_milestone` and inactivity approvals pay an override milestone in
+//!   its own token, less the job's fee rate, which goes to the treasury;
+//! - when the job closes, approved but unpaid override milestones go to the
+//!   freelancer and the others follow the outcome: refunded to the client on
+//!   cancellation, refund or expiry, paid out on completion, and split or
+//!   slashed as the dispute resolution says.
+//!
+//! Payouts to the freelancer go through the owed-payout queue like those in the
+//! job's token, so a frozen override token cannot block the job from closing;
+//! they are not converted. Partial payments, budget revisions and token
+//! migration of the job's own token do not apply to override milestones.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address,
+    Env, Symbol, Vec,
+};
+
+use crate::state::JobEvent;
+use crate::{DisputeResolution, EscrowContract, Job, JobStatus, Milestone, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Per-milestone token errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2100, clear of `NativeError`
+/// (2000..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MilestoneTokenError {
+    /// No job exists with the given ID.
+    JobNotFound = 2100,
+    /// Caller is not the job's client.
+    Unauthorized = 2101,
+    /// The job has already been funded.
+    InvalidStatus = 2102,
+    /// The job has no milestone with the given ID.
+    MilestoneNotFound = 2103,
+    /// The token is not on the escrow's token allowlist.
+    TokenNotAllowed = 2104,
+    /// The operation does not support milestones paid in another token.
+    Unsupported = 2105,
+}
+
+/// Storage keys for override milestones.
+#[contracttype]
+#[derive(Clone)]
+pub enum MilestoneTokenKey {
+    /// Set once an override milestone's funds have left escrow: (job_id, milestone_id).
+    Settled(u64, u32),
+}
+
+/// Where the unapproved part of override milestones goes when a job closes.
+pub(crate) enum Remainder {
+    /// Split between the parties; the value is the client's share in bps.
+    Split(u32),
+    /// Slashed to the treasury.
+    Treasury,
+}
+
+/// Whether `milestone` is paid in the job's own token.
+pub(crate) fn in_job_token(milestone: &Milestone) -> bool {
+    milestone.token.is_none()
+}
+
+/// Whether any milestone of `job` is paid in another token.
+pub(crate) fn has_overrides(job: &Job) -> bool {
+    job.milestones.iter().any(|m| !in_job_token(&m))
+}
+
+/// Abort with [`MilestoneTokenError::Unsupported`] if `job` has override
+/// milestones. Used from entry points that return `EscrowError`, which has no
+/// room for the new variant.
+pub(crate) fn reject_overrides(env: &Env, job: &Job) {
+    if has_overrides(job) {
+        panic_with_error!(env, MilestoneTokenError::Unsupported);
+    }
+}
+
+/// Token `milestone` is paid in.
+pub(crate) fn token_of(job: &Job, milestone: &Milestone) -> Address {
+    milestone.token.clone().unwrap_or(job.token.clone())
+}
+
+/// Amount of each override token the job holds in escrow when fully funded.
+fn override_totals(env: &Env, job: &Job) -> Vec<(Address, i128)> {
+    let mut totals: Vec<(Address, i128)> = Vec::new(env);
+    for m in job.milestones.iter() {
+        let Some(token) = m.token else {
+            continue;
+        };
+        match totals.iter().position(|(t, _)| t == token) {
+            Some(i) => {
+                let (t, sum) = totals.get_unchecked(i as u32);
+                totals.set(i as u32, (t, sum + m.amount));
+            }
+            None => totals.push_back((token, m.amount)),
+        }
+    }
+    totals
+}
+
+fn is_settled(env: &Env, job_id: u64, milestone_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&MilestoneTokenKey::Settled(job_id, milestone_id))
+}
+
+fn mark_settled(env: &Env, job_id: u64, milestone_id: u32) {
+    let key = MilestoneTokenKey::Settled(job_id, milestone_id);
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn send(env: &Env, token: &Address, to: &Address, amount: i128) {
+    if amount > 0 {
+        token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    }
+}
+
+/// Pull each override token's total from the client. Called by `fund_job`
+/// after the job's own token has been deposited.
+pub(crate) fn fund(env: &Env, job: &Job, client: &Address) -> Result<(), crate::EscrowError> {
+    let totals = override_totals(env, job);
+    for (token, amount) in totals.iter() {
+        crate::native::require_balance(env, &token, client, amount)?;
+    }
+    for (token, amount) in totals.iter() {
+        token::Client::new(env, &token).transfer(client, &env.current_contract_address(), &amount);
+    }
+    Ok(())
+}
+
+/// Pay an approved override milestone to the freelancer, less the job's fee
+/// rate. Returns `(fee_bps, fee_amount, net_amount)`.
+pub(crate) fn release(env: &Env, job: &Job, milestone: &Milestone) -> (u32, i128, i128) {
+    let token = token_of(job, milestone);
+    let (fee_bps, fee, _) = crate::fees::quote_fee(env, job.id, milestone.amount);
+    send(env, &token, &crate::fees::treasury(env), fee);
+    let net = milestone.amount - fee;
+    crate::owed::pay_or_owe(env, job.id, &token, &crate::payouts::recipient(env, job), net);
+    mark_settled(env, job.id, milestone.id);
+
+    env.events().publish(
+        (symbol_short!("escrow"), Symbol::new(env, "ms_tok_paid")),
+        (job.id, milestone.id, token, fee, net),
+    );
+    (fee_bps, fee, net)
+}
+
+/// Settle every override milestone of a funded `job` whose funds are still in
+/// escrow: approved ones are released to the freelancer, the rest go where
+/// `remainder` says.
+pub(crate) fn settle(env: &Env, job: &Job, remainder: Remainder) {
+    if job.funded_at.is_none() {
+        return;
+    }
+    for m in job.milestones.iter() {
+        if in_job_token(&m) || is_settled(env, job.id, m.id) {
+            continue;
+        }
+        if m.status == MilestoneStatus::Approved {
+            release(env, job, &m);
+            continue;
+        }
+        let token = token_of(job, &m);
+        match remainder {
+            Remainder::Split(client_bps) => {
+                let to_client = m.amount * client_bps.min(10_000) as i128 / 10_000;
+                send(env, &token, &job.client, to_client);
+                crate::owed::pay_or_owe(
+                    env,
+                    job.id,
+                    &token,
+                    &crate::payouts::recipient(env, job),
+                    m.amount - to_client,
+                );
+            }
+            Remainder::Treasury => send(env, &token, &crate::fees::treasury(env), m.amount),
+        }
+        mark_settled(env, job.id, m.id);
+    }
+}
+
+/// Settle override milestones for a job that just reached a terminal status.
+/// Completed jobs pay everything out; any other closing refunds the client.
+pub(crate) fn on_job_closed(env: &Env, job: &Job) {
+    let client_bps = if job.status == JobStatus::Completed {
+        0
+    } else {
+        10_000
+    };
+    settle(env, job, Remainder::Split(client_bps));
+}
+
+/// How a dispute resolution splits override milestones, or `None` if it
+/// leaves the job open.
+pub(crate) fn dispute_remainder(resolution: &DisputeResolution) -> Option<Remainder> {
+    let remainder = match resolution {
+        DisputeResolution::ClientWins => Remainder::Split(10_000),
+        DisputeResolution::FreelancerWins => Remainder::Split(0),
+        DisputeResolution::RefundBoth => Remainder::Split(5_000),
+        DisputeResolution::RefundSplit(pct) => Remainder::Split((*pct).min(100) * 100),
+        DisputeResolution::SplitAward(bps) => Remainder::Split(*bps),
+        DisputeResolution::MaliciousFiling => Remainder::Treasury,
+        DisputeResolution::Escalate => return None,
+    };
+    Some(remainder)
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Pay milestone `milestone_id` of a job that is not funded yet in `token`
+    /// instead of the job's token, or back in the job's token with `None`.
+    /// Client only; `token` must pass the allowlist and the token's minimum
+    /// amount.
+    pub fn set_milestone_token(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        milestone_id: u32,
+        token: Option<Address>,
+    ) -> Result<(), MilestoneTokenError> {
+        client.require_auth();
+        let mut job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(MilestoneTokenError::JobNotFound)?;
+        if job.client != client {
+            return Err(MilestoneTokenError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Configure)
+            .map_err(|_| MilestoneTokenError::InvalidStatus)?;
+        let mut milestone = job
+            .milestones
+            .get(milestone_id)
+            .ok_or(MilestoneTokenError::MilestoneNotFound)?;
+
+        let token = token.filter(|t| *t != job.token);
+        if let Some(token) = &token {
+            if !crate::is_token_allowed(&env, token) {
+                return Err(MilestoneTokenError::TokenNotAllowed);
+            }
+            crate::limits::enforce_min_amount(&env, token, milestone.amount);
+        }
+        match (in_job_token(&milestone), token.is_none()) {
+            (true, false) => job.total_amount -= milestone.amount,
+            (false, true) => job.total_amount += milestone.amount,
+            _ => {}
+        }
+        milestone.token = token.clone();
+        job.milestones.set(milestone_id, milestone);
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::Job(job_id), &job);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_token")),
+            (job_id, milestone_id, token),
+        );
+        Ok(())
+    }
+
+    /// Amount of each token a job holds in escrow once funded: the job's own
+    /// token first, then each override token.
+    pub fn get_job_token_totals(env: Env, job_id: u64) -> Vec<(Address, i128)> {
+        let Some(job) = env
+            .storage()
+            .persistent()
+            .get::<_, Job>(&crate::DataKey::Job(job_id))
+        else {
+            return Vec::new(&env);
+        };
+        let mut totals = soroban_sdk::vec![&env, (job.token.clone(), job.total_amount)];
+        totals.append(&override_totals(&env, &job));
+        totals
+    }
+}
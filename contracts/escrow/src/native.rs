@@ -0,0 +1,140 @@
+//! Native XLM jobs.
+//!
+//! XLM moves through its Stellar Asset Contract like any other token, but
+//! clients should not have to look that address up to pay in lumens. Signers
+//! register the network's native SAC once through an
+//! `AdminAction::SetNativeToken` proposal, which only accepts the SAC that
+//! actually wraps the native asset; clients then open jobs with
+//! [`EscrowContract::create_xlm_job`], which takes the same terms as
+//! `create_job` minus the token. From there a native job is an ordinary job:
+//! funding, approvals, refunds and dispute payouts all go through the SAC.
+//!
+//! Two things differ. `fund_job` and `top_up_escrow` check the client's XLM
+//! balance first and fail with `EscrowError::InsufficientFunds` instead of
+//! trapping inside the SAC (the transfer can still be refused if it would dip
+//! into the account's base reserve). And the exchange-rate parity check values
+//! native deposits at par without consulting the price oracle, since the
+//! agreed value is already quoted in stroops.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, Address,
+    Bytes, Env, String, Vec,
+};
+
+use crate::{EscrowContract, EscrowError};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Native XLM errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2000, clear of `AttestationError`
+/// (1900..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum NativeError {
+    /// Caller is not a registered multisig signer.
+    NotAdmin = 2000,
+    /// No native XLM token has been registered.
+    NativeTokenNotSet = 2001,
+    /// The address is not the native asset's Stellar Asset Contract.
+    NotNativeAsset = 2002,
+}
+
+/// XDR of `Asset::Native`, the asset the native SAC wraps.
+const NATIVE_ASSET_XDR: [u8; 4] = [0, 0, 0, 0];
+
+/// Storage keys for native XLM support.
+#[contracttype]
+#[derive(Clone)]
+pub enum NativeKey {
+    /// Address of the native XLM Stellar Asset Contract (instance).
+    Token,
+}
+
+/// The registered native XLM token, if any.
+pub(crate) fn native_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&NativeKey::Token)
+}
+
+/// Address of this network's native XLM Stellar Asset Contract.
+fn native_asset_address(env: &Env) -> Address {
+    env.deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET_XDR))
+        .deployed_address()
+}
+
+/// Register `token` as the native XLM token, on behalf of an executed
+/// `AdminAction::SetNativeToken` proposal. Aborts with
+/// [`NativeError::NotNativeAsset`] unless `token` is the native asset's SAC,
+/// since native deposits are valued at par without the oracle.
+pub(crate) fn set_native_token(env: &Env, token: Address, proposer: &Address) {
+    if token != native_asset_address(env) {
+        panic_with_error!(env, NativeError::NotNativeAsset);
+    }
+    env.storage().instance().set(&NativeKey::Token, &token);
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("native")),
+        (token, proposer.clone()),
+    );
+}
+
+/// Whether `token` is the registered native XLM token.
+pub(crate) fn is_native(env: &Env, token: &Address) -> bool {
+    native_token(env).is_some_and(|native| native == *token)
+}
+
+/// Fail with `InsufficientFunds` if `from` holds less than `amount` of
+/// `token` and `token` is native XLM. Other tokens are left to their own
+/// transfer checks.
+pub(crate) fn require_balance(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    amount: i128,
+) -> Result<(), EscrowError> {
+    if is_native(env, token) && token::Client::new(env, token).balance(from) < amount {
+        return Err(EscrowError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Return the registered native XLM token, if any. Jobs created in that
+    /// token before it was registered are native jobs too.
+    pub fn get_native_token(env: Env) -> Option<Address> {
+        native_token(&env)
+    }
+
+    /// Create a job paid in native XLM. Takes the same terms as `create_job`,
+    /// which it calls with the registered native token, and fails with its
+    /// error if they are invalid. Returns the job ID.
+    pub fn create_xlm_job(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        milestones: Vec<(String, i128, u64)>,
+        job_deadline: u64,
+        auto_refund_after: u64,
+        expiry_ledger: u32,
+    ) -> Result<u64, NativeError> {
+        let token = native_token(&env).ok_or(NativeError::NativeTokenNotSet)?;
+        let job_id = Self::create_job(
+            env.clone(),
+            client,
+            freelancer,
+            token,
+            milestones,
+            job_deadline,
+            auto_refund_after,
+            expiry_ledger,
+        )
+        .unwrap_or_else(|e| panic_with_error!(&env, e));
+        Ok(job_id)
+    }
+}
@@ -0,0 +1,172 @@
+//! Shared job notes.
+//!
+//! Parties sometimes agree to deviate from the job as written — swapping the
+//! scope of two milestones, moving a date informally. Either party can anchor
+//! such an agreement on-chain with [`EscrowContract::append_shared_note`],
+//! which records the hash of the off-chain note with its author and time. The
+//! note only counts as agreed once the other party confirms it with
+//! [`EscrowContract::acknowledge_shared_note`], so dispute jurors can tell a
+//! mutual understanding from one side's claim.
+//!
+//! Notes are append-only and capped at [`MAX_SHARED_NOTES`] per job.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec,
+};
+
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Maximum number of shared notes on one job.
+pub const MAX_SHARED_NOTES: u32 = 20;
+
+/// Shared note errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1600, clear of `BonusError`
+/// (1500..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum NoteError {
+    /// The contract is paused.
+    ContractPaused = 1600,
+    /// No job exists with the given ID.
+    JobNotFound = 1601,
+    /// Caller is not a party to the job, or acknowledged their own note.
+    Unauthorized = 1602,
+    /// The job already holds [`MAX_SHARED_NOTES`] notes.
+    TooManyNotes = 1603,
+    /// The job has no note at the given index.
+    NoteNotFound = 1604,
+    /// The note was already acknowledged.
+    AlreadyAcknowledged = 1605,
+}
+
+/// A note one party added to a job.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SharedNote {
+    pub author: Address,
+    /// Hash of the off-chain note text.
+    pub note_hash: BytesN<32>,
+    pub added_at: u64,
+    /// When the other party confirmed the note; `None` until then.
+    pub acknowledged_at: Option<u64>,
+}
+
+/// Storage keys for shared notes.
+#[contracttype]
+#[derive(Clone)]
+pub enum NoteKey {
+    /// Every note on a job, oldest first.
+    Notes(u64),
+}
+
+fn load_notes(env: &Env, job_id: u64) -> Vec<SharedNote> {
+    env.storage()
+        .persistent()
+        .get(&NoteKey::Notes(job_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_notes(env: &Env, job_id: u64, notes: &Vec<SharedNote>) {
+    let key = NoteKey::Notes(job_id);
+    env.storage().persistent().set(&key, notes);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Load `job_id` and check that `caller` is its client or freelancer.
+fn load_party_job(env: &Env, job_id: u64, caller: &Address) -> Result<Job, NoteError> {
+    let job: Job = env
+        .storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(NoteError::JobNotFound)?;
+    if *caller != job.client && *caller != job.freelancer {
+        return Err(NoteError::Unauthorized);
+    }
+    Ok(job)
+}
+
+/// Drop the notes of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage().persistent().remove(&NoteKey::Notes(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Append the hash of an off-chain note to the job. Client or freelancer
+    /// only. Returns the note's index.
+    pub fn append_shared_note(
+        env: Env,
+        job_id: u64,
+        caller: Address,
+        note_hash: BytesN<32>,
+    ) -> Result<u32, NoteError> {
+        crate::require_not_paused(&env).map_err(|_| NoteError::ContractPaused)?;
+        caller.require_auth();
+        load_party_job(&env, job_id, &caller)?;
+
+        let mut notes = load_notes(&env, job_id);
+        if notes.len() >= MAX_SHARED_NOTES {
+            return Err(NoteError::TooManyNotes);
+        }
+        let index = notes.len();
+        notes.push_back(SharedNote {
+            author: caller.clone(),
+            note_hash: note_hash.clone(),
+            added_at: env.ledger().timestamp(),
+            acknowledged_at: None,
+        });
+        save_notes(&env, job_id, &notes);
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "note_added")),
+            (job_id, index, caller, note_hash),
+        );
+        Ok(index)
+    }
+
+    /// Confirm a note added by the other party to the job.
+    pub fn acknowledge_shared_note(
+        env: Env,
+        job_id: u64,
+        caller: Address,
+        index: u32,
+    ) -> Result<(), NoteError> {
+        crate::require_not_paused(&env).map_err(|_| NoteError::ContractPaused)?;
+        caller.require_auth();
+        load_party_job(&env, job_id, &caller)?;
+
+        let mut notes = load_notes(&env, job_id);
+        let mut note = notes.get(index).ok_or(NoteError::NoteNotFound)?;
+        if note.author == caller {
+            return Err(NoteError::Unauthorized);
+        }
+        if note.acknowledged_at.is_some() {
+            return Err(NoteError::AlreadyAcknowledged);
+        }
+        note.acknowledged_at = Some(env.ledger().timestamp());
+        notes.set(index, note);
+        save_notes(&env, job_id, &notes);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("note_ack")),
+            (job_id, index, caller),
+        );
+        Ok(())
+    }
+
+    /// Every shared note on a job, oldest first.
+    pub fn get_shared_notes(env: Env, job_id: u64) -> Vec<SharedNote> {
+        load_notes(&env, job_id)
+    }
+}
@@ -0,0 +1,154 @@
+//! Payouts owed to freelancers whose transfer failed.
+//!
+//! A token transfer to the freelancer's payout address can trap: the issuer
+//! froze the trustline, revoked authorization, or the account cannot hold the
+//! asset yet. Paying the freelancer used to be part of the same transaction as
+//! the state change, so one bad trustline left a milestone unapprovable and a
+//! job uncancellable. Every escrow-funded payment to the freelancer now goes
+//! through [`pay_freelancer`]: when the transfer fails, the amount is added to
+//! what the escrow owes that payout address in that token, and the approval,
+//! refund or resolution goes ahead. The recipient collects the balance later
+//! with [`EscrowContract::withdraw_owed`], either once the address can receive
+//! the token again or to another address of their choice.
+//!
+//! Balances are pooled per recipient and token rather than per job, so a
+//! frozen freelancer clears everything owed across their jobs in one call. A
+//! job cannot be archived or moved to another token while its payout address
+//! is owed anything in the job's token.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Owed payout errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1700, clear of `NoteError`
+/// (1600..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OwedError {
+    /// The contract is paused.
+    ContractPaused = 1700,
+    /// Nothing is owed to the recipient in this token.
+    NothingOwed = 1701,
+    /// The destination cannot receive the token.
+    TransferFailed = 1702,
+}
+
+/// Storage keys for owed payouts.
+#[contracttype]
+#[derive(Clone)]
+pub enum OwedKey {
+    /// Owed to a payout address in a token: (recipient, token) → `i128`.
+    Owed(Address, Address),
+}
+
+fn try_pay(env: &Env, token: &Address, to: &Address, amount: i128) -> bool {
+    matches!(
+        token::Client::new(env, token).try_transfer(&env.current_contract_address(), to, &amount),
+        Ok(Ok(()))
+    )
+}
+
+fn owed(env: &Env, recipient: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&OwedKey::Owed(recipient.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether the job's freelancer or its current payout address is owed
+/// anything in one of the job's tokens.
+pub(crate) fn is_owed(env: &Env, job: &Job) -> bool {
+    let recipient = crate::payouts::recipient(env, job);
+    let owed_in = |token: &Address| {
+        owed(env, &recipient, token) > 0 || owed(env, &job.freelancer, token) > 0
+    };
+    owed_in(&job.token)
+        || job
+            .milestones
+            .iter()
+            .any(|m| m.token.as_ref().is_some_and(&owed_in))
+}
+
+/// Send `amount` of the job's token from escrow to the freelancer's payout
+/// address, converted if the freelancer asked for it, or record it as owed if
+/// the transfer fails.
+pub(crate) fn pay_freelancer(env: &Env, job: &Job, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let recipient = crate::payouts::recipient(env, job);
+    let amount = crate::conversion::pay_converted(env, job, &recipient, amount);
+    pay_or_owe(env, job.id, &job.token, &recipient, amount);
+}
+
+/// Send `amount` of `token` from escrow to `recipient`, or record it as owed
+/// if the transfer fails. For payouts of `job_id` in a token other than the
+/// job's, which are never converted.
+pub(crate) fn pay_or_owe(env: &Env, job_id: u64, token: &Address, recipient: &Address, amount: i128) {
+    if amount <= 0 || try_pay(env, token, recipient, amount) {
+        return;
+    }
+
+    let key = OwedKey::Owed(recipient.clone(), token.clone());
+    let total = owed(env, recipient, token) + amount;
+    env.storage().persistent().set(&key, &total);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+
+    env.events().publish(
+        (symbol_short!("escrow"), symbol_short!("pay_owed")),
+        (job_id, recipient.clone(), amount, total),
+    );
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Pay out everything owed to `recipient` in `token`, to `to`: the
+    /// recipient itself once it can hold the token again, or any other
+    /// address it names. Recipient only. Returns the amount paid.
+    pub fn withdraw_owed(
+        env: Env,
+        recipient: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<i128, OwedError> {
+        crate::pause::require_scope_open(&env, crate::PauseScope::Payouts)
+            .map_err(|_| OwedError::ContractPaused)?;
+        recipient.require_auth();
+
+        let amount = owed(&env, &recipient, &token);
+        if amount <= 0 {
+            return Err(OwedError::NothingOwed);
+        }
+        if !try_pay(&env, &token, &to, amount) {
+            return Err(OwedError::TransferFailed);
+        }
+        env.storage()
+            .persistent()
+            .remove(&OwedKey::Owed(recipient.clone(), token.clone()));
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "owed_paid")),
+            (recipient, token, to, amount),
+        );
+        Ok(amount)
+    }
+
+    /// Amount owed to `recipient` in `token` (`0` when nothing is owed).
+    pub fn get_owed(env: Env, recipient: Address, token: Address) -> i128 {
+        owed(&env, &recipient, &token)
+    }
+}
@@ -0,0 +1,191 @@
+//! Job history by party.
+//!
+//! Repeat-relationship checks — the reputation contract's anti-collusion cap,
+//! repeat engagements — need every job a given pair has shared. `create_job`
+//! appends each new job ID to a composite index keyed by (client, freelancer),
+//! so [`EscrowContract::get_jobs_between`] can page through that history
+//! without scanning all jobs. The same call appends it to the client's and the
+//! freelancer's own indexes, paged by [`EscrowContract::get_jobs_by_client`]
+//! and [`EscrowContract::get_jobs_by_freelancer`].
+//!
+//! Anyone can create jobs naming any freelancer, so no index may live in a
+//! single entry that grows with every job. Like the status index, each one is
+//! split into pages of [`PARTY_PAGE_CAPACITY`] IDs.
+//!
+//! The indexes keep the IDs of archived jobs, whose summaries stay readable
+//! through `get_job_summary`. Private jobs are left out of the views like every
+//! other public index, and dropped from them outright once archived.
+
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{EscrowContract, Job};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Maximum page size for `get_jobs_between`, `get_jobs_by_client` and
+/// `get_jobs_by_freelancer`.
+pub const MAX_PAIR_PAGE_SIZE: u32 = 50;
+/// Number of job IDs stored per index page.
+pub const PARTY_PAGE_CAPACITY: u32 = 100;
+
+/// A job history kept by party.
+#[contracttype]
+#[derive(Clone)]
+pub enum PartyIndex {
+    /// Jobs created between (client, freelancer).
+    Pair(Address, Address),
+    /// Jobs created with this address as client.
+    Client(Address),
+    /// Jobs created with this address as freelancer.
+    Freelancer(Address),
+}
+
+/// Storage keys for the party indexes.
+#[contracttype]
+#[derive(Clone)]
+pub enum PairKey {
+    /// Number of pages allocated to an index.
+    Pages(PartyIndex),
+    /// Up to [`PARTY_PAGE_CAPACITY`] job IDs of an index, oldest first.
+    Page(PartyIndex, u32),
+}
+
+// The history outlives any single job in it, so like the deadline buckets it
+// is kept alive for as long as the network allows.
+fn save<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(env: &Env, key: &PairKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    let max_ttl = env.storage().max_ttl();
+    env.storage()
+        .persistent()
+        .extend_ttl(key, max_ttl.min(crate::TTL_THRESHOLD_LEDGERS), max_ttl);
+}
+
+fn page_count(env: &Env, index: &PartyIndex) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&PairKey::Pages(index.clone()))
+        .unwrap_or(0)
+}
+
+fn load_page(env: &Env, index: &PartyIndex, page: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&PairKey::Page(index.clone(), page))
+        .unwrap_or(Vec::new(env))
+}
+
+fn append(env: &Env, index: &PartyIndex, job_id: u64) {
+    let pages = page_count(env, index);
+    let mut page = pages.saturating_sub(1);
+    let mut ids = if pages > 0 {
+        load_page(env, index, page)
+    } else {
+        Vec::new(env)
+    };
+    if pages == 0 || ids.len() >= PARTY_PAGE_CAPACITY {
+        page = pages;
+        ids = Vec::new(env);
+        save(env, &PairKey::Pages(index.clone()), &(pages + 1));
+    }
+    ids.push_back(job_id);
+    save(env, &PairKey::Page(index.clone(), page), &ids);
+}
+
+fn remove(env: &Env, index: &PartyIndex, job_id: u64) {
+    for page in 0..page_count(env, index) {
+        let mut ids = load_page(env, index, page);
+        if let Some(i) = ids.first_index_of(job_id) {
+            ids.remove(i);
+            save(env, &PairKey::Page(index.clone(), page), &ids);
+            return;
+        }
+    }
+}
+
+/// Page through the public job IDs of `index`.
+fn page(env: &Env, index: &PartyIndex, offset: u32, limit: u32) -> Vec<u64> {
+    let limit = limit.min(MAX_PAIR_PAGE_SIZE);
+    let mut jobs = Vec::new(env);
+    if limit == 0 {
+        return jobs;
+    }
+
+    let mut skipped: u32 = 0;
+    for page in 0..page_count(env, index) {
+        for job_id in load_page(env, index, page).iter() {
+            if crate::visibility::is_private(env, job_id) {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            jobs.push_back(job_id);
+            if jobs.len() >= limit {
+                return jobs;
+            }
+        }
+    }
+    jobs
+}
+
+fn indexes(job: &Job) -> [PartyIndex; 3] {
+    [
+        PartyIndex::Pair(job.client.clone(), job.freelancer.clone()),
+        PartyIndex::Client(job.client.clone()),
+        PartyIndex::Freelancer(job.freelancer.clone()),
+    ]
+}
+
+/// Append `job` to the indexes of its client, its freelancer and the pair.
+pub(crate) fn index_job(env: &Env, job: &Job) {
+    for index in indexes(job) {
+        append(env, &index, job.id);
+    }
+}
+
+/// Drop a private job from its parties' histories when it is archived, since
+/// the visibility flag that hid it goes with the job.
+pub(crate) fn on_archive(env: &Env, job: &Job) {
+    if !crate::visibility::is_private(env, job.id) {
+        return;
+    }
+    for index in indexes(job) {
+        remove(env, &index, job.id);
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Page through the IDs of jobs created between `client` and `freelancer`,
+    /// oldest first. `limit` is capped at [`MAX_PAIR_PAGE_SIZE`]; archived
+    /// jobs are included and private jobs are left out.
+    pub fn get_jobs_between(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        page(&env, &PartyIndex::Pair(client, freelancer), offset, limit)
+    }
+
+    /// Page through the IDs of jobs created with `client` as the client,
+    /// oldest first. Paged and filtered like `get_jobs_between`.
+    pub fn get_jobs_by_client(env: Env, client: Address, offset: u32, limit: u32) -> Vec<u64> {
+        page(&env, &PartyIndex::Client(client), offset, limit)
+    }
+
+    /// Page through the IDs of jobs created with `freelancer` as the
+    /// freelancer, oldest first. Paged and filtered like `get_jobs_between`.
+    pub fn get_jobs_by_freelancer(
+        env: Env,
+        freelancer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        page(&env, &PartyIndex::Freelancer(freelancer), offset, limit)
+    }
+}
@@ -0,0 +1,119 @@
+//! Payout routing to a named beneficiary.
+//!
+//! A freelancer working through a legal entity may want earnings paid to the
+//! entity's address rather than the identity that signs for the job. With
+//! [`EscrowContract::set_payout_address`] the freelancer names that address
+//! before any milestone has been approved; from then on every payment for the
+//! job's work goes to it. Authorization is unaffected: the freelancer identity
+//! still submits milestones, signs exits and is the party of record.
+
+use soroban_sdk::{contracterror, contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Payout routing errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1300, clear of `RejectionError`
+/// (1200..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PayoutError {
+    /// The contract is paused.
+    ContractPaused = 1300,
+    /// No job exists with the given ID.
+    JobNotFound = 1301,
+    /// Caller is not the job's freelancer.
+    Unauthorized = 1302,
+    /// The job is closed, or a milestone has already been approved or paid.
+    InvalidStatus = 1303,
+}
+
+/// Storage keys for payout routing.
+#[contracttype]
+#[derive(Clone)]
+pub enum PayoutKey {
+    /// Address receiving the freelancer's payouts for a job.
+    Payout(u64),
+}
+
+/// Address that receives the freelancer's payouts for `job`: the named
+/// beneficiary if one was set, otherwise the freelancer.
+pub(crate) fn recipient(env: &Env, job: &Job) -> Address {
+    env.storage()
+        .persistent()
+        .get(&PayoutKey::Payout(job.id))
+        .unwrap_or(job.freelancer.clone())
+}
+
+/// Drop the payout address of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage().persistent().remove(&PayoutKey::Payout(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Route the freelancer's payouts for `job_id` to `payout`. Freelancer
+    /// only, before any milestone has been approved or paid. Setting the
+    /// freelancer's own address clears the route.
+    pub fn set_payout_address(
+        env: Env,
+        freelancer: Address,
+        job_id: u64,
+        payout: Address,
+    ) -> Result<(), PayoutError> {
+        crate::require_not_paused(&env).map_err(|_| PayoutError::ContractPaused)?;
+        freelancer.require_auth();
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(PayoutError::JobNotFound)?;
+        if job.freelancer != freelancer {
+            return Err(PayoutError::Unauthorized);
+        }
+        if crate::state::transition(&job.status, JobEvent::Amend).is_err()
+            || job.milestones.iter().any(|m| {
+            matches!(
+                m.status,
+                MilestoneStatus::Approved | MilestoneStatus::PartiallyPaid
+            )
+        }) {
+            return Err(PayoutError::InvalidStatus);
+        }
+
+        let key = PayoutKey::Payout(job_id);
+        if payout == freelancer {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &payout);
+            env.storage().persistent().extend_ttl(
+                &key,
+                crate::TTL_THRESHOLD_LEDGERS,
+                crate::TTL_EXTEND_TO_LEDGERS,
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("payout_to")),
+            (job_id, freelancer, payout),
+        );
+        Ok(())
+    }
+
+    /// Address receiving the freelancer's payouts for `job_id`.
+    pub fn get_payout_address(env: Env, job_id: u64) -> Result<Address, PayoutError> {
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(PayoutError::JobNotFound)?;
+        Ok(recipient(&env, &job))
+    }
+}
@@ -0,0 +1,336 @@
+//! Open job postings.
+//!
+//! `create_job` needs the freelancer up front. A client who has not picked one
+//! yet can instead publish the job's terms with [`EscrowContract::post_job`].
+//! Freelancers apply with [`EscrowContract::apply_to_posting`], each quoting a
+//! total bid and the hash of an off-chain proposal. When the client picks one
+//! with [`EscrowContract::accept_application`] the posting becomes an ordinary
+//! job through `create_job`, with the posted milestone amounts rescaled to the
+//! accepted bid; the client then funds it as usual.
+//!
+//! A posting takes at most [`MAX_APPLICATIONS`] applications, one per
+//! freelancer, and can be withdrawn with [`EscrowContract::close_posting`]
+//! until an application is accepted.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, panic_with_error, symbol_short, Address, BytesN,
+    Env, String, Symbol, Vec,
+};
+
+use crate::pause::{require_scope_open, PauseScope};
+use crate::EscrowContract;
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Maximum number of applications on one posting.
+pub const MAX_APPLICATIONS: u32 = 50;
+
+/// Job posting errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1800, clear of `OwedError`
+/// (1700..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PostingError {
+    /// The contract, or job creation when posting, is paused.
+    ContractPaused = 1800,
+    /// No posting exists with the given ID.
+    PostingNotFound = 1801,
+    /// Caller is not the posting's client, or the client applied to their own
+    /// posting.
+    Unauthorized = 1802,
+    /// The posting was already filled or closed.
+    PostingClosed = 1803,
+    /// The posting has no milestones, or a milestone amount is not positive.
+    InvalidTerms = 1804,
+    /// A bid is not positive.
+    InvalidBid = 1805,
+    /// The freelancer already applied to the posting.
+    AlreadyApplied = 1806,
+    /// The posting already holds [`MAX_APPLICATIONS`] applications.
+    TooManyApplications = 1807,
+    /// The freelancer has not applied to the posting.
+    ApplicationNotFound = 1808,
+    /// The posting's token is not on the escrow's token allowlist.
+    TokenNotAllowed = 1809,
+}
+
+/// Lifecycle of a posting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PostingStatus {
+    /// Accepting applications.
+    Open,
+    /// An application was accepted and turned into the given job.
+    Filled(u64),
+    /// Withdrawn by the client.
+    Closed,
+}
+
+/// A job published without a freelancer. The milestones use the same
+/// `(description, amount, deadline)` layout as `create_job`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Posting {
+    pub id: u64,
+    pub client: Address,
+    pub token: Address,
+    pub milestones: Vec<(String, i128, u64)>,
+    /// Sum of the posted milestone amounts.
+    pub budget: i128,
+    pub job_deadline: u64,
+    pub auto_refund_after: u64,
+    pub expiry_ledger: u32,
+    pub status: PostingStatus,
+    pub created_at: u64,
+}
+
+/// A freelancer's offer on a posting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Application {
+    pub freelancer: Address,
+    /// Total the freelancer asks for the whole job.
+    pub bid: i128,
+    /// Hash of the off-chain proposal.
+    pub proposal_hash: BytesN<32>,
+    pub applied_at: u64,
+}
+
+/// Storage keys for postings.
+#[contracttype]
+#[derive(Clone)]
+pub enum PostingKey {
+    /// Number of postings ever created (instance).
+    Count,
+    Posting(u64),
+    /// Applications on a posting, oldest first.
+    Applications(u64),
+}
+
+fn save<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(env: &Env, key: &PostingKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(
+        key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn load_posting(env: &Env, posting_id: u64) -> Result<Posting, PostingError> {
+    env.storage()
+        .persistent()
+        .get(&PostingKey::Posting(posting_id))
+        .ok_or(PostingError::PostingNotFound)
+}
+
+fn load_applications(env: &Env, posting_id: u64) -> Vec<Application> {
+    env.storage()
+        .persistent()
+        .get(&PostingKey::Applications(posting_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Scale the posted milestone amounts so they add up to `bid`. The last
+/// milestone absorbs the rounding.
+fn milestones_for_bid(env: &Env, posting: &Posting, bid: i128) -> Vec<(String, i128, u64)> {
+    let mut scaled = Vec::new(env);
+    let mut allotted: i128 = 0;
+    let last = posting.milestones.len() - 1;
+    for (i, (desc, amount, deadline)) in posting.milestones.iter().enumerate() {
+        let share = if i as u32 == last {
+            bid - allotted
+        } else {
+            amount * bid / posting.budget
+        };
+        allotted += share;
+        scaled.push_back((desc, share, deadline));
+    }
+    scaled
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Publish a job without a freelancer. Takes the same terms as
+    /// `create_job`, which checks them in full when an application is
+    /// accepted; the token allowlist is checked up front so freelancers do
+    /// not apply to a posting that can never be filled. Returns the posting
+    /// ID.
+    pub fn post_job(
+        env: Env,
+        client: Address,
+        token: Address,
+        milestones: Vec<(String, i128, u64)>,
+        job_deadline: u64,
+        auto_refund_after: u64,
+        expiry_ledger: u32,
+    ) -> Result<u64, PostingError> {
+        require_scope_open(&env, PauseScope::Create).map_err(|_| PostingError::ContractPaused)?;
+        client.require_auth();
+        if !crate::is_token_allowed(&env, &token) {
+            return Err(PostingError::TokenNotAllowed);
+        }
+        if milestones.is_empty() {
+            return Err(PostingError::InvalidTerms);
+        }
+        let mut budget: i128 = 0;
+        for (_, amount, _) in milestones.iter() {
+            if amount <= 0 {
+                return Err(PostingError::InvalidTerms);
+            }
+            budget = budget.saturating_add(amount);
+        }
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&PostingKey::Count)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&PostingKey::Count, &id);
+        let posting = Posting {
+            id,
+            client: client.clone(),
+            token: token.clone(),
+            milestones,
+            budget,
+            job_deadline,
+            auto_refund_after,
+            expiry_ledger,
+            status: PostingStatus::Open,
+            created_at: env.ledger().timestamp(),
+        };
+        save(&env, &PostingKey::Posting(id), &posting);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("posted")),
+            (id, client, token, budget),
+        );
+        Ok(id)
+    }
+
+    /// Apply to an open posting with a total `bid` and the hash of an
+    /// off-chain proposal. One application per freelancer.
+    pub fn apply_to_posting(
+        env: Env,
+        posting_id: u64,
+        freelancer: Address,
+        bid: i128,
+        proposal_hash: BytesN<32>,
+    ) -> Result<(), PostingError> {
+        crate::require_not_paused(&env).map_err(|_| PostingError::ContractPaused)?;
+        freelancer.require_auth();
+        let posting = load_posting(&env, posting_id)?;
+        if posting.status != PostingStatus::Open {
+            return Err(PostingError::PostingClosed);
+        }
+        if freelancer == posting.client {
+            return Err(PostingError::Unauthorized);
+        }
+        if bid <= 0 {
+            return Err(PostingError::InvalidBid);
+        }
+
+        let mut applications = load_applications(&env, posting_id);
+        if applications.iter().any(|a| a.freelancer == freelancer) {
+            return Err(PostingError::AlreadyApplied);
+        }
+        if applications.len() >= MAX_APPLICATIONS {
+            return Err(PostingError::TooManyApplications);
+        }
+        applications.push_back(Application {
+            freelancer: freelancer.clone(),
+            bid,
+            proposal_hash: proposal_hash.clone(),
+            applied_at: env.ledger().timestamp(),
+        });
+        save(&env, &PostingKey::Applications(posting_id), &applications);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("applied")),
+            (posting_id, freelancer, bid, proposal_hash),
+        );
+        Ok(())
+    }
+
+    /// Accept `freelancer`'s application and create the job through
+    /// `create_job`, with milestone amounts rescaled to the bid. Client only.
+    /// Fails with the `create_job` error if the posted terms are no longer
+    /// valid (e.g. a deadline has passed). Returns the new job's ID.
+    pub fn accept_application(
+        env: Env,
+        posting_id: u64,
+        client: Address,
+        freelancer: Address,
+    ) -> Result<u64, PostingError> {
+        // `create_job` below takes the client's authorization; asking for it
+        // twice in one frame is rejected by the host.
+        let mut posting = load_posting(&env, posting_id)?;
+        if client != posting.client {
+            return Err(PostingError::Unauthorized);
+        }
+        if posting.status != PostingStatus::Open {
+            return Err(PostingError::PostingClosed);
+        }
+        let application = load_applications(&env, posting_id)
+            .iter()
+            .find(|a| a.freelancer == freelancer)
+            .ok_or(PostingError::ApplicationNotFound)?;
+
+        let job_id = Self::create_job(
+            env.clone(),
+            client,
+            freelancer.clone(),
+            posting.token.clone(),
+            milestones_for_bid(&env, &posting, application.bid),
+            posting.job_deadline,
+            posting.auto_refund_after,
+            posting.expiry_ledger,
+        )
+        .unwrap_or_else(|e| panic_with_error!(&env, e));
+
+        posting.status = PostingStatus::Filled(job_id);
+        save(&env, &PostingKey::Posting(posting_id), &posting);
+
+        env.events().publish(
+            (symbol_short!("escrow"), Symbol::new(&env, "app_accepted")),
+            (posting_id, job_id, freelancer, application.bid),
+        );
+        Ok(job_id)
+    }
+
+    /// Withdraw an open posting. Client only.
+    pub fn close_posting(env: Env, posting_id: u64, client: Address) -> Result<(), PostingError> {
+        client.require_auth();
+        let mut posting = load_posting(&env, posting_id)?;
+        if client != posting.client {
+            return Err(PostingError::Unauthorized);
+        }
+        if posting.status != PostingStatus::Open {
+            return Err(PostingError::PostingClosed);
+        }
+        posting.status = PostingStatus::Closed;
+        save(&env, &PostingKey::Posting(posting_id), &posting);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("unposted")),
+            posting_id,
+        );
+        Ok(())
+    }
+
+    pub fn get_posting(env: Env, posting_id: u64) -> Option<Posting> {
+        env.storage()
+            .persistent()
+            .get(&PostingKey::Posting(posting_id))
+    }
+
+    /// Applications on a posting, oldest first.
+    pub fn get_applications(env: Env, posting_id: u64) -> Vec<Application> {
+        load_applications(&env, posting_id)
+    }
+}
@@ -0,0 +1,305 @@
+//! Recurring (retainer) jobs.
+//!
+//! Before funding, a client can turn a single-milestone job into a retainer
+//! with [`EscrowContract::set_recurring`]. The milestone then becomes the
+//! template for every period. Once a period has elapsed since funding, anyone
+//! can call [`EscrowContract::renew`] to append an identical milestone, due
+//! one period later. Renewal pays for itself through the client's token
+//! allowance to the escrow: the client keeps the retainer going by keeping
+//! that allowance and their balance topped up. A renewal they cannot cover
+//! fails with [`RecurringError::NotFunded`], and the retainer simply stops
+//! growing until they do. Periods that pass without a renewal are skipped,
+//! not back-billed: the next renewal covers the period it is made in.
+//!
+//! Releasing every milestone does not complete a running retainer: it stays
+//! open for the next period. Either party can end the retainer with
+//! [`EscrowContract::terminate_recurring`]. Termination only stops future
+//! renewals, and completes the job if everything generated has already been
+//! paid out. Otherwise the milestones already generated are delivered,
+//! approved, disputed or cancelled like those of any other job.
+
+use soroban_sdk::{contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, String};
+
+use crate::pause::PauseScope;
+use crate::state::{self, JobEvent};
+use crate::{DataKey, EscrowContract, Job, Milestone, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Shortest period a retainer may renew on.
+pub const MIN_RECURRING_PERIOD_SECS: u64 = 24 * 3600;
+
+/// Recurring job errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2700, clear of `HourlyError`
+/// (2600..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RecurringError {
+    /// The contract, or job creation and funding, are paused.
+    ContractPaused = 2700,
+    /// No job exists with the given ID.
+    JobNotFound = 2701,
+    /// Caller is not the party allowed to do this.
+    Unauthorized = 2702,
+    /// The job's status does not allow this.
+    InvalidStatus = 2703,
+    /// The job is not a retainer.
+    NotRecurring = 2704,
+    /// Period is shorter than `MIN_RECURRING_PERIOD_SECS`.
+    InvalidPeriod = 2705,
+    /// Only single-milestone jobs can become retainers.
+    NotSingleMilestone = 2706,
+    /// The current period has not elapsed yet.
+    NotDue = 2707,
+    /// The retainer has been terminated.
+    Terminated = 2708,
+    /// The client's allowance or balance cannot cover the renewal.
+    NotFunded = 2709,
+    /// The job already holds the maximum number of milestones.
+    MilestoneLimit = 2710,
+}
+
+/// Terms of a retainer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringTerms {
+    pub period_secs: u64,
+    /// Description of every generated milestone.
+    pub description: String,
+    /// Amount of every generated milestone.
+    pub amount: i128,
+    /// Period of the latest renewal, counted from funding; the next is due
+    /// `renewals + 1` periods after funding. Lapsed periods are skipped, so
+    /// this can run ahead of the number of milestones generated.
+    pub renewals: u32,
+    /// Party that ended the retainer, if it has ended.
+    pub terminated_by: Option<Address>,
+}
+
+/// Storage keys for retainers.
+#[contracttype]
+#[derive(Clone)]
+pub enum RecurringKey {
+    /// Retainer terms: job_id → `RecurringTerms`.
+    RecurringTerms(u64),
+}
+
+fn load_terms(env: &Env, job_id: u64) -> Option<RecurringTerms> {
+    env.storage()
+        .persistent()
+        .get(&RecurringKey::RecurringTerms(job_id))
+}
+
+fn save_terms(env: &Env, job_id: u64, terms: &RecurringTerms) {
+    let key = RecurringKey::RecurringTerms(job_id);
+    env.storage().persistent().set(&key, terms);
+    env.storage().persistent().extend_ttl(
+        &key,
+        crate::TTL_THRESHOLD_LEDGERS,
+        crate::TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, RecurringError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Job(job_id))
+        .ok_or(RecurringError::JobNotFound)
+}
+
+/// When the next renewal of a funded retainer falls due.
+fn next_renewal_at(job: &Job, terms: &RecurringTerms) -> Option<u64> {
+    let periods = terms.renewals as u64 + 1;
+    job.funded_at
+        .map(|funded_at| funded_at.saturating_add(terms.period_secs.saturating_mul(periods)))
+}
+
+/// Whether `job_id` is a retainer that has not been terminated. Releasing
+/// its last milestone leaves it open for the next renewal.
+pub(crate) fn is_running(env: &Env, job_id: u64) -> bool {
+    load_terms(env, job_id).is_some_and(|terms| terms.terminated_by.is_none())
+}
+
+/// Drop the retainer terms of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&RecurringKey::RecurringTerms(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Turn a single-milestone job into a retainer renewing every
+    /// `period_secs`. Client only, before funding. The milestone is the
+    /// template for every period.
+    pub fn set_recurring(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        period_secs: u64,
+    ) -> Result<(), RecurringError> {
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(RecurringError::Unauthorized);
+        }
+        state::transition(&job.status, JobEvent::Configure)
+            .map_err(|_| RecurringError::InvalidStatus)?;
+        if period_secs < MIN_RECURRING_PERIOD_SECS {
+            return Err(RecurringError::InvalidPeriod);
+        }
+        if job.milestones.len() != 1 {
+            return Err(RecurringError::NotSingleMilestone);
+        }
+        let template = job.milestones.get(0).ok_or(RecurringError::NotSingleMilestone)?;
+        save_terms(
+            &env,
+            job_id,
+            &RecurringTerms {
+                period_secs,
+                description: template.description,
+                amount: template.amount,
+                renewals: 0,
+                terminated_by: None,
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("recurring")),
+            (job_id, period_secs, template.amount),
+        );
+        Ok(())
+    }
+
+    /// Append the next period's milestone to a funded retainer whose current
+    /// period has elapsed. Callable by anyone. The milestone amount, and the
+    /// client's share of the fee on it, are drawn from the client through the
+    /// escrow's token allowance. Returns the new milestone's ID.
+    pub fn renew(env: Env, job_id: u64) -> Result<u32, RecurringError> {
+        crate::pause::require_scope_open(&env, PauseScope::Create)
+            .map_err(|_| RecurringError::ContractPaused)?;
+        let mut job = load_job(&env, job_id)?;
+        let mut terms = load_terms(&env, job_id).ok_or(RecurringError::NotRecurring)?;
+        if terms.terminated_by.is_some() {
+            return Err(RecurringError::Terminated);
+        }
+        state::transition(&job.status, JobEvent::Work).map_err(|_| RecurringError::InvalidStatus)?;
+        let due = next_renewal_at(&job, &terms).ok_or(RecurringError::InvalidStatus)?;
+        let now = env.ledger().timestamp();
+        if now < due {
+            return Err(RecurringError::NotDue);
+        }
+        // Renew for the period `now` falls in, skipping any the client let lapse.
+        let funded_at = job.funded_at.unwrap_or(due);
+        let period = ((now - funded_at) / terms.period_secs).min(u32::MAX as u64);
+        let starts_at = funded_at.saturating_add(terms.period_secs.saturating_mul(period));
+        if job.milestones.len() >= crate::MAX_MILESTONES {
+            return Err(RecurringError::MilestoneLimit);
+        }
+        crate::attestation::enforce_attestation(
+            &env,
+            &job.token,
+            job.total_amount + terms.amount,
+            &job.client,
+            &job.freelancer,
+        );
+
+        let fee = crate::fees::accrue_added_value(&env, job_id, terms.amount);
+        let pull = terms.amount + fee;
+        let escrow = env.current_contract_address();
+        let token_client = token::Client::new(&env, &job.token);
+        if token_client.allowance(&job.client, &escrow) < pull
+            || token_client.balance(&job.client) < pull
+        {
+            return Err(RecurringError::NotFunded);
+        }
+        token_client.transfer_from(&escrow, &job.client, &escrow, &pull);
+
+        let milestone_id = job.milestones.len();
+        let deadline = starts_at.saturating_add(terms.period_secs);
+        job.milestones.push_back(Milestone {
+            id: milestone_id,
+            description: terms.description.clone(),
+            amount: terms.amount,
+            status: MilestoneStatus::Pending,
+            deadline,
+            token: None,
+            released: 0,
+        });
+        job.total_amount += terms.amount;
+        job.funded_amount += terms.amount;
+        job.job_deadline = job.job_deadline.max(deadline);
+        env.storage().persistent().set(&DataKey::Job(job_id), &job);
+        crate::bump_job_ttl(&env, job_id);
+        crate::deadlines::index_deadline(&env, job_id, deadline);
+        terms.renewals = period as u32;
+        save_terms(&env, job_id, &terms);
+        crate::changes::record(&env, job_id, crate::changes::ChangeKind::Renewed);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("renewed")),
+            (job_id, milestone_id, terms.amount, deadline),
+        );
+        Ok(milestone_id)
+    }
+
+    /// End a retainer. Client or freelancer. No further renewals happen; the
+    /// milestones already generated settle as usual. A retainer whose
+    /// milestones have all been paid out completes right away.
+    pub fn terminate_recurring(
+        env: Env,
+        job_id: u64,
+        caller: Address,
+    ) -> Result<(), RecurringError> {
+        caller.require_auth();
+        let mut job = load_job(&env, job_id)?;
+        if caller != job.client && caller != job.freelancer {
+            return Err(RecurringError::Unauthorized);
+        }
+        let mut terms = load_terms(&env, job_id).ok_or(RecurringError::NotRecurring)?;
+        if terms.terminated_by.is_some() {
+            return Err(RecurringError::Terminated);
+        }
+        terms.terminated_by = Some(caller.clone());
+        save_terms(&env, job_id, &terms);
+
+        let paid_out = job
+            .milestones
+            .iter()
+            .all(|m| m.status == MilestoneStatus::Approved && m.released == m.amount);
+        if paid_out {
+            if let Ok(next) = state::transition(&job.status, JobEvent::Complete) {
+                job.status = next;
+                job.completed_at = Some(env.ledger().timestamp());
+                env.storage().persistent().set(&DataKey::Job(job_id), &job);
+                crate::bump_job_ttl(&env, job_id);
+                crate::bonds::return_bond(&env, &job);
+                crate::on_job_closed(&env, &job);
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("rec_end")),
+            (job_id, caller, terms.renewals),
+        );
+        Ok(())
+    }
+
+    /// Retainer terms of `job_id`, or `None` if it is not a retainer.
+    pub fn get_recurring_terms(env: Env, job_id: u64) -> Option<RecurringTerms> {
+        load_terms(&env, job_id)
+    }
+
+    /// When `job_id`'s next renewal falls due, or `None` if it is not a
+    /// funded, running retainer.
+    pub fn get_next_renewal_at(env: Env, job_id: u64) -> Option<u64> {
+        let terms = load_terms(&env, job_id).filter(|t| t.terminated_by.is_none())?;
+        let job: Job = env.storage().persistent().get(&DataKey::Job(job_id))?;
+        next_renewal_at(&job, &terms)
+    }
+}
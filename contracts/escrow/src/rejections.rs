@@ -0,0 +1,211 @@
+//! Milestone rejections with structured reasons.
+//!
+//! A client who is not satisfied with a submitted milestone calls
+//! [`EscrowContract::reject_milestone`] instead of approving it. The milestone
+//! goes back to `InProgress` so the freelancer can rework and resubmit it.
+//!
+//! Each rejection carries a [`RejectionReason`] and, optionally, the hash of
+//! a free-text comment kept off-chain. Rejections are stored per job and
+//! emitted as events, so quality trends can be analysed on-chain and dispute
+//! reviewers can see why work was sent back.
+//!
+//! Each milestone also keeps a rejection count
+//! ([`EscrowContract::get_rejection_count`]). When the freelancer resubmits a
+//! rejected milestone, `submit_milestone` publishes `("escrow", "ms_resub")`
+//! with that count, so both sides of the back-and-forth show up in the event
+//! stream.
+
+use soroban_sdk::{
+    contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec,
+};
+
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Milestone rejection errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 1200, clear of `DisplayError`
+/// (1100..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RejectionError {
+    /// The contract is paused.
+    ContractPaused = 1200,
+    /// No job exists with the given ID.
+    JobNotFound = 1201,
+    /// Caller is not the job's client.
+    Unauthorized = 1202,
+    /// The job is not active, or the milestone is not awaiting review.
+    InvalidStatus = 1203,
+    /// The job has no milestone with the given ID.
+    MilestoneNotFound = 1204,
+}
+
+/// Why a client sent a milestone back.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RejectionReason {
+    IncompleteWork,
+    QualityBelowSpec,
+    WrongDeliverable,
+    Other,
+}
+
+/// One rejection of a submitted milestone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRejection {
+    pub milestone_id: u32,
+    pub reason: RejectionReason,
+    /// Hash of the client's off-chain comment; all zeros without one.
+    pub comment_hash: BytesN<32>,
+    pub rejected_at: u64,
+}
+
+/// Storage keys for milestone rejections.
+#[contracttype]
+#[derive(Clone)]
+pub enum RejectionKey {
+    /// Every rejection on a job, oldest first.
+    Rejections(u64),
+    /// Times a milestone has been rejected: (job_id, milestone_id) → `u32`.
+    Count(u64, u32),
+}
+
+fn load_rejections(env: &Env, job_id: u64) -> Vec<MilestoneRejection> {
+    env.storage()
+        .persistent()
+        .get(&RejectionKey::Rejections(job_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, RejectionError> {
+    env.storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(RejectionError::JobNotFound)
+}
+
+fn rejection_count(env: &Env, job_id: u64, milestone_id: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&RejectionKey::Count(job_id, milestone_id))
+        .unwrap_or(0)
+}
+
+/// Publish `("escrow", "ms_resub")` if the milestone just submitted had been
+/// rejected before. Called by `submit_milestone`.
+pub(crate) fn on_submitted(env: &Env, job_id: u64, milestone_id: u32) {
+    let count = rejection_count(env, job_id, milestone_id);
+    if count > 0 {
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_resub")),
+            (job_id, milestone_id, count),
+        );
+    }
+}
+
+/// Drop the rejection history and counts of an archived job.
+pub(crate) fn remove(env: &Env, job: &Job) {
+    env.storage()
+        .persistent()
+        .remove(&RejectionKey::Rejections(job.id));
+    for m in job.milestones.iter() {
+        env.storage()
+            .persistent()
+            .remove(&RejectionKey::Count(job.id, m.id));
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Send a submitted milestone back to the freelancer for rework. Client
+    /// only. The milestone returns to `InProgress` and may be resubmitted.
+    pub fn reject_milestone(
+        env: Env,
+        job_id: u64,
+        milestone_id: u32,
+        client: Address,
+        reason: RejectionReason,
+        comment_hash: Option<BytesN<32>>,
+    ) -> Result<(), RejectionError> {
+        crate::require_not_paused(&env).map_err(|_| RejectionError::ContractPaused)?;
+        client.require_auth();
+        let mut job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(RejectionError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Work)
+            .map_err(|_| RejectionError::InvalidStatus)?;
+        let mut milestone = job
+            .milestones
+            .get(milestone_id)
+            .ok_or(RejectionError::MilestoneNotFound)?;
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(RejectionError::InvalidStatus);
+        }
+
+        milestone.status = MilestoneStatus::InProgress;
+        job.milestones.set(milestone_id, milestone);
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::Job(job_id), &job);
+        crate::bump_job_ttl(&env, job_id);
+
+        // The submission no longer counts toward inactivity auto-approval.
+        env.storage()
+            .persistent()
+            .remove(&crate::DataKey::MilestoneSubmittedAt(job_id, milestone_id));
+        env.storage()
+            .persistent()
+            .remove(&crate::DataKey::InactivityAutoApproveAt(job_id, milestone_id));
+
+        let comment_hash = comment_hash.unwrap_or(BytesN::from_array(&env, &[0; 32]));
+        let mut rejections = load_rejections(&env, job_id);
+        rejections.push_back(MilestoneRejection {
+            milestone_id,
+            reason,
+            comment_hash: comment_hash.clone(),
+            rejected_at: env.ledger().timestamp(),
+        });
+        let key = RejectionKey::Rejections(job_id);
+        env.storage().persistent().set(&key, &rejections);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        let count = rejection_count(&env, job_id, milestone_id) + 1;
+        let count_key = RejectionKey::Count(job_id, milestone_id);
+        env.storage().persistent().set(&count_key, &count);
+        env.storage().persistent().extend_ttl(
+            &count_key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        crate::changes::record(&env, job_id, crate::changes::ChangeKind::MilestoneRejected);
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("ms_reject")),
+            (job_id, milestone_id, reason, comment_hash, count),
+        );
+        Ok(())
+    }
+
+    /// Number of times a milestone has been rejected.
+    pub fn get_rejection_count(env: Env, job_id: u64, milestone_id: u32) -> u32 {
+        rejection_count(&env, job_id, milestone_id)
+    }
+
+    /// Every rejection recorded on a job, oldest first.
+    pub fn get_milestone_rejections(env: Env, job_id: u64) -> Vec<MilestoneRejection> {
+        load_rejections(&env, job_id)
+    }
+}
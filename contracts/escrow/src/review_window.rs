@@ -0,0 +1,177 @@
+//! Client review windows.
+//!
+//! A freelancer whose client goes silent on a submitted milestone can already
+//! start the inactivity flow, but that takes a week plus a grace period and a
+//! second call from one of the parties. Every job therefore has a review
+//! period, [`DEFAULT_REVIEW_PERIOD_SECS`] unless the client picks another one
+//! with [`EscrowContract::set_review_period`] while the job is still
+//! `Created`. The client cannot opt out of it: the window is what protects the
+//! freelancer from a silent client. Once a milestone has sat `Submitted` for
+//! that long, anyone may call
+//! [`EscrowContract::finalize_overdue_milestone`], which approves it and pays
+//! it out exactly as the inactivity flow does.
+//!
+//! Rejecting or approving the milestone before the window ends stops the
+//! clock; a resubmission starts it again.
+
+use soroban_sdk::{contracterror, contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::pause::PauseScope;
+use crate::state::JobEvent;
+use crate::{EscrowContract, Job, MilestoneStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Shortest review period a job may set.
+pub const MIN_REVIEW_PERIOD_SECS: u64 = 24 * 3600;
+/// Longest review period a job may set.
+pub const MAX_REVIEW_PERIOD_SECS: u64 = 60 * 24 * 3600;
+/// Review period of a job whose client has not set one.
+pub const DEFAULT_REVIEW_PERIOD_SECS: u64 = 14 * 24 * 3600;
+
+/// Review window errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2200, clear of
+/// `MilestoneTokenError` (2100..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReviewError {
+    /// The contract, or its payouts, are paused.
+    ContractPaused = 2200,
+    /// No job exists with the given ID.
+    JobNotFound = 2201,
+    /// Caller is not the job's client.
+    Unauthorized = 2202,
+    /// The job is not in a status that allows the call, or the milestone is
+    /// not `Submitted`.
+    InvalidStatus = 2203,
+    /// The job has no milestone with the given ID.
+    MilestoneNotFound = 2204,
+    /// Review period is non-zero and outside
+    /// `MIN_REVIEW_PERIOD_SECS..=MAX_REVIEW_PERIOD_SECS`.
+    InvalidPeriod = 2205,
+    /// The client's review window for the milestone has not ended yet.
+    WindowOpen = 2207,
+}
+
+/// Storage keys for review windows.
+#[contracttype]
+#[derive(Clone)]
+pub enum ReviewKey {
+    /// Seconds the client has to review a submitted milestone: job_id → `u64`.
+    Period(u64),
+}
+
+fn load_job(env: &Env, job_id: u64) -> Result<Job, ReviewError> {
+    env.storage()
+        .persistent()
+        .get(&crate::DataKey::Job(job_id))
+        .ok_or(ReviewError::JobNotFound)
+}
+
+fn review_period(env: &Env, job_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ReviewKey::Period(job_id))
+        .unwrap_or(DEFAULT_REVIEW_PERIOD_SECS)
+}
+
+/// Drop the review period of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage().persistent().remove(&ReviewKey::Period(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Give the client `period_secs` to review each submitted milestone, after
+    /// which anyone may approve it with `finalize_overdue_milestone`. Client
+    /// only, before the job is funded; zero restores
+    /// [`DEFAULT_REVIEW_PERIOD_SECS`].
+    pub fn set_review_period(
+        env: Env,
+        job_id: u64,
+        client: Address,
+        period_secs: u64,
+    ) -> Result<(), ReviewError> {
+        client.require_auth();
+        let job = load_job(&env, job_id)?;
+        if job.client != client {
+            return Err(ReviewError::Unauthorized);
+        }
+        crate::state::transition(&job.status, JobEvent::Configure)
+            .map_err(|_| ReviewError::InvalidStatus)?;
+        if period_secs != 0
+            && !(MIN_REVIEW_PERIOD_SECS..=MAX_REVIEW_PERIOD_SECS).contains(&period_secs)
+        {
+            return Err(ReviewError::InvalidPeriod);
+        }
+
+        let key = ReviewKey::Period(job_id);
+        if period_secs == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &period_secs);
+            env.storage().persistent().extend_ttl(
+                &key,
+                crate::TTL_THRESHOLD_LEDGERS,
+                crate::TTL_EXTEND_TO_LEDGERS,
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("rev_per")),
+            (job_id, period_secs),
+        );
+        Ok(())
+    }
+
+    /// The job's review period in seconds.
+    pub fn get_review_period(env: Env, job_id: u64) -> u64 {
+        review_period(&env, job_id)
+    }
+
+    /// Approve and pay a submitted milestone whose review window has ended.
+    /// Anyone may call this; the payment goes to the freelancer as if the
+    /// client had approved it.
+    pub fn finalize_overdue_milestone(
+        env: Env,
+        job_id: u64,
+        milestone_id: u32,
+    ) -> Result<(), ReviewError> {
+        crate::pause::require_scope_open(&env, PauseScope::Payouts)
+            .map_err(|_| ReviewError::ContractPaused)?;
+        let mut job = load_job(&env, job_id)?;
+        let next = crate::state::transition(&job.status, JobEvent::Progress)
+            .map_err(|_| ReviewError::InvalidStatus)?;
+        let milestone = job
+            .milestones
+            .get(milestone_id)
+            .ok_or(ReviewError::MilestoneNotFound)?;
+        if milestone.status != MilestoneStatus::Submitted {
+            return Err(ReviewError::InvalidStatus);
+        }
+
+        let period = review_period(&env, job_id);
+        let submitted_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::MilestoneSubmittedAt(job_id, milestone_id))
+            .ok_or(ReviewError::InvalidStatus)?;
+        if env.ledger().timestamp() < submitted_at.saturating_add(period) {
+            return Err(ReviewError::WindowOpen);
+        }
+
+        let (fee_bps, fee_amount, net_amount) =
+            crate::approve_unreviewed(&env, &mut job, &milestone, next);
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("overdue")),
+            (job_id, milestone_id, fee_bps, fee_amount, net_amount),
+        );
+        Ok(())
+    }
+}
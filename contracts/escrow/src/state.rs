@@ -0,0 +1,109 @@
+//! Job status state machine.
+//!
+//! Every entry point that acts on a job names what it is doing as a
+//! [`JobEvent`] and asks [`transition`] which status the job moves to. The
+//! match in `transition` is the one table of legal moves: any (status, event)
+//! pair it does not list is rejected with `EscrowError::InvalidStatus`, so an
+//! operation cannot reach a job in a state nobody considered for it — a
+//! milestone approved on a disputed job, a revision on a cancelled one.
+//!
+//! | event             | from                                  | to          |
+//! |-------------------|---------------------------------------|-------------|
+//! | `Configure`       | Created                               | unchanged   |
+//! | `Fund`            | Created                               | Funded      |
+//! | `Amend`           | Created, Funded, InProgress           | unchanged   |
+//! | `Work`            | Funded, InProgress                    | unchanged   |
+//! | `Progress`        | Funded, InProgress                    | InProgress  |
+//! | `Complete`        | InProgress                            | Completed   |
+//! | `Cancel`          | Funded, InProgress                    | Cancelled   |
+//! | `Dispute`         | Funded, InProgress, Disputed          | Disputed    |
+//! | `Adjust`          | Funded, InProgress, Disputed          | unchanged   |
+//! | `AwardFreelancer` | Funded, InProgress, Disputed          | Completed   |
+//! | `AwardClient`     | Funded, InProgress, Disputed          | Cancelled   |
+//! | `Escalate`        | Funded, InProgress, Disputed          | unchanged   |
+//! | `Withdraw`        | Funded, InProgress, Disputed          | Cancelled   |
+//! | `Expire`          | Created, Funded, InProgress, Disputed | Expired     |
+//!
+//! Completed, Cancelled and Expired admit no event.
+
+use crate::{EscrowError, JobStatus};
+
+/// What an entry point does to a job.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum JobEvent {
+    /// Set up terms that only apply before funding (fee payer).
+    Configure,
+    /// Deposit the escrow.
+    Fund,
+    /// Change the terms or attachments of an open job: revisions, deadline
+    /// extensions, bonds, bonuses, payout routing, token migration.
+    Amend,
+    /// Work-phase bookkeeping that leaves the status alone: top-ups,
+    /// rejections, expenses, checkpoint reviews, review extensions.
+    Work,
+    /// Submit, approve or pay a milestone.
+    Progress,
+    /// Close the job once every milestone is delivered.
+    Complete,
+    /// Client cancels, reclaims an abandoned job or exits at a checkpoint.
+    Cancel,
+    /// The dispute contract freezes the job.
+    Dispute,
+    /// Signers move part of the escrow without closing the job.
+    Adjust,
+    /// A dispute awards the remaining escrow to the freelancer.
+    AwardFreelancer,
+    /// A dispute returns, splits or slashes the remaining escrow.
+    AwardClient,
+    /// A dispute is escalated without settling.
+    Escalate,
+    /// Signers withdraw the escrow in an emergency.
+    Withdraw,
+    /// The job deadline passed.
+    Expire,
+}
+
+impl JobEvent {
+    /// Every event, for exhaustive checks of the transition table.
+    #[cfg(test)]
+    pub(crate) const ALL: [JobEvent; 14] = [
+        JobEvent::Configure,
+        JobEvent::Fund,
+        JobEvent::Amend,
+        JobEvent::Work,
+        JobEvent::Progress,
+        JobEvent::Complete,
+        JobEvent::Cancel,
+        JobEvent::Dispute,
+        JobEvent::Adjust,
+        JobEvent::AwardFreelancer,
+        JobEvent::AwardClient,
+        JobEvent::Escalate,
+        JobEvent::Withdraw,
+        JobEvent::Expire,
+    ];
+}
+
+/// Status a job in `status` moves to on `event`, or `InvalidStatus` if the
+/// event is not allowed there.
+pub(crate) fn transition(status: &JobStatus, event: JobEvent) -> Result<JobStatus, EscrowError> {
+    use JobEvent::*;
+    use JobStatus::{Cancelled, Completed, Created, Disputed, Expired, Funded, InProgress};
+
+    let next = match (status, event) {
+        (Created, Configure) => Created,
+        (Created, Fund) => Funded,
+        (Created | Funded | InProgress, Amend) => status.clone(),
+        (Funded | InProgress, Work) => status.clone(),
+        (Funded | InProgress, Progress) => InProgress,
+        (InProgress, Complete) => Completed,
+        (Funded | InProgress, Cancel) => Cancelled,
+        (Funded | InProgress | Disputed, Dispute) => Disputed,
+        (Funded | InProgress | Disputed, Adjust | Escalate) => status.clone(),
+        (Funded | InProgress | Disputed, AwardFreelancer) => Completed,
+        (Funded | InProgress | Disputed, AwardClient | Withdraw) => Cancelled,
+        (Created | Funded | InProgress | Disputed, Expire) => Expired,
+        _ => return Err(EscrowError::InvalidStatus),
+    };
+    Ok(next)
+}
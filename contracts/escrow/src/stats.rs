@@ -0,0 +1,113 @@
+//! Daily job lifecycle counts.
+//!
+//! Marketplace dashboards chart how many jobs are created, funded, completed,
+//! cancelled and disputed each day. Rather than have every frontend run an
+//! indexer for that, the escrow keeps one [`DailyStats`] per UTC day in a ring
+//! of [`STATS_WINDOW_DAYS`] slots in instance storage, fed from the same hook
+//! as the change feed, and serves them through
+//! [`EscrowContract::get_daily_stats`]. Expired and refunded jobs count as
+//! cancelled.
+//!
+//! When the first job of a new day is counted, the previous active day's
+//! totals are published as a `("escrow", "day_stats")` event so indexers that
+//! do exist can keep the full history past the window.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, Vec};
+
+use crate::changes::ChangeKind;
+use crate::EscrowContract;
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Number of days of counts retained.
+pub const STATS_WINDOW_DAYS: u32 = 30;
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Job lifecycle counts for one UTC day.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DailyStats {
+    /// Days since the Unix epoch.
+    pub day: u64,
+    pub created: u32,
+    pub funded: u32,
+    pub completed: u32,
+    pub cancelled: u32,
+    pub disputed: u32,
+}
+
+/// Storage keys for the daily counts.
+#[contracttype]
+#[derive(Clone)]
+pub enum StatsKey {
+    /// Ring slot `day % STATS_WINDOW_DAYS` (instance).
+    Day(u32),
+    /// Last day anything was counted (`u64`, instance).
+    LastDay,
+}
+
+fn today(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECS_PER_DAY
+}
+
+fn load(env: &Env, day: u64) -> Option<DailyStats> {
+    env.storage()
+        .instance()
+        .get::<_, DailyStats>(&StatsKey::Day((day % STATS_WINDOW_DAYS as u64) as u32))
+        .filter(|stats| stats.day == day)
+}
+
+/// Count `kind` towards today's totals. Changes that are not one of the
+/// tracked lifecycle steps are ignored.
+pub(crate) fn count(env: &Env, kind: ChangeKind) {
+    let day = today(env);
+    let mut stats = load(env, day).unwrap_or(DailyStats {
+        day,
+        ..Default::default()
+    });
+    let counter = match kind {
+        ChangeKind::Created => &mut stats.created,
+        ChangeKind::Funded => &mut stats.funded,
+        ChangeKind::Completed => &mut stats.completed,
+        ChangeKind::Cancelled | ChangeKind::Expired => &mut stats.cancelled,
+        ChangeKind::Disputed => &mut stats.disputed,
+        _ => return,
+    };
+    *counter = counter.saturating_add(1);
+
+    let last_day: Option<u64> = env.storage().instance().get(&StatsKey::LastDay);
+    if last_day != Some(day) {
+        if let Some(previous) = last_day.and_then(|d| load(env, d)) {
+            env.events()
+                .publish((symbol_short!("escrow"), symbol_short!("day_stats")), previous);
+        }
+        env.storage().instance().set(&StatsKey::LastDay, &day);
+    }
+    env.storage().instance().set(
+        &StatsKey::Day((day % STATS_WINDOW_DAYS as u64) as u32),
+        &stats,
+    );
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Counts for today and the `days_back` days before it, newest first.
+    /// Days without activity are returned zeroed; at most
+    /// [`STATS_WINDOW_DAYS`] days are retained.
+    pub fn get_daily_stats(env: Env, days_back: u32) -> Vec<DailyStats> {
+        let today = today(&env);
+        let days = days_back.min(STATS_WINDOW_DAYS - 1) as u64;
+        let mut stats = Vec::new(&env);
+        for back in 0..=days.min(today) {
+            let day = today - back;
+            stats.push_back(load(&env, day).unwrap_or(DailyStats {
+                day,
+                ..Default::default()
+            }));
+        }
+        stats
+    }
+}
@@ -0,0 +1,170 @@
+//! Jobs by status.
+//!
+//! Indexers and dashboards want the open, disputed or completed jobs without
+//! walking every ID up to the job counter. Each status keeps an index of the
+//! jobs currently in it, split into pages of [`STATUS_PAGE_CAPACITY`] IDs so
+//! no single entry grows with the contract's history. Every status transition
+//! moves the job from its old status's index to the new one, and
+//! [`EscrowContract::get_jobs_by_status`] pages through an index oldest first.
+//!
+//! Pages are only ever appended to; a job leaving a status leaves a gap, and
+//! pages that empty out at the front are skipped from then on. Archived jobs
+//! stay in the index of their final status, with their summaries readable
+//! through `get_job_summary`. Private jobs are left out of the view like every
+//! other public index, and dropped from it when archived. Jobs created before
+//! the index existed are picked up at their next transition.
+
+use soroban_sdk::{contractimpl, contracttype, Env, Vec};
+
+use crate::{EscrowContract, Job, JobStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Number of job IDs stored per index page.
+pub const STATUS_PAGE_CAPACITY: u32 = 100;
+/// Maximum page size for `get_jobs_by_status`.
+pub const MAX_STATUS_PAGE_SIZE: u32 = 50;
+
+/// Storage keys for the status index.
+#[contracttype]
+#[derive(Clone)]
+pub enum StatusKey {
+    /// `(first, end)` range of pages in use for a status: `first` is the
+    /// oldest page that may still hold IDs, `end` the next page to allocate.
+    Pages(JobStatus),
+    /// Up to [`STATUS_PAGE_CAPACITY`] IDs of jobs in a status, oldest first.
+    Page(JobStatus, u32),
+    /// The status and page a job is indexed under.
+    Slot(u64),
+}
+
+// Index entries have to outlive the jobs they list, which are only bumped
+// when touched, so like the pair history they are kept alive for as long as
+// the network allows.
+fn save<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(env: &Env, key: &StatusKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    let max_ttl = env.storage().max_ttl();
+    env.storage()
+        .persistent()
+        .extend_ttl(key, max_ttl.min(crate::TTL_THRESHOLD_LEDGERS), max_ttl);
+}
+
+fn page_range(env: &Env, status: &JobStatus) -> (u32, u32) {
+    env.storage()
+        .persistent()
+        .get(&StatusKey::Pages(status.clone()))
+        .unwrap_or((0, 0))
+}
+
+fn load_page(env: &Env, status: &JobStatus, page: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&StatusKey::Page(status.clone(), page))
+        .unwrap_or(Vec::new(env))
+}
+
+fn insert(env: &Env, status: &JobStatus, job_id: u64) {
+    let (first, mut end) = page_range(env, status);
+    let mut page = end.saturating_sub(1);
+    let mut ids = if end > 0 {
+        load_page(env, status, page)
+    } else {
+        Vec::new(env)
+    };
+    if end == 0 || ids.len() >= STATUS_PAGE_CAPACITY {
+        page = end;
+        end += 1;
+        ids = Vec::new(env);
+        save(env, &StatusKey::Pages(status.clone()), &(first, end));
+    }
+    ids.push_back(job_id);
+    save(env, &StatusKey::Page(status.clone(), page), &ids);
+    save(env, &StatusKey::Slot(job_id), &(status.clone(), page));
+}
+
+fn remove(env: &Env, status: &JobStatus, page: u32, job_id: u64) {
+    let mut ids = load_page(env, status, page);
+    let Some(i) = ids.first_index_of(job_id) else {
+        return;
+    };
+    ids.remove(i);
+    save(env, &StatusKey::Page(status.clone(), page), &ids);
+
+    // Skip leading pages that no longer hold anything, but never the page
+    // still being filled.
+    let (first, end) = page_range(env, status);
+    if page != first || !ids.is_empty() {
+        return;
+    }
+    let mut next = first;
+    while next + 1 < end && load_page(env, status, next).is_empty() {
+        next += 1;
+    }
+    if next != first {
+        save(env, &StatusKey::Pages(status.clone()), &(next, end));
+    }
+}
+
+/// Move `job` into the index of its current status. A no-op when it is
+/// already indexed there, so it is safe to call after any write that may
+/// have changed the status.
+pub(crate) fn sync(env: &Env, job: &Job) {
+    let slot: Option<(JobStatus, u32)> =
+        env.storage().persistent().get(&StatusKey::Slot(job.id));
+    if let Some((status, page)) = slot {
+        if status == job.status {
+            return;
+        }
+        remove(env, &status, page, job.id);
+    }
+    insert(env, &job.status, job.id);
+}
+
+/// Drop a private job from its final status's index when it is archived,
+/// since the visibility flag that hid it goes with the job.
+pub(crate) fn on_archive(env: &Env, job: &Job) {
+    if !crate::visibility::is_private(env, job.id) {
+        return;
+    }
+    let slot_key = StatusKey::Slot(job.id);
+    let slot: Option<(JobStatus, u32)> = env.storage().persistent().get(&slot_key);
+    if let Some((status, page)) = slot {
+        remove(env, &status, page, job.id);
+        env.storage().persistent().remove(&slot_key);
+    }
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Page through the IDs of jobs currently in `status`, oldest first.
+    /// `limit` is capped at [`MAX_STATUS_PAGE_SIZE`]; private jobs are left
+    /// out.
+    pub fn get_jobs_by_status(env: Env, status: JobStatus, offset: u32, limit: u32) -> Vec<u64> {
+        let limit = limit.min(MAX_STATUS_PAGE_SIZE);
+        let mut jobs = Vec::new(&env);
+        if limit == 0 {
+            return jobs;
+        }
+
+        let (first, end) = page_range(&env, &status);
+        let mut skipped: u32 = 0;
+        for page in first..end {
+            for job_id in load_page(&env, &status, page).iter() {
+                if crate::visibility::is_private(&env, job_id) {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                jobs.push_back(job_id);
+                if jobs.len() >= limit {
+                    return jobs;
+                }
+            }
+        }
+        jobs
+    }
+}
@@ -2,7 +2,7 @@ use soroban_sdk::{
     contract, contractimpl,
     testutils::{Address as _, Events, Ledger},
     token::{StellarAssetClient, TokenClient},
-    vec, Address, Env, IntoVal, String, Symbol, Vec,
+    vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
 
 use crate::*;
@@ -16,7 +16,6 @@ impl MockToken {
 }
 
 const GRACE_PERIOD: u64 = 604_800; // 7 days in seconds
-const MIN_STAKE: i128 = 10_000_000;
 const JOB_DEADLINE: u64 = 1_000_000; // Example value
 const DEFAULT_EXPIRY_LEDGER: u32 = 518_400; // 30 days in ledgers (17,280 ledgers/day * 30)
 
@@ -51,7 +50,7 @@ fn pause_escrow(env: &Env, client: &EscrowContractClient<'_>, admin: &Address) {
     client.approve_admin_action(&temp_signer, &proposal_id);
 }
 
-fn unpause_escrow(env: &Env, client: &EscrowContractClient<'_>, admin: &Address) {
+fn unpause_escrow(_env: &Env, client: &EscrowContractClient<'_>, admin: &Address) {
     // Unpause has no time lock and threshold is 1, so it auto-executes on propose.
     client.propose_admin_action(admin, &AdminAction::Unpause);
 }
@@ -82,7 +81,7 @@ fn test_create_job() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, client_addr, freelancer, token, admin) = setup_test(&env);
+    let (contract, client_addr, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![
         &env,
@@ -183,7 +182,7 @@ fn test_job_count_increments() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, user, freelancer, token, admin) = setup_test(&env);
+    let (contract, user, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Task 1"), 100_i128, JOB_DEADLINE)];
 
@@ -218,7 +217,7 @@ fn test_create_job_invalid_deadline() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, user, freelancer, token, admin) = setup_test(&env);
+    let (contract, user, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![
         &env,
@@ -243,7 +242,7 @@ fn test_create_job_empty_milestones() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, user, freelancer, token, admin) = setup_test(&env);
+    let (contract, user, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env];
 
@@ -265,7 +264,7 @@ fn test_create_job_too_many_milestones() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, user, freelancer, token, admin) = setup_test(&env);
+    let (contract, user, freelancer, token, _admin) = setup_test(&env);
 
     let mut milestones = vec![&env];
     for i in 0..51u64 {
@@ -289,7 +288,7 @@ fn test_create_job_max_milestones_succeeds() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, user, freelancer, token, admin) = setup_test(&env);
+    let (contract, user, freelancer, token, _admin) = setup_test(&env);
 
     let mut milestones = vec![&env];
     for i in 0u64..20 {
@@ -314,7 +313,7 @@ fn test_create_job_single_milestone_succeeds() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, user, freelancer, token, admin) = setup_test(&env);
+    let (contract, user, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Single"), 100_i128, 2000_u64)];
 
@@ -389,13 +388,13 @@ fn test_is_milestone_overdue() {
     );
 
     // not overdue initially
-    assert_eq!(client.is_milestone_overdue(&job_id, &0), false);
+    assert!(!client.is_milestone_overdue(&job_id, &0));
 
     // fast forward past deadline
     env.ledger().with_mut(|l| l.timestamp = 2500);
 
     // overdue now
-    assert_eq!(client.is_milestone_overdue(&job_id, &0), true);
+    assert!(client.is_milestone_overdue(&job_id, &0));
 }
 
 #[test]
@@ -445,6 +444,14 @@ fn setup_refund_env(env: &Env) -> (EscrowContractClient<'_>, Address) {
     (escrow, token_addr)
 }
 
+/// Initialize an escrow from `setup_refund_env` and register a dispute
+/// contract, so tests can call `resolve_dispute_callback` directly.
+fn register_dispute_contract(env: &Env, escrow: &EscrowContractClient<'_>) {
+    let admin = Address::generate(env);
+    escrow.initialize(&vec![env, admin.clone()], &1, &admin, &0, &604800);
+    escrow.set_dispute_contract(&admin, &Address::generate(env));
+}
+
 fn mint_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
     let admin_client = StellarAssetClient::new(env, token);
     admin_client.mint(to, &amount);
@@ -767,7 +774,7 @@ fn test_claim_refund_fails_on_cancelled_job() {
 fn test_client_can_propose_revision() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -785,6 +792,8 @@ fn test_client_can_propose_revision() {
             amount: m0_amount,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
         Milestone {
             id: 1,
@@ -792,6 +801,8 @@ fn test_client_can_propose_revision() {
             amount: m1_amount,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
 
@@ -809,7 +820,7 @@ fn test_client_can_propose_revision() {
 fn test_freelancer_can_propose_revision() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -824,6 +835,8 @@ fn test_freelancer_can_propose_revision() {
             amount: m0_amount,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -840,7 +853,7 @@ fn test_freelancer_can_propose_revision() {
 fn test_propose_revision_fails_for_disputed_job() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -860,6 +873,8 @@ fn test_propose_revision_fails_for_disputed_job() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
 
@@ -871,7 +886,7 @@ fn test_propose_revision_fails_for_disputed_job() {
 fn test_propose_revision_fails_for_completed_job() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -891,6 +906,8 @@ fn test_propose_revision_fails_for_completed_job() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
 
@@ -902,7 +919,7 @@ fn test_propose_revision_fails_for_completed_job() {
 fn test_propose_revision_fails_for_cancelled_job() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -922,6 +939,8 @@ fn test_propose_revision_fails_for_cancelled_job() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
 
@@ -962,6 +981,8 @@ fn test_accept_revision_preserves_partially_paid_milestone() {
             amount: new_amount,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -970,11 +991,11 @@ fn test_accept_revision_preserves_partially_paid_milestone() {
     let job = contract.get_job(&job_id);
     assert_eq!(job.total_amount, new_amount);
 
-    // The already-disbursed 700 must be preserved: the stored milestone should reflect
-    // only the remaining, unpaid balance (1200 - 700 = 500), not the raw proposed amount.
+    // The already-disbursed 700 must be preserved: the stored milestone carries it in
+    // `released`, leaving only the unpaid balance (1200 - 700 = 500) to release.
     let ms = job.milestones.get(0).unwrap();
     assert_eq!(ms.status, MilestoneStatus::PartiallyPaid);
-    assert_eq!(ms.amount, new_amount - disbursed);
+    assert_eq!((ms.amount, ms.released), (new_amount, disbursed));
 
     // Freelancer's already-received funds are untouched.
     assert_eq!(token.balance(&freelancer), disbursed);
@@ -1015,6 +1036,8 @@ fn test_accept_revision_fails_when_new_total_below_disbursed_amount() {
             amount: 500,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1054,6 +1077,8 @@ fn test_accept_revision_fails_when_dropping_a_paid_milestone() {
             amount: 600,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE + 1,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1066,7 +1091,7 @@ fn test_accept_revision_fails_when_dropping_a_paid_milestone() {
 fn test_approve_milestone_fails_for_disputed_job() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1087,7 +1112,7 @@ fn test_approve_milestone_fails_for_disputed_job() {
 fn test_submit_milestone_fails_for_disputed_job() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1108,7 +1133,7 @@ fn test_submit_milestone_fails_for_disputed_job() {
 fn test_approve_milestones_batch_fails_for_disputed_job() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1129,7 +1154,7 @@ fn test_approve_milestones_batch_fails_for_disputed_job() {
 fn test_propose_revision_fails_for_non_party() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
     let third_party = Address::generate(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
@@ -1143,6 +1168,8 @@ fn test_propose_revision_fails_for_non_party() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&third_party, &job_id, &new_milestones);
@@ -1153,7 +1180,7 @@ fn test_propose_revision_fails_for_non_party() {
 fn test_propose_revision_fails_when_pending_proposal_exists() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1166,6 +1193,8 @@ fn test_propose_revision_fails_when_pending_proposal_exists() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1177,7 +1206,7 @@ fn test_propose_revision_fails_when_pending_proposal_exists() {
 fn test_propose_revision_too_many_milestones() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1190,6 +1219,8 @@ fn test_propose_revision_too_many_milestones() {
             amount: 10,
             status: MilestoneStatus::Pending,
             deadline: (i as u64 + 1) * 10_000_u64,
+            token: None,
+            released: 0,
         });
     }
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1199,7 +1230,7 @@ fn test_propose_revision_too_many_milestones() {
 fn test_propose_revision_allowed_after_rejection() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1212,6 +1243,8 @@ fn test_propose_revision_allowed_after_rejection() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1230,7 +1263,7 @@ fn test_propose_revision_allowed_after_rejection() {
 fn test_propose_revision_fails_for_empty_milestones() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1243,7 +1276,7 @@ fn test_propose_revision_fails_for_empty_milestones() {
 fn test_propose_revision_new_total_equals_sum_of_milestones() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1261,6 +1294,8 @@ fn test_propose_revision_new_total_equals_sum_of_milestones() {
             amount: m0,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
         Milestone {
             id: 1,
@@ -1268,6 +1303,8 @@ fn test_propose_revision_new_total_equals_sum_of_milestones() {
             amount: m1,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1301,6 +1338,8 @@ fn test_accept_revision_same_total_updates_milestones_only() {
             amount: half,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
         Milestone {
             id: 1,
@@ -1308,6 +1347,8 @@ fn test_accept_revision_same_total_updates_milestones_only() {
             amount: half,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1348,6 +1389,8 @@ fn test_accept_revision_with_increased_total_transfers_difference_from_client()
             amount: new_amount,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1385,6 +1428,8 @@ fn test_accept_revision_with_decreased_total_refunds_difference_to_client() {
             amount: new_amount,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1399,7 +1444,7 @@ fn test_accept_revision_with_decreased_total_refunds_difference_to_client() {
 fn test_reject_revision_sets_status_to_rejected() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let original_total: i128 = 1000;
     let milestones = vec![&env, (String::from_str(&env, "Initial"), original_total, JOB_DEADLINE)];
@@ -1413,6 +1458,8 @@ fn test_reject_revision_sets_status_to_rejected() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1433,7 +1480,7 @@ fn test_reject_revision_sets_status_to_rejected() {
 fn test_proposer_cannot_accept_own_proposal() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1446,6 +1493,8 @@ fn test_proposer_cannot_accept_own_proposal() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1456,7 +1505,7 @@ fn test_proposer_cannot_accept_own_proposal() {
 fn test_propose_revision_emits_event() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1469,6 +1518,8 @@ fn test_propose_revision_emits_event() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1483,7 +1534,7 @@ fn test_propose_revision_emits_event() {
 fn test_accept_revision_emits_event() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -1497,6 +1548,8 @@ fn test_accept_revision_emits_event() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1525,6 +1578,8 @@ fn test_cancel_revision_proposal_happy_path() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1553,6 +1608,8 @@ fn test_freelancer_can_cancel_own_revision_proposal() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1581,6 +1638,8 @@ fn test_cancel_revision_fails_for_non_proposer() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1608,6 +1667,8 @@ fn test_cancel_revision_fails_for_third_party() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1635,6 +1696,8 @@ fn test_cancel_revision_fails_when_already_accepted() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&freelancer, &job_id, &new_milestones);
@@ -1662,6 +1725,8 @@ fn test_cancel_revision_fails_when_already_rejected() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1688,6 +1753,8 @@ fn test_cancel_revision_proposal_clears_slot_for_new_proposal() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1717,6 +1784,8 @@ fn test_cancel_revision_proposal_emits_event() {
             amount: 1200,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client, &job_id, &new_milestones);
@@ -1753,6 +1822,7 @@ fn test_resolve_dispute_callback_client_wins() {
     mint_tokens(&env, &token, &client, total);
     escrow.fund_job(&job_id, &client, &0, &0);
 
+    register_dispute_contract(&env, &escrow);
     escrow.resolve_dispute_callback(&job_id, &DisputeResolution::ClientWins);
 
     let job = escrow.get_job(&job_id);
@@ -1762,6 +1832,42 @@ fn test_resolve_dispute_callback_client_wins() {
     assert_eq!(token_client.balance(&client), total);
 }
 
+#[test]
+fn test_resolve_dispute_callback_requires_dispute_contract() {
+    let env = Env::default();
+    let (escrow, token) = setup_refund_env(&env);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let job_id = escrow.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &default_milestones(&env),
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    mint_tokens(&env, &token, &client, 3000);
+    escrow.fund_job(&job_id, &client, &0, &0);
+
+    // Nobody can settle a dispute before a dispute contract is registered.
+    assert_eq!(
+        escrow.try_resolve_dispute_callback(&job_id, &DisputeResolution::FreelancerWins),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+
+    // Once one is, a caller without its authorization is rejected and the
+    // funds stay in escrow.
+    register_dispute_contract(&env, &escrow);
+    env.set_auths(&[]);
+    assert!(escrow
+        .try_resolve_dispute_callback(&job_id, &DisputeResolution::FreelancerWins)
+        .is_err());
+    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Funded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 0);
+}
+
 #[test]
 fn test_resolve_dispute_callback_freelancer_wins() {
     let env = Env::default();
@@ -1786,6 +1892,7 @@ fn test_resolve_dispute_callback_freelancer_wins() {
     mint_tokens(&env, &token, &client, total);
     escrow.fund_job(&job_id, &client, &0, &0);
 
+    register_dispute_contract(&env, &escrow);
     escrow.resolve_dispute_callback(&job_id, &DisputeResolution::FreelancerWins);
 
     let job = escrow.get_job(&job_id);
@@ -1821,6 +1928,7 @@ fn test_resolve_dispute_callback_refund_both() {
     mint_tokens(&env, &token, &client, total);
     escrow.fund_job(&job_id, &client, &0, &0);
 
+    register_dispute_contract(&env, &escrow);
     escrow.resolve_dispute_callback(&job_id, &DisputeResolution::RefundBoth);
 
     let job = escrow.get_job(&job_id);
@@ -2150,7 +2258,7 @@ fn test_read_only_functions_when_paused() {
     assert_eq!(count, 1);
 
     let overdue = client.is_milestone_overdue(&job_id, &0);
-    assert_eq!(overdue, false);
+    assert!(!overdue);
 }
 
 // ── Batch Milestone Approval Tests ─────────────────────────────────────────────
@@ -2547,6 +2655,43 @@ fn test_complete_job_zero_fee() {
     assert_eq!(token_client.balance(&freelancer), amount);
 }
 
+#[test]
+fn test_job_records_lifecycle_timestamps() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (escrow, token) = setup_refund_env(&env);
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    mint_tokens(&env, &token, &client, 6000);
+
+    let milestones = vec![&env, (String::from_str(&env, "Task"), 500_i128, 2000_u64)];
+    let job_id = escrow.create_job(&client, &freelancer, &token, &milestones, &3000_u64, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let job = escrow.get_job(&job_id);
+    assert_eq!(job.created_at, Some(1000));
+    assert_eq!(job.funded_at, None);
+
+    env.ledger().with_mut(|l| l.timestamp = 1100);
+    escrow.fund_job(&job_id, &client, &0_i128, &0_u32);
+    escrow.submit_milestone(&job_id, &0, &freelancer);
+    env.ledger().with_mut(|l| l.timestamp = 1200);
+    escrow.approve_milestone(&job_id, &0, &client);
+    escrow.complete_job(&job_id, &client);
+    let job = escrow.get_job(&job_id);
+    assert_eq!(job.funded_at, Some(1100));
+    assert_eq!(job.completed_at, Some(1200));
+    assert_eq!(job.cancelled_at, None);
+
+    let job_id = escrow.create_job(&client, &freelancer, &token, &default_milestones(&env), &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    escrow.fund_job(&job_id, &client, &0, &0);
+    env.ledger().with_mut(|l| l.timestamp = 1300);
+    escrow.cancel_job(&job_id, &client, &0);
+    let job = escrow.get_job(&job_id);
+    assert_eq!(job.cancelled_at, Some(1300));
+    assert_eq!(job.completed_at, None);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #3)")] // InvalidStatus
 fn test_complete_job_not_all_approved() {
@@ -3031,14 +3176,14 @@ fn test_multisig_pause_flow() {
     let (contract, _, _, _, signer1, signer2) = setup_multisig(&env);
 
     // Initial state: not paused
-    assert_eq!(contract.is_paused(), false);
+    assert!(!contract.is_paused());
 
     // signer1 proposes pause
     let proposal_id = contract.propose_admin_action(&signer1, &AdminAction::Pause);
     assert_eq!(proposal_id, 1);
     
     // Proposal exists but not executed yet (needs 2/2)
-    assert_eq!(contract.is_paused(), false);
+    assert!(!contract.is_paused());
 
     // Advance past the 48-hour time lock required for Pause proposals
     env.ledger().with_mut(|l| l.timestamp += 48 * 60 * 60 + 1);
@@ -3047,7 +3192,125 @@ fn test_multisig_pause_flow() {
     contract.approve_admin_action(&signer2, &proposal_id);
 
     // Execution should be automatic after second approval
-    assert_eq!(contract.is_paused(), true);
+    assert!(contract.is_paused());
+}
+
+#[test]
+fn test_emergency_pause_by_one_signer_unpause_needs_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (contract, _, _, _, signer1, signer2) = setup_multisig(&env);
+
+    assert_eq!(
+        contract.try_emergency_pause(&Address::generate(&env)),
+        Err(Ok(EscrowError::SignerNotFound))
+    );
+    contract.emergency_pause(&signer1);
+    assert!(contract.is_paused());
+    assert_eq!(
+        contract.try_emergency_pause(&signer2),
+        Err(Ok(EscrowError::ContractPaused))
+    );
+
+    // One signer cannot lift the pause on their own.
+    let proposal_id = contract.propose_admin_action(&signer1, &AdminAction::Unpause);
+    assert!(contract.is_paused());
+    contract.approve_admin_action(&signer2, &proposal_id);
+    assert!(!contract.is_paused());
+}
+
+#[test]
+fn test_pause_scopes_freeze_payouts_but_keep_refunds_and_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Task 1"), 100_i128, 500_000_u64),
+        (String::from_str(&env, "Task 2"), 100_i128, 600_000_u64),
+    ];
+    let new_job = || {
+        client.create_job(&user, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER)
+    };
+    let working = new_job();
+    client.fund_job(&working, &user, &0, &0);
+    client.submit_milestone(&working, &0, &freelancer);
+    let idle = new_job();
+    client.fund_job(&idle, &user, &0, &0);
+
+    // Pausing a scope is time-locked like a full pause.
+    let temp_signer = Address::generate(&env);
+    client.propose_admin_action(&admin, &AdminAction::AddSigner(temp_signer.clone()));
+    let proposal_id =
+        client.propose_admin_action(&admin, &AdminAction::SetPauseScope(PauseScope::Payouts, true));
+    assert!(!client.is_scope_paused(&PauseScope::Payouts));
+    env.ledger().with_mut(|l| l.timestamp += 48 * 60 * 60 + 1);
+    client.approve_admin_action(&temp_signer, &proposal_id);
+    assert_eq!(client.get_paused_scopes(), vec![&env, PauseScope::Payouts]);
+    assert!(!client.is_paused());
+
+    assert_eq!(
+        client.try_approve_milestone(&working, &0, &user),
+        Err(Ok(EscrowError::ContractPaused))
+    );
+    // Creation, funding, deadline extensions and refunds still go through.
+    let fresh = new_job();
+    client.fund_job(&fresh, &user, &0, &0);
+    client.extend_deadline(&fresh, &0, &550_000_u64);
+    client.cancel_job(&idle, &user, &0);
+    assert_eq!(client.get_job(&idle).status, JobStatus::Cancelled);
+    // Side balances are paid out like milestones, so they wait too.
+    assert_eq!(
+        client.try_withdraw_expense_balance(&idle, &user),
+        Err(Ok(expenses::ExpenseError::ContractPaused))
+    );
+
+    // Resuming a scope is immediate and leaves the others untouched.
+    client.propose_admin_action(&admin, &AdminAction::SetPauseScope(PauseScope::Payouts, false));
+    client.approve_milestone(&working, &0, &user);
+    assert_eq!(
+        client.get_job(&working).milestones.get(0).unwrap().status,
+        MilestoneStatus::Approved
+    );
+    assert_eq!(client.get_paused_scopes(), vec![&env]);
+
+    // The global pause still covers every scope.
+    client.emergency_pause(&admin);
+    assert!(client.is_scope_paused(&PauseScope::Create));
+    assert_eq!(
+        client.try_create_job(&user, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER),
+        Err(Ok(EscrowError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_create_scope_pause_blocks_side_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (client, user, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Task"), 100_i128, JOB_DEADLINE)];
+    let job_id = client.create_job(&user, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    client.fund_job(&job_id, &user, &0, &0);
+
+    let proposal_id =
+        client.propose_admin_action(&admin, &AdminAction::SetPauseScope(PauseScope::Create, true));
+    env.ledger().with_mut(|l| l.timestamp += 48 * 60 * 60 + 1);
+    client.execute_proposal(&admin, &proposal_id);
+
+    assert_eq!(
+        client.try_fund_expenses(&job_id, &user, &50),
+        Err(Ok(expenses::ExpenseError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_add_early_bonus(&job_id, &user, &0, &10, &(JOB_DEADLINE - 1)),
+        Err(Ok(early_bonus::BonusError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_post_performance_bond(&job_id, &freelancer, &10),
+        Err(Ok(bonds::BondError::ContractPaused))
+    );
 }
 
 #[test]
@@ -3109,7 +3372,8 @@ fn test_release_partial_payment_happy_path() {
     let job = contract.get_job(&job_id);
     let ms = job.milestones.get(0).unwrap();
     assert_eq!(ms.status, MilestoneStatus::PartiallyPaid);
-    assert_eq!(ms.amount, amount - partial); // remaining = 300
+    assert_eq!(ms.amount, amount);
+    assert_eq!(ms.released, partial); // remaining = 300
     assert_eq!(token.balance(&freelancer), partial);
     assert_ne!(job.status, JobStatus::Completed); // not done yet
 }
@@ -3139,7 +3403,7 @@ fn test_release_partial_payment_fully_zeros_becomes_approved() {
     let job = contract.get_job(&job_id);
     let ms = job.milestones.get(0).unwrap();
     assert_eq!(ms.status, MilestoneStatus::Approved);
-    assert_eq!(ms.amount, 0);
+    assert_eq!(ms.released, amount);
     assert_eq!(job.status, JobStatus::InProgress);
     assert_eq!(token.balance(&freelancer), amount);
 }
@@ -3280,7 +3544,7 @@ fn test_execute_proposal_happy_path() {
     let proposal_id = contract.propose_admin_action(&admin, &AdminAction::Pause);
 
     // Verify it is NOT executed yet
-    assert_eq!(contract.is_paused(), false);
+    assert!(!contract.is_paused());
 
     // Call execute_proposal before time-lock expires, should fail
     let res = contract.try_execute_proposal(&admin, &proposal_id);
@@ -3291,7 +3555,7 @@ fn test_execute_proposal_happy_path() {
 
     // Execute successfully
     contract.execute_proposal(&admin, &proposal_id);
-    assert_eq!(contract.is_paused(), true);
+    assert!(contract.is_paused());
 }
 
 #[test]
@@ -3464,7 +3728,7 @@ fn test_top_up_escrow_emits_event() {
 fn test_add_allowed_token() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, _client, _freelancer, token, admin) = setup_test(&env);
+    let (contract, _client, _freelancer, _token, admin) = setup_test(&env);
 
     let new_token = Address::generate(&env);
     contract.add_allowed_token(&admin, &new_token);
@@ -3490,7 +3754,7 @@ fn test_add_allowed_token() {
 fn test_add_allowed_token_non_admin_fails() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, _token, _admin) = setup_test(&env);
+    let (contract, _client, freelancer, _token, _admin) = setup_test(&env);
 
     // freelancer is not a signer — should fail with NotAdmin (#16)
     let new_token = Address::generate(&env);
@@ -3542,7 +3806,7 @@ fn test_remove_allowed_token() {
 fn test_remove_allowed_token_non_admin_fails() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, client, freelancer, _token, _admin) = setup_test(&env);
+    let (contract, _client, freelancer, _token, _admin) = setup_test(&env);
 
     // freelancer is not a signer — should fail with NotAdmin (#16)
     let new_token = Address::generate(&env);
@@ -3654,6 +3918,25 @@ fn test_token_validation_happens_before_auth() {
     assert_eq!(contract_err, EscrowError::TokenNotAllowed);
 }
 
+#[test]
+fn test_post_job_with_non_whitelisted_token_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, _freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
+
+    // An empty allowlist admits every token.
+    let other_token = Address::generate(&env);
+    contract.post_job(&client, &other_token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+
+    contract.add_allowed_token(&admin, &token);
+    assert_eq!(
+        contract.try_post_job(&client, &other_token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER),
+        Err(Ok(postings::PostingError::TokenNotAllowed))
+    );
+    contract.post_job(&client, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+}
+
 // ============================================================
 // STATE MACHINE VALIDATION TESTS
 // ============================================================
@@ -4018,12 +4301,13 @@ fn test_resolve_dispute_fails_when_created() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
     
     // Try to resolve dispute on Created job (invalid: not disputable)
+    contract.set_dispute_contract(&admin, &Address::generate(&env));
     contract.resolve_dispute_callback(&job_id, &DisputeResolution::ClientWins);
 }
 
@@ -4034,7 +4318,7 @@ fn test_resolve_dispute_fails_when_completed() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -4045,6 +4329,7 @@ fn test_resolve_dispute_fails_when_completed() {
 
     // Job is now Completed (terminal state)
     // Try to resolve dispute (invalid: Completed is terminal)
+    contract.set_dispute_contract(&admin, &Address::generate(&env));
     contract.resolve_dispute_callback(&job_id, &DisputeResolution::ClientWins);
 }
 
@@ -4055,7 +4340,7 @@ fn test_resolve_dispute_fails_when_cancelled() {
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
 
-    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
 
     let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
     let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
@@ -4064,6 +4349,7 @@ fn test_resolve_dispute_fails_when_cancelled() {
     
     // Job is now Cancelled (terminal state)
     // Try to resolve dispute (invalid: Cancelled is terminal)
+    contract.set_dispute_contract(&admin, &Address::generate(&env));
     contract.resolve_dispute_callback(&job_id, &DisputeResolution::ClientWins);
 }
 
@@ -4195,6 +4481,7 @@ fn test_release_milestone_success() {
     let milestone = job.milestones.get(0).unwrap();
     assert_eq!(milestone.status, MilestoneStatus::Approved);
     assert_eq!(milestone.amount, 500);
+    assert_eq!(milestone.released, 500);
     // Job should still be InProgress (second milestone not yet submitted)
     assert_eq!(job.status, JobStatus::InProgress);
 }
@@ -4230,6 +4517,10 @@ fn test_release_milestone_completes_job() {
     assert_eq!(job.status, JobStatus::Completed);
 }
 
+/// `ms_released` event data: (job_id, milestone, client, freelancer, amount,
+/// fee_bps, fee_amount, net_amount).
+type MsReleasedPayload = (u64, u32, Address, Address, i128, u32, i128, i128);
+
 #[test]
 fn test_release_milestone_event_emitted() {
     let env = Env::default();
@@ -4259,7 +4550,7 @@ fn test_release_milestone_event_emitted() {
 
     let events = env.events().all();
     let mut found_ms_released = false;
-    let mut payload: Option<(u64, u32, Address, Address, i128)> = None;
+    let mut payload: Option<MsReleasedPayload> = None;
     for event in events.iter() {
         if event.0 == contract.address && event.1.len() >= 2 {
             let topic0: Symbol = event.1.get(0).unwrap().into_val(&env);
@@ -4278,6 +4569,8 @@ fn test_release_milestone_event_emitted() {
     assert_eq!(payload.2, client_addr);
     assert_eq!(payload.3, freelancer);
     assert_eq!(payload.4, 500);
+    // No fee configured: the whole milestone is net to the freelancer.
+    assert_eq!((payload.5, payload.6, payload.7), (0, 0, 500));
 }
 
 #[test]
@@ -4601,6 +4894,8 @@ fn test_expire_proposal_success() {
             amount: 600,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client_addr, &job_id, &new_milestones);
@@ -4649,6 +4944,8 @@ fn test_expire_proposal_event_emitted() {
             amount: 600,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client_addr, &job_id, &new_milestones);
@@ -4709,6 +5006,8 @@ fn test_expire_proposal_fails_before_ttl() {
             amount: 600,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client_addr, &job_id, &new_milestones);
@@ -4752,6 +5051,8 @@ fn test_expire_proposal_fails_non_proposer() {
             amount: 600,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client_addr, &job_id, &new_milestones);
@@ -4797,6 +5098,8 @@ fn test_expire_proposal_fails_non_pending() {
             amount: 500,
             status: MilestoneStatus::Pending,
             deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
         },
     ];
     contract.propose_revision(&client_addr, &job_id, &new_milestones);
@@ -5304,92 +5607,335 @@ fn test_xlm_only_job_bypasses_oracle() {
     assert!(escrow.get_rate_snapshot(&job_id).is_none());
 }
 
+/// Register `token` (a SAC standing in for the network's native asset) as the
+/// escrow's native XLM token.
+/// Storage layout of a Stellar Asset Contract balance, for crediting native
+/// XLM in tests: the native SAC has no admin to mint with.
+mod native_sac {
+    use soroban_sdk::{contracttype, Address};
+
+    #[contracttype]
+    pub struct BalanceValue {
+        pub amount: i128,
+        pub authorized: bool,
+        pub clawback: bool,
+    }
+
+    #[contracttype]
+    pub enum DataKey {
+        Balance(Address),
+    }
+}
+
+/// Deploy the native XLM SAC, credit `holder` with `amount` stroops and
+/// register it with the escrow. Returns its address.
+fn setup_native_xlm(env: &Env, escrow: &EscrowContractClient<'_>, admin: &Address, holder: &Address, amount: i128) -> Address {
+    let xlm = env
+        .deployer()
+        .with_stellar_asset(soroban_sdk::Bytes::from_array(env, &[0, 0, 0, 0]))
+        .deploy();
+    env.as_contract(&xlm, || {
+        env.storage().persistent().set(
+            &native_sac::DataKey::Balance(holder.clone()),
+            &native_sac::BalanceValue { amount, authorized: true, clawback: false },
+        );
+    });
+
+    // Only the native asset's own SAC can be registered.
+    let other = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    assert_eq!(
+        escrow.try_propose_admin_action(admin, &AdminAction::SetNativeToken(other)),
+        Err(Err(soroban_sdk::InvokeError::Contract(native::NativeError::NotNativeAsset as u32)))
+    );
+    escrow.propose_admin_action(admin, &AdminAction::SetNativeToken(xlm.clone()));
+    assert_eq!(escrow.get_native_token(), Some(xlm.clone()));
+    xlm
+}
+
 #[test]
-fn test_oracle_unavailable_when_not_configured() {
+fn test_xlm_job_fund_and_approve() {
     let env = Env::default();
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client_addr, freelancer, _, admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 4_000_i128, JOB_DEADLINE - 1),
+        (String::from_str(&env, "Build"), 6_000_i128, JOB_DEADLINE),
+    ];
 
-    let (escrow, client_addr, freelancer, token, _admin) = setup_test(&env);
-    let job_id = create_priced_job(&env, &escrow, &client_addr, &freelancer, &token, 100);
-
-    // agreed value > 0 but no oracle set -> OracleUnavailable, not a panic.
-    let res = escrow.try_fund_job(&job_id, &client_addr, &100, &200);
-    assert_eq!(res, Err(Ok(EscrowError::OracleUnavailable)));
-    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Created);
+    assert_eq!(
+        escrow.try_create_xlm_job(&client_addr, &freelancer, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER),
+        Err(Ok(native::NativeError::NativeTokenNotSet))
+    );
+    let xlm = setup_native_xlm(&env, &escrow, &admin, &client_addr, 10_000);
+    let job_id = escrow.create_xlm_job(&client_addr, &freelancer, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(escrow.get_job(&job_id).token, xlm);
+
+    // Native XLM is valued at par: no oracle is configured, yet an agreed
+    // value in stroops is checked and snapshotted.
+    escrow.fund_job(&job_id, &client_addr, &10_000, &0);
+    let snapshot = escrow.get_rate_snapshot(&job_id).unwrap();
+    assert_eq!((snapshot.twap_price, snapshot.deposited_value), (PRICE_SCALE, 10_000));
+
+    let balance = TokenClient::new(&env, &xlm);
+    assert_eq!(balance.balance(&escrow.address), 10_000);
+    escrow.submit_milestone(&job_id, &0, &freelancer);
+    escrow.approve_milestone(&job_id, &0, &client_addr);
+    escrow.submit_milestone(&job_id, &1, &freelancer);
+    escrow.approve_milestone(&job_id, &1, &client_addr);
+    escrow.complete_job(&job_id, &client_addr);
+    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(balance.balance(&freelancer), 10_000);
+    assert_eq!(balance.balance(&escrow.address), 0);
 }
 
 #[test]
-fn test_oracle_too_few_samples_unavailable() {
+fn test_xlm_job_balance_check_and_refund() {
     let env = Env::default();
     env.mock_all_auths();
     env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client_addr, freelancer, _, admin) = setup_test(&env);
+    let xlm = setup_native_xlm(&env, &escrow, &admin, &client_addr, 10_000);
+    let balance = TokenClient::new(&env, &xlm);
 
-    let (escrow, client_addr, freelancer, token, admin) = setup_test(&env);
-    // Only 9 samples (< MIN_TWAP_SAMPLES = 10) -> oracle considered unavailable.
-    setup_oracle(&env, &escrow, &admin, PRICE_SCALE, 9);
+    // The client holds 10_000 stroops; a larger job fails cleanly.
+    let too_big = vec![&env, (String::from_str(&env, "Work"), 10_001_i128, JOB_DEADLINE)];
+    let job_id = escrow.create_xlm_job(&client_addr, &freelancer, &too_big, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(
+        escrow.try_fund_job(&job_id, &client_addr, &0, &0),
+        Err(Ok(EscrowError::InsufficientFunds))
+    );
+    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Created);
 
-    let job_id = create_priced_job(&env, &escrow, &client_addr, &freelancer, &token, 100);
-    let res = escrow.try_fund_job(&job_id, &client_addr, &100, &200);
-    assert_eq!(res, Err(Ok(EscrowError::OracleUnavailable)));
+    let milestones = vec![&env, (String::from_str(&env, "Work"), 7_000_i128, JOB_DEADLINE)];
+    let job_id = escrow.create_xlm_job(&client_addr, &freelancer, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    escrow.fund_job(&job_id, &client_addr, &0, &0);
+    assert_eq!(balance.balance(&client_addr), 3_000);
+
+    escrow.cancel_job(&job_id, &client_addr, &0);
+    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Cancelled);
+    assert_eq!(balance.balance(&client_addr), 10_000);
+    assert_eq!(balance.balance(&escrow.address), 0);
+}
+
+/// A funded two-milestone job whose second milestone is paid in a second
+/// token. Returns the escrow, client, freelancer, job token, second token,
+/// admin and job ID.
+fn setup_two_token_job(
+    env: &Env,
+) -> (EscrowContractClient<'_>, Address, Address, Address, Address, Address, u64) {
+    let (escrow, client, freelancer, token_a, admin) = setup_test(env);
+    let token_b = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token_b).mint(&client, &5_000);
+    let milestones = vec![
+        env,
+        (String::from_str(env, "Design"), 1_000_i128, JOB_DEADLINE - 1),
+        (String::from_str(env, "Tokens"), 2_000_i128, JOB_DEADLINE),
+    ];
+    let job_id = escrow.create_job(&client, &freelancer, &token_a, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    escrow.set_milestone_token(&job_id, &client, &1, &Some(token_b.clone()));
+    escrow.fund_job(&job_id, &client, &0, &0);
+    (escrow, client, freelancer, token_a, token_b, admin, job_id)
 }
 
 #[test]
-fn test_escrow_ttl_flow_success() {
+fn test_milestone_token_override_funds_and_pays_per_token() {
     let env = Env::default();
     env.mock_all_auths();
-    env.ledger().with_mut(|l| {
-        l.timestamp = 1000;
-        l.sequence_number = 1;
-    });
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client, freelancer, token_a, token_b, _admin, job_id) = setup_two_token_job(&env);
+    let (a, b) = (TokenClient::new(&env, &token_a), TokenClient::new(&env, &token_b));
 
-    let (contract, client, freelancer, token, _admin) = setup_test(&env);
-    let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
-    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let job = escrow.get_job(&job_id);
+    assert_eq!(job.total_amount, 1_000);
+    assert_eq!(job.milestones.get(1).unwrap().token, Some(token_b.clone()));
+    assert_eq!(
+        escrow.get_job_token_totals(&job_id),
+        vec![&env, (token_a.clone(), 1_000_i128), (token_b.clone(), 2_000_i128)]
+    );
+    assert_eq!((a.balance(&escrow.address), b.balance(&escrow.address)), (1_000, 2_000));
+    assert_eq!((a.balance(&client), b.balance(&client)), (9_000, 3_000));
 
-    // Extend TTL permissionlessly (should succeed because the job is active).
-    contract.extend_escrow_ttl(&job_id);
+    // Overrides are fixed once the job is funded.
+    assert_eq!(
+        escrow.try_set_milestone_token(&job_id, &client, &0, &Some(token_b.clone())),
+        Err(Ok(milestone_tokens::MilestoneTokenError::InvalidStatus))
+    );
 
-    // Verify event is emitted.
-    let events = env.events().all();
-    let last_event = events.last().unwrap();
-    let topic0: Symbol = last_event.1.get(0).unwrap().into_val(&env);
-    let topic1: Symbol = last_event.1.get(1).unwrap().into_val(&env);
-    assert_eq!(topic0, symbol_short!("escrow"));
-    assert_eq!(topic1, symbol_short!("ttl_ext"));
+    escrow.submit_milestone(&job_id, &1, &freelancer);
+    escrow.release_milestone(&job_id, &1, &client, &0, &0);
+    assert_eq!(b.balance(&freelancer), 2_000);
+    assert_eq!(a.balance(&freelancer), 0);
+
+    escrow.submit_milestone(&job_id, &0, &freelancer);
+    escrow.release_milestone(&job_id, &0, &client, &0, &1);
+    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!((a.balance(&freelancer), b.balance(&freelancer)), (1_000, 2_000));
+    assert_eq!((a.balance(&escrow.address), b.balance(&escrow.address)), (0, 0));
 }
 
 #[test]
-#[should_panic]
-fn test_escrow_ttl_flow_archived_get_panics() {
+fn test_milestone_token_override_refunded_on_cancel() {
     let env = Env::default();
     env.mock_all_auths();
-    env.ledger().with_mut(|l| {
-        l.timestamp = 1000;
-        l.sequence_number = 1;
-    });
-
-    let (contract, client, freelancer, token, _admin) = setup_test(&env);
-    let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
-    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
-
-    // Advance sequence beyond ESCROW_TTL_LEDGERS.
-    env.ledger().with_mut(|l| {
-        l.sequence_number = 535002;
-    });
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client, _freelancer, token_a, token_b, _admin, job_id) = setup_two_token_job(&env);
 
-    // This should panic because the job is archived.
-    let _job = contract.get_job(&job_id);
+    escrow.cancel_job(&job_id, &client, &0);
+    assert_eq!(TokenClient::new(&env, &token_a).balance(&client), 10_000);
+    assert_eq!(TokenClient::new(&env, &token_b).balance(&client), 5_000);
+    assert_eq!(TokenClient::new(&env, &token_b).balance(&escrow.address), 0);
 }
 
 #[test]
-#[should_panic]
-fn test_escrow_ttl_flow_archived_extend_panics() {
+fn test_milestone_token_override_split_on_dispute() {
     let env = Env::default();
     env.mock_all_auths();
-    env.ledger().with_mut(|l| {
-        l.timestamp = 1000;
-        l.sequence_number = 1;
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client, freelancer, token_a, token_b, admin, job_id) = setup_two_token_job(&env);
+    let (a, b) = (TokenClient::new(&env, &token_a), TokenClient::new(&env, &token_b));
+
+    escrow.set_dispute_contract(&admin, &Address::generate(&env));
+    escrow.resolve_dispute_callback(&job_id, &DisputeResolution::RefundBoth);
+    assert_eq!((a.balance(&client), a.balance(&freelancer)), (9_500, 500));
+    assert_eq!((b.balance(&client), b.balance(&freelancer)), (4_000, 1_000));
+    assert_eq!((a.balance(&escrow.address), b.balance(&escrow.address)), (0, 0));
+}
+
+#[test]
+fn test_milestone_token_override_payout_owed_when_frozen() {
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client, freelancer, token_a, _admin) = setup_test(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token_b = sac.address();
+    let token_b_admin = StellarAssetClient::new(&env, &token_b);
+    token_b_admin.mint(&client, &5_000);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 1_000_i128, JOB_DEADLINE - 1),
+        (String::from_str(&env, "Tokens"), 2_000_i128, JOB_DEADLINE),
+    ];
+    let job_id = escrow.create_job(&client, &freelancer, &token_a, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    escrow.set_milestone_token(&job_id, &client, &1, &Some(token_b.clone()));
+    escrow.fund_job(&job_id, &client, &0, &0);
+
+    // The freelancer is frozen in the override token; the release still goes through.
+    token_b_admin.set_authorized(&freelancer, &false);
+    escrow.submit_milestone(&job_id, &1, &freelancer);
+    escrow.release_milestone(&job_id, &1, &client, &0, &0);
+    assert_eq!(escrow.get_owed(&freelancer, &token_b), 2_000);
+
+    token_b_admin.set_authorized(&freelancer, &true);
+    assert_eq!(escrow.withdraw_owed(&freelancer, &token_b, &freelancer), 2_000);
+    assert_eq!(TokenClient::new(&env, &token_b).balance(&freelancer), 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2105)")]
+fn test_milestone_token_override_rejects_partial_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (escrow, client, freelancer, _token_a, _token_b, _admin, job_id) = setup_two_token_job(&env);
+
+    escrow.submit_milestone(&job_id, &1, &freelancer);
+    escrow.release_partial_payment(&job_id, &1, &500, &client, &0);
+}
+
+#[test]
+fn test_oracle_unavailable_when_not_configured() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (escrow, client_addr, freelancer, token, _admin) = setup_test(&env);
+    let job_id = create_priced_job(&env, &escrow, &client_addr, &freelancer, &token, 100);
+
+    // agreed value > 0 but no oracle set -> OracleUnavailable, not a panic.
+    let res = escrow.try_fund_job(&job_id, &client_addr, &100, &200);
+    assert_eq!(res, Err(Ok(EscrowError::OracleUnavailable)));
+    assert_eq!(escrow.get_job(&job_id).status, JobStatus::Created);
+}
+
+#[test]
+fn test_oracle_too_few_samples_unavailable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (escrow, client_addr, freelancer, token, admin) = setup_test(&env);
+    // Only 9 samples (< MIN_TWAP_SAMPLES = 10) -> oracle considered unavailable.
+    setup_oracle(&env, &escrow, &admin, PRICE_SCALE, 9);
+
+    let job_id = create_priced_job(&env, &escrow, &client_addr, &freelancer, &token, 100);
+    let res = escrow.try_fund_job(&job_id, &client_addr, &100, &200);
+    assert_eq!(res, Err(Ok(EscrowError::OracleUnavailable)));
+}
+
+#[test]
+fn test_escrow_ttl_flow_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| {
+        l.timestamp = 1000;
+        l.sequence_number = 1;
+    });
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+
+    // Extend TTL permissionlessly (should succeed because the job is active).
+    contract.extend_escrow_ttl(&job_id);
+
+    // Verify event is emitted.
+    let events = env.events().all();
+    let last_event = events.last().unwrap();
+    let topic0: Symbol = last_event.1.get(0).unwrap().into_val(&env);
+    let topic1: Symbol = last_event.1.get(1).unwrap().into_val(&env);
+    assert_eq!(topic0, symbol_short!("escrow"));
+    assert_eq!(topic1, symbol_short!("ttl_ext"));
+}
+
+#[test]
+#[should_panic]
+fn test_escrow_ttl_flow_archived_get_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| {
+        l.timestamp = 1000;
+        l.sequence_number = 1;
+    });
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Work"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+
+    // Advance sequence beyond ESCROW_TTL_LEDGERS.
+    env.ledger().with_mut(|l| {
+        l.sequence_number = 535002;
+    });
+
+    // This should panic because the job is archived.
+    let _job = contract.get_job(&job_id);
+}
+
+#[test]
+#[should_panic]
+fn test_escrow_ttl_flow_archived_extend_panics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| {
+        l.timestamp = 1000;
+        l.sequence_number = 1;
     });
 
     let (contract, client, freelancer, token, _admin) = setup_test(&env);
@@ -5952,3 +6498,2966 @@ fn test_milestone_deadline_in_past_error_code_is_49() {
 fn test_milestone_deadlines_not_ordered_error_code_is_47() {
     assert_eq!(EscrowError::MilestoneDeadlinesNotOrdered as u32, 47);
 }
+
+// ── Per-token minimum milestone amounts ─────────────────────────────────────
+
+#[test]
+fn test_set_min_amount_roundtrip() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _client, _freelancer, token, admin) = setup_test(&env);
+
+    assert_eq!(contract.get_min_amount(&token), 0);
+    contract.set_min_amount(&admin, &token, &500);
+    assert_eq!(contract.get_min_amount(&token), 500);
+    contract.set_min_amount(&admin, &token, &0);
+    assert_eq!(contract.get_min_amount(&token), 0);
+}
+
+#[test]
+fn test_set_min_amount_non_signer_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _client, _freelancer, token, _admin) = setup_test(&env);
+
+    let result = contract.try_set_min_amount(&Address::generate(&env), &token, &500);
+    assert_eq!(result, Err(Ok(crate::limits::LimitsError::NotAdmin)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn test_create_job_below_min_amount_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    contract.set_min_amount(&admin, &token, &500);
+
+    let milestones = vec![&env, (String::from_str(&env, "Dust"), 499_i128, JOB_DEADLINE)];
+    contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn test_accept_revision_below_min_amount_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+
+    let milestones = vec![&env, (String::from_str(&env, "Initial"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    let new_milestones = vec![
+        &env,
+        Milestone {
+            id: 0,
+            description: String::from_str(&env, "Revised"),
+            amount: 900,
+            status: MilestoneStatus::Pending,
+            deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
+        },
+        Milestone {
+            id: 1,
+            description: String::from_str(&env, "Dust"),
+            amount: 10,
+            status: MilestoneStatus::Pending,
+            deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
+        },
+    ];
+    contract.propose_revision(&freelancer, &job_id, &new_milestones);
+    contract.set_min_amount(&admin, &token, &100);
+    contract.accept_revision(&client, &job_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn test_fund_job_over_client_active_cap_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    contract.set_active_job_caps(&admin, &1, &0);
+    assert_eq!(contract.get_active_job_caps(), (1, 0));
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let first = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&first, &client, &0, &0);
+    assert_eq!(contract.get_active_job_count(&client), 1);
+    assert_eq!(contract.get_active_job_count(&freelancer), 1);
+
+    let other_freelancer = Address::generate(&env);
+    let second = contract.create_job(&client, &other_freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&second, &client, &0, &0);
+}
+
+#[test]
+fn test_active_job_slot_released_on_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    contract.set_active_job_caps(&admin, &0, &1);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let first = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&first, &client, &0, &0);
+
+    let second = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let result = contract.try_fund_job(&second, &client, &0, &0);
+    assert!(result.is_err());
+
+    contract.cancel_job(&first, &client, &0);
+    assert_eq!(contract.get_active_job_count(&client), 0);
+    assert_eq!(contract.get_active_job_count(&freelancer), 0);
+
+    contract.fund_job(&second, &client, &0, &0);
+    assert_eq!(contract.get_active_job_count(&freelancer), 1);
+}
+
+// ── Off-milestone expenses ───────────────────────────────────────────────────
+
+fn setup_expense_job(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address, u64) {
+    let (contract, client, freelancer, token, _admin) = setup_test(env);
+    let milestones = vec![env, (String::from_str(env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    (contract, client, freelancer, token, job_id)
+}
+
+#[test]
+fn test_expense_approved_from_balance_pays_freelancer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, job_id) = setup_expense_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    contract.fund_expenses(&job_id, &client, &80);
+    let expense_id = contract.submit_expense(&job_id, &freelancer, &60, &BytesN::from_array(&env, &[7u8; 32]));
+    assert_eq!(contract.get_expense_count(&job_id), 1);
+
+    contract.approve_expense(&job_id, &client, &expense_id, &true);
+    let expense = contract.get_expense(&job_id, &expense_id);
+    assert_eq!(expense.status, expenses::ExpenseStatus::Approved);
+    assert_eq!(token_client.balance(&freelancer), 60);
+    assert_eq!(contract.get_expense_balance(&job_id), 20);
+
+    // Expenses never touch the milestone budget.
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.total_amount, 1000);
+    assert_eq!(job.funded_amount, 1000);
+}
+
+#[test]
+fn test_unspent_expense_balance_blocks_archival() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, job_id) = setup_expense_job(&env);
+    contract.fund_expenses(&job_id, &client, &80);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+
+    env.ledger().with_mut(|l| l.timestamp = JOB_DEADLINE + archive::DEFAULT_ARCHIVE_RETENTION_SECS);
+    assert_eq!(
+        contract.try_archive_job(&job_id, &client),
+        Err(Ok(archive::ArchiveError::BalanceOutstanding))
+    );
+    assert_eq!(contract.withdraw_expense_balance(&job_id, &client), 80);
+    contract.archive_job(&job_id, &client);
+}
+
+#[test]
+fn test_expense_approved_by_direct_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, job_id) = setup_expense_job(&env);
+
+    let expense_id = contract.submit_expense(&job_id, &freelancer, &50, &BytesN::from_array(&env, &[1u8; 32]));
+    contract.approve_expense(&job_id, &client, &expense_id, &false);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 50);
+    assert_eq!(TokenClient::new(&env, &token).balance(&client), 10000 - 1000 - 50);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #406)")]
+fn test_expense_cap_counts_pending_and_approved() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _client, freelancer, _token, job_id) = setup_expense_job(&env);
+
+    // Default cap is 10% of the 1000 job total.
+    contract.submit_expense(&job_id, &freelancer, &60, &BytesN::from_array(&env, &[1u8; 32]));
+    contract.submit_expense(&job_id, &freelancer, &41, &BytesN::from_array(&env, &[2u8; 32]));
+}
+
+#[test]
+fn test_rejected_expense_frees_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, job_id) = setup_expense_job(&env);
+
+    let first = contract.submit_expense(&job_id, &freelancer, &100, &BytesN::from_array(&env, &[1u8; 32]));
+    contract.reject_expense(&job_id, &client, &first);
+    assert_eq!(contract.get_expense(&job_id, &first).status, expenses::ExpenseStatus::Rejected);
+
+    let second = contract.submit_expense(&job_id, &freelancer, &100, &BytesN::from_array(&env, &[2u8; 32]));
+    assert_eq!(second, 1);
+    assert_eq!(
+        contract.try_approve_expense(&job_id, &client, &first, &false),
+        Err(Ok(expenses::ExpenseError::NotPending))
+    );
+}
+
+#[test]
+fn test_expense_cap_configurable_and_balance_checked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, job_id) = setup_expense_job(&env);
+    let admin = Address::generate(&env);
+    assert_eq!(
+        contract.try_set_expense_cap_bps(&admin, &2_000),
+        Err(Ok(expenses::ExpenseError::NotAdmin))
+    );
+
+    let expense_id = contract.submit_expense(&job_id, &freelancer, &30, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(
+        contract.try_approve_expense(&job_id, &client, &expense_id, &true),
+        Err(Ok(expenses::ExpenseError::InsufficientExpenseBalance))
+    );
+    assert_eq!(
+        contract.try_submit_expense(&job_id, &client, &10, &BytesN::from_array(&env, &[2u8; 32])),
+        Err(Ok(expenses::ExpenseError::Unauthorized))
+    );
+}
+
+// ── At-risk milestone queries ────────────────────────────────────────────────
+
+#[test]
+fn test_get_milestones_due_within_filters_by_horizon() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Soon"), 100_i128, 100_000_u64),
+        (String::from_str(&env, "Later"), 100_i128, 500_000_u64),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+
+    let due = contract.get_milestones_due_within(&job_id, &200_000);
+    assert_eq!(due.len(), 1);
+    assert_eq!(due.get(0).unwrap().id, 0);
+    assert_eq!(contract.get_milestones_due_within(&job_id, &600_000).len(), 2);
+}
+
+#[test]
+fn test_get_jobs_with_milestones_due_uses_index_and_paginates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let create = |deadline: u64| {
+        let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, deadline)];
+        contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER)
+    };
+    let soon = create(100_000);
+    let later = create(300_000);
+    let unfunded = create(100_000);
+    contract.fund_job(&soon, &client, &0, &0);
+    contract.fund_job(&later, &client, &0, &0);
+
+    assert_eq!(contract.get_jobs_with_milestones_due(&200_000, &0, &10), vec![&env, soon]);
+    assert_eq!(contract.get_jobs_with_milestones_due(&400_000, &0, &10), vec![&env, soon, later]);
+    assert_eq!(contract.get_jobs_with_milestones_due(&400_000, &1, &1), vec![&env, later]);
+    assert!(!contract.get_jobs_with_milestones_due(&400_000, &0, &10).contains(unfunded));
+
+    // Extending the deadline re-indexes the job and drops it from the near window.
+    contract.extend_deadline(&soon, &0, &900_000);
+    assert_eq!(contract.get_jobs_with_milestones_due(&200_000, &0, &10).len(), 0);
+    let wide = contract.get_jobs_with_milestones_due(&950_000, &0, &10);
+    assert_eq!(wide.len(), 2);
+    assert!(wide.contains(soon) && wide.contains(later));
+}
+
+#[test]
+fn test_submission_within_grace_is_accepted_and_flagged_late() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, 100_000_u64)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    assert_eq!(contract.get_submission_grace(), 0);
+    contract.set_submission_grace(&admin, &3600);
+    assert_eq!(contract.get_submission_grace(), 3600);
+
+    env.ledger().with_mut(|l| l.timestamp = 100_001);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    assert!(contract.is_submission_late(&job_id, &0));
+    assert_eq!(contract.get_job(&job_id).milestones.get(0).unwrap().status, MilestoneStatus::Submitted);
+}
+
+#[test]
+fn test_submission_after_grace_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, 100_000_u64)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.set_submission_grace(&admin, &3600);
+
+    env.ledger().with_mut(|l| l.timestamp = 100_000 + 3601);
+    assert_eq!(
+        contract.try_submit_milestone(&job_id, &0, &freelancer),
+        Err(Ok(EscrowError::MilestoneDeadlineExceeded))
+    );
+    assert_eq!(
+        contract.try_set_submission_grace(&admin, &(deadlines::MAX_SUBMISSION_GRACE_SECS + 1)),
+        Err(Ok(EscrowError::InvalidDeadline))
+    );
+    assert_eq!(
+        contract.try_set_submission_grace(&Address::generate(&env), &60),
+        Err(Ok(EscrowError::NotAdmin))
+    );
+}
+
+// ── Up-front fee accounting ──────────────────────────────────────────────────
+
+fn setup_upfront_fee_job(
+    env: &Env,
+) -> (
+    EscrowContractClient<'_>,
+    Address,
+    Address,
+    Address,
+    Address,
+    u64,
+) {
+    let contract = EscrowContractClient::new(env, &env.register_contract(None, EscrowContract));
+    let client = Address::generate(env);
+    let freelancer = Address::generate(env);
+    let admin = Address::generate(env);
+    let treasury = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(env, &token).mint(&client, &10000);
+    contract.initialize(&vec![env, admin.clone()], &1, &treasury, &500, &604800);
+    contract.set_upfront_fee(&admin, &true);
+
+    let milestones = vec![
+        env,
+        (String::from_str(env, "First"), 1000_i128, JOB_DEADLINE / 2),
+        (String::from_str(env, "Second"), 1000_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+    (contract, client, freelancer, token, treasury, job_id)
+}
+
+#[test]
+fn test_upfront_fee_refunded_in_full_on_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, _freelancer, token, _treasury, job_id) = setup_upfront_fee_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    // 5% of the 2000 job total is collected on top of the escrow deposit.
+    assert_eq!(token_client.balance(&client), 10000 - 2000 - 100);
+    assert_eq!(
+        contract.get_fee_ledger(&job_id),
+        fees::JobFeeLedger {
+            fee_bps: 500,
+            accrued: 100,
+            earned: 0,
+            refunded: 0,
+            freelancer_bps: 0,
+        }
+    );
+
+    contract.cancel_job(&job_id, &client, &0);
+    assert_eq!(token_client.balance(&client), 10000);
+    assert_eq!(contract.get_fee_ledger(&job_id).refunded, 100);
+}
+
+#[test]
+fn test_upfront_fee_earned_on_release_without_reducing_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (contract, client, freelancer, token, treasury, job_id) = setup_upfront_fee_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    // The fee comes out of the pre-paid balance, not the freelancer's payout.
+    assert_eq!(token_client.balance(&freelancer), 1000);
+    assert_eq!(token_client.balance(&treasury), 50);
+
+    assert_eq!(contract.get_fee_ledger(&job_id).earned, 50);
+}
+
+#[test]
+fn test_upfront_fee_split_between_treasury_and_client_on_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, treasury, job_id) = setup_upfront_fee_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    contract.cancel_job(&job_id, &client, &0);
+
+    // The fee on the delivered milestone is earned; the rest goes back.
+    assert_eq!(token_client.balance(&freelancer), 1000);
+    assert_eq!(token_client.balance(&treasury), 50);
+    assert_eq!(token_client.balance(&client), 10000 - 1000 - 50);
+    assert_eq!(
+        contract.get_fee_ledger(&job_id),
+        fees::JobFeeLedger {
+            fee_bps: 500,
+            accrued: 100,
+            earned: 50,
+            refunded: 50,
+            freelancer_bps: 0,
+        }
+    );
+}
+
+#[test]
+fn test_release_fee_unchanged_without_upfront_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _client, _freelancer, _token, admin) = setup_test(&env);
+    assert!(!contract.is_upfront_fee_enabled());
+    assert_eq!(contract.get_fee_ledger(&1), fees::JobFeeLedger::default());
+    assert_eq!(
+        contract.try_set_upfront_fee(&Address::generate(&env), &true),
+        Err(Ok(fees::FeeError::NotAdmin))
+    );
+    contract.set_upfront_fee(&admin, &true);
+    assert!(contract.is_upfront_fee_enabled());
+}
+
+#[test]
+fn test_client_pays_fee_on_top_at_funding() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+    let token_client = TokenClient::new(&env, &token);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(contract.get_fee_payer(&job_id), fees::FeePayer::Freelancer);
+    contract.set_fee_payer(&job_id, &client, &fees::FeePayer::Client);
+    assert_eq!(
+        contract.estimate_job_fees(&job_id),
+        fees::FeeEstimate { payer: fees::FeePayer::Client, client_fee: 50, freelancer_fee: 0 }
+    );
+
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(token_client.balance(&client), 10000 - 1000 - 50);
+    assert_eq!(
+        contract.try_set_fee_payer(&job_id, &client, &fees::FeePayer::Freelancer),
+        Err(Ok(fees::FeeError::InvalidStatus))
+    );
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(token_client.balance(&freelancer), 1000);
+}
+
+#[test]
+fn test_split_fee_payer_divides_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+    let token_client = TokenClient::new(&env, &token);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(
+        contract.try_set_fee_payer(&job_id, &freelancer, &fees::FeePayer::Split),
+        Err(Ok(fees::FeeError::Unauthorized))
+    );
+    contract.set_fee_payer(&job_id, &client, &fees::FeePayer::Split);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(token_client.balance(&client), 10000 - 1000 - 25);
+    assert_eq!(
+        contract.estimate_job_fees(&job_id),
+        fees::FeeEstimate { payer: fees::FeePayer::Split, client_fee: 25, freelancer_fee: 25 }
+    );
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(token_client.balance(&freelancer), 975);
+}
+
+// ── Performance bonds ────────────────────────────────────────────────────────
+
+fn setup_bonded_job(
+    env: &Env,
+) -> (
+    EscrowContractClient<'_>,
+    Address,
+    Address,
+    Address,
+    Address,
+    u64,
+) {
+    let (contract, client, freelancer, token, admin) = setup_test(env);
+    StellarAssetClient::new(env, &token).mint(&freelancer, &1000);
+    let milestones = vec![env, (String::from_str(env, "M"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+    (contract, client, freelancer, token, admin, job_id)
+}
+
+#[test]
+fn test_performance_bond_returned_on_completion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin, job_id) = setup_bonded_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    contract.post_performance_bond(&job_id, &freelancer, &300);
+    contract.post_performance_bond(&job_id, &freelancer, &200);
+    assert_eq!(contract.get_performance_bond(&job_id).unwrap().amount, 500);
+    assert_eq!(token_client.balance(&freelancer), 500);
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(contract.get_performance_bond(&job_id), None);
+    assert_eq!(token_client.balance(&freelancer), 1000 + 1000);
+}
+
+#[test]
+fn test_performance_bond_owed_when_freelancer_frozen() {
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, admin) = setup_test(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = sac.address();
+    let token_admin = StellarAssetClient::new(&env, &token);
+    token_admin.mint(&client, &1000);
+    token_admin.mint(&freelancer, &500);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.post_performance_bond(&job_id, &freelancer, &500);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+
+    // The frozen trustline neither blocks the release nor loses the bond.
+    token_admin.set_authorized(&freelancer, &false);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(contract.get_performance_bond(&job_id), None);
+    assert_eq!(contract.get_owed(&freelancer, &token), 1000 + 500);
+
+    token_admin.set_authorized(&freelancer, &true);
+    contract.withdraw_owed(&freelancer, &token, &freelancer);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 1500);
+}
+
+#[test]
+fn test_performance_bond_forfeited_on_client_win() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin, job_id) = setup_bonded_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    contract.set_bond_forfeit_bps(&admin, &4_000);
+    contract.post_performance_bond(&job_id, &freelancer, &500);
+    // The forfeit share is fixed when the bond is posted.
+    contract.set_bond_forfeit_bps(&admin, &10_000);
+
+    contract.set_dispute_contract(&admin, &Address::generate(&env));
+    contract.resolve_dispute_callback(&job_id, &DisputeResolution::ClientWins);
+    assert_eq!(token_client.balance(&client), 10000 + 200);
+    assert_eq!(token_client.balance(&freelancer), 500 + 300);
+    assert_eq!(contract.get_performance_bond(&job_id), None);
+}
+
+#[test]
+fn test_performance_bond_withdrawn_after_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin, job_id) = setup_bonded_job(&env);
+
+    contract.post_performance_bond(&job_id, &freelancer, &500);
+    assert_eq!(
+        contract.try_withdraw_performance_bond(&job_id, &freelancer),
+        Err(Ok(bonds::BondError::InvalidStatus))
+    );
+    contract.cancel_job(&job_id, &client, &0);
+    assert_eq!(
+        contract.try_withdraw_performance_bond(&job_id, &client),
+        Err(Ok(bonds::BondError::Unauthorized))
+    );
+    assert_eq!(
+        contract.withdraw_performance_bond(&job_id, &freelancer),
+        500
+    );
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 1000);
+    assert_eq!(
+        contract.try_withdraw_performance_bond(&job_id, &freelancer),
+        Err(Ok(bonds::BondError::NoBond))
+    );
+    assert_eq!(
+        contract.try_post_performance_bond(&job_id, &freelancer, &1),
+        Err(Ok(bonds::BondError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_set_bond_forfeit_bps(&admin, &10_001),
+        Err(Ok(bonds::BondError::InvalidForfeitBps))
+    );
+}
+
+#[test]
+fn test_required_bond_gates_funding_and_is_forfeited_on_abandonment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    StellarAssetClient::new(&env, &token).mint(&freelancer, &1000);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+
+    assert_eq!(
+        contract.try_set_required_bond(&job_id, &freelancer, &400),
+        Err(Ok(bonds::BondError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_set_required_bond(&job_id, &client, &-1),
+        Err(Ok(bonds::BondError::InvalidAmount))
+    );
+    contract.set_required_bond(&job_id, &client, &400);
+    assert_eq!(contract.get_required_bond(&job_id), 400);
+    contract.set_bond_forfeit_bps(&admin, &5_000);
+
+    // Funding waits until the freelancer has posted the full bond.
+    contract.post_performance_bond(&job_id, &freelancer, &300);
+    assert!(contract.try_fund_job(&job_id, &client, &0, &0).is_err());
+    contract.post_performance_bond(&job_id, &freelancer, &100);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(
+        contract.try_set_required_bond(&job_id, &client, &0),
+        Err(Ok(bonds::BondError::InvalidStatus))
+    );
+
+    // Nothing delivered by the deadline: half the bond goes to the client.
+    env.ledger().with_mut(|l| l.timestamp = JOB_DEADLINE + 1);
+    contract.expire_job(&job_id);
+    assert_eq!(contract.get_performance_bond(&job_id), None);
+    assert_eq!(token_client.balance(&client), 10000 + 200);
+    assert_eq!(token_client.balance(&freelancer), 600 + 200);
+}
+
+#[test]
+fn test_required_bond_refund_never_blocks_completion() {
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, admin) = setup_test(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = sac.address();
+    let token_admin = StellarAssetClient::new(&env, &token);
+    token_admin.mint(&client, &1000);
+    token_admin.mint(&freelancer, &400);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.set_required_bond(&job_id, &client, &400);
+    contract.post_performance_bond(&job_id, &freelancer, &400);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+
+    token_admin.set_authorized(&freelancer, &false);
+    contract.complete_job(&job_id, &client);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(contract.get_owed(&freelancer, &token), 1000 + 400);
+}
+
+#[test]
+fn test_performance_bond_kept_when_expired_job_was_delivered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin, job_id) = setup_bonded_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    contract.post_performance_bond(&job_id, &freelancer, &500);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+
+    // Everything was delivered and approved; only `complete_job` is missing.
+    env.ledger().with_mut(|l| l.timestamp = JOB_DEADLINE + 1);
+    contract.expire_job(&job_id);
+    assert_eq!(contract.get_performance_bond(&job_id).unwrap().amount, 500);
+    assert_eq!(contract.withdraw_performance_bond(&job_id, &freelancer), 500);
+    assert_eq!(token_client.balance(&freelancer), 1000 + 1000);
+    assert_eq!(token_client.balance(&client), 10000 - 1000);
+}
+
+// ── Job archival ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_archive_completed_job_after_retention() {
+    use soroban_sdk::xdr::ToXdr;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(
+        contract.try_archive_job(&job_id, &client),
+        Err(Ok(archive::ArchiveError::InvalidStatus))
+    );
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+
+    let job = contract.get_job(&job_id);
+    let live = contract.get_job_summary(&job_id);
+    assert_eq!(live.archived_at, 0);
+    let record_hash: BytesN<32> = env.crypto().sha256(&job.to_xdr(&env)).into();
+    assert_eq!(live.record_hash, record_hash);
+    assert_eq!(
+        contract.try_archive_job(&job_id, &client),
+        Err(Ok(archive::ArchiveError::RetentionNotElapsed))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += archive::DEFAULT_ARCHIVE_RETENTION_SECS);
+    assert_eq!(
+        contract.try_archive_job(&job_id, &Address::generate(&env)),
+        Err(Ok(archive::ArchiveError::Unauthorized))
+    );
+    let summary = contract.archive_job(&job_id, &freelancer);
+    assert_eq!(summary.status, JobStatus::Completed);
+    assert_eq!(summary.total_amount, 1000);
+    assert_eq!(summary.milestone_count, 1);
+    assert_eq!(summary.closed_at, live.closed_at);
+    assert_eq!(summary.record_hash, live.record_hash);
+
+    assert_eq!(contract.try_get_job(&job_id), Err(Ok(EscrowError::JobNotFound)));
+    assert_eq!(contract.get_job_summary(&job_id), summary);
+    assert_eq!(
+        contract.try_archive_job(&job_id, &client),
+        Err(Ok(archive::ArchiveError::JobNotFound))
+    );
+}
+
+#[test]
+fn test_recent_changes_feed_newest_first() {
+    use changes::ChangeKind;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    assert_eq!(contract.get_recent_changes(&10).len(), 0);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+
+    let feed = contract.get_recent_changes(&10);
+    let kinds = [ChangeKind::Completed, ChangeKind::PaymentReleased, ChangeKind::MilestoneSubmitted, ChangeKind::Funded, ChangeKind::Created];
+    assert_eq!(feed.len(), kinds.len() as u32);
+    for (i, kind) in kinds.iter().enumerate() {
+        let record = feed.get(i as u32).unwrap();
+        assert_eq!(record.kind, *kind);
+        assert_eq!(record.job_id, job_id);
+        assert_eq!(record.seq, (kinds.len() - i) as u64);
+        assert_eq!(record.ledger, env.ledger().sequence());
+    }
+    assert_eq!(contract.get_recent_changes(&2).get(1).unwrap().kind, ChangeKind::PaymentReleased);
+}
+
+#[test]
+fn test_daily_stats_count_lifecycle_per_day() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 100_i128, JOB_DEADLINE)];
+    env.ledger().with_mut(|l| l.timestamp = 3 * 86_400 + 10);
+
+    let first = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let second = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&first, &client, &0, &0);
+    contract.fund_job(&second, &client, &0, &0);
+
+    env.ledger().with_mut(|l| l.timestamp = 4 * 86_400 + 10);
+    contract.submit_milestone(&first, &0, &freelancer);
+    contract.release_milestone(&first, &0, &client, &0, &0);
+    let topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("escrow"), symbol_short!("day_stats")).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .expect("day_stats event");
+    let day_three: stats::DailyStats = data.into_val(&env);
+    assert_eq!((day_three.day, day_three.created, day_three.funded), (3, 2, 2));
+    contract.cancel_job(&second, &client, &0);
+
+    let stats = contract.get_daily_stats(&2);
+    assert_eq!(stats.len(), 3);
+    let today = stats.get(0).unwrap();
+    assert_eq!((today.day, today.created, today.funded, today.completed, today.cancelled), (4, 0, 0, 1, 1));
+    let yesterday = stats.get(1).unwrap();
+    assert_eq!((yesterday.day, yesterday.created, yesterday.funded, yesterday.completed), (3, 2, 2, 0));
+    assert_eq!(stats.get(2).unwrap(), stats::DailyStats { day: 2, ..Default::default() });
+
+    // Only the window is retained.
+    assert_eq!(contract.get_daily_stats(&u32::MAX).len(), 5);
+    env.ledger().with_mut(|l| l.timestamp = (4 + stats::STATS_WINDOW_DAYS as u64) * 86_400);
+    let stats = contract.get_daily_stats(&u32::MAX);
+    assert_eq!(stats.len(), stats::STATS_WINDOW_DAYS);
+    assert!(stats.iter().all(|s| s.completed == 0 && s.created == 0));
+}
+
+#[test]
+fn test_recent_changes_feed_overwrites_oldest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 10_i128, JOB_DEADLINE)];
+    let total = changes::RECENT_CHANGES_CAPACITY + 3;
+    for _ in 0..total {
+        contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    }
+
+    let feed = contract.get_recent_changes(&u32::MAX);
+    assert_eq!(feed.len(), changes::RECENT_CHANGES_CAPACITY);
+    assert_eq!(feed.get(0).unwrap().job_id, total as u64);
+    assert_eq!(feed.get(0).unwrap().seq, total as u64);
+    assert_eq!(feed.last().unwrap().job_id, 4);
+}
+
+#[test]
+fn test_create_job_with_hashed_milestone_content() {
+    use descriptions::MilestoneContent;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    let uri = String::from_str(&env, "ipfs://bafy-statement-of-work");
+    let milestones = vec![
+        &env,
+        (MilestoneContent::Hashed(hash.clone(), uri.clone()), 600_i128, JOB_DEADLINE - 10),
+        (MilestoneContent::Inline(String::from_str(&env, "Polish")), 400_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job_with_content(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.total_amount, 1000);
+    assert_eq!(job.milestones.get(0).unwrap().description, uri);
+    assert_eq!(contract.get_milestone_content(&job_id, &0), MilestoneContent::Hashed(hash.clone(), uri.clone()));
+    assert_eq!(contract.get_milestone_content(&job_id, &1), MilestoneContent::Inline(String::from_str(&env, "Polish")));
+    assert_eq!(contract.try_get_milestone_content(&job_id, &2), Err(Ok(EscrowError::MilestoneNotFound)));
+
+    // A revision that keeps the URI keeps the hash; one that rewrites it does not.
+    contract.fund_job(&job_id, &client, &0, &0);
+    let mut revised = job.milestones.clone();
+    let mut second = revised.get(1).unwrap();
+    second.description = String::from_str(&env, "Polish and docs");
+    revised.set(1, second);
+    contract.propose_revision(&client, &job_id, &revised);
+    contract.accept_revision(&freelancer, &job_id);
+    assert_eq!(contract.get_milestone_content(&job_id, &0), MilestoneContent::Hashed(hash, uri));
+
+    let mut first = revised.get(0).unwrap();
+    first.description = String::from_str(&env, "Build, inline");
+    revised.set(0, first);
+    contract.propose_revision(&client, &job_id, &revised);
+    contract.accept_revision(&freelancer, &job_id);
+    assert_eq!(contract.get_milestone_content(&job_id, &0), MilestoneContent::Inline(String::from_str(&env, "Build, inline")));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #800)")]
+fn test_create_job_with_content_rejects_empty_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (descriptions::MilestoneContent::Hashed(BytesN::from_array(&env, &[7u8; 32]), String::from_str(&env, "")), 1000_i128, JOB_DEADLINE),
+    ];
+    contract.create_job_with_content(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+}
+
+fn setup_checkpoint_job(env: &Env) -> (EscrowContractClient<'_>, Address, Address, Address, u64) {
+    let (contract, client, freelancer, token, _admin) = setup_test(env);
+    let milestones = vec![
+        env,
+        (String::from_str(env, "Design"), 500_i128, JOB_DEADLINE - 20),
+        (String::from_str(env, "Build"), 300_i128, JOB_DEADLINE - 10),
+        (String::from_str(env, "Launch"), 200_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.enable_checkpoint(&job_id, &client, &86_400);
+    contract.fund_job(&job_id, &client, &0, &0);
+    (contract, client, freelancer, token, job_id)
+}
+
+#[test]
+fn test_checkpoint_falls_due_at_half_budget_and_confirm_resumes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, job_id) = setup_checkpoint_job(&env);
+    assert_eq!(contract.try_confirm_continue(&job_id, &client), Err(Ok(checkpoints::CheckpointError::NoCheckpointPending)));
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    let checkpoint = contract.get_checkpoint(&job_id).unwrap();
+    assert_eq!(checkpoint.due_at, env.ledger().timestamp() + 86_400);
+    assert!(!checkpoint.confirmed);
+    assert_eq!(contract.try_enable_checkpoint(&job_id, &client, &60), Err(Ok(checkpoints::CheckpointError::InvalidStatus)));
+    assert_eq!(contract.try_exit_after_checkpoint(&job_id, &freelancer), Err(Ok(checkpoints::CheckpointError::WindowNotElapsed)));
+
+    contract.confirm_continue(&job_id, &client);
+    assert!(contract.get_checkpoint(&job_id).unwrap().confirmed);
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    assert_eq!(contract.get_job(&job_id).milestones.get(1).unwrap().status, MilestoneStatus::Submitted);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #905)")]
+fn test_checkpoint_blocks_submission_until_confirmed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, job_id) = setup_checkpoint_job(&env);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    contract.submit_milestone(&job_id, &1, &freelancer);
+}
+
+#[test]
+fn test_freelancer_exits_after_unconfirmed_checkpoint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, job_id) = setup_checkpoint_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+
+    env.ledger().with_mut(|l| l.timestamp += 86_400 + 1);
+    assert_eq!(contract.exit_after_checkpoint(&job_id, &freelancer), 800);
+
+    // Approved and submitted work is paid out; the untouched milestone is refunded.
+    assert_eq!(token_client.balance(&freelancer), 800);
+    assert_eq!(token_client.balance(&client), 9_200);
+    assert_eq!(token_client.balance(&contract.address), 0);
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.status, JobStatus::Cancelled);
+    assert_eq!(job.milestones.get(1).unwrap().status, MilestoneStatus::Approved);
+    assert_eq!(contract.try_confirm_continue(&job_id, &client), Err(Ok(checkpoints::CheckpointError::InvalidStatus)));
+}
+
+#[test]
+fn test_exit_after_checkpoint_does_not_repay_auto_approved_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, job_id) = setup_checkpoint_job(&env);
+    let token_client = TokenClient::new(&env, &token);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+
+    // The client never reviews milestone 0, so it is approved and paid by inactivity.
+    env.ledger().with_mut(|l| l.timestamp += INACTIVITY_THRESHOLD_SECS);
+    contract.trigger_inactivity_extension(&job_id, &0, &freelancer);
+    env.ledger().with_mut(|l| l.timestamp += INACTIVITY_GRACE_SECS);
+    contract.finalize_inactivity_approval(&job_id, &0, &freelancer);
+    assert_eq!(token_client.balance(&freelancer), 500);
+
+    env.ledger().with_mut(|l| l.timestamp += 86_400 + 1);
+    assert_eq!(contract.exit_after_checkpoint(&job_id, &freelancer), 0);
+    assert_eq!(token_client.balance(&freelancer), 500);
+    assert_eq!(token_client.balance(&client), 9_500);
+    assert_eq!(token_client.balance(&contract.address), 0);
+}
+
+#[contract]
+pub struct MockSwapAdapter;
+
+#[contractimpl]
+impl MockSwapAdapter {
+    pub fn swap(env: Env, _token_in: Address, token_out: Address, _amount_in: i128, min_amount_out: i128, to: Address) -> i128 {
+        TokenClient::new(&env, &token_out).transfer(&env.current_contract_address(), &to, &min_amount_out);
+        min_amount_out
+    }
+
+    pub fn swap_from(env: Env, token_in: Address, token_out: Address, amount_in: i128, min_amount_out: i128, from: Address, to: Address) -> i128 {
+        let adapter = env.current_contract_address();
+        TokenClient::new(&env, &token_in).transfer_from(&adapter, &from, &adapter, &amount_in);
+        TokenClient::new(&env, &token_out).transfer(&adapter, &to, &min_amount_out);
+        min_amount_out
+    }
+}
+
+#[test]
+fn test_migrate_job_token_via_adapter() {
+    use migrations::{MigrationError, SwapSource};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 600_i128, JOB_DEADLINE - 10),
+        (String::from_str(&env, "Build"), 400_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+
+    let new_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let adapter = env.register_contract(None, MockSwapAdapter);
+    StellarAssetClient::new(&env, &new_token).mint(&adapter, &10_000);
+    let rate = 2 * PRICE_SCALE;
+    assert_eq!(contract.try_migrate_job_token(&admin, &job_id, &new_token, &rate, &SwapSource::Adapter(adapter.clone())), Err(Ok(MigrationError::AdapterNotAllowed)));
+    contract.set_swap_adapter(&admin, &adapter, &true);
+    assert_eq!(contract.get_swap_adapters(), vec![&env, adapter.clone()]);
+    assert_eq!(contract.try_migrate_job_token(&admin, &job_id, &token, &rate, &SwapSource::Adapter(adapter.clone())), Err(Ok(MigrationError::SameToken)));
+    contract.migrate_job_token(&admin, &job_id, &new_token, &rate, &SwapSource::Adapter(adapter.clone()));
+
+    assert_eq!(contract.try_accept_token_migration(&job_id, &Address::generate(&env)), Err(Ok(MigrationError::Unauthorized)));
+    assert!(!contract.accept_token_migration(&job_id, &client));
+    assert_eq!(contract.get_job(&job_id).token, token);
+    assert!(contract.accept_token_migration(&job_id, &freelancer));
+    assert_eq!(contract.get_token_migration(&job_id), None);
+
+    // The unpaid 400 was swapped into 800 of the new token; amounts follow the rate.
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.token, new_token);
+    assert_eq!(job.total_amount, 2000);
+    assert_eq!(job.funded_amount, 2000);
+    assert_eq!(job.milestones.get(1).unwrap().amount, 800);
+    assert_eq!(TokenClient::new(&env, &token).balance(&adapter), 400);
+    assert_eq!(TokenClient::new(&env, &token).balance(&contract.address), 0);
+    assert_eq!(TokenClient::new(&env, &new_token).balance(&contract.address), 800);
+
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &1);
+    assert_eq!(TokenClient::new(&env, &new_token).balance(&freelancer), 800);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+}
+
+#[test]
+fn test_migrate_job_token_with_admin_liquidity() {
+    use migrations::{MigrationError, SwapSource};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    let new_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let provider = Address::generate(&env);
+    StellarAssetClient::new(&env, &new_token).mint(&provider, &10_000);
+    assert_eq!(contract.try_migrate_job_token(&admin, &job_id, &new_token, &0, &SwapSource::Liquidity(provider.clone())), Err(Ok(MigrationError::InvalidRate)));
+    contract.migrate_job_token(&admin, &job_id, &new_token, &(PRICE_SCALE / 2), &SwapSource::Liquidity(provider.clone()));
+    contract.accept_token_migration(&job_id, &freelancer);
+    contract.accept_token_migration(&job_id, &client);
+
+    // The provider swapped 500 new tokens for the 1000 old ones held in escrow.
+    assert_eq!(TokenClient::new(&env, &token).balance(&provider), 1000);
+    assert_eq!(TokenClient::new(&env, &new_token).balance(&provider), 9_500);
+    assert_eq!(TokenClient::new(&env, &new_token).balance(&contract.address), 500);
+    assert_eq!(contract.get_job(&job_id).milestones.get(0).unwrap().amount, 500);
+    assert_eq!(contract.try_cancel_token_migration(&admin, &job_id), Err(Ok(MigrationError::NoMigration)));
+}
+
+#[test]
+fn test_migrate_job_token_waits_for_held_early_bonus() {
+    use migrations::{MigrationError, SwapSource};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 500_i128, JOB_DEADLINE / 2),
+        (String::from_str(&env, "Build"), 500_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.add_early_bonus(&job_id, &client, &0, &100, &2000);
+
+    // The held bonus is in the old token, so the job cannot move yet.
+    let new_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let provider = Address::generate(&env);
+    let source = SwapSource::Liquidity(provider);
+    assert_eq!(contract.try_migrate_job_token(&admin, &job_id, &new_token, &PRICE_SCALE, &source), Err(Ok(MigrationError::SideBalanceOutstanding)));
+
+    // Once the bonus is paid out, nothing but the escrow is left to migrate.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    assert_eq!(contract.get_early_bonus(&job_id, &0).unwrap().status, early_bonus::BonusStatus::Paid);
+    contract.migrate_job_token(&admin, &job_id, &new_token, &PRICE_SCALE, &source);
+}
+
+#[contract]
+pub struct MockReferenceOracle;
+
+#[contractimpl]
+impl MockReferenceOracle {
+    pub fn reference_price(_env: Env, _token: Address, _symbol: Symbol) -> i128 {
+        15_000_000
+    }
+}
+
+#[test]
+fn test_reference_price_recorded_at_funding() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+
+    // Without an oracle, jobs are funded without a reference price.
+    let plain_job = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&plain_job, &client, &0, &0);
+    let info = contract.get_job_display_info(&plain_job);
+    assert_eq!(info.reference_symbol, None);
+    assert_eq!((info.reference_price, info.reference_value), (0, 0));
+
+    let oracle = env.register_contract(None, MockReferenceOracle);
+    let usd = Symbol::new(&env, "USD");
+    assert_eq!(contract.try_set_reference_oracle(&freelancer, &oracle, &usd), Err(Ok(display::DisplayError::NotAdmin)));
+    contract.set_reference_oracle(&admin, &oracle, &usd);
+    env.ledger().with_mut(|l| l.timestamp = 1_234);
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    let info = contract.get_job_display_info(&job_id);
+    assert_eq!(info.reference_symbol, Some(usd));
+    assert_eq!((info.reference_price, info.reference_value, info.priced_at), (15_000_000, 1500, 1_234));
+    assert_eq!(info.funded_amount, 1000);
+    assert_eq!(contract.try_get_job_display_info(&99), Err(Ok(display::DisplayError::JobNotFound)));
+}
+
+#[test]
+fn test_reject_milestone_records_reason_and_allows_resubmission() {
+    use rejections::{RejectionError, RejectionReason};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, _token, job_id) = setup_checkpoint_job(&env);
+    assert_eq!(
+        contract.try_reject_milestone(&job_id, &0, &client, &RejectionReason::Other, &None),
+        Err(Ok(RejectionError::InvalidStatus))
+    );
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    assert_eq!(
+        contract.try_reject_milestone(&job_id, &0, &freelancer, &RejectionReason::Other, &None),
+        Err(Ok(RejectionError::Unauthorized))
+    );
+    let comment = BytesN::from_array(&env, &[7u8; 32]);
+    env.ledger().with_mut(|l| l.timestamp = 500);
+    contract.reject_milestone(&job_id, &0, &client, &RejectionReason::QualityBelowSpec, &Some(comment.clone()));
+    assert_eq!(contract.get_job(&job_id).milestones.get(0).unwrap().status, MilestoneStatus::InProgress);
+
+    assert_eq!(contract.get_rejection_count(&job_id, &0), 1);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    let resub_topics: Vec<soroban_sdk::Val> = (symbol_short!("escrow"), symbol_short!("ms_resub")).into_val(&env);
+    let (_, _, data) = env.events().all().iter().find(|(_, topics, _)| *topics == resub_topics).unwrap();
+    let resubmitted: (u64, u32, u32) = data.into_val(&env);
+    assert_eq!(resubmitted, (job_id, 0, 1));
+    contract.reject_milestone(&job_id, &0, &client, &RejectionReason::IncompleteWork, &None);
+    assert_eq!(contract.get_rejection_count(&job_id, &0), 2);
+    assert_eq!(contract.get_rejection_count(&job_id, &1), 0);
+    let rejections = contract.get_milestone_rejections(&job_id);
+    assert_eq!(rejections.len(), 2);
+    let first = rejections.get(0).unwrap();
+    assert_eq!((first.milestone_id, first.reason, first.comment_hash, first.rejected_at), (0, RejectionReason::QualityBelowSpec, comment, 500));
+    let second = rejections.get(1).unwrap();
+    assert_eq!((second.reason, second.comment_hash), (RejectionReason::IncompleteWork, BytesN::from_array(&env, &[0u8; 32])));
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    assert_eq!(contract.get_job(&job_id).milestones.get(0).unwrap().status, MilestoneStatus::Approved);
+}
+
+#[test]
+fn test_payout_address_receives_freelancer_payments() {
+    use payouts::PayoutError;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 600_i128, JOB_DEADLINE - 10),
+        (String::from_str(&env, "Build"), 400_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(contract.get_payout_address(&job_id), freelancer);
+
+    let entity = Address::generate(&env);
+    assert_eq!(contract.try_set_payout_address(&client, &job_id, &entity), Err(Ok(PayoutError::Unauthorized)));
+    contract.set_payout_address(&freelancer, &job_id, &entity);
+    assert_eq!(contract.get_payout_address(&job_id), entity);
+
+    // The freelancer identity still signs; the money goes to the entity.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    assert_eq!(
+        contract.try_set_payout_address(&freelancer, &job_id, &freelancer),
+        Err(Ok(PayoutError::InvalidStatus))
+    );
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.approve_milestone(&job_id, &1, &client);
+    contract.complete_job(&job_id, &client);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&entity), 1000);
+    assert_eq!(token_client.balance(&freelancer), 0);
+}
+
+#[test]
+fn test_verify_completion_checks_status_parties_and_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    assert!(!contract.verify_completion(&job_id, &client, &freelancer, &1000));
+
+    contract.complete_job(&job_id, &client);
+    assert!(contract.verify_completion(&job_id, &client, &freelancer, &1000));
+    assert!(!contract.verify_completion(&job_id, &client, &freelancer, &1001));
+    assert!(!contract.verify_completion(&job_id, &freelancer, &client, &0));
+    assert!(!contract.verify_completion(&99, &client, &freelancer, &0));
+
+    // Archived jobs are verified against their summary.
+    env.ledger().with_mut(|l| l.timestamp += archive::DEFAULT_ARCHIVE_RETENTION_SECS + 1);
+    contract.archive_job(&job_id, &client);
+    assert!(contract.verify_completion(&job_id, &client, &freelancer, &1000));
+}
+
+#[test]
+fn test_checked_approval_rejects_amount_changed_by_revision() {
+    use approvals::ApprovalError;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+
+    // The client reviewed 1000; a revision lands before the approval does.
+    let revised = vec![
+        &env,
+        Milestone {
+            id: 0,
+            description: String::from_str(&env, "Build"),
+            amount: 800,
+            status: MilestoneStatus::Submitted,
+            deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
+        },
+    ];
+    contract.propose_revision(&freelancer, &job_id, &revised);
+    contract.accept_revision(&client, &job_id);
+    assert_eq!(
+        contract.try_approve_milestone_checked(&job_id, &0, &client, &1000),
+        Err(Ok(ApprovalError::StaleState))
+    );
+    assert_eq!(contract.get_job(&job_id).milestones.get(0).unwrap().status, MilestoneStatus::Submitted);
+    assert_eq!(
+        contract.try_approve_milestone_checked(&job_id, &1, &client, &800),
+        Err(Ok(ApprovalError::MilestoneNotFound))
+    );
+    assert_eq!(
+        contract.try_approve_milestone_checked(&job_id, &0, &freelancer, &800),
+        Err(Ok(ApprovalError::Unauthorized))
+    );
+
+    contract.approve_milestone_checked(&job_id, &0, &client, &800);
+    assert_eq!(contract.get_job(&job_id).milestones.get(0).unwrap().status, MilestoneStatus::Approved);
+}
+
+#[test]
+fn test_private_job_is_hidden_from_index_but_readable_by_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, 100_000_u64)];
+    let public = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let private = contract.create_private_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&public, &client, &0, &0);
+    contract.fund_job(&private, &client, &0, &0);
+
+    assert!(!contract.is_job_private(&public));
+    assert!(contract.is_job_private(&private));
+    assert_eq!(contract.get_jobs_with_milestones_due(&200_000, &0, &10), vec![&env, public]);
+
+    let job = contract.get_job(&private);
+    assert_eq!(job.client, client);
+    assert_eq!(job.status, JobStatus::Funded);
+}
+
+#[test]
+fn test_archived_private_job_stays_out_of_status_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, JOB_DEADLINE)];
+    let public = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let private = contract.create_private_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    for job_id in [public, private] {
+        contract.fund_job(&job_id, &client, &0, &0);
+        contract.submit_milestone(&job_id, &0, &freelancer);
+        contract.release_milestone(&job_id, &0, &client, &0, &job_id);
+    }
+    assert_eq!(contract.get_jobs_by_status(&JobStatus::Completed, &0, &10), vec![&env, public]);
+
+    env.ledger().with_mut(|l| l.timestamp += archive::DEFAULT_ARCHIVE_RETENTION_SECS);
+    contract.archive_job(&public, &client);
+    contract.archive_job(&private, &client);
+    assert!(!contract.is_job_private(&private));
+    assert_eq!(contract.get_jobs_by_status(&JobStatus::Completed, &0, &10), vec![&env, public]);
+}
+
+#[test]
+fn test_job_indexed_by_client_external_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let other_client = Address::generate(&env);
+    let external_id = BytesN::from_array(&env, &[7; 32]);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, JOB_DEADLINE)];
+    assert_eq!(contract.get_job_by_external_id(&client, &external_id), None);
+
+    let job_id = contract.create_job_with_external_id(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER, &external_id);
+    assert_eq!(contract.get_job_by_external_id(&client, &external_id), Some(job_id));
+    assert_eq!(contract.get_job(&job_id).client, client);
+
+    // Keys are namespaced per client.
+    let other_job = contract.create_job_with_external_id(&other_client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER, &external_id);
+    assert_ne!(other_job, job_id);
+    assert_eq!(contract.get_job_by_external_id(&other_client, &external_id), Some(other_job));
+    assert_eq!(contract.get_job_by_external_id(&client, &external_id), Some(job_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2400)")] // ExternalIdTaken
+fn test_duplicate_external_id_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let external_id = BytesN::from_array(&env, &[7; 32]);
+    let milestones = vec![&env, (String::from_str(&env, "M"), 100_i128, JOB_DEADLINE)];
+    contract.create_job_with_external_id(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER, &external_id);
+    contract.create_job_with_external_id(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER, &external_id);
+}
+
+#[test]
+fn test_send_bonus_pays_freelancer_outside_milestones() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+    let treasury = contract.get_treasury().unwrap();
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(
+        contract.try_send_bonus(&job_id, &100, &client),
+        Err(Ok(tips::TipError::InvalidStatus))
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(
+        contract.try_send_bonus(&job_id, &100, &freelancer),
+        Err(Ok(tips::TipError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_send_bonus(&job_id, &0, &client),
+        Err(Ok(tips::TipError::InvalidAmount))
+    );
+
+    // 5% of the bonus goes to the treasury, the rest to the freelancer.
+    contract.send_bonus(&job_id, &200, &client);
+    contract.send_bonus(&job_id, &100, &client);
+    assert_eq!(token_client.balance(&freelancer), 285);
+    assert_eq!(token_client.balance(&treasury), 15);
+    assert_eq!(token_client.balance(&client), 10000 - 1000 - 300);
+    assert_eq!(contract.get_bonus_total(&job_id), 300);
+
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.total_amount, 1000);
+    assert_eq!(job.funded_amount, 1000);
+    assert_eq!(job.milestones.get(0).unwrap().amount, 1000);
+}
+
+#[test]
+fn test_send_bonus_follows_fee_payer_like_a_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+    let treasury = contract.get_treasury().unwrap();
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.set_fee_payer(&job_id, &client, &fees::FeePayer::Split);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    // Half the fee on top of the bonus, half deducted from it.
+    contract.send_bonus(&job_id, &200, &client);
+    assert_eq!(token_client.balance(&client), 10000 - 1025 - 205);
+    assert_eq!(token_client.balance(&freelancer), 195);
+    assert_eq!(token_client.balance(&treasury), 10);
+
+    // The bonus did not eat into the fee pre-paid for the milestone.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(token_client.balance(&freelancer), 195 + 975);
+    assert_eq!(token_client.balance(&treasury), 60);
+}
+
+#[test]
+fn test_fee_rate_snapshotted_at_funding_and_changes_timelocked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(contract.get_job_fee_bps(&job_id), 500);
+
+    assert_eq!(
+        contract.try_propose_admin_action(&Address::generate(&env), &AdminAction::SetFeeChangeDelay(86_400)),
+        Err(Ok(EscrowError::SignerNotFound))
+    );
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeChangeDelay(86_400));
+    assert_eq!(contract.get_fee_change_delay(), 86_400);
+
+    // A shorter delay has to wait out the current one.
+    let shorten = contract.propose_admin_action(&admin, &AdminAction::SetFeeChangeDelay(0));
+    assert_eq!(contract.get_fee_change_delay(), 86_400);
+    assert_eq!(
+        contract.try_execute_proposal(&admin, &shorten),
+        Err(Ok(EscrowError::ProposalTimeLockActive))
+    );
+
+    // With a delay configured the change is only scheduled.
+    let now = env.ledger().timestamp();
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(100));
+    assert_eq!(contract.get_fee_bps(), 500);
+    assert_eq!(
+        contract.get_scheduled_fee_change(),
+        Some(fees::ScheduledFeeChange { fee_bps: 100, effective_at: now + 86_400 })
+    );
+    assert_eq!(contract.try_apply_fee_change(), Err(Ok(fees::FeeError::FeeChangeTimelocked)));
+    // The pending change cannot be overwritten by another.
+    assert_eq!(
+        contract.try_propose_admin_action(&admin, &AdminAction::SetFeeBps(300)),
+        Err(Err(soroban_sdk::InvokeError::Contract(fees::FeeError::FeeChangePending as u32)))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = now + 86_400);
+    assert_eq!(contract.apply_fee_change(), 100);
+    assert_eq!(contract.get_fee_bps(), 100);
+    assert_eq!(contract.get_scheduled_fee_change(), None);
+    assert_eq!(contract.try_apply_fee_change(), Err(Ok(fees::FeeError::NoFeeChangeScheduled)));
+
+    // The funded job still pays the rate it was funded under.
+    assert_eq!(contract.get_job_fee_bps(&job_id), 500);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(token_client.balance(&freelancer), 950);
+}
+
+#[test]
+fn test_early_bonus_paid_on_time_and_refunded_when_late() {
+    use early_bonus::{BonusError, BonusStatus};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 500_i128, JOB_DEADLINE / 2),
+        (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    assert_eq!(
+        contract.try_add_early_bonus(&job_id, &client, &0, &100, &1000),
+        Err(Ok(BonusError::InvalidTarget))
+    );
+    assert_eq!(
+        contract.try_add_early_bonus(&job_id, &freelancer, &0, &100, &2000),
+        Err(Ok(BonusError::Unauthorized))
+    );
+    contract.add_early_bonus(&job_id, &client, &0, &100, &2000);
+    contract.add_early_bonus(&job_id, &client, &1, &200, &2000);
+    assert_eq!(
+        contract.try_add_early_bonus(&job_id, &client, &0, &100, &2000),
+        Err(Ok(BonusError::BonusExists))
+    );
+    assert_eq!(token_client.balance(&client), 10000 - 1500 - 300);
+
+    // Milestone 0 approved before its target: the bonus goes out with it.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(
+        contract.get_early_bonus(&job_id, &0).unwrap().status,
+        BonusStatus::Paid
+    );
+    assert_eq!(token_client.balance(&freelancer), 500 + 100);
+
+    // Milestone 1 lands late: its bonus goes back to the client on completion.
+    env.ledger().with_mut(|l| l.timestamp = 3000);
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &1);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(
+        contract.get_early_bonus(&job_id, &1).unwrap().status,
+        BonusStatus::Refunded
+    );
+    assert_eq!(token_client.balance(&freelancer), 1500 + 100);
+    assert_eq!(token_client.balance(&client), 10000 - 1500 - 100);
+}
+
+#[test]
+fn test_get_jobs_between_pages_pair_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let other = Address::generate(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 100_i128, JOB_DEADLINE),
+    ];
+    let create = |freelancer: &Address| {
+        contract.create_job(
+            &client,
+            freelancer,
+            &token,
+            &milestones,
+            &JOB_DEADLINE,
+            &GRACE_PERIOD,
+            &DEFAULT_EXPIRY_LEDGER,
+        )
+    };
+
+    let first = create(&freelancer);
+    create(&other);
+    let private = contract.create_private_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    let second = create(&freelancer);
+    let third = create(&freelancer);
+
+    assert_eq!(
+        contract.get_jobs_between(&client, &freelancer, &0, &10),
+        vec![&env, first, second, third]
+    );
+    assert_eq!(
+        contract.get_jobs_between(&client, &freelancer, &1, &1),
+        vec![&env, second]
+    );
+    assert!(contract
+        .get_jobs_between(&freelancer, &client, &0, &10)
+        .is_empty());
+    assert!(contract.is_job_private(&private));
+}
+
+#[test]
+fn test_get_jobs_by_client_and_freelancer_page_each_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let other = Address::generate(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 100_i128, JOB_DEADLINE),
+    ];
+    let create = |client: &Address, freelancer: &Address| {
+        contract.create_job(
+            client,
+            freelancer,
+            &token,
+            &milestones,
+            &JOB_DEADLINE,
+            &GRACE_PERIOD,
+            &DEFAULT_EXPIRY_LEDGER,
+        )
+    };
+
+    let first = create(&client, &freelancer);
+    let second = create(&client, &other);
+    contract.create_private_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    let third = create(&other, &freelancer);
+
+    assert_eq!(
+        contract.get_jobs_by_client(&client, &0, &10),
+        vec![&env, first, second]
+    );
+    assert_eq!(
+        contract.get_jobs_by_client(&client, &1, &10),
+        vec![&env, second]
+    );
+    assert_eq!(
+        contract.get_jobs_by_freelancer(&freelancer, &0, &10),
+        vec![&env, first, third]
+    );
+    assert_eq!(
+        contract.get_jobs_by_freelancer(&freelancer, &1, &1),
+        vec![&env, third]
+    );
+    assert!(contract.get_jobs_by_client(&freelancer, &0, &10).is_empty());
+    assert!(contract.get_jobs_by_freelancer(&other, &0, &0).is_empty());
+}
+
+#[test]
+fn test_get_jobs_by_freelancer_pages_across_index_pages() {
+    use pairs::PARTY_PAGE_CAPACITY;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 10_i128, JOB_DEADLINE),
+    ];
+    let mut ids = Vec::new(&env);
+    for _ in 0..PARTY_PAGE_CAPACITY + 5 {
+        ids.push_back(contract.create_job(
+            &client,
+            &freelancer,
+            &token,
+            &milestones,
+            &JOB_DEADLINE,
+            &GRACE_PERIOD,
+            &DEFAULT_EXPIRY_LEDGER,
+        ));
+    }
+
+    // Every index spills into a second page, invisibly to callers.
+    let last = PARTY_PAGE_CAPACITY - 2;
+    assert_eq!(
+        contract.get_jobs_by_freelancer(&freelancer, &last, &4),
+        ids.slice(last..last + 4)
+    );
+    assert_eq!(
+        contract.get_jobs_by_client(&client, &last, &4),
+        ids.slice(last..last + 4)
+    );
+    assert_eq!(
+        contract.get_jobs_between(&client, &freelancer, &PARTY_PAGE_CAPACITY, &10),
+        ids.slice(PARTY_PAGE_CAPACITY..PARTY_PAGE_CAPACITY + 5)
+    );
+}
+
+#[test]
+fn test_shared_notes_need_counterparty_acknowledgement() {
+    use notes::{NoteError, MAX_SHARED_NOTES};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 100_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+
+    let hash = BytesN::from_array(&env, &[7; 32]);
+    assert_eq!(
+        contract.try_append_shared_note(&job_id, &Address::generate(&env), &hash),
+        Err(Ok(NoteError::Unauthorized))
+    );
+    assert_eq!(contract.append_shared_note(&job_id, &client, &hash), 0);
+    assert_eq!(
+        contract.try_acknowledge_shared_note(&job_id, &client, &0),
+        Err(Ok(NoteError::Unauthorized))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1500);
+    contract.acknowledge_shared_note(&job_id, &freelancer, &0);
+    let note = contract.get_shared_notes(&job_id).get(0).unwrap();
+    assert_eq!((note.author, note.added_at, note.acknowledged_at), (client.clone(), 1000, Some(1500)));
+    assert_eq!(
+        contract.try_acknowledge_shared_note(&job_id, &freelancer, &0),
+        Err(Ok(NoteError::AlreadyAcknowledged))
+    );
+    assert_eq!(
+        contract.try_acknowledge_shared_note(&job_id, &freelancer, &1),
+        Err(Ok(NoteError::NoteNotFound))
+    );
+
+    for _ in 1..MAX_SHARED_NOTES {
+        contract.append_shared_note(&job_id, &freelancer, &hash);
+    }
+    assert_eq!(
+        contract.try_append_shared_note(&job_id, &freelancer, &hash),
+        Err(Ok(NoteError::TooManyNotes))
+    );
+}
+
+#[test]
+fn test_transition_table_rejects_every_unlisted_move() {
+    use state::{transition, JobEvent};
+    use JobStatus::*;
+
+    let statuses = [Created, Funded, InProgress, Completed, Cancelled, Disputed, Expired];
+    let allowed = [
+        (Created, JobEvent::Configure, Created),
+        (Created, JobEvent::Fund, Funded),
+        (Created, JobEvent::Amend, Created),
+        (Funded, JobEvent::Amend, Funded),
+        (InProgress, JobEvent::Amend, InProgress),
+        (Funded, JobEvent::Work, Funded),
+        (InProgress, JobEvent::Work, InProgress),
+        (Funded, JobEvent::Progress, InProgress),
+        (InProgress, JobEvent::Progress, InProgress),
+        (InProgress, JobEvent::Complete, Completed),
+        (Funded, JobEvent::Cancel, Cancelled),
+        (InProgress, JobEvent::Cancel, Cancelled),
+        (Funded, JobEvent::Dispute, Disputed),
+        (InProgress, JobEvent::Dispute, Disputed),
+        (Disputed, JobEvent::Dispute, Disputed),
+        (Funded, JobEvent::Adjust, Funded),
+        (InProgress, JobEvent::Adjust, InProgress),
+        (Disputed, JobEvent::Adjust, Disputed),
+        (Funded, JobEvent::AwardFreelancer, Completed),
+        (InProgress, JobEvent::AwardFreelancer, Completed),
+        (Disputed, JobEvent::AwardFreelancer, Completed),
+        (Funded, JobEvent::AwardClient, Cancelled),
+        (InProgress, JobEvent::AwardClient, Cancelled),
+        (Disputed, JobEvent::AwardClient, Cancelled),
+        (Funded, JobEvent::Escalate, Funded),
+        (InProgress, JobEvent::Escalate, InProgress),
+        (Disputed, JobEvent::Escalate, Disputed),
+        (Funded, JobEvent::Withdraw, Cancelled),
+        (InProgress, JobEvent::Withdraw, Cancelled),
+        (Disputed, JobEvent::Withdraw, Cancelled),
+        (Created, JobEvent::Expire, Expired),
+        (Funded, JobEvent::Expire, Expired),
+        (InProgress, JobEvent::Expire, Expired),
+        (Disputed, JobEvent::Expire, Expired),
+    ];
+
+    for status in statuses.iter() {
+        for event in JobEvent::ALL {
+            let expected = allowed
+                .iter()
+                .find(|(from, e, _)| from == status && *e == event)
+                .map(|(_, _, to)| to.clone())
+                .ok_or(EscrowError::InvalidStatus);
+            assert_eq!(transition(status, event), expected, "{:?} on {:?}", event, status);
+        }
+    }
+}
+
+#[test]
+fn test_cancelled_job_rejects_leftover_submitted_milestone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 100_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.set_dispute_contract(&admin, &Address::generate(&env));
+    contract.resolve_dispute_callback(&job_id, &DisputeResolution::ClientWins);
+
+    // The milestone is still Submitted, but the job is closed.
+    assert_eq!(
+        contract.try_approve_milestone(&job_id, &0, &client),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_release_milestone(&job_id, &0, &client, &0, &0),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_trigger_inactivity_extension(&job_id, &0, &freelancer),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.try_extend_deadline(&job_id, &0, &(JOB_DEADLINE + 1000)),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Cancelled);
+}
+
+#[test]
+fn test_failed_payout_is_owed_and_withdrawn_later() {
+    use owed::OwedError;
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, _token, admin) = setup_test(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = sac.address();
+    let token_admin = StellarAssetClient::new(&env, &token);
+    token_admin.mint(&client, &100);
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 100_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(
+        &client,
+        &freelancer,
+        &token,
+        &milestones,
+        &JOB_DEADLINE,
+        &GRACE_PERIOD,
+        &DEFAULT_EXPIRY_LEDGER,
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+
+    // The issuer freezes the freelancer; the release still goes through.
+    token_admin.set_authorized(&freelancer, &false);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(contract.get_owed(&freelancer, &token), 100);
+    assert_eq!(
+        contract.try_withdraw_owed(&freelancer, &token, &freelancer),
+        Err(Ok(OwedError::TransferFailed))
+    );
+    // The owed payout keeps the job from being archived.
+    contract.set_archive_retention(&admin, &0);
+    assert_eq!(
+        contract.try_archive_job(&job_id, &client),
+        Err(Ok(archive::ArchiveError::BalanceOutstanding))
+    );
+
+    // The freelancer collects it at another address of theirs.
+    let wallet = Address::generate(&env);
+    assert_eq!(contract.withdraw_owed(&freelancer, &token, &wallet), 100);
+    assert_eq!(TokenClient::new(&env, &token).balance(&wallet), 100);
+    assert_eq!(contract.get_owed(&freelancer, &token), 0);
+    assert_eq!(
+        contract.try_withdraw_owed(&freelancer, &token, &wallet),
+        Err(Ok(OwedError::NothingOwed))
+    );
+    contract.archive_job(&job_id, &client);
+}
+
+#[test]
+fn test_owed_payout_blocks_token_migration() {
+    use migrations::{MigrationError, SwapSource};
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let frozen = sac.address();
+    let frozen_admin = StellarAssetClient::new(&env, &frozen);
+    frozen_admin.mint(&client, &100);
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 50_i128, JOB_DEADLINE - 10),
+        (String::from_str(&env, "Build"), 50_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &frozen, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    frozen_admin.set_authorized(&freelancer, &false);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(contract.get_owed(&freelancer, &frozen), 50);
+
+    // The owed 50 is held in the old token, so the job cannot move off it.
+    let source = SwapSource::Liquidity(Address::generate(&env));
+    assert_eq!(
+        contract.try_migrate_job_token(&admin, &job_id, &token, &PRICE_SCALE, &source),
+        Err(Ok(MigrationError::SideBalanceOutstanding))
+    );
+}
+
+#[test]
+fn test_payout_record_keeps_fee_snapshot_after_fee_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 1000_i128, JOB_DEADLINE - 1),
+        (String::from_str(&env, "Build"), 2000_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(100));
+    assert_eq!(contract.get_payout_record(&job_id, &0), None);
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone(&job_id, &0, &client);
+    let record = contract.get_payout_record(&job_id, &0).unwrap();
+    assert_eq!(
+        (record.fee_bps, record.fee_amount, record.net_amount),
+        (500, 50, 950)
+    );
+
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &0);
+    let record = contract.get_payout_record(&job_id, &1).unwrap();
+    assert_eq!(
+        (record.fee_bps, record.fee_amount, record.net_amount),
+        (500, 100, 1900)
+    );
+
+    let events = env.events().all();
+    let released: Vec<soroban_sdk::Val> =
+        (symbol_short!("escrow"), Symbol::new(&env, "ms_released")).into_val(&env);
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| *topics == released)
+        .unwrap();
+    let payload: MsReleasedPayload = data.into_val(&env);
+    assert_eq!(payload, (job_id, 1, client, freelancer, 2000, 500, 100, 1900));
+}
+
+#[test]
+fn test_open_posting_converts_accepted_application_into_job() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, user, _, token, _) = setup_test(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let proposal = BytesN::from_array(&env, &[7; 32]);
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 100_i128, 500_000_u64),
+        (String::from_str(&env, "Build"), 300_i128, 600_000_u64),
+    ];
+    let posting_id = client.post_job(&user, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(client.get_posting(&posting_id).unwrap().budget, 400);
+
+    client.apply_to_posting(&posting_id, &alice, &400, &proposal);
+    client.apply_to_posting(&posting_id, &bob, &200, &proposal);
+    assert_eq!(
+        client.try_apply_to_posting(&posting_id, &bob, &150, &proposal),
+        Err(Ok(postings::PostingError::AlreadyApplied))
+    );
+    assert_eq!(
+        client.try_apply_to_posting(&posting_id, &user, &150, &proposal),
+        Err(Ok(postings::PostingError::Unauthorized))
+    );
+    assert_eq!(client.get_applications(&posting_id).len(), 2);
+
+    assert_eq!(
+        client.try_accept_application(&posting_id, &alice, &bob),
+        Err(Ok(postings::PostingError::Unauthorized))
+    );
+    let job_id = client.accept_application(&posting_id, &user, &bob);
+
+    // The accepted bid replaces the budget, split in the posted proportions.
+    let job = client.get_job(&job_id);
+    assert_eq!(job.freelancer, bob);
+    assert_eq!(job.total_amount, 200);
+    assert_eq!(job.milestones.get(0).unwrap().amount, 50);
+    assert_eq!(job.milestones.get(1).unwrap().amount, 150);
+    assert_eq!(job.status, JobStatus::Created);
+    assert_eq!(
+        client.get_posting(&posting_id).unwrap().status,
+        postings::PostingStatus::Filled(job_id)
+    );
+    client.fund_job(&job_id, &user, &0, &0);
+    assert_eq!(client.get_job(&job_id).status, JobStatus::Funded);
+
+    assert_eq!(
+        client.try_apply_to_posting(&posting_id, &Address::generate(&env), &100, &proposal),
+        Err(Ok(postings::PostingError::PostingClosed))
+    );
+    assert_eq!(
+        client.try_accept_application(&posting_id, &user, &alice),
+        Err(Ok(postings::PostingError::PostingClosed))
+    );
+
+    // A withdrawn posting takes no more applications.
+    let other = client.post_job(&user, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    client.close_posting(&other, &user);
+    assert_eq!(
+        client.try_apply_to_posting(&other, &alice, &100, &proposal),
+        Err(Ok(postings::PostingError::PostingClosed))
+    );
+}
+
+#[contract]
+pub struct MockAttester;
+
+#[contractimpl]
+impl MockAttester {
+    pub fn set_attested(env: Env, subject: Address, attested: bool) {
+        env.storage().instance().set(&subject, &attested);
+    }
+
+    pub fn is_attested(env: Env, subject: Address) -> bool {
+        env.storage().instance().get(&subject).unwrap_or(false)
+    }
+}
+
+fn setup_attestation_gate(
+    env: &Env,
+    contract: &EscrowContractClient<'_>,
+    admin: &Address,
+    token: &Address,
+) -> MockAttesterClient<'static> {
+    let attester = MockAttesterClient::new(env, &env.register_contract(None, MockAttester));
+    let proposal_id = contract.propose_admin_action(
+        admin,
+        &AdminAction::SetAttestationContract(Some(attester.address.clone())),
+    );
+    // Swapping the attester is time-locked like a treasury change.
+    assert_eq!(contract.get_attestation_contract(), None);
+    env.ledger().with_mut(|l| l.timestamp += 172800 + 1);
+    contract.execute_proposal(admin, &proposal_id);
+    contract.set_attestation_threshold(admin, token, &1000);
+    attester
+}
+
+#[test]
+fn test_attestation_gate_applies_from_threshold() {
+    use attestation::AttestationError;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    assert_eq!(contract.get_attestation_contract(), None);
+    assert_eq!(
+        contract.try_set_attestation_threshold(&Address::generate(&env), &token, &1000),
+        Err(Ok(AttestationError::NotAdmin))
+    );
+    assert_eq!(
+        contract.try_set_attestation_threshold(&admin, &token, &-1),
+        Err(Ok(AttestationError::InvalidThreshold))
+    );
+
+    let attester = setup_attestation_gate(&env, &contract, &admin, &token);
+    assert_eq!(contract.get_attestation_contract(), Some(attester.address.clone()));
+    assert_eq!(contract.get_attestation_threshold(&token), 1000);
+
+    // Below the threshold no attestation is needed.
+    let small = vec![&env, (String::from_str(&env, "Small"), 999_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &small, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    attester.set_attested(&client, &true);
+    attester.set_attested(&freelancer, &true);
+    let large = vec![&env, (String::from_str(&env, "Large"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &large, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Funded);
+
+    // Removing the contract turns the gate off.
+    attester.set_attested(&freelancer, &false);
+    let proposal_id = contract.propose_admin_action(&admin, &AdminAction::SetAttestationContract(None));
+    env.ledger().with_mut(|l| l.timestamp += 172800 + 1);
+    contract.execute_proposal(&admin, &proposal_id);
+    contract.create_job(&client, &freelancer, &token, &large, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1902)")] // AttestationRequired
+fn test_create_high_value_job_without_attestation_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let attester = setup_attestation_gate(&env, &contract, &admin, &token);
+    attester.set_attested(&client, &true);
+
+    let milestones = vec![&env, (String::from_str(&env, "Large"), 1000_i128, JOB_DEADLINE)];
+    contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1902)")] // AttestationRequired
+fn test_fund_job_after_attestation_revoked_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let attester = setup_attestation_gate(&env, &contract, &admin, &token);
+    attester.set_attested(&client, &true);
+    attester.set_attested(&freelancer, &true);
+
+    let milestones = vec![&env, (String::from_str(&env, "Large"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    attester.set_attested(&client, &false);
+    contract.fund_job(&job_id, &client, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1902)")] // AttestationRequired
+fn test_revision_past_attestation_threshold_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    setup_attestation_gate(&env, &contract, &admin, &token);
+
+    // Created below the threshold, the job cannot be revised above it unattested.
+    let milestones = vec![&env, (String::from_str(&env, "Small"), 500_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    let new_milestones = vec![
+        &env,
+        Milestone {
+            id: 0,
+            description: String::from_str(&env, "Large"),
+            amount: 1000,
+            status: MilestoneStatus::Pending,
+            deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
+        },
+    ];
+    contract.propose_revision(&client, &job_id, &new_milestones);
+    contract.accept_revision(&freelancer, &job_id);
+}
+
+#[test]
+fn test_get_jobs_by_status_follows_transitions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 100_i128, JOB_DEADLINE),
+    ];
+    let create = || {
+        contract.create_job(
+            &client,
+            &freelancer,
+            &token,
+            &milestones,
+            &JOB_DEADLINE,
+            &GRACE_PERIOD,
+            &DEFAULT_EXPIRY_LEDGER,
+        )
+    };
+    let first = create();
+    let second = create();
+    let third = create();
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::Created, &0, &10),
+        vec![&env, first, second, third]
+    );
+
+    contract.fund_job(&first, &client, &0, &0);
+    contract.fund_job(&second, &client, &0, &0);
+    contract.submit_milestone(&second, &0, &freelancer);
+    contract.fund_job(&third, &client, &0, &0);
+    contract.cancel_job(&third, &client, &0);
+
+    assert!(contract
+        .get_jobs_by_status(&JobStatus::Created, &0, &10)
+        .is_empty());
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::Funded, &0, &10),
+        vec![&env, first]
+    );
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::InProgress, &0, &10),
+        vec![&env, second]
+    );
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::Cancelled, &0, &10),
+        vec![&env, third]
+    );
+}
+
+#[test]
+fn test_get_jobs_by_status_pages_across_index_pages() {
+    use status_index::STATUS_PAGE_CAPACITY;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    StellarAssetClient::new(&env, &token).mint(&client, &1_000_000);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Work"), 10_i128, JOB_DEADLINE),
+    ];
+    let mut ids = Vec::new(&env);
+    for _ in 0..STATUS_PAGE_CAPACITY + 5 {
+        ids.push_back(contract.create_job(
+            &client,
+            &freelancer,
+            &token,
+            &milestones,
+            &JOB_DEADLINE,
+            &GRACE_PERIOD,
+            &DEFAULT_EXPIRY_LEDGER,
+        ));
+    }
+
+    // The page boundary is invisible to callers.
+    let last = STATUS_PAGE_CAPACITY - 2;
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::Created, &last, &4),
+        ids.slice(last..last + 4)
+    );
+
+    // Emptying the first page leaves the view starting at the second.
+    for i in 0..STATUS_PAGE_CAPACITY {
+        contract.fund_job(&ids.get(i).unwrap(), &client, &0, &0);
+    }
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::Created, &0, &10),
+        ids.slice(STATUS_PAGE_CAPACITY..STATUS_PAGE_CAPACITY + 5)
+    );
+    assert_eq!(
+        contract.get_jobs_by_status(&JobStatus::Funded, &0, &3),
+        ids.slice(0..3)
+    );
+}
+
+#[test]
+fn test_finalize_overdue_milestone_after_review_period() {
+    use review_window::ReviewError;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 600_i128, JOB_DEADLINE - 10),
+        (String::from_str(&env, "Build"), 400_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    // Every job starts with the default window.
+    assert_eq!(contract.get_review_period(&job_id), review_window::DEFAULT_REVIEW_PERIOD_SECS);
+    let period = 2 * 24 * 3600;
+    assert_eq!(
+        contract.try_set_review_period(&job_id, &client, &3600),
+        Err(Ok(ReviewError::InvalidPeriod))
+    );
+    assert_eq!(
+        contract.try_set_review_period(&job_id, &freelancer, &period),
+        Err(Ok(ReviewError::Unauthorized))
+    );
+    contract.set_review_period(&job_id, &client, &period);
+    assert_eq!(contract.get_review_period(&job_id), period);
+
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(
+        contract.try_set_review_period(&job_id, &client, &0),
+        Err(Ok(ReviewError::InvalidStatus))
+    );
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    assert_eq!(
+        contract.try_finalize_overdue_milestone(&job_id, &0),
+        Err(Ok(ReviewError::WindowOpen))
+    );
+    assert_eq!(
+        contract.try_finalize_overdue_milestone(&job_id, &1),
+        Err(Ok(ReviewError::InvalidStatus))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += period);
+    contract.finalize_overdue_milestone(&job_id, &0);
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.milestones.get(0).unwrap().status, MilestoneStatus::Approved);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 600);
+    assert_eq!(
+        contract.try_finalize_overdue_milestone(&job_id, &0),
+        Err(Ok(ReviewError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_default_review_period_applies_without_client_opt_in() {
+    use review_window::{ReviewError, DEFAULT_REVIEW_PERIOD_SECS};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Work"), 500_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+
+    // Zero cannot switch the window off, only restore the default.
+    contract.set_review_period(&job_id, &client, &(2 * 24 * 3600));
+    contract.set_review_period(&job_id, &client, &0);
+    assert_eq!(contract.get_review_period(&job_id), DEFAULT_REVIEW_PERIOD_SECS);
+
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    env.ledger().with_mut(|l| l.timestamp += DEFAULT_REVIEW_PERIOD_SECS - 1);
+    assert_eq!(
+        contract.try_finalize_overdue_milestone(&job_id, &0),
+        Err(Ok(ReviewError::WindowOpen))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    contract.finalize_overdue_milestone(&job_id, &0);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 500);
+}
+
+#[test]
+fn test_payout_conversion_swaps_and_falls_back() {
+    use conversion::ConversionError;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    setup_oracle(&env, &contract, &admin, PRICE_SCALE, 10);
+    let preferred = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let adapter = env.register_contract(None, MockSwapAdapter);
+    // Enough liquidity for the first payout only.
+    StellarAssetClient::new(&env, &preferred).mint(&adapter, &594);
+
+    assert_eq!(
+        contract.try_set_payout_conversion(&freelancer, &preferred, &adapter, &100),
+        Err(Ok(ConversionError::AdapterNotAllowed))
+    );
+    contract.set_swap_adapter(&admin, &adapter, &true);
+    assert_eq!(
+        contract.try_set_payout_conversion(&freelancer, &preferred, &adapter, &5_000),
+        Err(Ok(ConversionError::InvalidSlippage))
+    );
+    contract.set_payout_conversion(&freelancer, &preferred, &adapter, &100);
+    assert_eq!(contract.get_payout_conversion(&freelancer).unwrap().max_slippage_bps, 100);
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 600_i128, JOB_DEADLINE - 10),
+        (String::from_str(&env, "Build"), 400_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    // At par with 1% slippage, 600 converts into at least 594.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(TokenClient::new(&env, &preferred).balance(&freelancer), 594);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 0);
+    assert_eq!(TokenClient::new(&env, &token).balance(&adapter), 600);
+
+    // The adapter is out of liquidity: the swap is undone and 400 is paid as is.
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &1);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 400);
+    assert_eq!(TokenClient::new(&env, &token).balance(&adapter), 600);
+    let fail_topics: Vec<soroban_sdk::Val> = (symbol_short!("escrow"), symbol_short!("conv_fail")).into_val(&env);
+    assert!(env.events().all().iter().any(|(_, topics, _)| topics == fail_topics));
+
+    contract.clear_payout_conversion(&freelancer);
+    assert_eq!(contract.get_payout_conversion(&freelancer), None);
+    assert_eq!(
+        contract.try_clear_payout_conversion(&freelancer),
+        Err(Ok(ConversionError::NoConversion))
+    );
+}
+
+// In a module of its own: `#[contractimpl]` names its exports after the
+// functions, which would clash with `MockSwapAdapter::swap_from`.
+mod short_swap {
+    use soroban_sdk::{contract, contractimpl, token::TokenClient, Address, Env};
+
+    #[contract]
+    pub struct MockShortSwapAdapter;
+
+    #[contractimpl]
+    impl MockShortSwapAdapter {
+        pub fn swap_from(env: Env, token_in: Address, token_out: Address, amount_in: i128, min_amount_out: i128, from: Address, to: Address) -> i128 {
+            let adapter = env.current_contract_address();
+            TokenClient::new(&env, &token_in).transfer_from(&adapter, &from, &adapter, &amount_in);
+            TokenClient::new(&env, &token_out).transfer(&adapter, &to, &(min_amount_out - 1));
+            min_amount_out - 1
+        }
+    }
+}
+
+#[test]
+fn test_payout_conversion_short_swap_does_not_block_payout() {
+    use conversion::ConversionError;
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    setup_oracle(&env, &contract, &admin, PRICE_SCALE, 10);
+    let preferred_sac = env.register_stellar_asset_contract_v2(admin.clone());
+    preferred_sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let preferred = preferred_sac.address();
+    let preferred_admin = StellarAssetClient::new(&env, &preferred);
+    let adapter = env.register_contract(None, short_swap::MockShortSwapAdapter);
+    preferred_admin.mint(&adapter, &1_000);
+    contract.set_swap_adapter(&admin, &adapter, &true);
+    contract.set_payout_conversion(&freelancer, &preferred, &adapter, &100);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 600_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+
+    // The adapter returns one unit below the floor to a frozen freelancer: the
+    // release still goes through and what came back is owed to them.
+    preferred_admin.set_authorized(&freelancer, &false);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(TokenClient::new(&env, &token).balance(&adapter), 600);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 0);
+    assert_eq!(contract.get_owed(&freelancer, &preferred), 593);
+    let fail_topics: Vec<soroban_sdk::Val> = (symbol_short!("escrow"), symbol_short!("conv_fail")).into_val(&env);
+    let fail = env.events().all().iter().find(|(_, topics, _)| *topics == fail_topics).unwrap();
+    let (_, _, _, code): (u64, i128, Address, u32) = soroban_sdk::TryFromVal::try_from_val(&env, &fail.2).unwrap();
+    assert_eq!(code, ConversionError::InsufficientOutput as u32);
+    assert_eq!(contract.get_payout_conversion(&freelancer), None);
+    assert!(!contract.get_swap_adapters().contains(&adapter));
+}
+
+#[test]
+fn test_payout_conversion_short_swap_happens_at_most_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    setup_oracle(&env, &contract, &admin, PRICE_SCALE, 10);
+    let preferred = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let adapter = env.register_contract(None, short_swap::MockShortSwapAdapter);
+    StellarAssetClient::new(&env, &preferred).mint(&adapter, &10_000);
+    contract.set_swap_adapter(&admin, &adapter, &true);
+    contract.set_payout_conversion(&freelancer, &preferred, &adapter, &100);
+
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Build"), 600_i128, JOB_DEADLINE / 2),
+        (String::from_str(&env, "Ship"), 600_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    // The first payout comes back one unit short of the 594 floor; the
+    // conversion is cleared and the adapter delisted on the spot.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(TokenClient::new(&env, &preferred).balance(&freelancer), 593);
+    assert_eq!(contract.get_payout_conversion(&freelancer), None);
+    assert!(contract.get_swap_adapters().is_empty());
+
+    // The next payout is a direct transfer in the job's token.
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &1);
+    assert_eq!(TokenClient::new(&env, &preferred).balance(&freelancer), 593);
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 600);
+    assert_eq!(TokenClient::new(&env, &token).balance(&adapter), 600);
+
+    // Opting back in needs the adapter to be allowlisted again.
+    assert_eq!(
+        contract.try_set_payout_conversion(&freelancer, &preferred, &adapter, &100),
+        Err(Ok(conversion::ConversionError::AdapterNotAllowed))
+    );
+}
+
+#[test]
+fn test_approve_milestone_partial_tracks_released_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    assert_eq!(
+        contract.try_approve_milestone_partial(&job_id, &0, &3_000, &client),
+        Err(Ok(EscrowError::InvalidStatus))
+    );
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    assert_eq!(
+        contract.try_approve_milestone_partial(&job_id, &0, &10_001, &client),
+        Err(Ok(EscrowError::InvalidPartialAmount))
+    );
+    assert_eq!(
+        contract.try_approve_milestone_partial(&job_id, &0, &3_000, &freelancer),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+
+    contract.approve_milestone_partial(&job_id, &0, &3_000, &client);
+    let milestone = contract.get_job(&job_id).milestones.get(0).unwrap();
+    assert_eq!((milestone.amount, milestone.released, milestone.status), (1000, 300, MilestoneStatus::PartiallyPaid));
+
+    // Percentages are of the full milestone, not of what is left.
+    contract.approve_milestone_partial(&job_id, &0, &5_000, &client);
+    let milestone = contract.get_job(&job_id).milestones.get(0).unwrap();
+    assert_eq!((milestone.amount, milestone.released), (1000, 800));
+    assert_eq!(
+        contract.try_approve_milestone_partial(&job_id, &0, &5_000, &client),
+        Err(Ok(EscrowError::InvalidPartialAmount))
+    );
+
+    contract.approve_milestone_partial(&job_id, &0, &2_000, &client);
+    let milestone = contract.get_job(&job_id).milestones.get(0).unwrap();
+    assert_eq!((milestone.amount, milestone.released, milestone.status), (1000, 1000, MilestoneStatus::Approved));
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 1000);
+}
+
+#[test]
+fn test_cancel_after_partial_release_refunds_only_unpaid_value() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    let milestones = vec![
+        &env,
+        (String::from_str(&env, "Design"), 1000_i128, JOB_DEADLINE / 3),
+        (String::from_str(&env, "Build"), 600_i128, JOB_DEADLINE / 2),
+        (String::from_str(&env, "Ship"), 400_i128, JOB_DEADLINE),
+    ];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.approve_milestone_partial(&job_id, &0, &3_000, &client);
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &0);
+    assert_eq!(token_client.balance(&freelancer), 900);
+
+    // Only the unpaid 700 of "Design" and the untouched "Ship" go back.
+    contract.cancel_job(&job_id, &client, &0);
+    assert_eq!(token_client.balance(&client), 10000 - 900);
+    assert_eq!(token_client.balance(&freelancer), 900);
+    assert_eq!(token_client.balance(&contract.address), 0);
+}
+
+#[test]
+fn test_hourly_job_pays_approved_work_logs_and_refunds_unspent_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+    let treasury = contract.get_treasury().unwrap();
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    let job_id = contract.create_hourly_job(&client, &freelancer, &token, &120, &1000, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(contract.get_job_kind(&job_id), hourly::JobKind::Hourly);
+    assert_eq!(contract.get_hourly_rate(&job_id), Some(120));
+    assert_eq!(
+        contract.try_submit_work_log(&job_id, &freelancer, &60, &hash),
+        Err(Ok(hourly::HourlyError::InvalidStatus))
+    );
+    contract.fund_job(&job_id, &client, &0, &0);
+
+    // 4h at 120/h, then 5h more would overrun the 1000 budget.
+    let first = contract.submit_work_log(&job_id, &freelancer, &240, &hash);
+    assert_eq!(contract.get_work_log(&job_id, &first).unwrap().amount, 480);
+    assert_eq!(
+        contract.try_submit_work_log(&job_id, &freelancer, &300, &hash),
+        Err(Ok(hourly::HourlyError::BudgetExceeded))
+    );
+    let second = contract.submit_work_log(&job_id, &freelancer, &150, &hash);
+    let third = contract.submit_work_log(&job_id, &freelancer, &30, &hash);
+    assert_eq!(contract.get_work_log_count(&job_id), 3);
+
+    // Approved logs are paid at once, net of the 5% fee.
+    contract.approve_work_log(&job_id, &first, &client);
+    assert_eq!(token_client.balance(&freelancer), 456);
+    assert_eq!(token_client.balance(&treasury), 24);
+    assert_eq!(
+        contract.try_approve_work_log(&job_id, &first, &client),
+        Err(Ok(hourly::HourlyError::LogNotPending))
+    );
+    contract.reject_work_log(&job_id, &second, &client);
+    assert_eq!(
+        contract.get_work_log(&job_id, &second).unwrap().status,
+        hourly::WorkLogStatus::Rejected
+    );
+
+    // Unreviewed logs can be finalized by anyone once the window passes.
+    assert_eq!(
+        contract.try_close_hourly_job(&job_id, &freelancer),
+        Err(Ok(hourly::HourlyError::LogsPending))
+    );
+    assert_eq!(
+        contract.try_finalize_work_log(&job_id, &third),
+        Err(Ok(hourly::HourlyError::ReviewWindowOpen))
+    );
+    env.ledger().with_mut(|l| l.timestamp += hourly::WORK_LOG_REVIEW_SECS);
+    contract.finalize_work_log(&job_id, &third);
+    assert_eq!(token_client.balance(&freelancer), 456 + 57);
+
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.status, JobStatus::InProgress);
+    assert_eq!(job.milestones.get(0).unwrap().released, 540);
+
+    // Closing refunds what was never billed and completes the job.
+    contract.close_hourly_job(&job_id, &client);
+    assert_eq!(token_client.balance(&client), 10000 - 540);
+    assert_eq!(token_client.balance(&contract.address), 0);
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.status, JobStatus::Completed);
+    assert_eq!(job.milestones.get(0).unwrap().status, MilestoneStatus::Approved);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2605)")]
+fn test_hourly_job_rejects_milestone_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+
+    let job_id = contract.create_hourly_job(&client, &freelancer, &token, &120, &1000, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_milestone(&job_id, &0, &freelancer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2605)")]
+fn test_hourly_job_rejects_budget_revision() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+
+    let job_id = contract.create_hourly_job(&client, &freelancer, &token, &120, &1000, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.fund_job(&job_id, &client, &0, &0);
+    contract.submit_work_log(&job_id, &freelancer, &240, &BytesN::from_array(&env, &[7u8; 32]));
+    let shrunk = vec![
+        &env,
+        Milestone {
+            id: 0,
+            description: String::from_str(&env, "Hourly budget"),
+            amount: 100,
+            status: MilestoneStatus::Pending,
+            deadline: JOB_DEADLINE,
+            token: None,
+            released: 0,
+        },
+    ];
+    contract.propose_revision(&client, &job_id, &shrunk);
+}
+
+#[test]
+fn test_recurring_job_renews_from_client_allowance_until_terminated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    let period = 30 * 24 * 3600;
+
+    let milestones = vec![&env, (String::from_str(&env, "Monthly retainer"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    assert_eq!(
+        contract.try_set_recurring(&job_id, &client, &3600),
+        Err(Ok(recurring::RecurringError::InvalidPeriod))
+    );
+    contract.set_recurring(&job_id, &client, &period);
+    contract.fund_job(&job_id, &client, &0, &0);
+    let funded_at = contract.get_job(&job_id).funded_at.unwrap();
+    assert_eq!(contract.get_next_renewal_at(&job_id), Some(funded_at + period));
+    assert_eq!(
+        contract.try_renew(&job_id),
+        Err(Ok(recurring::RecurringError::NotDue))
+    );
+
+    // Renewal draws on the client's allowance to the escrow.
+    env.ledger().with_mut(|l| l.timestamp = funded_at + period);
+    assert_eq!(
+        contract.try_renew(&job_id),
+        Err(Ok(recurring::RecurringError::NotFunded))
+    );
+    token_client.approve(&client, &contract.address, &5000, &(env.ledger().sequence() + 1000));
+    assert_eq!(contract.renew(&job_id), 1);
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.total_amount, 2000);
+    assert_eq!(job.funded_amount, 2000);
+    let renewed = job.milestones.get(1).unwrap();
+    assert_eq!(renewed.amount, 1000);
+    assert_eq!(renewed.status, MilestoneStatus::Pending);
+    assert_eq!(renewed.deadline, funded_at + 2 * period);
+    assert!(job.job_deadline >= renewed.deadline);
+    assert_eq!(token_client.balance(&client), 10000 - 2000);
+    assert_eq!(contract.get_recurring_terms(&job_id).unwrap().renewals, 1);
+
+    // Either party can end it; later periods no longer renew.
+    assert_eq!(
+        contract.try_terminate_recurring(&job_id, &Address::generate(&env)),
+        Err(Ok(recurring::RecurringError::Unauthorized))
+    );
+    contract.terminate_recurring(&job_id, &freelancer);
+    assert_eq!(contract.get_next_renewal_at(&job_id), None);
+    env.ledger().with_mut(|l| l.timestamp = funded_at + 2 * period);
+    assert_eq!(
+        contract.try_renew(&job_id),
+        Err(Ok(recurring::RecurringError::Terminated))
+    );
+    assert_eq!(contract.get_job(&job_id).milestones.len(), 2);
+}
+
+#[test]
+fn test_recurring_renewal_skips_lapsed_periods() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    let period = 30 * 24 * 3600;
+
+    let milestones = vec![&env, (String::from_str(&env, "Monthly retainer"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.set_recurring(&job_id, &client, &period);
+    contract.fund_job(&job_id, &client, &0, &0);
+    let funded_at = contract.get_job(&job_id).funded_at.unwrap();
+    token_client.approve(&client, &contract.address, &5000, &(env.ledger().sequence() + 1000));
+
+    // Nobody renews for three periods: only the current one is billed.
+    env.ledger().with_mut(|l| l.timestamp = funded_at + 3 * period + 10);
+    assert_eq!(contract.renew(&job_id), 1);
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.milestones.len(), 2);
+    assert_eq!(job.milestones.get(1).unwrap().deadline, funded_at + 4 * period);
+    assert_eq!(token_client.balance(&client), 10000 - 2000);
+    assert_eq!(contract.get_recurring_terms(&job_id).unwrap().renewals, 3);
+    assert_eq!(contract.get_next_renewal_at(&job_id), Some(funded_at + 4 * period));
+    assert_eq!(
+        contract.try_renew(&job_id),
+        Err(Ok(recurring::RecurringError::NotDue))
+    );
+}
+
+#[test]
+fn test_recurring_job_renews_after_every_milestone_is_released() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    let period = 30 * 24 * 3600;
+
+    let milestones = vec![&env, (String::from_str(&env, "Monthly retainer"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    contract.set_recurring(&job_id, &client, &period);
+    contract.fund_job(&job_id, &client, &0, &0);
+    let funded_at = contract.get_job(&job_id).funded_at.unwrap();
+    token_client.approve(&client, &contract.address, &5000, &(env.ledger().sequence() + 1000));
+
+    // Releasing the only milestone leaves the retainer open.
+    contract.submit_milestone(&job_id, &0, &freelancer);
+    contract.release_milestone(&job_id, &0, &client, &0, &0);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::InProgress);
+
+    env.ledger().with_mut(|l| l.timestamp = funded_at + period);
+    assert_eq!(contract.renew(&job_id), 1);
+    assert_eq!(contract.get_jobs_with_milestones_due(&period, &0, &10), vec![&env, job_id]);
+    let latest = contract.get_recent_changes(&1).get(0).unwrap();
+    assert_eq!((latest.job_id, latest.kind), (job_id, changes::ChangeKind::Renewed));
+
+    // Once terminated, releasing the last milestone completes the job.
+    contract.submit_milestone(&job_id, &1, &freelancer);
+    contract.release_milestone(&job_id, &1, &client, &0, &1);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::InProgress);
+    contract.terminate_recurring(&job_id, &client);
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Completed);
+    assert_eq!(token_client.balance(&freelancer), 2000);
+    assert_eq!(token_client.balance(&contract.address), 0);
+}
+
+#[test]
+fn test_relayer_funds_job_under_client_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+    let relayer = Address::generate(&env);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let expiry = env.ledger().sequence() + 100;
+    assert_eq!(
+        contract.try_prefund_authorization(&freelancer, &job_id, &1200, &expiry),
+        Err(Ok(funding_auth::FundingAuthError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_prefund_authorization(&client, &job_id, &1200, &env.ledger().sequence()),
+        Err(Ok(funding_auth::FundingAuthError::InvalidExpiry))
+    );
+    token_client.approve(&client, &contract.address, &500, &expiry);
+    contract.prefund_authorization(&client, &job_id, &1200, &expiry);
+    assert_eq!(
+        contract.get_funding_authorization(&job_id),
+        Some(funding_auth::FundingAuthorization { amount: 1200, expiry })
+    );
+    assert_eq!(
+        contract.try_prefund_authorization(&client, &job_id, &1200, &expiry),
+        Err(Ok(funding_auth::FundingAuthError::AlreadyAuthorized))
+    );
+    // The authorized amount is held by the escrow; the client's allowance is untouched.
+    assert_eq!(token_client.balance(&client), 10000 - 1200);
+    assert_eq!(token_client.allowance(&client, &contract.address), 500);
+
+    // The relayer alone submits the funding; the surplus goes back to the client.
+    contract.fund_job_with_authorization(&job_id, &relayer);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, relayer);
+    let job = contract.get_job(&job_id);
+    assert_eq!(job.status, JobStatus::Funded);
+    assert_eq!(job.funded_amount, 1000);
+    assert_eq!(token_client.balance(&client), 10000 - 1000);
+    assert_eq!(token_client.allowance(&client, &contract.address), 500);
+    assert_eq!(contract.get_funding_authorization(&job_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2806)")]
+fn test_relayed_funding_rejected_after_authorization_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, _admin) = setup_test(&env);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let expiry = env.ledger().sequence() + 10;
+    contract.prefund_authorization(&client, &job_id, &1000, &expiry);
+    env.ledger().with_mut(|l| l.sequence_number = expiry + 1);
+    contract.fund_job_with_authorization(&job_id, &Address::generate(&env));
+}
+
+#[test]
+fn test_relayed_funding_bound_to_authorized_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, client, freelancer, token, admin) = setup_test(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    let milestones = vec![&env, (String::from_str(&env, "Build"), 1000_i128, JOB_DEADLINE)];
+    let job_id = contract.create_job(&client, &freelancer, &token, &milestones, &JOB_DEADLINE, &GRACE_PERIOD, &DEFAULT_EXPIRY_LEDGER);
+    let expiry = env.ledger().sequence() + 100;
+    contract.prefund_authorization(&client, &job_id, &1000, &expiry);
+
+    // A client-paid fee pushes the cost past what the client authorized.
+    contract.propose_admin_action(&admin, &AdminAction::SetFeeBps(500));
+    contract.set_fee_payer(&job_id, &client, &fees::FeePayer::Client);
+    assert_eq!(
+        contract.try_fund_job_with_authorization(&job_id, &Address::generate(&env)),
+        Err(Err(soroban_sdk::InvokeError::Contract(
+            funding_auth::FundingAuthError::AmountExceeded as u32
+        )))
+    );
+    assert_eq!(contract.get_job(&job_id).status, JobStatus::Created);
+
+    contract.revoke_funding_authorization(&client, &job_id);
+    assert_eq!(token_client.balance(&client), 10000);
+    assert_eq!(contract.get_funding_authorization(&job_id), None);
+}
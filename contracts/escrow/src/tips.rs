@@ -0,0 +1,136 @@
+//! Client bonuses outside milestone accounting.
+//!
+//! A client who wants to reward work that exceeded expectations can send the
+//! freelancer an extra amount with [`EscrowContract::send_bonus`]. The bonus
+//! is pulled from the client in the job's token and paid out through escrow
+//! straight away, so it is charged the job's fee the way a release is, split
+//! by the job's fee payer, follows the freelancer's payout address and
+//! conversion settings, and is recorded as owed if the transfer fails, like
+//! any milestone payout. It never touches
+//! `Job::total_amount`, `Job::funded_amount` or the milestones; the running
+//! total sent on a job is kept alongside it.
+
+use soroban_sdk::{contracterror, contractimpl, contracttype, symbol_short, token, Address, Env};
+
+use crate::pause::PauseScope;
+use crate::{EscrowContract, Job, JobStatus};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Bonus payment errors.
+///
+/// Kept in a dedicated enum for the same reason as `GovError`: `EscrowError` is
+/// at the SDK's 50-case cap. Codes start at 2500, clear of `ExternalIdError`
+/// (2400..).
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TipError {
+    /// The contract, or its payouts, are paused.
+    ContractPaused = 2500,
+    /// No job exists with the given ID.
+    JobNotFound = 2501,
+    /// Caller is not the job's client.
+    Unauthorized = 2502,
+    /// The job is not funded, in progress or completed.
+    InvalidStatus = 2503,
+    /// Amount must be strictly positive.
+    InvalidAmount = 2504,
+}
+
+/// Storage keys for bonuses.
+#[contracttype]
+#[derive(Clone)]
+pub enum TipKey {
+    /// Gross bonuses the client has sent on a job: job_id → `i128`.
+    Total(u64),
+}
+
+fn bonus_total(env: &Env, job_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TipKey::Total(job_id))
+        .unwrap_or(0)
+}
+
+/// Drop the bonus total of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage().persistent().remove(&TipKey::Total(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// Send the freelancer a bonus of `amount` in the job's token. Client
+    /// only, once the job is funded. The fee is charged as on a release: the
+    /// client's share of it is pulled on top of the bonus, the freelancer's
+    /// share is deducted and the rest goes to the freelancer's payout address.
+    pub fn send_bonus(env: Env, job_id: u64, amount: i128, client: Address) -> Result<(), TipError> {
+        crate::pause::require_scope_open(&env, PauseScope::Payouts)
+            .map_err(|_| TipError::ContractPaused)?;
+        client.require_auth();
+        if amount <= 0 {
+            return Err(TipError::InvalidAmount);
+        }
+        let job: Job = env
+            .storage()
+            .persistent()
+            .get(&crate::DataKey::Job(job_id))
+            .ok_or(TipError::JobNotFound)?;
+        if job.client != client {
+            return Err(TipError::Unauthorized);
+        }
+        if !matches!(
+            job.status,
+            JobStatus::Funded | JobStatus::InProgress | JobStatus::Completed
+        ) {
+            return Err(TipError::InvalidStatus);
+        }
+
+        // Booked like added milestone value, so a job whose client pays the
+        // fee has the client's share on the ledger for `release_fee` to draw.
+        let client_fee = crate::fees::accrue_added_value(&env, job_id, amount);
+        let token_client = token::Client::new(&env, &job.token);
+        token_client.transfer(
+            &client,
+            &env.current_contract_address(),
+            &(amount + client_fee),
+        );
+        let (fee, deducted) = crate::fees::release_fee(
+            &env,
+            job_id,
+            amount,
+            crate::fees::job_fee_bps(&env, job_id),
+        );
+        if fee > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &crate::fees::treasury(&env),
+                &fee,
+            );
+        }
+        let net = amount - deducted;
+        crate::owed::pay_freelancer(&env, &job, net);
+
+        let key = TipKey::Total(job_id);
+        let total = bonus_total(&env, job_id) + amount;
+        env.storage().persistent().set(&key, &total);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events().publish(
+            (symbol_short!("escrow"), symbol_short!("bonus")),
+            (job_id, client, amount, fee, net),
+        );
+        Ok(())
+    }
+
+    /// Gross bonuses the client has sent on a job, before fees.
+    pub fn get_bonus_total(env: Env, job_id: u64) -> i128 {
+        bonus_total(&env, job_id)
+    }
+}
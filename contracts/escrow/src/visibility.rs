@@ -0,0 +1,85 @@
+//! Private jobs.
+//!
+//! Some engagements should not be enumerable through the contract's public
+//! index views. A job created with [`EscrowContract::create_private_job`] is
+//! left out of [`EscrowContract::get_jobs_with_milestones_due`] and
+//! [`EscrowContract::get_jobs_between`]; everything else about it is
+//! unchanged. Reads by ID stay open, since the parties, the admin and the
+//! dispute and reputation contracts all look jobs up that way — the flag
+//! hides a job from discovery, not from someone who already knows it.
+//! The flag lives in a side entry, so the `Job` layout and plain `create_job`
+//! are unchanged.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Vec};
+
+use crate::{EscrowContract, EscrowError};
+// The escrow's `#[contractimpl]` in lib.rs generates `EscrowContractClient`; the
+// second `#[contractimpl]` block below needs that type in scope.
+#[allow(unused_imports)]
+use crate::EscrowContractClient;
+
+/// Storage keys for job visibility.
+#[contracttype]
+#[derive(Clone)]
+pub enum VisibilityKey {
+    /// Present for jobs hidden from the public index views.
+    Private(u64),
+}
+
+/// Whether `job_id` is hidden from the public index views.
+pub(crate) fn is_private(env: &Env, job_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&VisibilityKey::Private(job_id))
+}
+
+/// Drop the visibility flag of an archived job.
+pub(crate) fn remove(env: &Env, job_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&VisibilityKey::Private(job_id));
+}
+
+#[contractimpl]
+impl EscrowContract {
+    /// [`EscrowContract::create_job`] for a job kept out of the public index
+    /// views.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_private_job(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        token: Address,
+        milestones: Vec<(String, i128, u64)>,
+        job_deadline: u64,
+        auto_refund_after: u64,
+        expiry_ledger: u32,
+    ) -> Result<u64, EscrowError> {
+        let job_id = Self::create_job(
+            env.clone(),
+            client,
+            freelancer,
+            token,
+            milestones,
+            job_deadline,
+            auto_refund_after,
+            expiry_ledger,
+        )?;
+        let key = VisibilityKey::Private(job_id);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            crate::TTL_THRESHOLD_LEDGERS,
+            crate::TTL_EXTEND_TO_LEDGERS,
+        );
+
+        env.events()
+            .publish((symbol_short!("escrow"), symbol_short!("private")), job_id);
+        Ok(job_id)
+    }
+
+    /// Whether `job_id` is hidden from the public index views.
+    pub fn is_job_private(env: Env, job_id: u64) -> bool {
+        is_private(&env, job_id)
+    }
+}